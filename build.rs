@@ -12,10 +12,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .protoc_arg("--experimental_allow_proto3_optional")
         .compile(
             &[
+                "./proto/api_keys.proto",
                 "./proto/authentication.proto",
                 "./proto/common.proto",
                 "./proto/logins.proto",
+                "./proto/me.proto",
+                "./proto/mfa.proto",
+                "./proto/oidc_clients.proto",
+                "./proto/organisations.proto",
+                "./proto/roles.proto",
+                "./proto/service_accounts.proto",
                 "./proto/sessions.proto",
+                "./proto/signing_keys.proto",
                 "./proto/users.proto",
                 "./proto/utilities.proto",
             ],
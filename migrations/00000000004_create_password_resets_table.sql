@@ -0,0 +1,12 @@
+-- ./migrations/00000000004_create_password_resets_table.sql
+-- Create Password Resets table
+CREATE TABLE IF NOT EXISTS password_resets (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- ./migrations/00000000005_create_email_verifications_table.sql
+-- Create Email Verifications table
+CREATE TABLE IF NOT EXISTS email_verifications (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    email TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- ./migrations/00000000006_create_totp_secrets_table.sql
+-- Create TOTP Secrets table, one per user
+CREATE TABLE IF NOT EXISTS totp_secrets (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL UNIQUE,
+    secret_encrypted BYTEA NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    last_used_step BIGINT,
+    enabled_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+-- ./migrations/00000000007_create_recovery_codes_table.sql
+-- Create MFA Recovery Codes table
+-- Used codes are kept as an audit record of when each code was consumed
+CREATE TABLE IF NOT EXISTS recovery_codes (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    code_hash TEXT NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
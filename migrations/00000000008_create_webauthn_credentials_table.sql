@@ -0,0 +1,14 @@
+-- ./migrations/00000000008_create_webauthn_credentials_table.sql
+-- Create WebAuthn Credentials (passkeys) table
+CREATE TABLE IF NOT EXISTS webauthn_credentials (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    credential_id BYTEA NOT NULL UNIQUE,
+    public_key BYTEA NOT NULL,
+    sign_count BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    last_used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
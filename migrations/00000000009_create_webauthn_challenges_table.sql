@@ -0,0 +1,14 @@
+-- ./migrations/00000000009_create_webauthn_challenges_table.sql
+-- Create WebAuthn Challenges table
+-- Passwordless login challenges are not bound to a user, so user_id is optional
+CREATE TABLE IF NOT EXISTS webauthn_challenges (
+    id UUID NOT NULL,
+    user_id UUID,
+    ceremony TEXT NOT NULL,
+    challenge_hash TEXT NOT NULL UNIQUE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
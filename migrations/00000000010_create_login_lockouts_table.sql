@@ -0,0 +1,14 @@
+-- ./migrations/00000000010_create_login_lockouts_table.sql
+-- Create Login Lockouts table
+-- Failed login attempts are counted per account (user id) and per client IP address
+CREATE TABLE IF NOT EXISTS login_lockouts (
+    id UUID NOT NULL,
+    scope TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    failed_attempts INT NOT NULL,
+    last_failed_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    locked_until TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE (scope, subject)
+);
@@ -0,0 +1,3 @@
+-- ./migrations/00000000011_add_is_success_to_logins_table.sql
+-- Add is_success to Logins table, so failed login attempts are recorded too
+ALTER TABLE logins ADD COLUMN IF NOT EXISTS is_success BOOLEAN NOT NULL DEFAULT TRUE;
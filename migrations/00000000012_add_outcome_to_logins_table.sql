@@ -0,0 +1,16 @@
+-- ./migrations/00000000012_add_outcome_to_logins_table.sql
+-- Replace is_success on the Logins table with the outcome of the login attempt,
+-- and allow attempts with an unknown email address, which have no user
+CREATE TYPE login_outcome AS ENUM (
+    'success',
+    'bad_password',
+    'unknown_email',
+    'inactive',
+    'locked',
+    'mfa_failed',
+    'invalid_token'
+);
+ALTER TABLE logins ADD COLUMN outcome login_outcome NOT NULL DEFAULT 'success';
+UPDATE logins SET outcome = 'bad_password' WHERE NOT is_success;
+ALTER TABLE logins DROP COLUMN is_success;
+ALTER TABLE logins ALTER COLUMN user_id DROP NOT NULL;
@@ -0,0 +1,5 @@
+-- ./migrations/00000000013_change_login_ip_to_inet.sql
+-- Store the Logins IP address as INET, so IPv6 addresses can be recorded.
+-- Existing IPv4 addresses were stored as the u32 value cast to a signed INT
+ALTER TABLE logins ALTER COLUMN login_ip TYPE INET
+    USING '0.0.0.0'::INET + (login_ip::BIGINT & 4294967295);
@@ -0,0 +1,15 @@
+-- ./migrations/00000000014_create_signing_keys_table.sql
+-- Create Signing Keys table, the key ring used to sign and verify tokens.
+-- Keys sign tokens from signs_from, and stop verifying tokens at retires_on
+CREATE TYPE jwt_algorithm AS ENUM ('EdDSA', 'RS256');
+CREATE TABLE IF NOT EXISTS signing_keys (
+    id UUID NOT NULL,
+    key_id TEXT NOT NULL UNIQUE,
+    algorithm jwt_algorithm NOT NULL,
+    private_key BYTEA NOT NULL,
+    public_key TEXT NOT NULL,
+    signs_from TIMESTAMP WITH TIME ZONE NOT NULL,
+    retires_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
@@ -0,0 +1,9 @@
+-- ./migrations/00000000015_add_family_id_to_sessions_table.sql
+-- Group Sessions into refresh token families, one per login on a device. Each
+-- refresh rotates the Session within its family, and reuse of a rotated
+-- Refresh Token revokes the whole family
+ALTER TABLE sessions ADD COLUMN family_id UUID;
+UPDATE sessions SET family_id = id;
+ALTER TABLE sessions ALTER COLUMN family_id SET NOT NULL;
+CREATE INDEX IF NOT EXISTS sessions_family_id_idx ON sessions (family_id);
+ALTER TYPE login_outcome ADD VALUE IF NOT EXISTS 'token_reuse';
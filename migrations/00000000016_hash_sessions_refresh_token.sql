@@ -0,0 +1,25 @@
+-- ./migrations/00000000016_hash_sessions_refresh_token.sql
+-- Store a keyed hash of the Refresh Token in the Sessions table, instead of the
+-- token itself, so a database dump cannot be used to hijack a session.
+--
+-- Existing tokens are hashed with HMAC-SHA256 keyed with the database setting
+-- authentication.refresh_token_hash_key, which must be set to the configured
+-- jwt.refresh_token_hash_key before migrating, such as with:
+--
+--   ALTER DATABASE <name> SET authentication.refresh_token_hash_key = '<key>';
+--
+-- Without the setting, existing Sessions are revoked and users log in again.
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+ALTER TABLE sessions RENAME COLUMN refresh_token TO refresh_token_hash;
+UPDATE sessions
+SET refresh_token_hash = encode(
+        hmac(
+            refresh_token_hash,
+            COALESCE(current_setting('authentication.refresh_token_hash_key', true), ''),
+            'sha256'
+        ),
+        'hex'
+    ),
+    is_active = is_active
+        AND COALESCE(current_setting('authentication.refresh_token_hash_key', true), '') <> '';
+CREATE UNIQUE INDEX IF NOT EXISTS sessions_refresh_token_hash_idx ON sessions (refresh_token_hash);
@@ -0,0 +1,15 @@
+-- Record the Access Token issued with each Session, so revoking a Session also
+-- revokes its Access Token
+ALTER TABLE sessions ADD COLUMN IF NOT EXISTS access_token_id UUID;
+
+-- Create Revoked Tokens table
+-- Access Tokens revoked before they expire, keyed by the token id (jti). Rows
+-- are deleted once the token has expired
+CREATE TABLE IF NOT EXISTS revoked_tokens (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    revoked_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS revoked_tokens_expires_on_idx ON revoked_tokens (expires_on);
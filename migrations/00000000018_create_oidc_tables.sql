@@ -0,0 +1,33 @@
+-- ./migrations/00000000018_create_oidc_tables.sql
+-- Create OIDC Clients table
+-- Registered OpenID Connect relying parties. Public clients, such as mobile and
+-- single page apps, have no secret and must use PKCE
+CREATE TABLE IF NOT EXISTS oidc_clients (
+    id UUID NOT NULL,
+    name TEXT NOT NULL,
+    client_secret_hash TEXT,
+    redirect_uris TEXT[] NOT NULL,
+    is_active BOOLEAN NOT NULL,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+
+-- Create OIDC Authorization Codes table
+-- Single use codes issued by the authorize endpoint and exchanged, with the
+-- PKCE code verifier, for tokens at the token endpoint
+CREATE TABLE IF NOT EXISTS oidc_authorization_codes (
+    id UUID NOT NULL,
+    client_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    code_hash TEXT NOT NULL UNIQUE,
+    redirect_uri TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    nonce TEXT,
+    code_challenge TEXT NOT NULL,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (client_id) REFERENCES oidc_clients(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
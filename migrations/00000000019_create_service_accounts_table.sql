@@ -0,0 +1,15 @@
+-- ./migrations/00000000019_create_service_accounts_table.sql
+-- Create Service Accounts table
+-- Clients, such as background jobs, that authenticate with the client
+-- credentials grant instead of a user logging in. Only the hash of the client
+-- secret is stored, and scopes name the admin services the account can call
+CREATE TABLE IF NOT EXISTS service_accounts (
+    id UUID NOT NULL,
+    name TEXT NOT NULL,
+    client_secret_hash TEXT NOT NULL,
+    scopes TEXT[] NOT NULL,
+    is_active BOOLEAN NOT NULL,
+    secret_rotated_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
@@ -0,0 +1,19 @@
+-- ./migrations/00000000020_create_api_keys_table.sql
+-- Create API Keys table
+-- Long lived keys users authenticate scripts with instead of logging in. The
+-- prefix identifies the key, and only the hash of the secret is stored. An
+-- empty scope list gives the key the same access as its user
+CREATE TABLE IF NOT EXISTS api_keys (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    name TEXT NOT NULL,
+    prefix TEXT NOT NULL UNIQUE,
+    secret_hash TEXT NOT NULL,
+    scopes TEXT[] NOT NULL,
+    expires_on TIMESTAMP WITH TIME ZONE,
+    last_used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS api_keys_user_id_idx ON api_keys (user_id);
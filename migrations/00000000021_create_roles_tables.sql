@@ -0,0 +1,78 @@
+-- ./migrations/00000000021_create_roles_tables.sql
+-- Create Roles table
+-- Roles are named sets of permissions that are assigned to users. The admin,
+-- user and guest roles are seeded from the user_role enum, and every user
+-- holds the system role named by their users.role along with any roles
+-- assigned to them in user_roles
+CREATE TABLE IF NOT EXISTS roles (
+    id UUID NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    description TEXT NOT NULL,
+    permissions TEXT[] NOT NULL,
+    is_system BOOLEAN NOT NULL,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+
+-- Create User Roles table
+-- The roles assigned to a user on top of the system role of their users.role
+CREATE TABLE IF NOT EXISTS user_roles (
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role_id UUID NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (user_id, role_id)
+);
+
+-- Find the users holding a role when it is updated or deleted
+CREATE INDEX idx_user_roles_role_id ON user_roles (role_id);
+
+-- Seed a system role for each user_role enum value, with the permissions the
+-- role was granted before roles were stored in the database
+INSERT INTO roles (
+    id, name, description, permissions, is_system, created_on
+) VALUES (
+    '0192b7a0-6c00-7000-8000-000000000001',
+    'admin',
+    'Administrators can take every action on any record',
+    ARRAY[
+        'users:create:any',
+        'users:read:self',
+        'users:read:any',
+        'users:update:any',
+        'users:delete:any',
+        'users:unlock:any',
+        'sessions:read:self',
+        'sessions:read:any',
+        'sessions:revoke:self',
+        'sessions:revoke:any',
+        'sessions:delete:any',
+        'logins:create:any',
+        'logins:read:self',
+        'logins:read:any',
+        'logins:update:any',
+        'logins:delete:any'
+    ],
+    TRUE,
+    '2024-10-01T00:00:00.000000Z'
+), (
+    '0192b7a0-6c00-7000-8000-000000000002',
+    'user',
+    'Users can see their own account, sessions and logins, and sign their own sessions out',
+    ARRAY[
+        'users:read:self',
+        'sessions:read:self',
+        'sessions:revoke:self',
+        'logins:read:self'
+    ],
+    TRUE,
+    '2024-10-01T00:00:00.000000Z'
+), (
+    '0192b7a0-6c00-7000-8000-000000000003',
+    'guest',
+    'Guests can only see their own account',
+    ARRAY[
+        'users:read:self'
+    ],
+    TRUE,
+    '2024-10-01T00:00:00.000000Z'
+);
@@ -0,0 +1,7 @@
+-- ./migrations/00000000022_add_users_update_self_permission.sql
+-- Let admins and users update their own profile through the Me endpoint
+UPDATE roles
+SET permissions = array_append(permissions, 'users:update:self')
+WHERE is_system
+    AND name IN ('admin', 'user')
+    AND NOT 'users:update:self' = ANY (permissions);
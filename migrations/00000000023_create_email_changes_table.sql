@@ -0,0 +1,15 @@
+-- ./migrations/00000000023_create_email_changes_table.sql
+-- Create Email Changes table
+-- A change of email address requested by a user, holding the new address
+-- until it is confirmed with the token sent to it
+CREATE TABLE IF NOT EXISTS email_changes (
+    id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    email TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
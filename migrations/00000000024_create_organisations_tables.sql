@@ -0,0 +1,46 @@
+-- ./migrations/00000000024_create_organisations_tables.sql
+-- Create Organisations tables
+-- Organisations, such as a household, group users that share records. Each
+-- member holds one organisation role, and users are invited to join by email
+DROP TYPE IF EXISTS organisation_role CASCADE;
+CREATE TYPE organisation_role AS ENUM ('owner', 'member', 'viewer');
+
+CREATE TABLE IF NOT EXISTS organisations (
+    id UUID NOT NULL,
+    name TEXT NOT NULL,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+
+-- Create Organisation Members table
+-- The users that belong to an organisation and the role they hold in it
+CREATE TABLE IF NOT EXISTS organisation_members (
+    organisation_id UUID NOT NULL REFERENCES organisations (id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role organisation_role NOT NULL,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (organisation_id, user_id)
+);
+
+-- Find the organisations a user belongs to
+CREATE INDEX idx_organisation_members_user_id ON organisation_members (user_id);
+
+-- Create Organisation Invitations table
+-- An invitation to join an organisation sent to an email address, which can
+-- be accepted by an existing user or used to register a new user
+CREATE TABLE IF NOT EXISTS organisation_invitations (
+    id UUID NOT NULL,
+    organisation_id UUID NOT NULL REFERENCES organisations (id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    role organisation_role NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_on TIMESTAMP WITH TIME ZONE,
+    created_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+
+-- The organisation a session is active in, carried into the Access Tokens
+-- issued with it
+ALTER TABLE sessions
+    ADD COLUMN organisation_id UUID REFERENCES organisations (id) ON DELETE SET NULL;
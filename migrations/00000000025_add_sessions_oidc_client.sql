@@ -0,0 +1,5 @@
+-- ./migrations/00000000025_add_sessions_oidc_client.sql
+-- The OIDC client a session was issued to, which must authenticate to refresh
+-- it. Sessions are removed with their client.
+ALTER TABLE sessions
+    ADD COLUMN oidc_client_id UUID REFERENCES oidc_clients (id) ON DELETE CASCADE;
@@ -0,0 +1,14 @@
+-- ./migrations/00000000026_create_service_account_tokens_table.sql
+-- Create Service Account Tokens table
+-- Record the Access Tokens issued to each Service Account, so disabling the
+-- Service Account also revokes its Access Tokens. Rows are only needed until
+-- the token expires. Revoked Service Account tokens keep the Service Account
+-- id in the revoked_tokens user_id column, as the token subject
+CREATE TABLE IF NOT EXISTS service_account_tokens (
+    id UUID NOT NULL,
+    service_account_id UUID NOT NULL REFERENCES service_accounts (id) ON DELETE CASCADE,
+    expires_on TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS service_account_tokens_service_account_id_idx
+    ON service_account_tokens (service_account_id);
@@ -0,0 +1,6 @@
+-- ./migrations/00000000027_add_users_is_pending.sql
+-- Add a pending flag to the Users table
+-- Users who register under the pending policy are inactive until they verify
+-- their email address. The flag tells them apart from users an admin has
+-- deactivated, who must not be activated by verifying their email
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_pending BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,9 @@
+-- ./migrations/00000000028_add_roles_assign_permission.sql
+-- Setting or changing the user role of a user needs its own permission, so
+-- users:create:any and users:update:any cannot be used to grant admin. Only
+-- admins hold it to start with
+UPDATE roles
+SET permissions = array_append(permissions, 'roles:assign:any')
+WHERE is_system
+    AND name = 'admin'
+    AND NOT 'roles:assign:any' = ANY (permissions);
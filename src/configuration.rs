@@ -30,6 +30,27 @@ pub struct Configuration {
 
     /// Database configuration
     pub database: DatabaseConfiguration,
+
+    /// JSON Web Token signing configuration
+    pub jwt: JwtConfiguration,
+
+    /// User self registration configuration
+    pub registration: RegistrationConfiguration,
+
+    /// Outbound email configuration
+    pub email: EmailConfiguration,
+
+    /// Failed login protection configuration
+    pub lockout: LockoutConfiguration,
+
+    /// Multi-factor authentication configuration
+    pub mfa: MfaConfiguration,
+
+    /// WebAuthn (passkey) configuration
+    pub webauthn: WebAuthnConfiguration,
+
+    /// OpenID Connect provider configuration
+    pub oidc: OidcConfiguration,
 }
 
 /// Configuration for running the API application
@@ -42,8 +63,51 @@ pub struct ApplicationConfiguration {
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub port: u16,
 
-    // Secret used to generate JWT keys
-    pub token_secret: Secret<String>,
+    /// Issuer (iss) claim added to, and required in, tokens
+    pub token_issuer: String,
+
+    /// Audience (aud) claim added to, and required in, tokens
+    pub token_audience: String,
+
+    /// Seconds an Access Token is valid for
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub access_token_seconds: u64,
+
+    /// Seconds a Refresh Token is valid for, unless the user asks to be remembered
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub refresh_token_seconds: u64,
+
+    /// The longest a user can ask to be remembered for, in seconds
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub remember_me_max_seconds: u64,
+}
+
+impl ApplicationConfiguration {
+    /// Get the Refresh Token lifetime for a login, which is the configured
+    /// lifetime unless the user asks to be remembered for longer.
+    ///
+    /// # Parameters
+    ///
+    /// * `remember_me_seconds`: Optional lifetime requested with the login
+    /// ---
+    pub fn refresh_token_lifetime(
+        &self,
+        remember_me_seconds: Option<u64>,
+    ) -> Result<u64, BackendError> {
+        match remember_me_seconds {
+            None => Ok(self.refresh_token_seconds),
+            Some(seconds)
+                if (self.refresh_token_seconds..=self.remember_me_max_seconds)
+                    .contains(&seconds) =>
+            {
+                Ok(seconds)
+            }
+            Some(_) => Err(BackendError::RememberMeOutOfBounds(
+                self.refresh_token_seconds,
+                self.remember_me_max_seconds,
+            )),
+        }
+    }
 }
 
 /// Configuration for connecting to the database server
@@ -89,6 +153,216 @@ impl DatabaseConfiguration {
     }
 }
 
+/// Configuration for signing and verifying JSON Web Tokens
+///
+/// The PEM key files are the first signing key, imported into the database key
+/// ring on first start. Rotated keys are generated with the same algorithm.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct JwtConfiguration {
+    /// The asymmetric algorithm tokens are signed with
+    pub algorithm: JwtAlgorithm,
+
+    /// Key id added to the token header and published in the JWKS
+    pub key_id: String,
+
+    /// Path to the PKCS#8 PEM private key used to sign tokens
+    pub private_key_path: String,
+
+    /// Path to the PEM public key used to verify tokens
+    pub public_key_path: String,
+
+    /// Key used to encrypt signing keys stored in the database
+    pub encryption_key: Secret<String>,
+
+    /// Key used to hash the tokens stored in the database, such as Refresh Tokens
+    pub token_hash_key: Secret<String>,
+
+    /// Seconds a rotated key is published for before it signs tokens, so other
+    /// services can fetch it first
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub promotion_seconds: i64,
+
+    /// Seconds between reloading the key ring from the database
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub refresh_seconds: u64,
+}
+
+/// The asymmetric algorithms available for signing JSON Web Tokens.
+#[derive(Clone, Debug, PartialEq, Copy, serde::Deserialize, Display, sqlx::Type)]
+#[sqlx(type_name = "jwt_algorithm")]
+pub enum JwtAlgorithm {
+    /// Edwards-curve signatures using an Ed25519 key
+    EdDSA,
+    /// RSASSA-PKCS1-v1_5 signatures using SHA-256 and an RSA key
+    RS256,
+}
+
+/// Configuration for users registering themselves
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct RegistrationConfiguration {
+    /// The sign up policy applied to new registrations
+    pub policy: RegistrationPolicy,
+}
+
+/// The sign up policies for self registered users.
+#[derive(Clone, Debug, PartialEq, Copy, serde::Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RegistrationPolicy {
+    /// Self registration is turned off, only admins can create users
+    Closed,
+    /// New users are active and can log in straight away, pending verification
+    /// of their email address
+    Open,
+    /// New users are created inactive, pending verification of their email
+    /// address before they can log in
+    Pending,
+}
+
+impl RegistrationPolicy {
+    /// Is a newly registered user active under this policy
+    pub fn is_active(&self) -> bool {
+        matches!(self, RegistrationPolicy::Open)
+    }
+}
+
+/// Configuration for sending emails to users
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct EmailConfiguration {
+    /// The backend used to deliver emails
+    pub backend: EmailBackend,
+
+    /// The mailbox emails are sent from, i.e. "Name <user@example.com>"
+    pub from: String,
+
+    /// Spool configuration, used by the spool backend
+    pub spool: SpoolConfiguration,
+
+    /// SMTP server configuration, used by the smtp backend
+    pub smtp: SmtpConfiguration,
+}
+
+/// The backends available for delivering emails.
+#[derive(Clone, Debug, PartialEq, Copy, serde::Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EmailBackend {
+    /// Write emails to the tracing log
+    Tracing,
+    /// Write emails as `.eml` files into the spool directory
+    Spool,
+    /// Send emails through an SMTP server
+    Smtp,
+}
+
+/// Configuration for writing emails to a spool directory
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SpoolConfiguration {
+    /// Directory the `.eml` files are written to
+    pub directory: String,
+}
+
+/// Configuration for connecting to an SMTP server
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SmtpConfiguration {
+    /// SMTP server host address
+    pub host: String,
+
+    /// SMTP server port
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+
+    /// SMTP username for login, no authentication is used if empty
+    pub username: String,
+
+    /// SMTP password for login
+    pub password: Secret<String>,
+
+    /// How the connection to the SMTP server is secured
+    pub tls: SmtpTls,
+}
+
+/// The connection security used with the SMTP server.
+#[derive(Clone, Debug, PartialEq, Copy, serde::Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SmtpTls {
+    /// Plain text connection, only for local development servers
+    None,
+    /// Upgrade a plain text connection with STARTTLS
+    Starttls,
+    /// Connect using TLS from the start
+    Tls,
+}
+
+/// Configuration for protecting login from password guessing
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LockoutConfiguration {
+    /// Failed attempts on an account before it is locked
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub account_threshold: i32,
+
+    /// Failed attempts from a client IP address before it is locked
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ip_threshold: i32,
+
+    /// Seconds failed attempts are counted over, since the last failure
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub window_seconds: i64,
+
+    /// Seconds an account or client IP address is locked for
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub duration_seconds: i64,
+
+    /// Milliseconds delay after the first failed attempt, doubled after each
+    /// further failed attempt
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub delay_base_millis: u64,
+
+    /// Maximum milliseconds delay before checking a password
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub delay_max_millis: u64,
+}
+
+/// Configuration for multi-factor authentication
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MfaConfiguration {
+    /// Issuer name shown in the users authenticator app
+    pub issuer: String,
+
+    /// Key used to encrypt TOTP secrets stored in the database
+    pub encryption_key: Secret<String>,
+}
+
+/// Configuration for WebAuthn (passkey) registration and login
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct WebAuthnConfiguration {
+    /// Relying party id, the domain passkeys are scoped to
+    pub rp_id: String,
+
+    /// Relying party name shown to the user when creating a passkey
+    pub rp_name: String,
+
+    /// Web and mobile client origins allowed to use passkeys
+    pub origins: Vec<String>,
+}
+
+/// Configuration for the OpenID Connect provider HTTP server
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct OidcConfiguration {
+    /// The port that the OIDC HTTP server should bind to
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+
+    /// Issuer URL the provider is reached at, used in the discovery document
+    /// and as the ID Token issuer (iss) claim
+    pub issuer: String,
+
+    /// Seconds an authorization code can be exchanged for tokens
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub authorization_code_seconds: i64,
+}
+
 /// The possible runtime environment for our application.
 #[derive(Clone, Debug, PartialEq, Copy, serde::Deserialize, Display)]
 #[strum(serialize_all = "snake_case")]
@@ -145,7 +419,7 @@ impl Configuration {
 
         // Set the environment config file path
         let environment_config_file =
-            configuration_directory.join(format!("{}.yaml", environment));
+            configuration_directory.join(format!("{}.yaml", environment.as_str()));
 
         // Build our configuration instance. Configuration files are added in
         // this order, with subsequent files overwriting previous configurations
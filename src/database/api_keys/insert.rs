@@ -0,0 +1,102 @@
+//-- ./src/database/api_keys/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an API Key into the database, returning a result with the API Keys
+//! Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::ApiKeys;
+
+impl ApiKeys {
+    /// Insert an API Key into the database, returning the database instance
+    /// created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The API Key instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new API Key into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ApiKeys,
+            r#"
+                INSERT INTO api_keys (
+                    id,
+                    user_id,
+                    name,
+                    prefix,
+                    secret_hash,
+                    scopes,
+                    expires_on,
+                    last_used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.name,
+            self.prefix,
+            self.secret_hash,
+            &self.scopes,
+            self.expires_on,
+            self.last_used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("API Key database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let (random_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_api_key.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_api_key);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Wrapper around API Keys database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::ApiKeys;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
@@ -0,0 +1,158 @@
+//-- ./src/database/api_keys/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The API Keys database model
+//!
+//! Users create API keys to authenticate scripts without sharing their
+//! password. A key with no scopes has the same access as its user, otherwise it
+//! can only call the services it has been granted the scope for. Only the
+//! keyed hash of the secret is stored.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::{database, domain, prelude::*};
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct ApiKeys {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_on: Option<DateTime<Utc>>,
+    pub last_used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl ApiKeys {
+    /// Create a new API Key instance, storing only the keyed hash of the secret
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - The user that owns the API key
+    /// * `name` - The name of the API key, such as the script using it
+    /// * `scopes` - The services the API key can call, or all when empty
+    /// * `expires_on` - When the API key stops working, or never when None
+    /// * `api_key` - The API key prefix and secret
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        user: &database::Users,
+        name: &str,
+        scopes: &[domain::ServiceScope],
+        expires_on: Option<DateTime<Utc>>,
+        api_key: &domain::ApiKey,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user.id.to_owned();
+        let name = name.to_owned();
+        let prefix = api_key.prefix().to_owned();
+        let secret_hash = api_key.secret_hash(hash_key);
+        let scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        let expires_on = expires_on.map(|expires_on| expires_on.round_subsecs(0));
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            expires_on,
+            last_used_on: None,
+            created_on,
+        }
+    }
+
+    /// Check the API key presented with a request matches this record
+    pub fn verify_secret(&self, api_key: &domain::ApiKey, hash_key: &Secret<String>) -> bool {
+        api_key.prefix() == self.prefix && api_key.secret_hash(hash_key) == self.secret_hash
+    }
+
+    /// Check if the API key has expired by `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_on.is_some_and(|expires_on| expires_on <= now)
+    }
+
+    /// Parse the scopes granted to the API key
+    pub fn granted_scopes(&self) -> Result<Vec<domain::ServiceScope>, BackendError> {
+        domain::ServiceScope::parse_list(&self.scopes)
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user: &database::Users,
+    ) -> Result<(Self, domain::ApiKey), BackendError> {
+        use fake::faker::lorem::en::Word;
+        use fake::Fake;
+
+        let name: String = Word().fake();
+        let api_key = domain::ApiKey::generate();
+        let expires_on = Utc::now() + chrono::Duration::days(30);
+        let database_record = Self::new(
+            user,
+            &name,
+            &[domain::ServiceScope::Logins],
+            Some(expires_on),
+            &api_key,
+            &domain::OneTimeToken::mock_hash_key(),
+        );
+
+        Ok((database_record, api_key))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn api_key_verifies_secret() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        let (api_key_record, api_key) = ApiKeys::mock_data(&random_user)?;
+
+        //-- Checks (Assertions)
+        assert!(api_key_record.verify_secret(&api_key, &hash_key));
+        assert!(!api_key_record.verify_secret(&domain::ApiKey::generate(), &hash_key));
+        assert!(!api_key_record.secret_hash.contains(&api_key.to_string()));
+        assert_eq!(
+            api_key_record.granted_scopes()?,
+            vec![domain::ServiceScope::Logins]
+        );
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn api_key_expires() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let (mut api_key_record, _api_key) = ApiKeys::mock_data(&random_user)?;
+
+        //-- Checks (Assertions)
+        assert!(!api_key_record.is_expired(Utc::now()));
+        assert!(api_key_record.is_expired(Utc::now() + Duration::days(31)));
+        api_key_record.expires_on = None;
+        assert!(!api_key_record.is_expired(Utc::now() + Duration::days(3650)));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+//-- ./src/database/api_keys/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read API Keys from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::ApiKeys;
+
+impl ApiKeys {
+    /// Get an API Key owned by a user from the database, returning the API Key
+    /// or an sqlx RowNotFound error if the user has no API Key with the id.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The API Key id
+    /// * `user_id` - The user that owns the API Key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Get a users API Key from the database: ", skip(database))]
+    pub async fn from_id_and_user_id(
+        id: &Uuid,
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ApiKeys,
+            r#"
+                SELECT *
+                FROM api_keys
+                WHERE id = $1 AND user_id = $2
+            "#,
+            id,
+            user_id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("API Key database record retrieved: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Get an index of the API Keys owned by a user from the database
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user that owns the API Keys
+    /// * `limit` - A i64 limiting the page length
+    /// * `offset` - A i64 of where the limit should start
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Index of a users API Keys with offset and limit: ",
+        skip(database)
+    )]
+    pub async fn index_user_id(
+        user_id: &Uuid,
+        limit: &i64,
+        offset: &i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            ApiKeys,
+            r#"
+                SELECT *
+                FROM api_keys
+                WHERE user_id = $1
+                ORDER BY id
+                LIMIT $2 OFFSET $3
+            "#,
+            user_id,
+            limit,
+            offset,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("API Key database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+
+    /// Get the API Keys that can authenticate requests, being those that have
+    /// not expired and are owned by active users, with the role and permissions
+    /// of each user.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - Only get the API Keys of this user, or all users when None
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Index of usable API Keys: ", skip(database))]
+    pub async fn index_usable(
+        user_id: Option<&Uuid>,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<(Self, domain::UserRole, Vec<domain::Permission>)>, BackendError> {
+        let database_records = sqlx::query!(
+            r#"
+                SELECT
+                    api_keys.id,
+                    api_keys.user_id,
+                    api_keys.name,
+                    api_keys.prefix,
+                    api_keys.secret_hash,
+                    api_keys.scopes,
+                    api_keys.expires_on,
+                    api_keys.last_used_on,
+                    api_keys.created_on,
+                    users.role as "role:domain::UserRole",
+                    ARRAY(
+                        SELECT DISTINCT UNNEST(roles.permissions)
+                        FROM roles
+                        WHERE roles.name = users.role::TEXT
+                            OR roles.id IN (
+                                SELECT user_roles.role_id
+                                FROM user_roles
+                                WHERE user_roles.user_id = users.id
+                            )
+                    ) as "permissions!"
+                FROM api_keys
+                INNER JOIN users ON users.id = api_keys.user_id
+                WHERE users.is_active
+                    AND (api_keys.expires_on IS NULL OR api_keys.expires_on > NOW())
+                    AND ($1::UUID IS NULL OR api_keys.user_id = $1)
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Usable API Keys retrieved: {}", database_records.len());
+
+        database_records
+            .into_iter()
+            .map(|record| {
+                let api_key = ApiKeys {
+                    id: record.id,
+                    user_id: record.user_id,
+                    name: record.name,
+                    prefix: record.prefix,
+                    secret_hash: record.secret_hash,
+                    scopes: record.scopes,
+                    expires_on: record.expires_on,
+                    last_used_on: record.last_used_on,
+                    created_on: record.created_on,
+                };
+
+                let permissions = record
+                    .permissions
+                    .iter()
+                    .map(|permission| domain::Permission::parse(permission))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((api_key, record.role, permissions))
+            })
+            .collect()
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_api_key_by_id_and_user_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let (random_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+        random_api_key.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            ApiKeys::from_id_and_user_id(&random_api_key.id, &random_user.id, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_api_key);
+        assert!(
+            ApiKeys::from_id_and_user_id(&random_api_key.id, &Uuid::now_v7(), &database)
+                .await
+                .is_err()
+        );
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_api_keys_of_user(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        for _ in 0..3 {
+            let (random_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+            random_api_key.insert(&database).await?;
+        }
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+        let (other_api_key, _api_key) = ApiKeys::mock_data(&other_user)?;
+        other_api_key.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let all_records = ApiKeys::index_user_id(&random_user.id, &10, &0, &database).await?;
+        let page_records = ApiKeys::index_user_id(&random_user.id, &2, &0, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(all_records.len(), 3);
+        assert_eq!(page_records.len(), 2);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_usable_skips_expired_and_inactive(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut random_user = database::Users::mock_data()?;
+        random_user.is_active = true;
+        random_user.insert(&database).await?;
+        let (usable_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+        usable_api_key.insert(&database).await?;
+        let (mut expired_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+        expired_api_key.expires_on = Some(Utc::now() - Duration::minutes(1));
+        expired_api_key.insert(&database).await?;
+
+        let mut inactive_user = database::Users::mock_data()?;
+        inactive_user.is_active = false;
+        inactive_user.insert(&database).await?;
+        let (inactive_api_key, _api_key) = ApiKeys::mock_data(&inactive_user)?;
+        inactive_api_key.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let usable_records = ApiKeys::index_usable(None, &database).await?;
+        let user_records = ApiKeys::index_usable(Some(&inactive_user.id), &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(usable_records.len(), 1);
+        assert_eq!(usable_records[0].0, usable_api_key);
+        assert_eq!(usable_records[0].1, random_user.role);
+        assert!(!usable_records[0].2.is_empty());
+        assert!(user_records.is_empty());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//-- ./src/database/api_keys/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update API Keys in the database
+//! ---
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::ApiKeys;
+
+impl ApiKeys {
+    /// Rename an API Key owned by a user, returning the updated API Key or an
+    /// sqlx RowNotFound error if the user has no API Key with the id.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The API Key id
+    /// * `user_id` - The user that owns the API Key
+    /// * `name` - The new name of the API Key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Update an API Key name in the database: ", skip(database))]
+    pub async fn update_name(
+        id: &Uuid,
+        user_id: &Uuid,
+        name: &str,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ApiKeys,
+            r#"
+                UPDATE api_keys
+                SET name = $3
+                WHERE id = $1 AND user_id = $2
+                RETURNING *
+            "#,
+            id,
+            user_id,
+            name,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("API Key name updated: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Record when an API Key was last used, never moving the time backwards,
+    /// returning the number of rows updated
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The API Key id
+    /// * `last_used_on` - When the API Key last authenticated a request
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Update an API Key last used in the database: ", skip(database))]
+    pub async fn update_last_used_on(
+        id: &Uuid,
+        last_used_on: &DateTime<Utc>,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE api_keys
+                SET last_used_on = $2
+                WHERE id = $1 AND (last_used_on IS NULL OR last_used_on < $2)
+            "#,
+            id,
+            last_used_on,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("API Key last used updated: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, SubsecRound};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn rename_api_key(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let (random_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+        random_api_key.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = ApiKeys::update_name(
+            &random_api_key.id,
+            &random_user.id,
+            "Renamed",
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.name, "Renamed");
+        assert!(
+            ApiKeys::update_name(&random_api_key.id, &Uuid::now_v7(), "Other", &database)
+                .await
+                .is_err()
+        );
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn last_used_on_only_moves_forward(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let (random_api_key, _api_key) = ApiKeys::mock_data(&random_user)?;
+        random_api_key.insert(&database).await?;
+        let last_used_on = Utc::now().round_subsecs(0);
+
+        //-- Execute Function (Act)
+        let updated =
+            ApiKeys::update_last_used_on(&random_api_key.id, &last_used_on, &database).await?;
+        let earlier = last_used_on - Duration::minutes(5);
+        let not_updated =
+            ApiKeys::update_last_used_on(&random_api_key.id, &earlier, &database).await?;
+
+        //-- Checks (Assertions)
+        let database_record =
+            ApiKeys::from_id_and_user_id(&random_api_key.id, &random_user.id, &database)
+                .await?;
+        assert_eq!(updated, 1);
+        assert_eq!(not_updated, 0);
+        assert_eq!(database_record.last_used_on, Some(last_used_on));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Wrapper around Email Changes database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{EmailChanges, EMAIL_CHANGE_DURATION, EMAIL_CHANGE_THROTTLE};
+
+mod insert;
+mod model;
+mod read;
+mod update;
@@ -0,0 +1,109 @@
+//-- ./src/database/email_changes/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Email Changes from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::EmailChanges;
+
+impl EmailChanges {
+    /// Get the most recently issued Email Change for a user, returning
+    /// None if one has never been issued.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Email Change was issued to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read latest Email Change from the database: ",
+        skip(database)
+    )]
+    pub async fn latest_from_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Self>, BackendError> {
+        let database_record = sqlx::query_as!(
+            EmailChanges,
+            r#"
+                SELECT *
+                FROM email_changes
+                WHERE user_id = $1
+                ORDER BY created_on DESC
+                LIMIT 1
+            "#,
+            user_id,
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!("Email Change database record retrieved: {database_record:#?}");
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_latest_email_change(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        // Add an older Email Change
+        let (mut older_email_change, _random_token) =
+            EmailChanges::mock_data(&random_user)?;
+        older_email_change.created_on -= Duration::hours(1);
+        older_email_change.insert(&database).await?;
+
+        let (latest_email_change, _random_token) =
+            EmailChanges::mock_data(&random_user)?;
+        latest_email_change.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            EmailChanges::latest_from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, Some(latest_email_change));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn no_email_change_returns_none(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            EmailChanges::latest_from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//-- ./src/database/email_verifications/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an Email Verification into the database, returning a result with the
+//! Email Verifications Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::EmailVerifications;
+
+impl EmailVerifications {
+    /// Insert an Email Verification into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Email Verification instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Email Verification into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            EmailVerifications,
+            r#"
+                INSERT INTO email_verifications (
+                    id,
+                    user_id,
+                    email,
+                    token_hash,
+                    expires_on,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.email.as_ref(),
+            self.token_hash,
+            self.expires_on,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Email Verification database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_email_verification, _random_token) =
+            EmailVerifications::mock_data(&random_user)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_email_verification.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_email_verification);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+//! Wrapper around Email Verifications database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{
+    EmailVerifications, EMAIL_VERIFICATION_DURATION, EMAIL_VERIFICATION_THROTTLE,
+};
+
+mod insert;
+mod model;
+mod read;
+mod update;
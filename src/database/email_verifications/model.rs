@@ -0,0 +1,128 @@
+//-- ./src/database/email_verifications/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Email Verifications database model
+//! ---
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::{database, domain};
+
+pub static EMAIL_VERIFICATION_DURATION: i64 = 24 * 60 * 60; // 24 hours as seconds
+
+pub static EMAIL_VERIFICATION_THROTTLE: i64 = 60; // 1 minute as seconds
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct EmailVerifications {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub email: domain::EmailAddress,
+    pub token_hash: String,
+    pub expires_on: DateTime<Utc>,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl EmailVerifications {
+    /// Create a new Email Verification instance for the users current email
+    /// address, storing only the keyed hash of the One Time Token.
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - The user whose email address is being verified
+    /// * `token` - The One Time Token that will be emailed to the user
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        user: &database::Users,
+        token: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user.id.to_owned();
+        let email = user.email.to_owned();
+        let token_hash = token.keyed_hash(hash_key);
+        let created_on = Utc::now().round_subsecs(0);
+        let expires_on = created_on + Duration::seconds(EMAIL_VERIFICATION_DURATION);
+
+        Self {
+            id,
+            user_id,
+            email,
+            token_hash,
+            expires_on,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    /// Has the verification been issued recently enough that another should
+    /// not be sent yet.
+    pub fn is_throttled(&self) -> bool {
+        Utc::now() < self.created_on + Duration::seconds(EMAIL_VERIFICATION_THROTTLE)
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user: &database::Users,
+    ) -> Result<(Self, domain::OneTimeToken), crate::prelude::BackendError> {
+        let random_token = domain::OneTimeToken::generate();
+        let email_verification =
+            Self::new(user, &random_token, &domain::OneTimeToken::mock_hash_key());
+
+        Ok((email_verification, random_token))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_email_verification() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let random_token = domain::OneTimeToken::generate();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let email_verification = EmailVerifications::new(&random_user, &random_token, &hash_key);
+
+        //-- Checks (Assertions)
+        assert_eq!(email_verification.user_id, random_user.id);
+        assert_eq!(email_verification.email, random_user.email);
+        assert_eq!(email_verification.token_hash, random_token.keyed_hash(&hash_key));
+        assert!(email_verification.is_throttled());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn old_email_verification_is_not_throttled() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let (mut email_verification, _random_token) =
+            EmailVerifications::mock_data(&random_user)?;
+
+        //-- Execute Function (Act)
+        email_verification.created_on -=
+            Duration::seconds(EMAIL_VERIFICATION_THROTTLE + 1);
+
+        //-- Checks (Assertions)
+        assert!(!email_verification.is_throttled());
+
+        //-- Return
+        Ok(())
+    }
+}
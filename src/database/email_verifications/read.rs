@@ -0,0 +1,109 @@
+//-- ./src/database/email_verifications/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Email Verifications from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::EmailVerifications;
+
+impl EmailVerifications {
+    /// Get the most recently issued Email Verification for a user, returning
+    /// None if one has never been issued.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Email Verification was issued to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read latest Email Verification from the database: ",
+        skip(database)
+    )]
+    pub async fn latest_from_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Self>, BackendError> {
+        let database_record = sqlx::query_as!(
+            EmailVerifications,
+            r#"
+                SELECT *
+                FROM email_verifications
+                WHERE user_id = $1
+                ORDER BY created_on DESC
+                LIMIT 1
+            "#,
+            user_id,
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!("Email Verification database record retrieved: {database_record:#?}");
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_latest_email_verification(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        // Add an older Email Verification
+        let (mut older_email_verification, _random_token) =
+            EmailVerifications::mock_data(&random_user)?;
+        older_email_verification.created_on -= Duration::hours(1);
+        older_email_verification.insert(&database).await?;
+
+        let (latest_email_verification, _random_token) =
+            EmailVerifications::mock_data(&random_user)?;
+        latest_email_verification.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            EmailVerifications::latest_from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, Some(latest_email_verification));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn no_email_verification_returns_none(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            EmailVerifications::latest_from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, None);
+
+        //-- Return
+        Ok(())
+    }
+}
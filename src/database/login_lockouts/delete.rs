@@ -0,0 +1,85 @@
+//-- ./src/database/login_lockouts/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete Login Lockouts in the database, returning a Result with an u64 of
+//! the number of rows affected.
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::{LockoutScope, LoginLockouts};
+
+impl LoginLockouts {
+    /// Delete the Login Lockout for an account or client IP address, clearing
+    /// the failed attempts and any lock, returning a Result with the number of
+    /// rows deleted or a sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope` - What the failed attempts are counted against
+    /// * `subject` - The user id or client IP address
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Delete Login Lockout from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_by_subject(
+        scope: LockoutScope,
+        subject: &str,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM login_lockouts
+                WHERE scope = $1 AND subject = $2
+            "#,
+            scope.to_string(),
+            subject,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Login Lockout database records deleted: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_login_lockout(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let config = LoginLockouts::mock_config();
+        LoginLockouts::record_failure(LockoutScope::IpAddress, "::1", &config, &database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            LoginLockouts::delete_by_subject(LockoutScope::IpAddress, "::1", &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        let database_record =
+            LoginLockouts::from_subject(LockoutScope::IpAddress, "::1", &database).await?;
+        assert_eq!(database_record, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Wrapper around Login Lockouts database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{LockoutScope, LoginLockouts};
+
+mod delete;
+mod model;
+mod read;
+mod update;
@@ -0,0 +1,140 @@
+//-- ./src/database/login_lockouts/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Login Lockouts database model
+//!
+//! Failed login attempts are counted per account and per client IP address,
+//! so password guessing is slowed down, and then stopped, whether an attacker
+//! targets one account or tries many accounts from one address.
+//! ---
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use strum::Display;
+use uuid::Uuid;
+
+use crate::configuration::LockoutConfiguration;
+
+/// What failed login attempts are counted against
+#[derive(Clone, Debug, PartialEq, Copy, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum LockoutScope {
+    /// A user account, with the user id as the subject
+    Account,
+    /// A client IP address, with the address as the subject
+    IpAddress,
+}
+
+impl LockoutScope {
+    /// The number of failed attempts before the scope subject is locked
+    pub fn threshold(&self, config: &LockoutConfiguration) -> i32 {
+        match self {
+            LockoutScope::Account => config.account_threshold,
+            LockoutScope::IpAddress => config.ip_threshold,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct LoginLockouts {
+    pub id: Uuid,
+    pub scope: String,
+    pub subject: String,
+    pub failed_attempts: i32,
+    pub last_failed_on: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl LoginLockouts {
+    /// Is the account or client IP address currently locked
+    pub fn is_locked(&self) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| locked_until > Utc::now())
+    }
+
+    /// The delay before checking a password, which doubles with each failed
+    /// attempt in the window, up to the configured maximum.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - The failed login protection configuration
+    /// ---
+    pub fn delay(&self, config: &LockoutConfiguration) -> Duration {
+        let window_start = Utc::now() - chrono::Duration::seconds(config.window_seconds);
+        if self.failed_attempts < 1 || self.last_failed_on < window_start {
+            return Duration::ZERO;
+        }
+
+        let doublings = (self.failed_attempts - 1).min(16) as u32;
+        let delay = config.delay_base_millis.saturating_mul(1 << doublings);
+
+        Duration::from_millis(delay.min(config.delay_max_millis))
+    }
+
+    #[cfg(test)]
+    pub fn mock_config() -> LockoutConfiguration {
+        LockoutConfiguration {
+            account_threshold: 5,
+            ip_threshold: 20,
+            window_seconds: 900,
+            duration_seconds: 900,
+            delay_base_millis: 250,
+            delay_max_millis: 4000,
+        }
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::SubsecRound;
+
+    use super::*;
+
+    fn login_lockout(failed_attempts: i32, last_failed_on: DateTime<Utc>) -> LoginLockouts {
+        LoginLockouts {
+            id: Uuid::now_v7(),
+            scope: LockoutScope::Account.to_string(),
+            subject: Uuid::now_v7().to_string(),
+            failed_attempts,
+            last_failed_on,
+            locked_until: None,
+            created_on: Utc::now().round_subsecs(0),
+        }
+    }
+
+    #[test]
+    fn delay_doubles_up_to_maximum() {
+        let config = LoginLockouts::mock_config();
+        let now = Utc::now();
+
+        assert_eq!(login_lockout(1, now).delay(&config), Duration::from_millis(250));
+        assert_eq!(login_lockout(2, now).delay(&config), Duration::from_millis(500));
+        assert_eq!(login_lockout(3, now).delay(&config), Duration::from_millis(1000));
+        assert_eq!(login_lockout(10, now).delay(&config), Duration::from_millis(4000));
+        assert_eq!(login_lockout(100, now).delay(&config), Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn no_delay_after_window() {
+        let config = LoginLockouts::mock_config();
+        let last_failed_on = Utc::now() - chrono::Duration::seconds(config.window_seconds + 1);
+
+        assert_eq!(login_lockout(3, last_failed_on).delay(&config), Duration::ZERO);
+    }
+
+    #[test]
+    fn lock_expires() {
+        let mut login_lockout = login_lockout(5, Utc::now());
+        assert!(!login_lockout.is_locked());
+
+        login_lockout.locked_until = Some(Utc::now() + chrono::Duration::seconds(60));
+        assert!(login_lockout.is_locked());
+
+        login_lockout.locked_until = Some(Utc::now() - chrono::Duration::seconds(1));
+        assert!(!login_lockout.is_locked());
+    }
+}
@@ -0,0 +1,93 @@
+//-- ./src/database/login_lockouts/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Login Lockouts from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::{LockoutScope, LoginLockouts};
+
+impl LoginLockouts {
+    /// Get the Login Lockout for an account or client IP address, returning
+    /// None if there have been no failed attempts.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope` - What the failed attempts are counted against
+    /// * `subject` - The user id or client IP address
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read Login Lockout from the database: ",
+        skip(database)
+    )]
+    pub async fn from_subject(
+        scope: LockoutScope,
+        subject: &str,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Self>, BackendError> {
+        let database_record = sqlx::query_as!(
+            LoginLockouts,
+            r#"
+                SELECT *
+                FROM login_lockouts
+                WHERE scope = $1 AND subject = $2
+            "#,
+            scope.to_string(),
+            subject,
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!(
+            "Login Lockout database record retrieved: {:?}",
+            database_record.as_ref().map(|record| record.id)
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_login_lockout_by_subject(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let config = LoginLockouts::mock_config();
+        let login_lockout = LoginLockouts::record_failure(
+            LockoutScope::IpAddress,
+            "127.0.0.1",
+            &config,
+            &database,
+        )
+        .await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            LoginLockouts::from_subject(LockoutScope::IpAddress, "127.0.0.1", &database)
+                .await?;
+        let other_scope =
+            LoginLockouts::from_subject(LockoutScope::Account, "127.0.0.1", &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, Some(login_lockout));
+        assert_eq!(other_scope, None);
+
+        //-- Return
+        Ok(())
+    }
+}
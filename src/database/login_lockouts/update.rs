@@ -0,0 +1,172 @@
+//-- ./src/database/login_lockouts/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Record failed login attempts in the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::configuration::LockoutConfiguration;
+use crate::prelude::*;
+
+use super::{LockoutScope, LoginLockouts};
+
+impl LoginLockouts {
+    /// Record a failed login attempt against an account or client IP address,
+    /// returning the updated Login Lockout.
+    ///
+    /// The count restarts when the last failure is older than the window, and
+    /// the subject is locked for the configured duration once the count
+    /// reaches the scope threshold. The count and lock are updated in a single
+    /// query, so concurrent attempts are all counted.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope` - What the failed attempt is counted against
+    /// * `subject` - The user id or client IP address
+    /// * `config` - The failed login protection configuration
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Record a failed login attempt in the database: ",
+        skip(config, database)
+    )]
+    pub async fn record_failure(
+        scope: LockoutScope,
+        subject: &str,
+        config: &LockoutConfiguration,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            LoginLockouts,
+            r#"
+                INSERT INTO login_lockouts (
+                    id,
+                    scope,
+                    subject,
+                    failed_attempts,
+                    last_failed_on,
+                    locked_until,
+                    created_on
+                )
+                VALUES (
+                    $1,
+                    $2,
+                    $3,
+                    1,
+                    NOW(),
+                    CASE WHEN $5 <= 1 THEN NOW() + make_interval(secs => $6) END,
+                    NOW()
+                )
+                ON CONFLICT (scope, subject) DO UPDATE SET
+                    failed_attempts = CASE
+                        WHEN login_lockouts.last_failed_on > NOW() - make_interval(secs => $4)
+                            THEN login_lockouts.failed_attempts + 1
+                        ELSE 1
+                    END,
+                    last_failed_on = NOW(),
+                    locked_until = CASE
+                        WHEN login_lockouts.last_failed_on > NOW() - make_interval(secs => $4)
+                            AND login_lockouts.failed_attempts + 1 >= $5
+                            THEN NOW() + make_interval(secs => $6)
+                        WHEN $5 <= 1 THEN NOW() + make_interval(secs => $6)
+                        ELSE login_lockouts.locked_until
+                    END
+                RETURNING *
+            "#,
+            Uuid::now_v7(),
+            scope.to_string(),
+            subject,
+            config.window_seconds as f64,
+            scope.threshold(config),
+            config.duration_seconds as f64,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "Login Lockout failed attempts recorded: {}",
+            database_record.failed_attempts
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn failures_are_counted_until_locked(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = LoginLockouts::mock_config();
+        config.account_threshold = 3;
+        let subject = Uuid::now_v7().to_string();
+
+        //-- Execute Function (Act)
+        let first =
+            LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+                .await?;
+        let second =
+            LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+                .await?;
+        let third =
+            LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(first.failed_attempts, 1);
+        assert!(!first.is_locked());
+        assert_eq!(second.failed_attempts, 2);
+        assert!(!second.is_locked());
+        assert_eq!(third.failed_attempts, 3);
+        assert!(third.is_locked());
+        assert_eq!(third.id, first.id);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn count_restarts_after_window(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = LoginLockouts::mock_config();
+        config.account_threshold = 3;
+        let subject = Uuid::now_v7().to_string();
+
+        LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+            .await?;
+        LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+            .await?;
+
+        // Move the last failure outside the window
+        sqlx::query!(
+            "UPDATE login_lockouts SET last_failed_on = NOW() - make_interval(secs => $1)",
+            (config.window_seconds + 1) as f64,
+        )
+        .execute(&database)
+        .await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            LoginLockouts::record_failure(LockoutScope::Account, &subject, &config, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.failed_attempts, 1);
+        assert!(!database_record.is_locked());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -5,7 +5,9 @@
 //! Insert a Login into the database, returning a result with the Login Model
 //! ---
 
-use crate::prelude::BackendError;
+use std::net::IpAddr;
+
+use crate::{domain, prelude::BackendError};
 
 use super::Logins;
 
@@ -43,15 +45,17 @@ impl Logins {
                     id,
                     user_id,
                     login_on,
-                    login_ip
+                    login_ip,
+                    outcome
                 )
-                VALUES ($1, $2, $3, $4)
-                RETURNING *
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING id, user_id, login_on, login_ip AS "login_ip: IpAddr", outcome AS "outcome: domain::LoginOutcome"
             "#,
             self.id,
             self.user_id,
             self.login_on,
-            self.login_ip,
+            self.login_ip as Option<IpAddr>,
+            self.outcome as domain::LoginOutcome,
         )
         .fetch_one(database)
         .await?;
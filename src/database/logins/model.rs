@@ -5,43 +5,45 @@
 //! The Logins database model
 //! ---
 
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 
 use chrono::{DateTime, SubsecRound, Utc};
 use uuid::Uuid;
 
+use crate::domain;
+
 #[derive(Debug, Clone, Default, PartialEq, sqlx::FromRow, serde::Deserialize)]
 pub struct Logins {
     pub id: Uuid,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     pub login_on: DateTime<Utc>,
-    pub login_ip: Option<i32>,
+    pub login_ip: Option<IpAddr>,
+    pub outcome: domain::LoginOutcome,
 }
 
 impl Logins {
-    // pub fn login_ip(&self) -> Option<Ipv4Addr> {
-    //     match self.login_ip {
-    //         Some(x) => Some(self.login_ip.into()),
-    //         None => None,
-    //     }
-    // }
-    pub fn new(user_id: &Uuid, login_ip: Option<Ipv4Addr>) -> Self {
+    /// Create a new successful Login instance
+    pub fn new(user_id: &Uuid, login_ip: Option<IpAddr>) -> Self {
+        Self::new_attempt(Some(user_id), login_ip, domain::LoginOutcome::Success)
+    }
+
+    /// Create a new Login instance recording a login attempt and its outcome,
+    /// without a user if the email address is not registered
+    pub fn new_attempt(
+        user_id: Option<&Uuid>,
+        login_ip: Option<IpAddr>,
+        outcome: domain::LoginOutcome,
+    ) -> Self {
         let id = Uuid::now_v7();
-        let user_id = user_id.to_owned();
+        let user_id = user_id.copied();
         let login_on = Utc::now().round_subsecs(0);
-        let login_ip = match login_ip {
-            Some(ip_address) => {
-                let ip_address = u32::from(ip_address.to_owned()) as i32;
-                Some(ip_address)
-            }
-            None => None,
-        };
 
         Logins {
             id,
             user_id,
             login_on,
             login_ip,
+            outcome,
         }
     }
 
@@ -50,8 +52,10 @@ impl Logins {
         use crate::utils;
         use chrono::SubsecRound;
         use fake::faker::chrono::en::DateTime;
-        use fake::faker::internet::en::IPv4;
+        use fake::faker::boolean::en::Boolean;
+        use fake::faker::internet::en::{IPv4, IPv6};
         use fake::Fake;
+        use std::net::{Ipv4Addr, Ipv6Addr};
 
         // Generate random Uuid V7
         let random_id = utils::mock_uuid();
@@ -59,16 +63,18 @@ impl Logins {
         let random_login_on: DateTime<Utc> = DateTime().fake();
         // Adjust order of accuracy to be consistent with Postgres
         let random_login_on = random_login_on.round_subsecs(0);
-        // Generate random IPV4 address
-        let random_ip: Ipv4Addr = IPv4().fake();
-        // Convert IPV4 to an i32 to be consistent with Postgres INT type
-        let random_ip = u32::from(random_ip) as i32;
+        // Generate a random IPV4 or IPV6 address
+        let random_ip: IpAddr = match Boolean(50).fake() {
+            true => IpAddr::V4(IPv4().fake::<Ipv4Addr>()),
+            false => IpAddr::V6(IPv6().fake::<Ipv6Addr>()),
+        };
 
         Ok(Logins {
             id: random_id,
-            user_id: user_id.to_owned(),
+            user_id: Some(user_id.to_owned()),
             login_on: random_login_on,
             login_ip: Some(random_ip),
+            outcome: domain::LoginOutcome::Success,
         })
     }
 }
@@ -76,7 +82,10 @@ impl Logins {
 //-- Unit Tests
 #[cfg(test)]
 mod tests {
-    use fake::{faker::internet::en::IPv4, Fake};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use fake::faker::internet::en::{IPv4, IPv6};
+    use fake::Fake;
     use sqlx::{Pool, Postgres};
     use tracing_subscriber::registry::Data;
 
@@ -93,15 +102,54 @@ mod tests {
     fn create_new_login() -> Result<()> {
         //-- Setup and Fixtures (Arrange)
         let random_user = database::Users::mock_data()?;
-        let random_login_ip: Ipv4Addr = IPv4().fake();
-        let random_login_ip = Some(random_login_ip);
+        let random_login_ip = IpAddr::V4(IPv4().fake::<Ipv4Addr>());
         
         //-- Execute Function (Act)
-        let database_record = database::Logins::new(&random_user.id, random_login_ip);
+        let database_record = database::Logins::new(&random_user.id, Some(random_login_ip));
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.user_id, Some(random_user.id));
+        assert_eq!(database_record.login_ip, Some(random_login_ip));
+        assert_eq!(database_record.outcome, domain::LoginOutcome::Success);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn create_new_login_ipv6() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let random_login_ip = IpAddr::V6(IPv6().fake::<Ipv6Addr>());
+
+        //-- Execute Function (Act)
+        let database_record = database::Logins::new(&random_user.id, Some(random_login_ip));
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.login_ip, Some(random_login_ip));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn create_new_login_attempt() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+
+        //-- Execute Function (Act)
+        let database_record = database::Logins::new_attempt(
+            Some(&random_user.id),
+            None,
+            domain::LoginOutcome::BadPassword,
+        );
+        let unknown_email =
+            database::Logins::new_attempt(None, None, domain::LoginOutcome::UnknownEmail);
 
         //-- Checks (Assertions)
-        assert_eq!(database_record.user_id, random_user.id);
-        assert_eq!(database_record.login_ip.unwrap(), u32::from(random_login_ip.unwrap()) as i32);
+        assert_eq!(database_record.user_id, Some(random_user.id));
+        assert_eq!(database_record.outcome, domain::LoginOutcome::BadPassword);
+        assert_eq!(unknown_email.user_id, None);
 
         //-- Return
         Ok(())
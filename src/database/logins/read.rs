@@ -5,9 +5,11 @@
 //! Read Logins from the database
 //! ---
 
+use std::net::IpAddr;
+
 use uuid::Uuid;
 
-use crate::error::BackendError;
+use crate::{domain, error::BackendError};
 
 use super::Logins;
 
@@ -34,7 +36,7 @@ impl Logins {
         let database_record = sqlx::query_as!(
             Logins,
             r#"
-                SELECT *
+                SELECT id, user_id, login_on, login_ip AS "login_ip: IpAddr", outcome AS "outcome: domain::LoginOutcome"
                 FROM logins
                 WHERE id = $1
             "#,
@@ -72,7 +74,7 @@ impl Logins {
         let database_records = sqlx::query_as!(
             Logins,
             r#"
-					SELECT *
+					SELECT id, user_id, login_on, login_ip AS "login_ip: IpAddr", outcome AS "outcome: domain::LoginOutcome"
 					FROM logins
                     WHERE user_id = $1
 					ORDER BY id
@@ -90,12 +92,14 @@ impl Logins {
         Ok(database_records)
     }
 
-    /// Get an index of Logins, returning a vector of Logins
+    /// Get an index of Logins, optionally only those with an outcome, returning
+    /// a vector of Logins
     ///
     /// # Parameters
     ///
     /// * `limit` - An i64 limiting the page length
     /// * `offset` - An i64 of where the limit should start
+    /// * `outcome` - Only return Logins with this outcome, or all if None
     /// * `database` - An sqlx database pool that the things will be searched in.
     /// ---
     #[tracing::instrument(
@@ -105,18 +109,21 @@ impl Logins {
     pub async fn index(
         limit: &i64,
         offset: &i64,
+        outcome: Option<domain::LoginOutcome>,
         database: &sqlx::Pool<sqlx::Postgres>,
     ) -> Result<Vec<Logins>, BackendError> {
         let database_records = sqlx::query_as!(
             Logins,
             r#"
-					SELECT *
+					SELECT id, user_id, login_on, login_ip AS "login_ip: IpAddr", outcome AS "outcome: domain::LoginOutcome"
 					FROM logins
+					WHERE ($3::login_outcome IS NULL OR outcome = $3)
 					ORDER BY id
 					LIMIT $1 OFFSET $2
 				"#,
             limit,
             offset,
+            outcome as Option<domain::LoginOutcome>,
         )
             .fetch_all(database)
             .await?;
@@ -226,6 +233,7 @@ mod tests {
         let database_records = Logins::index(
             &random_limit,
             &random_offset,
+            None,
             &database,
         )
             .await?;
@@ -243,4 +251,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn read_logins_index_by_outcome(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let _database_record = random_user.insert(&database).await?;
+
+        Logins::mock_data(&random_user.id)?.insert(&database).await?;
+        Logins::new_attempt(None, None, domain::LoginOutcome::UnknownEmail)
+            .insert(&database)
+            .await?;
+        Logins::new_attempt(Some(&random_user.id), None, domain::LoginOutcome::BadPassword)
+            .insert(&database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let database_records = Logins::index(
+            &10,
+            &0,
+            Some(domain::LoginOutcome::UnknownEmail),
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), 1);
+        assert_eq!(database_records[0].outcome, domain::LoginOutcome::UnknownEmail);
+        assert_eq!(database_records[0].user_id, None);
+
+        Ok(())
+    }
 }
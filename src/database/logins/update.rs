@@ -5,9 +5,11 @@
 //! Update Login in the database
 //! ---
 
+use std::net::IpAddr;
+
 use uuid::Uuid;
 
-use crate::error::BackendError;
+use crate::{domain, error::BackendError};
 
 use super::Logins;
 
@@ -28,14 +30,15 @@ impl Logins {
             Logins,
             r#"
 				UPDATE logins
-				SET user_id = $2, login_on = $3, login_ip = $4
+				SET user_id = $2, login_on = $3, login_ip = $4, outcome = $5
 				WHERE id = $1
-				RETURNING *
+				RETURNING id, user_id, login_on, login_ip AS "login_ip: IpAddr", outcome AS "outcome: domain::LoginOutcome"
 			"#,
             self.id,
             self.user_id,
             self.login_on,
-            self.login_ip,
+            self.login_ip as Option<IpAddr>,
+            self.outcome as domain::LoginOutcome,
         )
             .fetch_one(database)
             .await?;
@@ -75,6 +78,7 @@ mod tests {
         let random_login_update = Logins::mock_data(&random_user.id)?;
         random_login.login_on = random_login_update.login_on;
         random_login.login_ip = random_login_update.login_ip;
+        random_login.outcome = domain::LoginOutcome::MfaFailed;
         let database_record = random_login.update(&database).await?;
         // println!("{database_record:#?}");
 
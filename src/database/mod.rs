@@ -7,15 +7,57 @@
 use sqlx::{postgres::PgPoolOptions, PgPool};
 
 // Reexport for cleaner code
+pub use api_keys::ApiKeys;
+pub use email_changes::{EmailChanges, EMAIL_CHANGE_DURATION, EMAIL_CHANGE_THROTTLE};
+pub use email_verifications::{
+    EmailVerifications, EMAIL_VERIFICATION_DURATION, EMAIL_VERIFICATION_THROTTLE,
+};
+pub use login_lockouts::{LockoutScope, LoginLockouts};
+pub use oidc_authorization_codes::OidcAuthorizationCodes;
+pub use oidc_clients::OidcClients;
+pub use organisation_invitations::{
+    OrganisationInvitations, ORGANISATION_INVITATION_DURATION,
+};
+pub use organisation_members::OrganisationMembers;
+pub use organisations::Organisations;
+pub use password_resets::{PasswordResets, PASSWORD_RESET_DURATION};
+pub use recovery_codes::RecoveryCodes;
+pub use revoked_tokens::{RevocationScope, RevokedTokens};
+pub use roles::Roles;
+pub use service_accounts::ServiceAccounts;
 pub use sessions::Sessions;
+pub use signing_keys::SigningKeys;
+pub use totp_secrets::TotpSecrets;
+pub use user_roles::UserRoles;
 pub use users::Users;
+pub use webauthn_challenges::{WebAuthnChallenges, WEBAUTHN_CHALLENGE_DURATION};
+pub use webauthn_credentials::WebAuthnCredentials;
 pub use logins::Logins;
 
 use crate::{configuration::DatabaseConfiguration, prelude::*};
 
+mod api_keys;
+mod email_changes;
+mod email_verifications;
+mod login_lockouts;
 mod logins;
+mod oidc_authorization_codes;
+mod oidc_clients;
+mod organisation_invitations;
+mod organisation_members;
+mod organisations;
+mod password_resets;
+mod recovery_codes;
+mod revoked_tokens;
+mod roles;
+mod service_accounts;
 mod sessions;
+mod signing_keys;
+mod totp_secrets;
+mod user_roles;
 mod users;
+mod webauthn_challenges;
+mod webauthn_credentials;
 
 pub async fn init_pool(
     database_configuration: &DatabaseConfiguration,
@@ -0,0 +1,115 @@
+//-- ./src/database/oidc_authorization_codes/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an OIDC Authorization Code into the database, returning a result with
+//! the OIDC Authorization Codes Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::OidcAuthorizationCodes;
+
+impl OidcAuthorizationCodes {
+    /// Insert an Authorization Code into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Authorization Code instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new OIDC Authorization Code into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            client_id = % self.client_id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OidcAuthorizationCodes,
+            r#"
+                INSERT INTO oidc_authorization_codes (
+                    id,
+                    client_id,
+                    user_id,
+                    code_hash,
+                    redirect_uri,
+                    scope,
+                    nonce,
+                    code_challenge,
+                    expires_on,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                RETURNING *
+            "#,
+            self.id,
+            self.client_id,
+            self.user_id,
+            self.code_hash,
+            self.redirect_uri,
+            self.scope,
+            self.nonce,
+            self.code_challenge,
+            self.expires_on,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "OIDC Authorization Code database record inserted: {}",
+            database_record.id
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_oidc_client, _client_secret) = database::OidcClients::mock_data(false)?;
+        random_oidc_client.insert(&database).await?;
+
+        let (random_authorization_code, _random_code) =
+            OidcAuthorizationCodes::mock_data(&random_oidc_client.id, &random_user.id)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_authorization_code.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_authorization_code);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Wrapper around OIDC Authorization Codes database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::OidcAuthorizationCodes;
+
+mod insert;
+mod model;
+mod update;
@@ -0,0 +1,100 @@
+//-- ./src/database/oidc_authorization_codes/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The OIDC Authorization Codes database model
+//!
+//! Codes are issued by the authorize endpoint and exchanged once at the token
+//! endpoint. Only the hash of the code is stored, along with the PKCE code
+//! challenge the token request must prove.
+//! ---
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::domain;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct OidcAuthorizationCodes {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub redirect_uri: String,
+    pub scope: String,
+    pub nonce: Option<String>,
+    pub code_challenge: String,
+    pub expires_on: DateTime<Utc>,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl OidcAuthorizationCodes {
+    /// Create a new Authorization Code instance, storing only the keyed hash
+    /// of the code.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The OIDC Client the code was issued to
+    /// * `user_id` - The user that authorized the client
+    /// * `code` - The code that will be sent to the redirect URI
+    /// * `hash_key` - The configured token hash key
+    /// * `redirect_uri` - The redirect URI the token request must match
+    /// * `scope` - The scopes granted
+    /// * `nonce` - The nonce to include in the ID Token
+    /// * `code_challenge` - The S256 PKCE code challenge
+    /// * `duration` - Seconds until the code expires
+    /// ---
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_id: &Uuid,
+        user_id: &Uuid,
+        code: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        redirect_uri: &str,
+        scope: &str,
+        nonce: Option<String>,
+        code_challenge: &str,
+        duration: i64,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let created_on = Utc::now().round_subsecs(0);
+        let expires_on = created_on + Duration::seconds(duration);
+
+        Self {
+            id,
+            client_id: client_id.to_owned(),
+            user_id: user_id.to_owned(),
+            code_hash: code.keyed_hash(hash_key),
+            redirect_uri: redirect_uri.to_owned(),
+            scope: scope.to_owned(),
+            nonce,
+            code_challenge: code_challenge.to_owned(),
+            expires_on,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        client_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(Self, domain::OneTimeToken), crate::prelude::BackendError> {
+        let random_code = domain::OneTimeToken::generate();
+        let authorization_code = Self::new(
+            client_id,
+            user_id,
+            &random_code,
+            &domain::OneTimeToken::mock_hash_key(),
+            "https://client.example.com/callback",
+            "openid profile email",
+            Some(Uuid::now_v7().to_string()),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+            60,
+        );
+
+        Ok((authorization_code, random_code))
+    }
+}
@@ -0,0 +1,126 @@
+//-- ./src/database/oidc_authorization_codes/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update OIDC Authorization Codes in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+
+use crate::{domain, prelude::*};
+
+use super::OidcAuthorizationCodes;
+
+impl OidcAuthorizationCodes {
+    /// Redeem (mark as used) the unused and unexpired Authorization Code,
+    /// returning the Authorization Code or an sqlx RowNotFound error if the
+    /// code is unknown, used or expired.
+    ///
+    /// The update is done in a single query so a code cannot be redeemed twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `code` - The code sent to the client redirect URI
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem an OIDC Authorization Code in the database: ",
+        skip_all
+    )]
+    pub async fn redeem(
+        code: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OidcAuthorizationCodes,
+            r#"
+                UPDATE oidc_authorization_codes
+                SET used_on = NOW()
+                WHERE code_hash = $1 AND used_on IS NULL AND expires_on > NOW()
+                RETURNING *
+            "#,
+            code.keyed_hash(hash_key),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "OIDC Authorization Code database record redeemed: {}",
+            database_record.id
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn redeem_code_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_oidc_client, _client_secret) = database::OidcClients::mock_data(false)?;
+        random_oidc_client.insert(&database).await?;
+
+        let (random_authorization_code, random_code) =
+            OidcAuthorizationCodes::mock_data(&random_oidc_client.id, &random_user.id)?;
+        random_authorization_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            OidcAuthorizationCodes::redeem(&random_code, &hash_key, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.id, random_authorization_code.id);
+        assert!(database_record.used_on.is_some());
+
+        // The code cannot be redeemed a second time
+        assert!(OidcAuthorizationCodes::redeem(&random_code, &hash_key, &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn expired_code_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_oidc_client, _client_secret) = database::OidcClients::mock_data(false)?;
+        random_oidc_client.insert(&database).await?;
+
+        let (mut random_authorization_code, random_code) =
+            OidcAuthorizationCodes::mock_data(&random_oidc_client.id, &random_user.id)?;
+        random_authorization_code.expires_on = Utc::now() - Duration::seconds(1);
+        random_authorization_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let result = OidcAuthorizationCodes::redeem(&random_code, &hash_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert!(result.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
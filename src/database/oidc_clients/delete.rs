@@ -0,0 +1,79 @@
+//-- ./src/database/oidc_clients/delete.rs
+
+//! Delete OIDC Clients in the database, returning a Result with an u64 of the
+//! number of rows affected.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::OidcClients;
+
+impl OidcClients {
+    /// Delete an OIDC Client from the database by the client id, along with
+    /// its outstanding Authorization Codes, returning the number of rows deleted.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The client id of the OIDC Client to be deleted
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Delete OIDC Client from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_by_id(
+        id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM oidc_clients
+                WHERE id = $1
+            "#,
+            id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("OIDC Client database records deleted: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_oidc_client(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_oidc_client, _client_secret) = OidcClients::mock_data(true)?;
+        random_oidc_client.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            OidcClients::delete_by_id(&random_oidc_client.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        assert!(OidcClients::from_id(&random_oidc_client.id, &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+//-- ./src/database/oidc_clients/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an OIDC Client into the database, returning a result with the OIDC
+//! Clients Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::OidcClients;
+
+impl OidcClients {
+    /// Insert an OIDC Client into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The OIDC Client instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new OIDC Client into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            name = % self.name,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OidcClients,
+            r#"
+                INSERT INTO oidc_clients (
+                    id,
+                    name,
+                    client_secret_hash,
+                    redirect_uris,
+                    is_active,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.client_secret_hash,
+            &self.redirect_uris,
+            self.is_active,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("OIDC Client database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_oidc_client, _client_secret) = OidcClients::mock_data(true)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_oidc_client.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_oidc_client);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Wrapper around OIDC Clients database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::OidcClients;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
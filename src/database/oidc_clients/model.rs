@@ -0,0 +1,150 @@
+//-- ./src/database/oidc_clients/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The OIDC Clients database model
+//!
+//! Registered OpenID Connect relying parties, identified by the client id. Only
+//! the keyed hash of a confidential clients secret is stored, and public
+//! clients without a secret must prove the authorization request with PKCE.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::domain;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct OidcClients {
+    pub id: Uuid,
+    pub name: String,
+    pub client_secret_hash: Option<String>,
+    pub redirect_uris: Vec<String>,
+    pub is_active: bool,
+    pub created_on: DateTime<Utc>,
+}
+
+impl OidcClients {
+    /// Create a new OIDC Client instance, storing only the keyed hash of the
+    /// client secret.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the client shown to users
+    /// * `redirect_uris` - The exact URIs authorization responses can be sent to
+    /// * `client_secret` - The secret of a confidential client, or `None` for a
+    ///   public client
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        name: &str,
+        redirect_uris: Vec<String>,
+        client_secret: Option<&domain::OneTimeToken>,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let name = name.to_owned();
+        let client_secret_hash = client_secret.map(|client_secret| client_secret.keyed_hash(hash_key));
+        let is_active = true;
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            name,
+            client_secret_hash,
+            redirect_uris,
+            is_active,
+            created_on,
+        }
+    }
+
+    /// Confidential clients have a secret they must authenticate with
+    pub fn is_confidential(&self) -> bool {
+        self.client_secret_hash.is_some()
+    }
+
+    /// Redirect URIs must exactly match one registered for the client
+    pub fn has_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+
+    /// Check the client secret presented to the token endpoint. Public clients
+    /// have no secret to check.
+    pub fn verify_secret(&self, client_secret: Option<&str>, hash_key: &Secret<String>) -> bool {
+        match (&self.client_secret_hash, client_secret) {
+            (None, _) => true,
+            (Some(client_secret_hash), Some(client_secret)) => {
+                &domain::OneTimeToken::from(client_secret.to_owned()).keyed_hash(hash_key)
+                    == client_secret_hash
+            }
+            (Some(_), None) => false,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        is_confidential: bool,
+    ) -> Result<(Self, Option<domain::OneTimeToken>), crate::prelude::BackendError> {
+        use fake::faker::company::en::CompanyName;
+        use fake::Fake;
+
+        let name: String = CompanyName().fake();
+        let redirect_uris = vec![format!("https://{}.example.com/callback", Uuid::now_v7())];
+        let client_secret = is_confidential.then(domain::OneTimeToken::generate);
+        let oidc_client = Self::new(
+            &name,
+            redirect_uris,
+            client_secret.as_ref(),
+            &domain::OneTimeToken::mock_hash_key(),
+        );
+
+        Ok((oidc_client, client_secret))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn confidential_client_verifies_secret() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+        let (oidc_client, client_secret) = OidcClients::mock_data(true)?;
+        let client_secret = client_secret.unwrap();
+
+        //-- Checks (Assertions)
+        assert!(oidc_client.is_confidential());
+        assert!(oidc_client.verify_secret(Some(client_secret.as_ref()), &hash_key));
+        assert!(!oidc_client.verify_secret(Some(client_secret.as_ref()), &other_hash_key));
+        assert!(!oidc_client.verify_secret(Some("not-the-secret"), &hash_key));
+        assert!(!oidc_client.verify_secret(None, &hash_key));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn public_client_matches_redirect_uri() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let (oidc_client, client_secret) = OidcClients::mock_data(false)?;
+
+        //-- Checks (Assertions)
+        assert!(client_secret.is_none());
+        assert!(!oidc_client.is_confidential());
+        assert!(oidc_client.verify_secret(None, &hash_key));
+        assert!(oidc_client.has_redirect_uri(&oidc_client.redirect_uris[0]));
+        assert!(!oidc_client.has_redirect_uri("https://attacker.example.com/callback"));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//-- ./src/database/oidc_clients/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read OIDC Clients from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::OidcClients;
+
+impl OidcClients {
+    /// Get an OIDC Client from the database by the client id, returning the
+    /// OIDC Client or an sqlx RowNotFound error if the client is not registered.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The client id of the OIDC Client
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Get an OIDC Client from the database: ",
+        skip(database)
+    )]
+    pub async fn from_id(
+        id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OidcClients,
+            r#"
+                SELECT *
+                FROM oidc_clients
+                WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("OIDC Client database record retrieved: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Get an index of OIDC Clients from the database
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - A i64 limiting the page length
+    /// * `offset` - A i64 of where the limit should start
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Index of OIDC Clients with offset and limit: ",
+        skip(database)
+    )]
+    pub async fn index(
+        limit: &i64,
+        offset: &i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            OidcClients,
+            r#"
+                SELECT *
+                FROM oidc_clients
+                ORDER BY id
+                LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("OIDC Client database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_oidc_client_by_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_oidc_client, _client_secret) = OidcClients::mock_data(false)?;
+        random_oidc_client.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            OidcClients::from_id(&random_oidc_client.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_oidc_client);
+        assert!(OidcClients::from_id(&Uuid::now_v7(), &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_oidc_clients(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        for is_confidential in [true, false, true] {
+            let (random_oidc_client, _client_secret) =
+                OidcClients::mock_data(is_confidential)?;
+            random_oidc_client.insert(&database).await?;
+        }
+
+        //-- Execute Function (Act)
+        let database_records = OidcClients::index(&2, &0, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), 2);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//-- ./src/database/organisation_invitations/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an Organisation Invitation into the database, returning a result
+//! with the Organisation Invitations Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::{domain, prelude::*};
+
+use super::OrganisationInvitations;
+
+impl OrganisationInvitations {
+    /// Insert an Organisation Invitation into the database, returning the
+    /// database instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Organisation Invitation instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Organisation Invitation into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            organisation_id = % self.organisation_id,
+        ),
+    )]
+    pub async fn insert(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OrganisationInvitations,
+            r#"
+                INSERT INTO organisation_invitations (
+                    id,
+                    organisation_id,
+                    email,
+                    role,
+                    token_hash,
+                    expires_on,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, organisation_id, email, role AS "role: domain::OrganisationRole", token_hash, expires_on, used_on, created_on
+            "#,
+            self.id,
+            self.organisation_id,
+            self.email.as_ref(),
+            self.role as domain::OrganisationRole,
+            self.token_hash,
+            self.expires_on,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "Organisation Invitation database record inserted: {}",
+            database_record.id
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let (random_invitation, _random_token) =
+            OrganisationInvitations::mock_data(&random_organisation.id)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_invitation.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_invitation);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Wrapper around Organisation Invitations database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{OrganisationInvitations, ORGANISATION_INVITATION_DURATION};
+
+mod insert;
+mod model;
+mod update;
@@ -0,0 +1,87 @@
+//-- ./src/database/organisation_invitations/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Organisation Invitations database model
+//!
+//! An invitation to join an Organisation sent to an email address. It is
+//! accepted with the One Time Token sent to the address, either by the user
+//! registered with it or when registering a new user with it. Only the hash of
+//! the token is stored.
+//! ---
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::domain;
+
+pub static ORGANISATION_INVITATION_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days as seconds
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct OrganisationInvitations {
+    pub id: Uuid,
+    pub organisation_id: Uuid,
+    pub email: domain::EmailAddress,
+    pub role: domain::OrganisationRole,
+    pub token_hash: String,
+    pub expires_on: DateTime<Utc>,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl OrganisationInvitations {
+    /// Create a new Organisation Invitation instance, storing only the keyed hash
+    /// of the One Time Token.
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The Organisation the invitation is to join
+    /// * `email` - The email address the token is emailed to
+    /// * `role` - The role the user will hold in the Organisation
+    /// * `token` - The One Time Token that will be emailed to the address
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        organisation_id: &Uuid,
+        email: &domain::EmailAddress,
+        role: domain::OrganisationRole,
+        token: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let organisation_id = organisation_id.to_owned();
+        let email = email.to_owned();
+        let token_hash = token.keyed_hash(hash_key);
+        let created_on = Utc::now().round_subsecs(0);
+        let expires_on = created_on + Duration::seconds(ORGANISATION_INVITATION_DURATION);
+
+        Self {
+            id,
+            organisation_id,
+            email,
+            role,
+            token_hash,
+            expires_on,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        organisation_id: &Uuid,
+    ) -> Result<(Self, domain::OneTimeToken), crate::prelude::BackendError> {
+        let random_email = domain::EmailAddress::mock_data()?;
+        let random_token = domain::OneTimeToken::generate();
+        let invitation = Self::new(
+            organisation_id,
+            &random_email,
+            domain::OrganisationRole::Member,
+            &random_token,
+            &domain::OneTimeToken::mock_hash_key(),
+        );
+
+        Ok((invitation, random_token))
+    }
+}
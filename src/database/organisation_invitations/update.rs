@@ -0,0 +1,176 @@
+//-- ./src/database/organisation_invitations/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update Organisation Invitations in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+
+use crate::{domain, prelude::*};
+
+use super::OrganisationInvitations;
+
+impl OrganisationInvitations {
+    /// Redeem (mark as used) the unused and unexpired Organisation Invitation
+    /// for the One Time Token and email address, returning the Organisation
+    /// Invitation or an sqlx RowNotFound error if the token is unknown, used,
+    /// expired or was sent to another email address.
+    ///
+    /// The update is done in a single query so a token cannot be redeemed twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - The One Time Token sent to the email address
+    /// * `hash_key` - The configured token hash key
+    /// * `email` - The email address of the user accepting the invitation
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem an Organisation Invitation in the database: ",
+        skip_all
+    )]
+    pub async fn redeem(
+        token: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        email: &domain::EmailAddress,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OrganisationInvitations,
+            r#"
+                UPDATE organisation_invitations
+                SET used_on = NOW()
+                WHERE token_hash = $1 AND email = $2 AND used_on IS NULL AND expires_on > NOW()
+                RETURNING id, organisation_id, email, role AS "role: domain::OrganisationRole", token_hash, expires_on, used_on, created_on
+            "#,
+            token.keyed_hash(hash_key),
+            email.as_ref(),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "Organisation Invitation database record redeemed: {}",
+            database_record.id
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn redeem_token_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let (random_invitation, random_token) =
+            OrganisationInvitations::mock_data(&random_organisation.id)?;
+        random_invitation.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = OrganisationInvitations::redeem(
+            &random_token,
+            &hash_key,
+            &random_invitation.email,
+            &database,
+        )
+        .await?;
+        let second_redeem = OrganisationInvitations::redeem(
+            &random_token,
+            &hash_key,
+            &random_invitation.email,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.id, random_invitation.id);
+        assert!(database_record.used_on.is_some());
+        assert!(second_redeem.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn token_for_other_email_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let (random_invitation, random_token) =
+            OrganisationInvitations::mock_data(&random_organisation.id)?;
+        random_invitation.insert(&database).await?;
+        let other_email = domain::EmailAddress::mock_data()?;
+
+        //-- Execute Function (Act)
+        let other_redeem = OrganisationInvitations::redeem(
+            &random_token,
+            &hash_key,
+            &other_email,
+            &database,
+        )
+        .await;
+        let database_record = OrganisationInvitations::redeem(
+            &random_token,
+            &hash_key,
+            &random_invitation.email,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert!(other_redeem.is_err());
+        assert!(database_record.is_ok());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn expired_token_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let (mut random_invitation, random_token) =
+            OrganisationInvitations::mock_data(&random_organisation.id)?;
+        random_invitation.expires_on = Utc::now() - Duration::seconds(1);
+        random_invitation.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = OrganisationInvitations::redeem(
+            &random_token,
+            &hash_key,
+            &random_invitation.email,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+//-- ./src/database/organisation_members/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete Organisation Members from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::OrganisationMembers;
+
+impl OrganisationMembers {
+    /// Remove a user from an Organisation, returning the number of rows
+    /// deleted. The last owner of an Organisation is not removed, so every
+    /// Organisation keeps an owner to manage its members, and
+    /// `BackendError::LastOrganisationOwner` is returned instead.
+    ///
+    /// The owner rows of the Organisation are locked with `FOR UPDATE` before
+    /// the owner check and delete, so two owners removing each other at the
+    /// same time are done one after the other and the second is refused.
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The Organisation the user is removed from
+    /// * `user_id` - The user being removed
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Delete an Organisation Member from the database: ",
+        skip(database)
+    )]
+    pub async fn delete(
+        organisation_id: &Uuid,
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let mut transaction = database.begin().await?;
+
+        let owners = sqlx::query!(
+            r#"
+                SELECT user_id
+                FROM organisation_members
+                WHERE organisation_id = $1 AND role = 'owner'
+                FOR UPDATE
+            "#,
+            organisation_id,
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        tracing::debug!("Organisation owners locked: {}", owners.len());
+
+        if owners.len() == 1 && owners[0].user_id == *user_id {
+            tracing::error!("Last owner cannot be removed from: {organisation_id}");
+            return Err(BackendError::LastOrganisationOwner);
+        }
+
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE FROM organisation_members
+                WHERE organisation_id = $1 AND user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+
+        transaction.commit().await?;
+
+        tracing::debug!("Organisation Members deleted: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::{database, domain};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn last_owner_is_not_removed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let owner = database::Users::mock_data()?;
+        owner.insert(&database).await?;
+        OrganisationMembers::new(
+            &random_organisation.id,
+            &owner.id,
+            domain::OrganisationRole::Owner,
+        )
+        .insert(&database)
+        .await?;
+
+        let member = database::Users::mock_data()?;
+        member.insert(&database).await?;
+        OrganisationMembers::new(
+            &random_organisation.id,
+            &member.id,
+            domain::OrganisationRole::Member,
+        )
+        .insert(&database)
+        .await?;
+
+        //-- Execute Function (Act)
+        let owner_removed =
+            OrganisationMembers::delete(&random_organisation.id, &owner.id, &database).await;
+        let member_removed =
+            OrganisationMembers::delete(&random_organisation.id, &member.id, &database).await?;
+        let members =
+            OrganisationMembers::index_organisation_id(&random_organisation.id, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert!(matches!(owner_removed, Err(BackendError::LastOrganisationOwner)));
+        assert_eq!(member_removed, 1);
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user_id, owner.id);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//-- ./src/database/organisation_members/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an Organisation Member into the database, returning a result with
+//! the Organisation Members Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::{domain, prelude::*};
+
+use super::OrganisationMembers;
+
+impl OrganisationMembers {
+    /// Add a user to an Organisation, returning the database instance. Adding
+    /// a user that is already a member returns the existing membership, so an
+    /// invitation cannot change the role of a member.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Organisation Member instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Organisation Member into the database: ",
+        skip(self, database),
+        fields(
+            organisation_id = % self.organisation_id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            OrganisationMembers,
+            r#"
+                INSERT INTO organisation_members (organisation_id, user_id, role, created_on)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (organisation_id, user_id)
+                    DO UPDATE SET created_on = organisation_members.created_on
+                RETURNING organisation_id, user_id, role AS "role: domain::OrganisationRole", created_on
+            "#,
+            self.organisation_id,
+            self.user_id,
+            self.role as domain::OrganisationRole,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "Organisation Member database record inserted: {}",
+            database_record.user_id
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn adding_twice_keeps_first_membership(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+        let member = OrganisationMembers::new(
+            &random_organisation.id,
+            &random_user.id,
+            domain::OrganisationRole::Owner,
+        );
+
+        //-- Execute Function (Act)
+        let database_record = member.insert(&database).await?;
+        let readded = OrganisationMembers::new(
+            &random_organisation.id,
+            &random_user.id,
+            domain::OrganisationRole::Viewer,
+        )
+        .insert(&database)
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, member);
+        assert_eq!(readded, member);
+
+        //-- Return
+        Ok(())
+    }
+}
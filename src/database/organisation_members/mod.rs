@@ -0,0 +1,10 @@
+//! Wrapper around Organisation Members database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::OrganisationMembers;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
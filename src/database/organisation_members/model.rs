@@ -0,0 +1,41 @@
+//-- ./src/database/organisation_members/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Organisation Members database model
+//!
+//! Adds a user to an Organisation, with the role they hold in it.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::domain;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct OrganisationMembers {
+    pub organisation_id: Uuid,
+    pub user_id: Uuid,
+    pub role: domain::OrganisationRole,
+    pub created_on: DateTime<Utc>,
+}
+
+impl OrganisationMembers {
+    /// Create a new Organisation Member instance adding the user to the
+    /// Organisation
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The Organisation the user is added to
+    /// * `user_id` - The user being added
+    /// * `role` - The role the user holds in the Organisation
+    /// ---
+    pub fn new(organisation_id: &Uuid, user_id: &Uuid, role: domain::OrganisationRole) -> Self {
+        Self {
+            organisation_id: organisation_id.to_owned(),
+            user_id: user_id.to_owned(),
+            role,
+            created_on: Utc::now().round_subsecs(0),
+        }
+    }
+}
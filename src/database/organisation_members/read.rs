@@ -0,0 +1,246 @@
+//-- ./src/database/organisation_members/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Organisation Members from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{database, domain, prelude::*};
+
+use super::OrganisationMembers;
+
+impl OrganisationMembers {
+    /// Get the membership of a user in an Organisation, returning None if the
+    /// user is not a member.
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The uuid of the Organisation.
+    /// * `user_id` - The uuid of the user.
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read an Organisation Member from the database: ",
+        skip(database)
+    )]
+    pub async fn from_ids(
+        organisation_id: &Uuid,
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Self>, BackendError> {
+        let database_record = sqlx::query_as!(
+            OrganisationMembers,
+            r#"
+                SELECT organisation_id, user_id, role AS "role: domain::OrganisationRole", created_on
+                FROM organisation_members
+                WHERE organisation_id = $1 AND user_id = $2
+            "#,
+            organisation_id,
+            user_id,
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!("Organisation Member database record retrieved: {database_record:#?}");
+
+        Ok(database_record)
+    }
+
+    /// Get the members of an Organisation, oldest first
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The uuid of the Organisation.
+    /// * `database` - An sqlx database pool that the things will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index Organisation Members from the database: ",
+        skip(database)
+    )]
+    pub async fn index_organisation_id(
+        organisation_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            OrganisationMembers,
+            r#"
+                SELECT organisation_id, user_id, role AS "role: domain::OrganisationRole", created_on
+                FROM organisation_members
+                WHERE organisation_id = $1
+                ORDER BY created_on, user_id
+            "#,
+            organisation_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Organisation Member database records retrieved: {database_records:#?}");
+
+        Ok(database_records)
+    }
+
+    /// Get the members of an Organisation with their user, oldest first. The
+    /// users are joined in the same query rather than read one at a time.
+    ///
+    /// # Parameters
+    ///
+    /// * `organisation_id` - The uuid of the Organisation.
+    /// * `database` - An sqlx database pool that the things will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index Organisation Members and their users from the database: ",
+        skip(database)
+    )]
+    pub async fn index_organisation_id_with_users(
+        organisation_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<(Self, database::Users)>, BackendError> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT
+                    organisation_members.organisation_id,
+                    organisation_members.role AS "member_role: domain::OrganisationRole",
+                    organisation_members.created_on AS member_created_on,
+                    users.id,
+                    users.email,
+                    users.name,
+                    users.password_hash,
+                    users.role AS "role: domain::UserRole",
+                    users.is_active,
+                    users.is_verified,
+                    users.is_pending,
+                    users.created_on
+                FROM organisation_members
+                INNER JOIN users ON users.id = organisation_members.user_id
+                WHERE organisation_members.organisation_id = $1
+                ORDER BY organisation_members.created_on, organisation_members.user_id
+            "#,
+            organisation_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        let database_records: Vec<(Self, database::Users)> = rows
+            .into_iter()
+            .map(|row| {
+                let member = OrganisationMembers {
+                    organisation_id: row.organisation_id,
+                    user_id: row.id,
+                    role: row.member_role,
+                    created_on: row.member_created_on,
+                };
+                let user = database::Users {
+                    id: row.id,
+                    email: row.email.into(),
+                    name: row.name.into(),
+                    password_hash: row.password_hash.into(),
+                    role: row.role,
+                    is_active: row.is_active,
+                    is_verified: row.is_verified,
+                    is_pending: row.is_pending,
+                    created_on: row.created_on,
+                };
+                (member, user)
+            })
+            .collect();
+
+        tracing::debug!("Organisation Member database records retrieved: {database_records:#?}");
+
+        Ok(database_records)
+    }
+
+    /// Get the memberships of a user, one for each Organisation they belong to
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The uuid of the user.
+    /// * `database` - An sqlx database pool that the things will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index the Organisation Members of a user from the database: ",
+        skip(database)
+    )]
+    pub async fn index_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            OrganisationMembers,
+            r#"
+                SELECT organisation_id, user_id, role AS "role: domain::OrganisationRole", created_on
+                FROM organisation_members
+                WHERE user_id = $1
+                ORDER BY created_on, organisation_id
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Organisation Member database records retrieved: {database_records:#?}");
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn read_organisation_members(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_organisation = database::Organisations::mock_data()?;
+        random_organisation.insert(&database).await?;
+
+        let owner = database::Users::mock_data()?;
+        owner.insert(&database).await?;
+        let owner_member = OrganisationMembers::new(
+            &random_organisation.id,
+            &owner.id,
+            domain::OrganisationRole::Owner,
+        )
+        .insert(&database)
+        .await?;
+
+        // Add a user that is not a member
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let member =
+            OrganisationMembers::from_ids(&random_organisation.id, &owner.id, &database).await?;
+        let not_member =
+            OrganisationMembers::from_ids(&random_organisation.id, &other_user.id, &database)
+                .await?;
+        let members =
+            OrganisationMembers::index_organisation_id(&random_organisation.id, &database)
+                .await?;
+        let memberships = OrganisationMembers::index_user_id(&owner.id, &database).await?;
+        let members_with_users = OrganisationMembers::index_organisation_id_with_users(
+            &random_organisation.id,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(member, Some(owner_member.clone()));
+        assert_eq!(not_member, None);
+        assert_eq!(members, vec![owner_member.clone()]);
+        assert_eq!(memberships, vec![owner_member.clone()]);
+        assert_eq!(members_with_users, vec![(owner_member, owner)]);
+
+        //-- Return
+        Ok(())
+    }
+}
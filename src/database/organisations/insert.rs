@@ -0,0 +1,177 @@
+//-- ./src/database/organisations/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert an Organisation into the database, returning a result with the
+//! Organisations Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{database, domain, prelude::*};
+
+use super::Organisations;
+
+impl Organisations {
+    /// Insert an Organisation into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Organisation instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Organisation into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn insert(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            Organisations,
+            r#"
+                INSERT INTO organisations (id, name, created_on)
+                VALUES ($1, $2, $3)
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Organisation database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Insert an Organisation into the database with the user as its owner,
+    /// returning the database instances created.
+    ///
+    /// The inserts are done in a single transaction, so an Organisation is
+    /// never left without an owner to manage its members.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Organisation instance to be inserted in the database.
+    /// * `owner_id` - The user that owns the Organisation
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Organisation and its owner into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn insert_with_owner(
+        &self,
+        owner_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<(Self, database::OrganisationMembers), BackendError> {
+        let mut transaction = database.begin().await?;
+
+        let database_record = sqlx::query_as!(
+            Organisations,
+            r#"
+                INSERT INTO organisations (id, name, created_on)
+                VALUES ($1, $2, $3)
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.created_on,
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        let owner = database::OrganisationMembers::new(
+            &database_record.id,
+            owner_id,
+            domain::OrganisationRole::Owner,
+        );
+        let owner = sqlx::query_as!(
+            database::OrganisationMembers,
+            r#"
+                INSERT INTO organisation_members (organisation_id, user_id, role, created_on)
+                VALUES ($1, $2, $3, $4)
+                RETURNING organisation_id, user_id, role AS "role: domain::OrganisationRole", created_on
+            "#,
+            owner.organisation_id,
+            owner.user_id,
+            owner.role as domain::OrganisationRole,
+            owner.created_on,
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        tracing::debug!("Organisation database record inserted: {}", database_record.id);
+
+        Ok((database_record, owner))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_organisation = Organisations::mock_data()?;
+
+        //-- Execute Function (Act)
+        let database_record = random_organisation.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_organisation);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn create_database_record_with_owner(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let random_organisation = Organisations::mock_data()?;
+
+        // An owner that does not exist rolls back the Organisation
+        let missing_user = database::Users::mock_data()?;
+
+        //-- Execute Function (Act)
+        let rolled_back = random_organisation
+            .insert_with_owner(&missing_user.id, &database)
+            .await;
+        let missing_organisation = Organisations::from_id(&random_organisation.id, &database).await;
+        let (database_record, owner) = random_organisation
+            .insert_with_owner(&random_user.id, &database)
+            .await?;
+
+        //-- Checks (Assertions)
+        assert!(rolled_back.is_err());
+        assert!(missing_organisation.is_err());
+        assert_eq!(database_record, random_organisation);
+        assert_eq!(owner.organisation_id, random_organisation.id);
+        assert_eq!(owner.user_id, random_user.id);
+        assert_eq!(owner.role, domain::OrganisationRole::Owner);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Wrapper around Organisations database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::Organisations;
+
+mod insert;
+mod model;
+mod read;
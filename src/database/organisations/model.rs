@@ -0,0 +1,47 @@
+//-- ./src/database/organisations/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Organisations database model
+//!
+//! Organisations, such as a household, group users that share records. Users
+//! belong to an organisation through their Organisation Members record.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct Organisations {
+    pub id: Uuid,
+    pub name: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl Organisations {
+    /// Create a new Organisation instance
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the organisation, such as the household name
+    /// ---
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            name: name.to_owned(),
+            created_on: Utc::now().round_subsecs(0),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data() -> Result<Self, BackendError> {
+        use fake::faker::company::en::CompanyName;
+        use fake::Fake;
+
+        let random_name: String = CompanyName().fake();
+
+        Ok(Self::new(&random_name))
+    }
+}
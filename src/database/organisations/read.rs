@@ -0,0 +1,123 @@
+//-- ./src/database/organisations/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Organisations from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::Organisations;
+
+impl Organisations {
+    /// Get an Organisation from the database by querying the uuid, returning
+    /// an sqlx RowNotFound error if it does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The uuid of the Organisation.
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(name = "Read an Organisation from the database: ", skip(database))]
+    pub async fn from_id(id: &Uuid, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            Organisations,
+            r#"
+                SELECT *
+                FROM organisations
+                WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Organisation database record retrieved: {database_record:#?}");
+
+        Ok(database_record)
+    }
+
+    /// Get the Organisations a user is a member of, oldest first
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The uuid of the member.
+    /// * `database` - An sqlx database pool that the things will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index the Organisations of a user from the database: ",
+        skip(database)
+    )]
+    pub async fn index_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            Organisations,
+            r#"
+                SELECT organisations.*
+                FROM organisations
+                INNER JOIN organisation_members
+                    ON organisation_members.organisation_id = organisations.id
+                WHERE organisation_members.user_id = $1
+                ORDER BY organisations.created_on, organisations.id
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Organisation database records retrieved: {database_records:#?}");
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::{database, domain};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn index_user_organisations(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let member_organisation = Organisations::mock_data()?;
+        member_organisation.insert(&database).await?;
+        database::OrganisationMembers::new(
+            &member_organisation.id,
+            &random_user.id,
+            domain::OrganisationRole::Viewer,
+        )
+        .insert(&database)
+        .await?;
+
+        // Add an organisation the user is not a member of
+        let other_organisation = Organisations::mock_data()?;
+        other_organisation.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = Organisations::from_id(&member_organisation.id, &database).await?;
+        let database_records = Organisations::index_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, member_organisation);
+        assert_eq!(database_records, vec![member_organisation]);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+//-- ./src/database/password_resets/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a Password Reset into the database, returning a result with the
+//! Password Resets Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::PasswordResets;
+
+impl PasswordResets {
+    /// Insert a Password Reset into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Password Reset instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Password Reset into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            PasswordResets,
+            r#"
+                INSERT INTO password_resets (
+                    id,
+                    user_id,
+                    token_hash,
+                    expires_on,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.token_hash,
+            self.expires_on,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Password Reset database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_password_reset, _random_token) =
+            PasswordResets::mock_data(&random_user.id)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_password_reset.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_password_reset);
+
+        //-- Return
+        Ok(())
+    }
+}
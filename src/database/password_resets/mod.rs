@@ -0,0 +1,9 @@
+//! Wrapper around Password Resets database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{PasswordResets, PASSWORD_RESET_DURATION};
+
+mod insert;
+mod model;
+mod update;
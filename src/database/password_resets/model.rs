@@ -0,0 +1,99 @@
+//-- ./src/database/password_resets/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Password Resets database model
+//! ---
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::domain;
+
+pub static PASSWORD_RESET_DURATION: i64 = 60 * 60; // 1 hour as seconds
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct PasswordResets {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_on: DateTime<Utc>,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl PasswordResets {
+    /// Create a new Password Reset instance for the user, storing only the
+    /// hash of the One Time Token.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the password reset is for
+    /// * `token` - The One Time Token that will be sent to the user
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        user_id: &Uuid,
+        token: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user_id.to_owned();
+        let token_hash = token.keyed_hash(hash_key);
+        let created_on = Utc::now().round_subsecs(0);
+        let expires_on = created_on + Duration::seconds(PASSWORD_RESET_DURATION);
+
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_on,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user_id: &Uuid,
+    ) -> Result<(Self, domain::OneTimeToken), crate::prelude::BackendError> {
+        let random_token = domain::OneTimeToken::generate();
+        let password_reset =
+            Self::new(user_id, &random_token, &domain::OneTimeToken::mock_hash_key());
+
+        Ok((password_reset, random_token))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_password_reset() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let random_token = domain::OneTimeToken::generate();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let password_reset = PasswordResets::new(&random_user.id, &random_token, &hash_key);
+
+        //-- Checks (Assertions)
+        assert_eq!(password_reset.user_id, random_user.id);
+        assert_eq!(password_reset.token_hash, random_token.keyed_hash(&hash_key));
+        assert_eq!(password_reset.used_on, None);
+        assert!(password_reset.expires_on > password_reset.created_on);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//-- ./src/database/password_resets/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update Password Resets in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::PasswordResets;
+
+impl PasswordResets {
+    /// Redeem (mark as used) the unused and unexpired Password Reset for the
+    /// One Time Token, returning the Password Reset or an sqlx RowNotFound
+    /// error if the token is unknown, used or expired.
+    ///
+    /// The update is done in a single query so a token cannot be redeemed twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - The One Time Token sent to the user
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem a Password Reset in the database: ",
+        skip_all
+    )]
+    pub async fn redeem(
+        token: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            PasswordResets,
+            r#"
+                UPDATE password_resets
+                SET used_on = NOW()
+                WHERE token_hash = $1 AND used_on IS NULL AND expires_on > NOW()
+                RETURNING *
+            "#,
+            token.keyed_hash(hash_key),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Password Reset database record redeemed: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Revoke (mark as used) all unused Password Resets for a user, returning
+    /// the number of rows revoked.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user_id for the Password Resets to be revoked
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke all Password Resets for a user in the database: ",
+        skip(database)
+    )]
+    pub async fn revoke_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE password_resets
+                SET used_on = NOW()
+                WHERE user_id = $1 AND used_on IS NULL
+            "#,
+            user_id,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Password Reset database records revoked: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn redeem_token_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_password_reset, random_token) =
+            PasswordResets::mock_data(&random_user.id)?;
+        random_password_reset.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = PasswordResets::redeem(&random_token, &hash_key, &database).await?;
+        let second_redeem = PasswordResets::redeem(&random_token, &hash_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.id, random_password_reset.id);
+        assert!(database_record.used_on.is_some());
+        assert!(second_redeem.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn expired_token_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (mut random_password_reset, random_token) =
+            PasswordResets::mock_data(&random_user.id)?;
+        random_password_reset.expires_on = Utc::now() - Duration::seconds(1);
+        random_password_reset.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = PasswordResets::redeem(&random_token, &hash_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn revoke_user_password_resets(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_password_reset, random_token) =
+            PasswordResets::mock_data(&random_user.id)?;
+        random_password_reset.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            PasswordResets::revoke_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        assert!(PasswordResets::redeem(&random_token, &hash_key, &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+//-- ./src/database/recovery_codes/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete MFA Recovery Codes in the database, returning a Result with an u64
+//! of the number of rows affected.
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::RecoveryCodes;
+
+impl RecoveryCodes {
+    /// Delete the unused Recovery Codes for a user, keeping used codes as an
+    /// audit record, returning a Result with the number of rows deleted or a
+    /// sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Recovery Codes belong to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Delete unused Recovery Codes from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_unused_by_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM recovery_codes
+                WHERE user_id = $1 AND used_on IS NULL
+            "#,
+            user_id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Recovery Code database records deleted: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_unused_codes_for_user(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (unused_recovery_code, _unused_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        unused_recovery_code.insert(&database).await?;
+
+        let (mut used_recovery_code, _used_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        used_recovery_code.used_on = Some(used_recovery_code.created_on);
+        used_recovery_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            RecoveryCodes::delete_unused_by_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        assert_eq!(
+            RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?,
+            0
+        );
+
+        //-- Return
+        Ok(())
+    }
+}
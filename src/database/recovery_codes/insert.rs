@@ -0,0 +1,217 @@
+//-- ./src/database/recovery_codes/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert MFA Recovery Codes into the database, returning a result with the
+//! Recovery Codes Model
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::RecoveryCodes;
+
+impl RecoveryCodes {
+    /// Insert a Recovery Code into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Recovery Code instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Recovery Code into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            RecoveryCodes,
+            r#"
+                INSERT INTO recovery_codes (
+                    id,
+                    user_id,
+                    code_hash,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.code_hash,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Recovery Code database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Replace the unused Recovery Codes for a user with a new set, returning
+    /// the database instances created. Used codes are kept as an audit record.
+    ///
+    /// The delete and inserts are done in a single transaction, so the user is
+    /// never left with a partial set of codes.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Recovery Codes are for
+    /// * `codes` - The new Recovery Codes that will be shown to the user
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Replace the Recovery Codes for a user in the database: ",
+        skip(codes, hash_key, database)
+    )]
+    pub async fn replace_user_id(
+        user_id: &Uuid,
+        codes: &[domain::RecoveryCode],
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let mut transaction = database.begin().await?;
+
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM recovery_codes
+                WHERE user_id = $1 AND used_on IS NULL
+            "#,
+            user_id,
+        )
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Unused Recovery Code database records deleted: {rows_affected:#?}");
+
+        let mut database_records = Vec::with_capacity(codes.len());
+        for code in codes {
+            let recovery_code = Self::new(user_id, code, hash_key);
+            let database_record = sqlx::query_as!(
+                RecoveryCodes,
+                r#"
+                    INSERT INTO recovery_codes (
+                        id,
+                        user_id,
+                        code_hash,
+                        used_on,
+                        created_on
+                    )
+                    VALUES ($1, $2, $3, $4, $5)
+                    RETURNING *
+                "#,
+                recovery_code.id,
+                recovery_code.user_id,
+                recovery_code.code_hash,
+                recovery_code.used_on,
+                recovery_code.created_on,
+            )
+            .fetch_one(&mut *transaction)
+            .await?;
+
+            database_records.push(database_record);
+        }
+
+        transaction.commit().await?;
+
+        tracing::debug!("Recovery Code database records inserted: {}", database_records.len());
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_recovery_code, _random_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_recovery_code.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_recovery_code);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn replace_keeps_used_codes(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (unused_recovery_code, unused_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        unused_recovery_code.insert(&database).await?;
+
+        let (mut used_recovery_code, _used_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        used_recovery_code.used_on = Some(used_recovery_code.created_on);
+        used_recovery_code.insert(&database).await?;
+
+        let new_codes = domain::RecoveryCode::generate_set();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let database_records =
+            RecoveryCodes::replace_user_id(&random_user.id, &new_codes, &hash_key, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), new_codes.len());
+        assert_eq!(
+            RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?,
+            new_codes.len() as i64
+        );
+        assert!(RecoveryCodes::redeem(&random_user.id, &unused_code, &hash_key, &database)
+            .await
+            .is_err());
+
+        let used_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM recovery_codes WHERE user_id = $1 AND used_on IS NOT NULL",
+        )
+        .bind(random_user.id)
+        .fetch_one(&database)
+        .await?;
+        assert_eq!(used_count, 1);
+
+        //-- Return
+        Ok(())
+    }
+}
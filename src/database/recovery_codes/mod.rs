@@ -0,0 +1,11 @@
+//! Wrapper around MFA Recovery Codes database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::RecoveryCodes;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
@@ -0,0 +1,92 @@
+//-- ./src/database/recovery_codes/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The MFA Recovery Codes database model
+//!
+//! Each user has a set of single use codes, and used codes are kept as an
+//! audit record of when they were consumed.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::domain;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct RecoveryCodes {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl RecoveryCodes {
+    /// Create a new, unused, Recovery Code instance for the user, storing only
+    /// the hash of the code.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Recovery Code is for
+    /// * `code` - The Recovery Code that will be shown to the user
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(user_id: &Uuid, code: &domain::RecoveryCode, hash_key: &Secret<String>) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user_id.to_owned();
+        let code_hash = code.hash(hash_key);
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            user_id,
+            code_hash,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user_id: &Uuid,
+    ) -> Result<(Self, domain::RecoveryCode), crate::prelude::BackendError> {
+        let random_code = domain::RecoveryCode::generate();
+        let recovery_code =
+            Self::new(user_id, &random_code, &domain::OneTimeToken::mock_hash_key());
+
+        Ok((recovery_code, random_code))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_recovery_code() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let random_code = domain::RecoveryCode::generate();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let recovery_code = RecoveryCodes::new(&random_user.id, &random_code, &hash_key);
+
+        //-- Checks (Assertions)
+        assert_eq!(recovery_code.user_id, random_user.id);
+        assert_eq!(recovery_code.code_hash, random_code.hash(&hash_key));
+        assert_eq!(recovery_code.used_on, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//-- ./src/database/recovery_codes/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read MFA Recovery Codes from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::RecoveryCodes;
+
+impl RecoveryCodes {
+    /// Count the unused Recovery Codes remaining for a user
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Recovery Codes belong to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Count unused Recovery Codes in the database: ",
+        skip(database)
+    )]
+    pub async fn count_unused_by_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<i64, BackendError> {
+        let count = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM recovery_codes
+                WHERE user_id = $1 AND used_on IS NULL
+            "#,
+            user_id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Unused Recovery Codes remaining: {count}");
+
+        Ok(count)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn count_only_unused_codes(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (unused_recovery_code, _unused_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        unused_recovery_code.insert(&database).await?;
+
+        let (mut used_recovery_code, _used_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        used_recovery_code.used_on = Some(used_recovery_code.created_on);
+        used_recovery_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let count = RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(count, 1);
+
+        //-- Return
+        Ok(())
+    }
+}
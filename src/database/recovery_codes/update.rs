@@ -0,0 +1,122 @@
+//-- ./src/database/recovery_codes/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update MFA Recovery Codes in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::RecoveryCodes;
+
+impl RecoveryCodes {
+    /// Redeem (mark as used) the users unused Recovery Code, returning the
+    /// Recovery Code or an sqlx RowNotFound error if the code is unknown or
+    /// already used.
+    ///
+    /// The update is done in a single query so a code cannot be redeemed twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user completing the MFA challenge
+    /// * `code` - The Recovery Code provided by the user
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem a Recovery Code in the database: ",
+        skip(code, hash_key, database)
+    )]
+    pub async fn redeem(
+        user_id: &Uuid,
+        code: &domain::RecoveryCode,
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            RecoveryCodes,
+            r#"
+                UPDATE recovery_codes
+                SET used_on = NOW()
+                WHERE user_id = $1 AND code_hash = $2 AND used_on IS NULL
+                RETURNING *
+            "#,
+            user_id,
+            code.hash(hash_key),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Recovery Code database record redeemed: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn redeem_code_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        let (random_recovery_code, random_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        random_recovery_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            RecoveryCodes::redeem(&random_user.id, &random_code, &hash_key, &database).await?;
+        let second_redeem =
+            RecoveryCodes::redeem(&random_user.id, &random_code, &hash_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.id, random_recovery_code.id);
+        assert!(database_record.used_on.is_some());
+        assert!(second_redeem.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn code_is_not_redeemed_by_other_user(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        let (random_recovery_code, random_code) =
+            RecoveryCodes::mock_data(&random_user.id)?;
+        random_recovery_code.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            RecoveryCodes::redeem(&other_user.id, &random_code, &hash_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
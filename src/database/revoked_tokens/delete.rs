@@ -0,0 +1,84 @@
+//-- ./src/database/revoked_tokens/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete Revoked Tokens in the database, returning a Result with an u64 of
+//! the number of rows affected.
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::RevokedTokens;
+
+impl RevokedTokens {
+    /// Delete the Revoked Tokens that have expired, as an expired token is
+    /// rejected without checking the revocation list, returning a Result with
+    /// the number of rows deleted or a sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Delete expired Revoked Tokens from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_expired(database: &Pool<Postgres>) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM revoked_tokens
+                WHERE expires_on <= NOW()
+            "#,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Revoked Token database records deleted: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_expired_tokens(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let mut expired_token = RevokedTokens::mock_data(&random_user.id)?;
+        expired_token.expires_on = Utc::now() - Duration::minutes(1);
+        expired_token.insert(&database).await?;
+
+        let revoked_token = RevokedTokens::mock_data(&random_user.id)?;
+        revoked_token.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected = RevokedTokens::delete_expired(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        let database_records = RevokedTokens::index_unexpired(&database).await?;
+        assert_eq!(database_records, vec![revoked_token]);
+
+        //-- Return
+        Ok(())
+    }
+}
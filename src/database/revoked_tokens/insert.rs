@@ -0,0 +1,234 @@
+//-- ./src/database/revoked_tokens/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert Revoked Tokens into the database
+//! ---
+
+use chrono::Utc;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::{RevocationScope, RevokedTokens};
+
+impl RevokedTokens {
+    /// Insert a Revoked Token into the database, returning the database record.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - A Revoked Tokens instance
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Revoked Token into the database: ",
+        skip(database)
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            RevokedTokens,
+            r#"
+                INSERT INTO revoked_tokens (id, user_id, expires_on, revoked_on)
+                VALUES ($1, $2, $3, $4)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.expires_on,
+            self.revoked_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Revoked Token database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Revoke the Access Tokens issued with the Sessions in scope that have not
+    /// yet expired, returning the newly Revoked Tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope` - Which Sessions to revoke the Access Tokens of
+    /// * `access_token_seconds` - Seconds an Access Token is valid for
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke Access Tokens in the database: ",
+        skip(database)
+    )]
+    pub async fn revoke_sessions(
+        scope: RevocationScope,
+        access_token_seconds: u64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let (session_id, family_id, user_id, other_than_family_id) = scope.filters();
+
+        let database_records = sqlx::query_as!(
+            RevokedTokens,
+            r#"
+                INSERT INTO revoked_tokens (id, user_id, expires_on, revoked_on)
+                SELECT access_token_id, user_id, created_on + make_interval(secs => $4), $5
+                FROM sessions
+                WHERE access_token_id IS NOT NULL
+                    AND created_on + make_interval(secs => $4) > $5
+                    AND ($1::uuid IS NULL OR id = $1)
+                    AND ($2::uuid IS NULL OR family_id = $2)
+                    AND ($3::uuid IS NULL OR user_id = $3)
+                    AND ($6::uuid IS NULL OR family_id <> $6)
+                ON CONFLICT (id) DO NOTHING
+                RETURNING id AS "id!", user_id, expires_on AS "expires_on!", revoked_on
+            "#,
+            session_id,
+            family_id,
+            user_id,
+            access_token_seconds as f64,
+            Utc::now(),
+            other_than_family_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Access Tokens revoked: {}", database_records.len());
+
+        Ok(database_records)
+    }
+
+    /// Revoke the Access Tokens issued to a Service Account that have not yet
+    /// expired, returning the newly Revoked Tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `service_account_id` - The Service Account to revoke the Access Tokens of
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke Service Account Access Tokens in the database: ",
+        skip(database)
+    )]
+    pub async fn revoke_service_account(
+        service_account_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            RevokedTokens,
+            r#"
+                INSERT INTO revoked_tokens (id, user_id, expires_on, revoked_on)
+                SELECT id, service_account_id, expires_on, $2
+                FROM service_account_tokens
+                WHERE service_account_id = $1
+                    AND expires_on > $2
+                ON CONFLICT (id) DO NOTHING
+                RETURNING *
+            "#,
+            service_account_id,
+            Utc::now(),
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Service Account Access Tokens revoked: {}", database_records.len());
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn revoke_sessions_in_scope(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+
+        // Sessions issued now, one long expired and one for another user
+        let mut session = database::Sessions::mock_data(&random_user).await?;
+        session.created_on = Utc::now();
+        let session = session.insert(&database).await?;
+
+        let mut expired_session = database::Sessions::mock_data(&random_user).await?;
+        expired_session.created_on = Utc::now() - Duration::hours(1);
+        expired_session.insert(&database).await?;
+
+        let mut other_session = database::Sessions::mock_data(&other_user).await?;
+        other_session.created_on = Utc::now();
+        other_session.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let revoked_tokens = RevokedTokens::revoke_sessions(
+            RevocationScope::User(random_user.id),
+            300,
+            &database,
+        )
+        .await?;
+
+        // Revoking again does not return the same tokens
+        let revoked_again = RevokedTokens::revoke_sessions(
+            RevocationScope::User(random_user.id),
+            300,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(revoked_tokens.len(), 1);
+        assert_eq!(Some(revoked_tokens[0].id), session.access_token_id);
+        assert_eq!(revoked_tokens[0].user_id, random_user.id);
+        assert_eq!(
+            revoked_tokens[0].expires_on,
+            session.created_on + Duration::seconds(300)
+        );
+        assert!(revoked_again.is_empty());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn revoke_service_account_tokens(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (service_account, _client_secret) = database::ServiceAccounts::mock_data()?;
+        let service_account = service_account.insert(&database).await?;
+
+        // One Access Token still valid and one long expired
+        let token_id = Uuid::now_v7();
+        let expires_on = Utc::now() + Duration::minutes(5);
+        service_account
+            .insert_access_token(&token_id, expires_on, &database)
+            .await?;
+        service_account
+            .insert_access_token(&Uuid::now_v7(), Utc::now() - Duration::hours(1), &database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let revoked_tokens =
+            RevokedTokens::revoke_service_account(&service_account.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(revoked_tokens.len(), 1);
+        assert_eq!(revoked_tokens[0].id, token_id);
+        assert_eq!(revoked_tokens[0].user_id, service_account.id);
+
+        //-- Return
+        Ok(())
+    }
+}
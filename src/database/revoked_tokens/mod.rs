@@ -0,0 +1,10 @@
+//! Wrapper around Revoked Tokens database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{RevocationScope, RevokedTokens};
+
+mod delete;
+mod insert;
+mod model;
+mod read;
@@ -0,0 +1,87 @@
+//-- ./src/database/revoked_tokens/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Revoked Tokens database model
+//!
+//! Access Tokens revoked before they expire, keyed by the token id (jti). Rows
+//! are only needed until the token expires, after which they are deleted.
+//! ---
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+/// Which Sessions to revoke the Access Tokens of
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum RevocationScope {
+    /// A single Session, by the Session id
+    Session(Uuid),
+    /// Every Session in a refresh token family, by the family id
+    Family(Uuid),
+    /// Every Session of a user, by the user id
+    User(Uuid),
+    /// Every Session of a user outside a refresh token family, by the user id
+    /// and family id, such as when signing out other devices
+    OtherFamilies(Uuid, Uuid),
+    /// Every Session in the database
+    All,
+}
+
+impl RevocationScope {
+    /// The Session, family, user and excluded family ids to filter Sessions
+    /// on, where `None` matches any Session
+    pub fn filters(&self) -> (Option<Uuid>, Option<Uuid>, Option<Uuid>, Option<Uuid>) {
+        match *self {
+            RevocationScope::Session(id) => (Some(id), None, None, None),
+            RevocationScope::Family(family_id) => (None, Some(family_id), None, None),
+            RevocationScope::User(user_id) => (None, None, Some(user_id), None),
+            RevocationScope::OtherFamilies(user_id, family_id) => {
+                (None, None, Some(user_id), Some(family_id))
+            }
+            RevocationScope::All => (None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct RevokedTokens {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub expires_on: DateTime<Utc>,
+    pub revoked_on: DateTime<Utc>,
+}
+
+impl RevokedTokens {
+    /// Create a new Revoked Token for an Access Token, revoked now
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The Access Token id (jti)
+    /// * `user_id` - The user the Access Token was issued to
+    /// * `expires_on` - When the Access Token expires
+    /// ---
+    pub fn new(id: &Uuid, user_id: &Uuid, expires_on: DateTime<Utc>) -> Self {
+        Self {
+            id: id.to_owned(),
+            user_id: user_id.to_owned(),
+            expires_on,
+            revoked_on: Utc::now(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(user_id: &Uuid) -> Result<Self, BackendError> {
+        use chrono::{Duration, SubsecRound};
+
+        let revoked_on = Utc::now().round_subsecs(0);
+
+        Ok(Self {
+            id: Uuid::now_v7(),
+            user_id: user_id.to_owned(),
+            expires_on: revoked_on + Duration::minutes(5),
+            revoked_on,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+//-- ./src/database/revoked_tokens/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Revoked Tokens from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::RevokedTokens;
+
+impl RevokedTokens {
+    /// Get the Revoked Tokens that have not yet expired.
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index unexpired Revoked Tokens from the database: ",
+        skip(database)
+    )]
+    pub async fn index_unexpired(
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            RevokedTokens,
+            r#"
+                SELECT *
+                FROM revoked_tokens
+                WHERE expires_on > NOW()
+                ORDER BY expires_on
+            "#,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!(
+            "Revoked Token database records retrieved: {}",
+            database_records.len()
+        );
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn index_unexpired_skips_expired_tokens(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let mut expired_token = RevokedTokens::mock_data(&random_user.id)?;
+        expired_token.expires_on = Utc::now() - Duration::minutes(1);
+        expired_token.insert(&database).await?;
+
+        let revoked_token = RevokedTokens::mock_data(&random_user.id)?;
+        revoked_token.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_records = RevokedTokens::index_unexpired(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records, vec![revoked_token]);
+
+        //-- Return
+        Ok(())
+    }
+}
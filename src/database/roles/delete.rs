@@ -0,0 +1,76 @@
+//-- ./src/database/roles/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete Roles from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::Roles;
+
+impl Roles {
+    /// Delete a custom Role from the database, unassigning it from every user,
+    /// returning the number of rows deleted. System roles are never deleted.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The Role id
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Delete a Role from the database: ", skip(database))]
+    pub async fn delete_by_id(id: &Uuid, database: &Pool<Postgres>) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE FROM roles
+                WHERE id = $1 AND NOT is_system
+            "#,
+            id,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Roles deleted: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_only_custom_roles(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_role = Roles::mock_data()?;
+        random_role.insert(&database).await?;
+        let system_role = Roles::index(&10, &0, &database)
+            .await?
+            .into_iter()
+            .find(|role| role.is_system)
+            .ok_or("System role not seeded")?;
+
+        //-- Execute Function (Act)
+        let custom = Roles::delete_by_id(&random_role.id, &database).await?;
+        let system = Roles::delete_by_id(&system_role.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(custom, 1);
+        assert_eq!(system, 0);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//-- ./src/database/roles/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a Role into the database, returning a result with the Roles Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::Roles;
+
+impl Roles {
+    /// Insert a Role into the database, returning the database instance
+    /// created, or a RoleAlreadyExists error if the name is taken.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Role instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Role into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            name = % self.name,
+        ),
+    )]
+    pub async fn insert(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            Roles,
+            r#"
+                INSERT INTO roles (
+                    id,
+                    name,
+                    description,
+                    permissions,
+                    is_system,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.description,
+            &self.permissions,
+            self.is_system,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await
+        .map_err(|error| match error {
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                BackendError::RoleAlreadyExists(self.name.to_owned())
+            }
+            _ => BackendError::Sqlx(error),
+        })?;
+
+        tracing::debug!("Role database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_role = Roles::mock_data()?;
+
+        //-- Execute Function (Act)
+        let database_record = random_role.insert(&database).await?;
+        let duplicate = random_role.insert(&database).await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_role);
+        assert!(matches!(duplicate, Err(BackendError::RoleAlreadyExists(_))));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Wrapper around Roles database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::Roles;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
@@ -0,0 +1,117 @@
+//-- ./src/database/roles/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Roles database model
+//!
+//! Roles are named sets of permissions assigned to users. The admin, user and
+//! guest system roles are seeded from the user role enum, and every user holds
+//! the system role named by their user role along with any roles assigned to
+//! them.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct Roles {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<String>,
+    pub is_system: bool,
+    pub created_on: DateTime<Utc>,
+}
+
+impl Roles {
+    /// Create a new custom Role instance
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The unique name of the role, such as bookkeeper
+    /// * `description` - What users holding the role can do
+    /// * `permissions` - The permissions granted to users holding the role
+    /// ---
+    pub fn new(name: &str, description: &str, permissions: &[domain::Permission]) -> Self {
+        let id = Uuid::now_v7();
+        let name = name.to_owned();
+        let description = description.to_owned();
+        let permissions = permissions
+            .iter()
+            .map(|permission| permission.to_string())
+            .collect();
+        let is_system = false;
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            name,
+            description,
+            permissions,
+            is_system,
+            created_on,
+        }
+    }
+
+    /// Parse the permissions granted by the role
+    pub fn granted_permissions(&self) -> Result<Vec<domain::Permission>, BackendError> {
+        self.permissions
+            .iter()
+            .map(|permission| domain::Permission::parse(permission))
+            .collect()
+    }
+
+    #[cfg(test)]
+    pub fn mock_data() -> Result<Self, BackendError> {
+        use fake::faker::lorem::en::{Sentence, Word};
+        use fake::Fake;
+
+        let word: String = Word().fake();
+        let name = format!("{word}-{}", Uuid::now_v7().simple());
+        let description: String = Sentence(3..6).fake();
+        let role = Self::new(
+            &name,
+            &description,
+            &[
+                domain::Permission::UsersReadAny,
+                domain::Permission::LoginsReadAny,
+            ],
+        );
+
+        Ok(role)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn role_parses_permissions() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut role = Roles::mock_data()?;
+
+        //-- Checks (Assertions)
+        assert!(!role.is_system);
+        assert_eq!(
+            role.granted_permissions()?,
+            vec![
+                domain::Permission::UsersReadAny,
+                domain::Permission::LoginsReadAny
+            ]
+        );
+
+        role.permissions.push("ledger:read:any".to_string());
+        assert!(role.granted_permissions().is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+//-- ./src/database/roles/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Roles from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::Roles;
+
+impl Roles {
+    /// Get a Role from the database by id, returning the Role or an sqlx
+    /// RowNotFound error if it does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The Role id
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Get a Role from the database: ", skip(database))]
+    pub async fn from_id(id: &Uuid, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            Roles,
+            r#"
+                SELECT *
+                FROM roles
+                WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Role database record retrieved: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Get an index of Roles from the database, ordered by name
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - A i64 limiting the page length
+    /// * `offset` - A i64 of where the limit should start
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Index of Roles with offset and limit: ", skip(database))]
+    pub async fn index(
+        limit: &i64,
+        offset: &i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            Roles,
+            r#"
+                SELECT *
+                FROM roles
+                ORDER BY name
+                LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Role database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+
+    /// Get the Roles assigned to a user, ordered by name. The system role of
+    /// the users role is held without being assigned, so is not included.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Roles are assigned to
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Index of Roles assigned to a user: ", skip(database))]
+    pub async fn index_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            Roles,
+            r#"
+                SELECT roles.*
+                FROM roles
+                INNER JOIN user_roles ON user_roles.role_id = roles.id
+                WHERE user_roles.user_id = $1
+                ORDER BY roles.name
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Role database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+
+    /// Get the permissions held by a user, from the system role of their user
+    /// role and the Roles assigned to them
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user holding the permissions
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Get the permissions of a user: ", skip(database))]
+    pub async fn permissions_for_user(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<domain::Permission>, BackendError> {
+        let permissions = sqlx::query_scalar!(
+            r#"
+                SELECT DISTINCT UNNEST(roles.permissions) AS "permission!"
+                FROM roles
+                WHERE roles.name = (SELECT users.role::TEXT FROM users WHERE users.id = $1)
+                    OR roles.id IN (
+                        SELECT user_roles.role_id FROM user_roles WHERE user_roles.user_id = $1
+                    )
+                ORDER BY 1
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("User permissions retrieved: {}", permissions.len());
+
+        permissions
+            .iter()
+            .map(|permission| domain::Permission::parse(permission))
+            .collect()
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_role_by_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_role = Roles::mock_data()?;
+        random_role.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = Roles::from_id(&random_role.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_role);
+        assert!(Roles::from_id(&Uuid::now_v7(), &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_includes_system_roles(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        Roles::mock_data()?.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_records = Roles::index(&10, &0, &database).await?;
+        let page = Roles::index(&2, &0, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), 4);
+        assert_eq!(
+            database_records
+                .iter()
+                .filter(|role| role.is_system)
+                .map(|role| role.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["admin", "guest", "user"]
+        );
+        assert_eq!(page.len(), 2);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn admin_role_holds_every_permission(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut random_user = database::Users::mock_data()?;
+        random_user.role = domain::UserRole::Admin;
+        random_user.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let mut permissions = Roles::permissions_for_user(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        permissions.sort();
+        assert_eq!(
+            permissions,
+            <domain::Permission as strum::IntoEnumIterator>::iter().collect::<Vec<_>>()
+        );
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn permissions_combine_user_role_and_assigned_roles(
+        database: Pool<Postgres>,
+    ) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut random_user = database::Users::mock_data()?;
+        random_user.role = domain::UserRole::Guest;
+        random_user.insert(&database).await?;
+        let random_role = Roles::mock_data()?;
+        random_role.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let guest_permissions = Roles::permissions_for_user(&random_user.id, &database).await?;
+        database::UserRoles::new(&random_user.id, &random_role.id)
+            .insert(&database)
+            .await?;
+        let assigned_roles = Roles::index_user_id(&random_user.id, &database).await?;
+        let assigned_permissions =
+            Roles::permissions_for_user(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(guest_permissions, vec![domain::Permission::UsersReadSelf]);
+        assert_eq!(assigned_roles, vec![random_role]);
+        assert_eq!(
+            assigned_permissions,
+            vec![
+                domain::Permission::LoginsReadAny,
+                domain::Permission::UsersReadAny,
+                domain::Permission::UsersReadSelf,
+            ]
+        );
+
+        //-- Return
+        Ok(())
+    }
+}
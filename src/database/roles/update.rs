@@ -0,0 +1,101 @@
+//-- ./src/database/roles/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update Roles in the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::Roles;
+
+impl Roles {
+    /// Update the name, description and permissions of a Role, returning the
+    /// updated Role, an sqlx RowNotFound error if it does not exist, or a
+    /// RoleAlreadyExists error if the name is taken.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Role instance to be updated in the database
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Update a Role in the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            name = % self.name,
+        ),
+    )]
+    pub async fn update(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            Roles,
+            r#"
+                UPDATE roles
+                SET name = $2, description = $3, permissions = $4
+                WHERE id = $1
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.description,
+            &self.permissions,
+        )
+        .fetch_one(database)
+        .await
+        .map_err(|error| match error {
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                BackendError::RoleAlreadyExists(self.name.to_owned())
+            }
+            _ => BackendError::Sqlx(error),
+        })?;
+
+        tracing::debug!("Role database record updated: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::domain;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn update_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut random_role = Roles::mock_data()?;
+        random_role.insert(&database).await?;
+        let other_role = Roles::mock_data()?;
+        other_role.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        random_role.description = "Read only access to every login".to_string();
+        random_role.permissions = vec![domain::Permission::LoginsReadAny.to_string()];
+        let database_record = random_role.update(&database).await?;
+
+        random_role.name = other_role.name.to_owned();
+        let duplicate = random_role.update(&database).await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.description, "Read only access to every login");
+        assert_eq!(
+            database_record.granted_permissions()?,
+            vec![domain::Permission::LoginsReadAny]
+        );
+        assert!(matches!(duplicate, Err(BackendError::RoleAlreadyExists(_))));
+
+        //-- Return
+        Ok(())
+    }
+}
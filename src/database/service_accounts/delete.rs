@@ -0,0 +1,46 @@
+//-- ./src/database/service_accounts/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete the expired Access Tokens issued to Service Accounts, returning a
+//! Result with an u64 of the number of rows affected.
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::ServiceAccounts;
+
+impl ServiceAccounts {
+    /// Delete the records of Service Account Access Tokens that have expired,
+    /// as they no longer need revoking, returning a Result with the number of
+    /// rows deleted or a sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Delete expired Service Account Access Tokens from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_expired_access_tokens(
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM service_account_tokens
+                WHERE expires_on <= NOW()
+            "#,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Service Account Access Tokens deleted: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
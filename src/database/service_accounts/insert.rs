@@ -0,0 +1,134 @@
+//-- ./src/database/service_accounts/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a Service Account into the database, returning a result with the
+//! Service Accounts Model
+//! ---
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::ServiceAccounts;
+
+impl ServiceAccounts {
+    /// Insert a Service Account into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Service Account instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Service Account into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            name = % self.name,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ServiceAccounts,
+            r#"
+                INSERT INTO service_accounts (
+                    id,
+                    name,
+                    client_secret_hash,
+                    scopes,
+                    is_active,
+                    secret_rotated_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING *
+            "#,
+            self.id,
+            self.name,
+            self.client_secret_hash,
+            &self.scopes,
+            self.is_active,
+            self.secret_rotated_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Service Account database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Record an Access Token issued to the Service Account, so it can be
+    /// revoked when the Service Account is disabled.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Service Account the Access Token was issued to
+    /// * `token_id` - The Access Token id (jti)
+    /// * `expires_on` - When the Access Token expires
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a Service Account Access Token into the database: ",
+        skip(self, database),
+        fields(service_account_id = % self.id)
+    )]
+    pub async fn insert_access_token(
+        &self,
+        token_id: &Uuid,
+        expires_on: DateTime<Utc>,
+        database: &Pool<Postgres>,
+    ) -> Result<(), BackendError> {
+        sqlx::query!(
+            r#"
+                INSERT INTO service_account_tokens (id, service_account_id, expires_on)
+                VALUES ($1, $2, $3)
+            "#,
+            token_id,
+            self.id,
+            expires_on,
+        )
+        .execute(database)
+        .await?;
+
+        tracing::debug!("Service Account Access Token inserted: {token_id}");
+
+        Ok(())
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_service_account, _client_secret) = ServiceAccounts::mock_data()?;
+
+        //-- Execute Function (Act)
+        let database_record = random_service_account.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_service_account);
+
+        //-- Return
+        Ok(())
+    }
+}
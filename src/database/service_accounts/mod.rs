@@ -0,0 +1,11 @@
+//! Wrapper around Service Accounts database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::ServiceAccounts;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
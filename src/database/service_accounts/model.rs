@@ -0,0 +1,123 @@
+//-- ./src/database/service_accounts/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Service Accounts database model
+//!
+//! Service accounts authenticate with their client id and secret using the
+//! client credentials grant, and are issued Access Tokens limited to the
+//! scopes they have been granted. Only the keyed hash of the client secret is
+//! stored.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct ServiceAccounts {
+    pub id: Uuid,
+    pub name: String,
+    pub client_secret_hash: String,
+    pub scopes: Vec<String>,
+    pub is_active: bool,
+    pub secret_rotated_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl ServiceAccounts {
+    /// Create a new Service Account instance, storing only the keyed hash of
+    /// the client secret.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the service account, such as the job using it
+    /// * `scopes` - The admin services the service account can call
+    /// * `client_secret` - The secret the service account authenticates with
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        name: &str,
+        scopes: &[domain::ServiceScope],
+        client_secret: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let name = name.to_owned();
+        let client_secret_hash = client_secret.keyed_hash(hash_key);
+        let scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        let is_active = true;
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            name,
+            client_secret_hash,
+            scopes,
+            is_active,
+            secret_rotated_on: None,
+            created_on,
+        }
+    }
+
+    /// Check the client secret presented with the client credentials grant
+    pub fn verify_secret(&self, client_secret: &str, hash_key: &Secret<String>) -> bool {
+        domain::OneTimeToken::from(client_secret.to_owned()).keyed_hash(hash_key)
+            == self.client_secret_hash
+    }
+
+    /// Parse the scopes granted to the service account
+    pub fn granted_scopes(&self) -> Result<Vec<domain::ServiceScope>, BackendError> {
+        domain::ServiceScope::parse_list(&self.scopes)
+    }
+
+    #[cfg(test)]
+    pub fn mock_data() -> Result<(Self, domain::OneTimeToken), BackendError> {
+        use fake::faker::company::en::CompanyName;
+        use fake::Fake;
+
+        let name: String = CompanyName().fake();
+        let client_secret = domain::OneTimeToken::generate();
+        let service_account = Self::new(
+            &name,
+            &[domain::ServiceScope::Logins, domain::ServiceScope::Users],
+            &client_secret,
+            &domain::OneTimeToken::mock_hash_key(),
+        );
+
+        Ok((service_account, client_secret))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn service_account_verifies_secret() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+        let (service_account, client_secret) = ServiceAccounts::mock_data()?;
+
+        //-- Checks (Assertions)
+        assert_ne!(service_account.client_secret_hash, client_secret.to_string());
+        assert!(service_account.verify_secret(client_secret.as_ref(), &hash_key));
+        assert!(!service_account.verify_secret(client_secret.as_ref(), &other_hash_key));
+        assert!(!service_account.verify_secret("not-the-secret", &hash_key));
+        assert_eq!(
+            service_account.granted_scopes()?,
+            vec![domain::ServiceScope::Logins, domain::ServiceScope::Users]
+        );
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//-- ./src/database/service_accounts/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Service Accounts from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::ServiceAccounts;
+
+impl ServiceAccounts {
+    /// Get a Service Account from the database by the client id, returning the
+    /// Service Account or an sqlx RowNotFound error if it does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The client id of the Service Account
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Get a Service Account from the database: ",
+        skip(database)
+    )]
+    pub async fn from_id(
+        id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ServiceAccounts,
+            r#"
+                SELECT *
+                FROM service_accounts
+                WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Service Account database record retrieved: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Get an index of Service Accounts from the database
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - A i64 limiting the page length
+    /// * `offset` - A i64 of where the limit should start
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Index of Service Accounts with offset and limit: ",
+        skip(database)
+    )]
+    pub async fn index(
+        limit: &i64,
+        offset: &i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            ServiceAccounts,
+            r#"
+                SELECT *
+                FROM service_accounts
+                ORDER BY id
+                LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!(
+            "Service Account database records retrieved: {}",
+            database_records.len()
+        );
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_service_account_by_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_service_account, _client_secret) = ServiceAccounts::mock_data()?;
+        random_service_account.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            ServiceAccounts::from_id(&random_service_account.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_service_account);
+        assert!(ServiceAccounts::from_id(&Uuid::now_v7(), &database).await.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_service_accounts(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        for _ in 0..3 {
+            let (random_service_account, _client_secret) = ServiceAccounts::mock_data()?;
+            random_service_account.insert(&database).await?;
+        }
+
+        //-- Execute Function (Act)
+        let database_records = ServiceAccounts::index(&2, &0, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), 2);
+
+        //-- Return
+        Ok(())
+    }
+}
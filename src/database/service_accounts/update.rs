@@ -0,0 +1,154 @@
+//-- ./src/database/service_accounts/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update Service Accounts in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domain, prelude::*};
+
+use super::ServiceAccounts;
+
+impl ServiceAccounts {
+    /// Replace the client secret of a Service Account, returning the updated
+    /// Service Account or an sqlx RowNotFound error if it does not exist. The
+    /// old secret stops working straight away.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The client id of the Service Account
+    /// * `client_secret` - The new client secret
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Rotate a Service Account secret in the database: ",
+        skip(client_secret, hash_key, database)
+    )]
+    pub async fn rotate_secret(
+        id: &Uuid,
+        client_secret: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ServiceAccounts,
+            r#"
+                UPDATE service_accounts
+                SET client_secret_hash = $2, secret_rotated_on = NOW()
+                WHERE id = $1
+                RETURNING *
+            "#,
+            id,
+            client_secret.keyed_hash(hash_key),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Service Account secret rotated: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Set whether a Service Account is active, returning the updated Service
+    /// Account or an sqlx RowNotFound error if it does not exist. Inactive
+    /// Service Accounts cannot be issued Access Tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The client id of the Service Account
+    /// * `is_active` - Whether the Service Account can be issued Access Tokens
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Update a Service Account active status in the database: ",
+        skip(database)
+    )]
+    pub async fn update_is_active(
+        id: &Uuid,
+        is_active: bool,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            ServiceAccounts,
+            r#"
+                UPDATE service_accounts
+                SET is_active = $2
+                WHERE id = $1
+                RETURNING *
+            "#,
+            id,
+            is_active,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!(
+            "Service Account active status updated: {} {}",
+            database_record.id,
+            database_record.is_active
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn rotated_secret_replaces_old_secret(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_service_account, old_client_secret) = ServiceAccounts::mock_data()?;
+        random_service_account.insert(&database).await?;
+        let new_client_secret = domain::OneTimeToken::generate();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let database_record = ServiceAccounts::rotate_secret(
+            &random_service_account.id,
+            &new_client_secret,
+            &hash_key,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert!(database_record.verify_secret(new_client_secret.as_ref(), &hash_key));
+        assert!(!database_record.verify_secret(old_client_secret.as_ref(), &hash_key));
+        assert!(database_record.secret_rotated_on.is_some());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn disable_service_account(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let (random_service_account, _client_secret) = ServiceAccounts::mock_data()?;
+        random_service_account.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            ServiceAccounts::update_is_active(&random_service_account.id, false, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert!(!database_record.is_active);
+
+        //-- Return
+        Ok(())
+    }
+}
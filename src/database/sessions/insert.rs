@@ -31,15 +31,19 @@ impl Sessions {
         let database_record = sqlx::query_as!(
             Sessions,
             r#"
-				INSERT INTO sessions (id, user_id, refresh_token, is_active, created_on)
-				VALUES ($1, $2, $3, $4, $5) 
+				INSERT INTO sessions (id, user_id, family_id, refresh_token_hash, is_active, created_on, access_token_id, organisation_id, oidc_client_id)
+				VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
 				RETURNING *
 			"#,
             self.id,
             self.user_id,
-            self.refresh_token.as_ref(),
+            self.family_id,
+            self.refresh_token_hash,
             self.is_active,
-            self.created_on
+            self.created_on,
+            self.access_token_id,
+            self.organisation_id,
+            self.oidc_client_id
         )
         .fetch_one(database)
         .await?;
@@ -1,6 +1,14 @@
 //-- ./src/database/sessions/model.rs
 
 //! The Sessions database model
+//!
+//! Sessions are grouped into refresh token families, one per login on a device.
+//! Each refresh revokes the Session and creates the next Session in the same
+//! family, so a family only ever has one active Session.
+//!
+//! Only a keyed hash of the Refresh Token is stored, so a database dump cannot
+//! be used to hijack a session. The id of the Access Token issued with the
+//! Session is stored, so revoking the Session can also revoke the Access Token.
 //! ---
 
 // #![allow(unused)] // For development only
@@ -15,30 +23,102 @@ use crate::{database, domain, prelude::BackendError};
 pub struct Sessions {
     pub id: Uuid,
     pub user_id: Uuid,
-    pub refresh_token: domain::RefreshToken,
+    pub family_id: Uuid,
+    pub refresh_token_hash: String,
     pub is_active: bool,
     pub created_on: DateTime<Utc>,
+    pub access_token_id: Option<Uuid>,
+    pub organisation_id: Option<Uuid>,
+    pub oidc_client_id: Option<Uuid>,
 }
 
 impl Sessions {
+    /// Create a new Session for the Refresh Token, starting a new refresh token
+    /// family
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - The user the Session belongs to
+    /// * `access_token` - The Access Token issued with the Session
+    /// * `refresh_token` - The Refresh Token issued for the Session
+    /// * `hash_key` - The configured token hash key
+    /// ---
     #[tracing::instrument(
         name = "Create new Sessions instance for: ",
         skip_all,
     )]
-    pub fn new(user: &database::Users, token_secret: &Secret<String>) -> Result<Self, BackendError> {
+    pub fn new(
+        user: &database::Users,
+        access_token: &domain::AccessToken,
+        refresh_token: &domain::RefreshToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
         let id = Uuid::now_v7();
         let user_id = user.id.to_owned();
-        let refresh_token = domain::RefreshToken::new(token_secret, user)?;
+        let family_id = id;
+        let refresh_token_hash = refresh_token.hash(hash_key);
         let is_active = true;
         let created_on = Utc::now();
+        let access_token_id = Some(access_token.token_id());
 
-        Ok(Self {
+        Self {
             id,
             user_id,
-            refresh_token,
+            family_id,
+            refresh_token_hash,
             is_active,
             created_on,
-        })
+            access_token_id,
+            organisation_id: None,
+            oidc_client_id: None,
+        }
+    }
+
+    /// Create the next Session in the refresh token family of self, for a new
+    /// Access Token and Refresh Token
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - The user the Session belongs to
+    /// * `access_token` - The new Access Token issued with the Session
+    /// * `refresh_token` - The new Refresh Token issued for the Session
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    #[tracing::instrument(
+        name = "Rotate Sessions instance for: ",
+        skip_all,
+    )]
+    pub fn rotate(
+        &self,
+        user: &database::Users,
+        access_token: &domain::AccessToken,
+        refresh_token: &domain::RefreshToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let session = Self::new(user, access_token, refresh_token, hash_key);
+
+        Self {
+            family_id: self.family_id,
+            organisation_id: self.organisation_id,
+            oidc_client_id: self.oidc_client_id,
+            ..session
+        }
+    }
+
+    /// Set the organisation the Session is active in, or `None` for the users
+    /// own account
+    pub fn with_organisation(mut self, organisation_id: Option<Uuid>) -> Self {
+        self.organisation_id = organisation_id;
+
+        self
+    }
+
+    /// Set the OIDC client the Session was issued to, which must authenticate
+    /// to refresh it, or `None` for Sessions started with the Login RPC
+    pub fn with_oidc_client(mut self, oidc_client_id: Option<Uuid>) -> Self {
+        self.oidc_client_id = oidc_client_id;
+
+        self
     }
 
     #[cfg(test)]
@@ -47,21 +127,24 @@ impl Sessions {
     ) -> Result<Self, BackendError> {
         use fake::faker::boolean::en::Boolean;
         use fake::faker::chrono::en::DateTime;
+        use fake::faker::internet::en::Password;
         use fake::Fake;
-        use rand::distributions::DistString;
-        use secrecy::Secret;
         use chrono::SubsecRound;
 
         use crate::utils;
 
         let random_id = utils::mock_uuid();
         let user_id = user.id.to_owned();
-        let random_secret = rand::distributions::Alphanumeric
-            .sample_string(&mut rand::thread_rng(), 60);
-        let random_secret = Secret::new(random_secret);
+        let token_keys = domain::TokenKeys::mock_data()?;
+        let config = crate::configuration::Configuration::parse()?.application;
 
-        let random_token =
-            domain::RefreshToken::new(&random_secret, user)?;
+        let random_token = domain::RefreshToken::new(
+            &token_keys,
+            &config,
+            user,
+            config.refresh_token_seconds,
+        )?;
+        let random_hash_key = Secret::new(Password(16..32).fake());
 
         // Generate random boolean value
         let random_is_active: bool = Boolean(4).fake();
@@ -73,9 +156,13 @@ impl Sessions {
         Ok(Self {
             id: random_id,
             user_id,
-            refresh_token: random_token,
+            family_id: random_id,
+            refresh_token_hash: random_token.hash(&random_hash_key),
             is_active: random_is_active,
             created_on: random_created_on,
+            access_token_id: Some(utils::mock_uuid()),
+            organisation_id: None,
+            oidc_client_id: None,
         })
     }
 }
@@ -6,10 +6,12 @@
 
 // // #![allow(unused)] // For development only
 
+use secrecy::Secret;
 use sqlx::{Pool, Postgres};
 use uuid::Uuid;
 
 use crate::database::Sessions;
+use crate::domain;
 use crate::prelude::*;
 
 impl Sessions {
@@ -48,30 +50,35 @@ impl Sessions {
         Ok(database_record)
     }
 
-    /// Get a Sessions from the database by querying the Sessions, returning a
-    /// Sessions instance or sqlx error.
+    /// Get a Sessions from the database by the keyed hash of its Refresh Token,
+    /// returning a Sessions instance or sqlx error.
     ///
     /// # Parameters
     ///
-    /// * `refresh_tokens` - The &str of the Sessions in the database.
+    /// * `refresh_token` - The Refresh Token of the Session.
+    /// * `hash_key` - The configured token hash key.
     /// * `database` - The sqlx database pool for the database to be queried.
     /// ---
     #[tracing::instrument(
         name = "Get the session associated with: ",
-        skip(database)
+        skip_all
     )]
     pub async fn from_token(
         refresh_token: &str,
+        hash_key: &Secret<String>,
         database: &Pool<Postgres>,
     ) -> Result<Sessions, BackendError> {
+        let refresh_token_hash =
+            domain::RefreshToken::from(refresh_token.to_owned()).hash(hash_key);
+
         let database_record = sqlx::query_as!(
             Sessions,
             r#"
                     SELECT *
                     FROM sessions
-                    WHERE refresh_token = $1
+                    WHERE refresh_token_hash = $1
                 "#,
-            refresh_token
+            refresh_token_hash
         )
         .fetch_one(database)
         .await?;
@@ -83,6 +90,42 @@ impl Sessions {
         Ok(database_record)
     }
 
+    /// Get the Sessions the Access Token was issued with, if any, returning an
+    /// optional Sessions instance or sqlx error. Access Tokens are not issued
+    /// with a Session when the request is authenticated with an API key.
+    ///
+    /// # Parameters
+    ///
+    /// * `access_token_id` - The Access Token id (jti) of the Session.
+    /// * `database` - The sqlx database pool for the database to be queried.
+    /// ---
+    #[tracing::instrument(
+        name = "Get the Session issued with Access Token: ",
+        skip(database)
+    )]
+    pub async fn from_access_token_id(
+        access_token_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Sessions>, BackendError> {
+        let database_record = sqlx::query_as!(
+            Sessions,
+            r#"
+                    SELECT *
+                    FROM sessions
+                    WHERE access_token_id = $1
+                "#,
+            access_token_id
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!(
+            "Sessions database records retrieved: {database_record:#?}"
+        );
+
+        Ok(database_record)
+    }
+
     /// Get an index of Sessions from the database by querying a User ID (uuid),
     /// returning a Vec of Sessions or a sqlx error.
     ///
@@ -126,6 +169,49 @@ impl Sessions {
         Ok(database_records)
     }
 
+    /// Get an index of the active Sessions of a user, one for each device they
+    /// are signed in on, returning a Vec of Sessions or a sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The uuid of user to be returned.
+    /// * `limit` - A i64 limiting the page length
+    /// * `offset` - A i64 of where the limit should start
+    /// * `database` - The sqlx database pool for the database to be queried.
+    /// ---
+    #[tracing::instrument(
+        name = "Get the active Sessions from the database for a users id (uuid): ",
+        skip(database)
+    )]
+    pub async fn index_active_from_user_id(
+        user_id: &Uuid,
+        limit: &i64,
+        offset: &i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Sessions>, BackendError> {
+        let database_records = sqlx::query_as!(
+            Sessions,
+            r#"
+                    SELECT *
+                    FROM sessions
+                    WHERE user_id = $1 AND is_active = true
+                    ORDER BY id
+                    LIMIT $2 OFFSET $3
+                "#,
+            user_id,
+            limit,
+            offset,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!(
+            "Sessions database records retrieved: {database_records:#?}"
+        );
+
+        Ok(database_records)
+    }
+
     /// Get an index of Sessions, returning a vector of Sessions or
     /// and SQLx error.
     ///
@@ -170,9 +256,11 @@ impl Sessions {
 #[cfg(test)]
 pub mod tests {
     use fake::Fake;
+    use secrecy::Secret;
     use sqlx::{Pool, Postgres};
+    use uuid::Uuid;
 
-    use crate::database;
+    use crate::{database, domain};
 
     // Override with more flexible error
     pub type Result<T> = core::result::Result<T, Error>;
@@ -218,9 +306,23 @@ pub mod tests {
         // Insert user in the database
         random_user.insert(&database).await?;
 
-        // Generate a session
-        let session =
-            database::Sessions::mock_data(&random_user).await?;
+        // Generate a session for a Refresh Token
+        let token_keys = domain::TokenKeys::mock_data()?;
+        let hash_key = Secret::new("Super_Secret_Key".to_string());
+        let config = crate::configuration::Configuration::parse()?.application;
+        let refresh_token = domain::RefreshToken::new(
+            &token_keys,
+            &config,
+            &random_user,
+            config.refresh_token_seconds,
+        )?;
+        let access_token = domain::AccessToken::new(&token_keys, &config, &random_user, &[])?;
+        let session = database::Sessions::new(
+            &random_user,
+            &access_token,
+            &refresh_token,
+            &hash_key,
+        );
 
         // Insert session into database for reading later
         let session = session.insert(&database).await?;
@@ -228,7 +330,8 @@ pub mod tests {
         //-- Execute Function (Act)
         // Insert user into database
         let database_record = database::Sessions::from_token(
-            &session.refresh_token.as_ref(),
+            refresh_token.as_ref(),
+            &hash_key,
             &database,
         )
         .await?;
@@ -236,6 +339,17 @@ pub mod tests {
 
         //-- Checks (Assertions)
         assert_eq!(database_record, session);
+        assert_ne!(session.refresh_token_hash, refresh_token.to_string());
+
+        // The token cannot be found with another hash key
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+        assert!(database::Sessions::from_token(
+            refresh_token.as_ref(),
+            &other_hash_key,
+            &database,
+        )
+        .await
+        .is_err());
 
         // -- Return
         Ok(())
@@ -292,6 +406,66 @@ pub mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn session_for_access_token_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        // Generate random user and session for testing
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let session = database::Sessions::mock_data(&random_user).await?;
+        let session = session.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let access_token_id = session.access_token_id.unwrap();
+        let database_record =
+            database::Sessions::from_access_token_id(&access_token_id, &database).await?;
+        let missing_record =
+            database::Sessions::from_access_token_id(&Uuid::now_v7(), &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, Some(session));
+        assert_eq!(missing_record, None);
+
+        // -- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_active_from_user_id_skips_revoked(
+        database: Pool<Postgres>,
+    ) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        // Generate random user with active and revoked sessions
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_count: i64 = (2..10).fake::<i64>();
+        let mut active_count = 0;
+        for _count in 0..random_count {
+            let session = database::Sessions::mock_data(&random_user).await?;
+            if session.is_active {
+                active_count += 1;
+            }
+            session.insert(&database).await?;
+        }
+
+        //-- Execute Function (Act)
+        let database_records = database::Sessions::index_active_from_user_id(
+            &random_user.id,
+            &random_count,
+            &0,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), active_count);
+        assert!(database_records.iter().all(|session| session.is_active));
+
+        // -- Return
+        Ok(())
+    }
+
     // Test getting user from database using unique UUID
     #[sqlx::test]
     async fn count_index(database: Pool<Postgres>) -> Result<()> {
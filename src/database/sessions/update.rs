@@ -32,13 +32,13 @@ impl Sessions {
             Sessions,
             r#"
 				UPDATE sessions 
-				SET user_id = $2, refresh_token = $3, is_active = $4
+				SET user_id = $2, refresh_token_hash = $3, is_active = $4
 				WHERE id = $1 
 				RETURNING *
 			"#,
             self.id,
             self.user_id,
-            self.refresh_token.as_ref(),
+            self.refresh_token_hash,
             self.is_active,
         )
         .fetch_one(database)
@@ -160,6 +160,117 @@ impl Sessions {
         Ok(rows_affected)
     }
 
+    /// Revoke (make non-active) self in the database only if it is still
+    /// active, returning false if it had already been revoked. The check and
+    /// update are done in a single query, so a Refresh Token can only be
+    /// rotated once, even by concurrent requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - A Sessions instance.
+    /// * `database` - An Sqlx database connection pool.
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke active Session in the database: ",
+        skip(database)
+    )]
+    pub async fn revoke_if_active(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<bool, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                    UPDATE sessions
+                    SET is_active = false
+                    WHERE id = $1 AND is_active = true
+                "#,
+            self.id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!(
+            "Sessions database records revoked: {rows_affected:#?}"
+        );
+
+        Ok(rows_affected == 1)
+    }
+
+    /// Revoke (make non-active) all Sessions in the database in the refresh
+    /// token family of self, returning a result with the number of rows
+    /// revoked or an SQLx error
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - Sessions instance with the family_id to revoke.
+    /// * `database` - An Sqlx database connection pool.
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke all Sessions in the Self family_id: ",
+        skip(database)
+    )]
+    pub async fn revoke_family(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                    UPDATE sessions
+                    SET is_active = false
+                    WHERE family_id = $1 AND is_active = true
+                "#,
+            self.family_id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!(
+            "Sessions database records revoked: {rows_affected:#?}"
+        );
+
+        Ok(rows_affected)
+    }
+
+    /// Revoke (make non-active) the active Sessions of the Self user in every
+    /// refresh token family other than the Self family, such as when signing
+    /// out other devices, returning a result with the number of rows revoked
+    /// or an SQLx error
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - Sessions instance with the user_id and family_id to keep.
+    /// * `database` - An Sqlx database connection pool.
+    /// ---
+    #[tracing::instrument(
+        name = "Revoke the Sessions of the Self user in other families: ",
+        skip(database)
+    )]
+    pub async fn revoke_other_families(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                    UPDATE sessions
+                    SET is_active = false
+                    WHERE user_id = $1 AND family_id <> $2 AND is_active = true
+                "#,
+            self.user_id,
+            self.family_id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!(
+            "Sessions database records revoked: {rows_affected:#?}"
+        );
+
+        Ok(rows_affected)
+    }
+
     /// Revoke (make non-active) all Sessions in the database for a give user_id,
     /// returning a result with the number Sessions revoked or an SQLx error
     ///
@@ -270,7 +381,7 @@ pub mod tests {
 
         // Update Sessions data
         session.user_id = sessions_update.user_id;
-        session.refresh_token = sessions_update.refresh_token;
+        session.refresh_token_hash = sessions_update.refresh_token_hash;
         session.is_active = sessions_update.is_active;
 
         //-- Execute Function (Act)
@@ -314,7 +425,7 @@ pub mod tests {
         let database_record =
             database::Sessions::from_id(&session.id, &database).await?;
 
-        assert_eq!(database_record.is_active, false);
+        assert!(!database_record.is_active);
 
         // -- Return
         Ok(())
@@ -350,7 +461,7 @@ pub mod tests {
         let database_record = database::Sessions::from_id(&session.id, &database).await?;
 
         // Check the is_active status is false
-        assert_eq!(database_record.is_active, false);
+        assert!(!database_record.is_active);
 
         // -- Return
         Ok(())
@@ -403,7 +514,144 @@ pub mod tests {
         let database_record = database::Sessions::from_id(&session.id, &database).await?;
 
         // Check the is_active status is false
-        assert_eq!(database_record.is_active, false);
+        assert!(!database_record.is_active);
+
+        // -- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn revoke_if_active_only_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        // Generate random user for testing
+        let random_user = database::Users::mock_data()?;
+
+        // Insert user in the database
+        random_user.insert(&database).await?;
+
+        // Generate an active session and insert it in the database
+        let mut session = database::Sessions::mock_data(&random_user).await?;
+        session.is_active = true;
+        let session = session.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let first = session.revoke_if_active(&database).await?;
+        let second = session.revoke_if_active(&database).await?;
+
+        //-- Checks (Assertions)
+        assert!(first);
+        assert!(!second);
+
+        // -- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn revoke_family_leaves_other_families(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        // Generate random user for testing
+        let random_user = database::Users::mock_data()?;
+
+        // Insert user in the database
+        random_user.insert(&database).await?;
+
+        // Generate an active session, and the next session in its family
+        let token_keys = crate::domain::TokenKeys::mock_data()?;
+        let hash_key = secrecy::Secret::new("Super_Secret_Key".to_string());
+        let config = crate::configuration::Configuration::parse()?.application;
+        let access_token =
+            crate::domain::AccessToken::new(&token_keys, &config, &random_user, &[])?;
+        let refresh_token = || {
+            crate::domain::RefreshToken::new(
+                &token_keys,
+                &config,
+                &random_user,
+                config.refresh_token_seconds,
+            )
+        };
+
+        let session = database::Sessions::new(
+            &random_user,
+            &access_token,
+            &refresh_token()?,
+            &hash_key,
+        )
+        .insert(&database)
+        .await?;
+        let rotated_session =
+            session.rotate(&random_user, &access_token, &refresh_token()?, &hash_key);
+        let rotated_session = rotated_session.insert(&database).await?;
+
+        // Generate an active session in another family
+        let other_session = database::Sessions::new(
+            &random_user,
+            &access_token,
+            &refresh_token()?,
+            &hash_key,
+        )
+        .insert(&database)
+        .await?;
+
+        //-- Execute Function (Act)
+        let rows_affected = session.revoke_family(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 2);
+        assert_eq!(rotated_session.family_id, session.family_id);
+
+        let database_record =
+            database::Sessions::from_id(&rotated_session.id, &database).await?;
+        assert!(!database_record.is_active);
+
+        let database_record =
+            database::Sessions::from_id(&other_session.id, &database).await?;
+        assert!(database_record.is_active);
+
+        // -- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn revoke_other_families_leaves_own_family(
+        database: Pool<Postgres>,
+    ) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        // Generate random users for testing
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+
+        // Generate active sessions in two families of the user, and one of
+        // another user
+        let mut session = database::Sessions::mock_data(&random_user).await?;
+        session.is_active = true;
+        let session = session.insert(&database).await?;
+
+        let mut other_family = database::Sessions::mock_data(&random_user).await?;
+        other_family.is_active = true;
+        let other_family = other_family.insert(&database).await?;
+
+        let mut other_user_session = database::Sessions::mock_data(&other_user).await?;
+        other_user_session.is_active = true;
+        let other_user_session = other_user_session.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected = session.revoke_other_families(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+
+        let database_record = database::Sessions::from_id(&session.id, &database).await?;
+        assert!(database_record.is_active);
+
+        let database_record =
+            database::Sessions::from_id(&other_family.id, &database).await?;
+        assert!(!database_record.is_active);
+
+        let database_record =
+            database::Sessions::from_id(&other_user_session.id, &database).await?;
+        assert!(database_record.is_active);
 
         // -- Return
         Ok(())
@@ -456,7 +704,7 @@ pub mod tests {
         let database_record = database::Sessions::from_id(&session.id, &database).await?;
 
         // Check the is_active status is false
-        assert_eq!(database_record.is_active, false);
+        assert!(!database_record.is_active);
 
         // -- Return
         Ok(())
@@ -510,7 +758,7 @@ pub mod tests {
         let database_record = database::Sessions::from_id(&session.id, &database).await?;
 
         // Check the is_active status is false
-        assert_eq!(database_record.is_active, false);
+        assert!(!database_record.is_active);
 
         // -- Return
         Ok(())
@@ -0,0 +1,126 @@
+//-- ./src/database/signing_keys/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a Signing Key into the database, returning a result with the Signing
+//! Keys Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::configuration::JwtAlgorithm;
+use crate::prelude::*;
+
+use super::SigningKeys;
+
+impl SigningKeys {
+    /// Insert a Signing Key into the database, returning the database instance
+    /// created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The Signing Key instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new Signing Key into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            key_id = % self.key_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            SigningKeys,
+            r#"
+                INSERT INTO signing_keys (
+                    id,
+                    key_id,
+                    algorithm,
+                    private_key,
+                    public_key,
+                    signs_from,
+                    retires_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING
+                    id,
+                    key_id,
+                    algorithm AS "algorithm: JwtAlgorithm",
+                    private_key,
+                    public_key,
+                    signs_from,
+                    retires_on,
+                    created_on
+            "#,
+            self.id,
+            self.key_id,
+            self.algorithm as JwtAlgorithm,
+            self.private_key,
+            self.public_key,
+            self.signs_from,
+            self.retires_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("Signing Key database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use secrecy::Secret;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+        let random_signing_key = SigningKeys::mock_data(&encryption_key)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_signing_key.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_signing_key);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn duplicate_key_id_is_error(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+        let random_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        random_signing_key.insert(&database).await?;
+
+        let mut duplicate_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        duplicate_signing_key.key_id = random_signing_key.key_id;
+
+        //-- Execute Function (Act)
+        let result = duplicate_signing_key.insert(&database).await;
+
+        //-- Checks (Assertions)
+        assert!(result.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Wrapper around Signing Keys database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::SigningKeys;
+
+mod insert;
+mod model;
+mod read;
+mod update;
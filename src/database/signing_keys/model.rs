@@ -0,0 +1,149 @@
+//-- ./src/database/signing_keys/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The Signing Keys database model
+//!
+//! The key ring used to sign and verify tokens. Private keys are stored
+//! encrypted with the configured JWT encryption key. A key signs tokens from
+//! `signs_from`, and stops verifying tokens once `retires_on` has passed.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use secrecy::{ExposeSecret, Secret};
+use uuid::Uuid;
+
+use crate::configuration::JwtAlgorithm;
+use crate::{domain, prelude::*, utils};
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct SigningKeys {
+    pub id: Uuid,
+    pub key_id: String,
+    pub algorithm: JwtAlgorithm,
+    pub private_key: Vec<u8>,
+    pub public_key: String,
+    pub signs_from: DateTime<Utc>,
+    pub retires_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl SigningKeys {
+    /// Create a new Signing Key instance, encrypting the private key
+    ///
+    /// # Parameters
+    ///
+    /// * `key_id` - Key id added to token headers and the JWKS
+    /// * `algorithm` - The algorithm the keys are used with
+    /// * `private_key` - The PKCS#8 PEM private key
+    /// * `public_key` - The PEM public key
+    /// * `signs_from` - When the key starts signing tokens
+    /// * `encryption_key` - The configured JWT encryption key
+    /// ---
+    pub fn new(
+        key_id: &str,
+        algorithm: JwtAlgorithm,
+        private_key: &Secret<String>,
+        public_key: &str,
+        signs_from: DateTime<Utc>,
+        encryption_key: &Secret<String>,
+    ) -> Result<Self, BackendError> {
+        let id = Uuid::now_v7();
+        let private_key =
+            utils::encrypt(private_key.expose_secret().as_bytes(), encryption_key)
+                .map_err(|_| BackendError::TokenKey("encrypting private key".to_string()))?;
+        let created_on = Utc::now().round_subsecs(0);
+
+        Ok(Self {
+            id,
+            key_id: key_id.to_owned(),
+            algorithm,
+            private_key,
+            public_key: public_key.to_owned(),
+            signs_from: signs_from.trunc_subsecs(0),
+            retires_on: None,
+            created_on,
+        })
+    }
+
+    /// Decrypt the private key and build the domain Signing Key
+    ///
+    /// # Parameters
+    ///
+    /// * `encryption_key` - The configured JWT encryption key
+    /// ---
+    pub fn to_signing_key(
+        &self,
+        encryption_key: &Secret<String>,
+    ) -> Result<domain::SigningKey, BackendError> {
+        let private_key = utils::decrypt(&self.private_key, encryption_key)
+            .map_err(|_| BackendError::TokenKey("decrypting private key".to_string()))?;
+
+        let signing_key = domain::SigningKey::from_pem(
+            &self.key_id,
+            self.algorithm,
+            &private_key,
+            self.public_key.as_bytes(),
+        )?
+        .with_schedule(self.signs_from, self.retires_on);
+
+        Ok(signing_key)
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(encryption_key: &Secret<String>) -> Result<Self, BackendError> {
+        let (private_key, public_key) =
+            domain::SigningKey::generate_pem(JwtAlgorithm::EdDSA)?;
+
+        Self::new(
+            &Uuid::new_v4().to_string(),
+            JwtAlgorithm::EdDSA,
+            &private_key,
+            &public_key,
+            Utc::now(),
+            encryption_key,
+        )
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn private_key_is_encrypted() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+        let (private_key, public_key) =
+            domain::SigningKey::generate_pem(JwtAlgorithm::EdDSA)?;
+
+        //-- Execute Function (Act)
+        let signing_key = SigningKeys::new(
+            "new-key",
+            JwtAlgorithm::EdDSA,
+            &private_key,
+            &public_key,
+            Utc::now(),
+            &encryption_key,
+        )?;
+
+        //-- Checks (Assertions)
+        assert_ne!(signing_key.private_key, private_key.expose_secret().as_bytes());
+        assert_eq!(
+            signing_key.to_signing_key(&encryption_key)?.key_id(),
+            "new-key"
+        );
+
+        // The wrong key cannot decrypt the private key
+        let wrong_key = Secret::new("Wrong_Key".to_string());
+        assert!(signing_key.to_signing_key(&wrong_key).is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//-- ./src/database/signing_keys/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read Signing Keys from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::configuration::JwtAlgorithm;
+use crate::prelude::*;
+
+use super::SigningKeys;
+
+impl SigningKeys {
+    /// Get every Signing Key, including retired keys, ordered by when they
+    /// start signing tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index Signing Keys from the database: ",
+        skip(database)
+    )]
+    pub async fn index(database: &Pool<Postgres>) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            SigningKeys,
+            r#"
+                SELECT
+                    id,
+                    key_id,
+                    algorithm AS "algorithm: JwtAlgorithm",
+                    private_key,
+                    public_key,
+                    signs_from,
+                    retires_on,
+                    created_on
+                FROM signing_keys
+                ORDER BY signs_from
+            "#,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Signing Key database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+
+    /// Get the Signing Keys that have not been retired, ordered by when they
+    /// start signing tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index active Signing Keys from the database: ",
+        skip(database)
+    )]
+    pub async fn index_active(
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            SigningKeys,
+            r#"
+                SELECT
+                    id,
+                    key_id,
+                    algorithm AS "algorithm: JwtAlgorithm",
+                    private_key,
+                    public_key,
+                    signs_from,
+                    retires_on,
+                    created_on
+                FROM signing_keys
+                WHERE retires_on IS NULL OR retires_on > NOW()
+                ORDER BY signs_from
+            "#,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!("Signing Key database records retrieved: {}", database_records.len());
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use secrecy::Secret;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn index_active_skips_retired_keys(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+
+        let mut retired_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        retired_signing_key.signs_from = Utc::now() - Duration::hours(2);
+        retired_signing_key.retires_on = Some(Utc::now() - Duration::hours(1));
+        retired_signing_key.insert(&database).await?;
+
+        let active_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        active_signing_key.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let all_records = SigningKeys::index(&database).await?;
+        let active_records = SigningKeys::index_active(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(all_records.len(), 2);
+        assert_eq!(active_records, vec![active_signing_key]);
+
+        //-- Return
+        Ok(())
+    }
+}
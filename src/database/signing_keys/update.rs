@@ -0,0 +1,98 @@
+//-- ./src/database/signing_keys/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update Signing Keys in the database
+//! ---
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::SigningKeys;
+
+impl SigningKeys {
+    /// Retire every key that starts signing before a new key, so they stop
+    /// verifying tokens at `retires_on`. Keys already retiring earlier are left
+    /// alone. Returns the number of keys retired.
+    ///
+    /// # Parameters
+    ///
+    /// * `signs_from` - When the new key starts signing tokens
+    /// * `retires_on` - When the older keys stop verifying tokens
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Retire Signing Keys in the database: ",
+        skip(database)
+    )]
+    pub async fn retire_before(
+        signs_from: &DateTime<Utc>,
+        retires_on: &DateTime<Utc>,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE signing_keys
+                SET retires_on = $2
+                WHERE signs_from < $1 AND (retires_on IS NULL OR retires_on > $2)
+            "#,
+            signs_from,
+            retires_on,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("Signing Key database records retired: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use secrecy::Secret;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn older_keys_are_retired(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+        let now = Utc::now();
+
+        let mut older_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        older_signing_key.signs_from = now - Duration::hours(1);
+        older_signing_key.insert(&database).await?;
+
+        let mut newer_signing_key = SigningKeys::mock_data(&encryption_key)?;
+        newer_signing_key.signs_from = now + Duration::minutes(5);
+        newer_signing_key.insert(&database).await?;
+
+        let retires_on = now + Duration::days(1);
+
+        //-- Execute Function (Act)
+        let retired =
+            SigningKeys::retire_before(&newer_signing_key.signs_from, &retires_on, &database)
+                .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(retired, 1);
+
+        let database_records = SigningKeys::index(&database).await?;
+        assert!(database_records[0].retires_on.is_some());
+        assert_eq!(database_records[1].retires_on, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//-- ./src/database/totp_secrets/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete TOTP Secrets in the database, returning a Result with an u64 of the
+//! number of rows affected.
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::TotpSecrets;
+
+impl TotpSecrets {
+    /// Delete the TOTP Secret for a user, disabling TOTP, returning a Result
+    /// with the number of rows deleted or a sqlx error.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the TOTP Secret belongs to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Delete TOTP Secret from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_by_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE
+                FROM totp_secrets
+                WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("TOTP Secret database records deleted: {rows_affected:#?}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_totp_secret_for_user(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        TotpSecrets::mock_data(&random_user)?.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            TotpSecrets::delete_by_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        assert_eq!(TotpSecrets::from_user_id(&random_user.id, &database).await?, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+//-- ./src/database/totp_secrets/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a TOTP Secret into the database, returning a result with the TOTP
+//! Secrets Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::TotpSecrets;
+
+impl TotpSecrets {
+    /// Insert a pending TOTP Secret into the database, replacing any pending
+    /// secret for the user, returning the database instance created.
+    ///
+    /// An enabled secret is never replaced, returning an sqlx RowNotFound error.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The TOTP Secret instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new TOTP Secret into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            TotpSecrets,
+            r#"
+                INSERT INTO totp_secrets (
+                    id,
+                    user_id,
+                    secret_encrypted,
+                    is_enabled,
+                    last_used_step,
+                    enabled_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (user_id) DO UPDATE
+                SET id = EXCLUDED.id,
+                    secret_encrypted = EXCLUDED.secret_encrypted,
+                    is_enabled = EXCLUDED.is_enabled,
+                    last_used_step = EXCLUDED.last_used_step,
+                    enabled_on = EXCLUDED.enabled_on,
+                    created_on = EXCLUDED.created_on
+                WHERE totp_secrets.is_enabled = FALSE
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.secret_encrypted,
+            self.is_enabled,
+            self.last_used_step,
+            self.enabled_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("TOTP Secret database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_totp_secret = TotpSecrets::mock_data(&random_user)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_totp_secret.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_totp_secret);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn pending_record_is_replaced(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        TotpSecrets::mock_data(&random_user)?.insert(&database).await?;
+        let replacement_totp_secret = TotpSecrets::mock_data(&random_user)?;
+
+        //-- Execute Function (Act)
+        let database_record = replacement_totp_secret.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, replacement_totp_secret);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn enabled_record_is_not_replaced(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let totp_secret = TotpSecrets::mock_data(&random_user)?.insert(&database).await?;
+        totp_secret.enable(1, &database).await?;
+
+        //-- Execute Function (Act)
+        let result = TotpSecrets::mock_data(&random_user)?.insert(&database).await;
+
+        //-- Checks (Assertions)
+        assert!(result.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Wrapper around TOTP Secrets database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::TotpSecrets;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
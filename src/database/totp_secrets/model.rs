@@ -0,0 +1,94 @@
+//-- ./src/database/totp_secrets/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The TOTP Secrets database model
+//!
+//! Each user has at most one TOTP secret, which is pending until the user
+//! confirms enrolment with a valid code.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct TotpSecrets {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub secret_encrypted: Vec<u8>,
+    pub is_enabled: bool,
+    pub last_used_step: Option<i64>,
+    pub enabled_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl TotpSecrets {
+    /// Create a new, pending, TOTP Secret instance
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user enrolling in TOTP
+    /// * `secret_encrypted` - The encrypted TOTP secret
+    /// ---
+    pub fn new(user_id: &Uuid, secret_encrypted: Vec<u8>) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user_id.to_owned();
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            user_id,
+            secret_encrypted,
+            is_enabled: false,
+            last_used_step: None,
+            enabled_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user: &crate::database::Users,
+    ) -> Result<Self, crate::prelude::BackendError> {
+        use fake::faker::internet::en::Password;
+        use fake::Fake;
+        use secrecy::Secret;
+
+        let random_key: String = Password(16..32).fake();
+        let random_secret = crate::domain::TotpSecret::generate();
+        let secret_encrypted = random_secret.encrypt(&Secret::new(random_key))?;
+
+        Ok(Self::new(&user.id, secret_encrypted))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_totp_secret_is_pending() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+
+        //-- Execute Function (Act)
+        let totp_secret = TotpSecrets::new(&random_user.id, vec![1, 2, 3]);
+
+        //-- Checks (Assertions)
+        assert_eq!(totp_secret.user_id, random_user.id);
+        assert_eq!(totp_secret.secret_encrypted, vec![1, 2, 3]);
+        assert!(!totp_secret.is_enabled);
+        assert_eq!(totp_secret.last_used_step, None);
+        assert_eq!(totp_secret.enabled_on, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//-- ./src/database/totp_secrets/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read TOTP Secrets from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::TotpSecrets;
+
+impl TotpSecrets {
+    /// Get the TOTP Secret for a user, returning None if the user has never
+    /// started TOTP enrolment.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the TOTP Secret belongs to
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read TOTP Secret from the database: ",
+        skip(database)
+    )]
+    pub async fn from_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Option<Self>, BackendError> {
+        let database_record = sqlx::query_as!(
+            TotpSecrets,
+            r#"
+                SELECT *
+                FROM totp_secrets
+                WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .fetch_optional(database)
+        .await?;
+
+        tracing::debug!(
+            "TOTP Secret database record retrieved: {:?}",
+            database_record.as_ref().map(|record| record.id)
+        );
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_totp_secret_by_user_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_totp_secret = TotpSecrets::mock_data(&random_user)?;
+        random_totp_secret.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            TotpSecrets::from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, Some(random_totp_secret));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn no_totp_secret_returns_none(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record =
+            TotpSecrets::from_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+//-- ./src/database/totp_secrets/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update TOTP Secrets in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+
+use crate::{database, domain, prelude::*};
+
+use super::TotpSecrets;
+
+impl TotpSecrets {
+    /// Enable the TOTP Secret once the user has confirmed enrolment, recording
+    /// the time step of the confirmation code so it cannot be used again.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The TOTP Secret instance to be enabled.
+    /// * `step` - The time step of the TOTP code used to confirm enrolment
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Enable a TOTP Secret in the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn enable(
+        &self,
+        step: i64,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            TotpSecrets,
+            r#"
+                UPDATE totp_secrets
+                SET is_enabled = TRUE, enabled_on = NOW(), last_used_step = $2
+                WHERE id = $1
+                RETURNING *
+            "#,
+            self.id,
+            step,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("TOTP Secret database record enabled: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Record a TOTP code time step as used, returning false if the step, or
+    /// a later step, has already been used.
+    ///
+    /// The check and update are done in a single query so a code cannot be
+    /// replayed, even by concurrent requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The TOTP Secret the code was verified against.
+    /// * `step` - The time step of the verified TOTP code
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Use a TOTP Secret time step in the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn use_step(
+        &self,
+        step: i64,
+        database: &Pool<Postgres>,
+    ) -> Result<bool, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE totp_secrets
+                SET last_used_step = $2
+                WHERE id = $1 AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+            self.id,
+            step,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("TOTP Secret time step used: {rows_affected}");
+
+        Ok(rows_affected == 1)
+    }
+
+    /// Check a TOTP code against the secret and record it as used, returning a
+    /// TotpCodeInvalid error if the code is wrong or has already been used.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The TOTP Secret the code is checked against.
+    /// * `code` - The TOTP code from the users authenticator app
+    /// * `encryption_key` - The configured TOTP secret encryption key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem a TOTP code: ",
+        skip_all,
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn redeem_code(
+        &self,
+        code: &str,
+        encryption_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<(), BackendError> {
+        let secret = domain::TotpSecret::decrypt(&self.secret_encrypted, encryption_key)?;
+
+        let step = secret.verify(code).ok_or(BackendError::TotpCodeInvalid)?;
+
+        if !self.use_step(step, database).await? {
+            tracing::error!("TOTP code has already been used: {}", self.user_id);
+            return Err(BackendError::TotpCodeInvalid);
+        }
+
+        Ok(())
+    }
+
+    /// Check an MFA code, which is either a TOTP code or one of the users
+    /// Recovery Codes, and record it as used, returning a TotpCodeInvalid
+    /// error if the code is wrong or has already been used.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The TOTP Secret the code is checked against.
+    /// * `code` - The TOTP code or Recovery Code provided by the user
+    /// * `encryption_key` - The configured TOTP secret encryption key
+    /// * `hash_key` - The configured token hash key
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem an MFA code: ",
+        skip_all,
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn redeem_mfa_code(
+        &self,
+        code: &str,
+        encryption_key: &Secret<String>,
+        hash_key: &Secret<String>,
+        database: &Pool<Postgres>,
+    ) -> Result<(), BackendError> {
+        match self.redeem_code(code, encryption_key, database).await {
+            Err(BackendError::TotpCodeInvalid) => {}
+            result => return result,
+        }
+
+        let recovery_code = domain::RecoveryCode::from(code.to_owned());
+        let recovery_code =
+            database::RecoveryCodes::redeem(&self.user_id, &recovery_code, hash_key, database)
+                .await
+                .map_err(|_| BackendError::TotpCodeInvalid)?;
+
+        let remaining =
+            database::RecoveryCodes::count_unused_by_user_id(&self.user_id, database)
+                .await?;
+        tracing::warn!(
+            "Recovery Code {} used by user: {}, {} remaining",
+            recovery_code.id,
+            self.user_id,
+            remaining
+        );
+
+        Ok(())
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn enable_totp_secret(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let totp_secret = TotpSecrets::mock_data(&random_user)?.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = totp_secret.enable(42, &database).await?;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_enabled);
+        assert!(database_record.enabled_on.is_some());
+        assert_eq!(database_record.last_used_step, Some(42));
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn time_step_is_single_use(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let totp_secret = TotpSecrets::mock_data(&random_user)?.insert(&database).await?;
+        let totp_secret = totp_secret.enable(42, &database).await?;
+
+        //-- Execute Function (Act)
+        let same_step = totp_secret.use_step(42, &database).await?;
+        let earlier_step = totp_secret.use_step(41, &database).await?;
+        let later_step = totp_secret.use_step(43, &database).await?;
+        let later_step_again = totp_secret.use_step(43, &database).await?;
+
+        //-- Checks (Assertions)
+        assert!(!same_step);
+        assert!(!earlier_step);
+        assert!(later_step);
+        assert!(!later_step_again);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn redeem_code_is_single_use(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let encryption_key = Secret::new("Super_Secret_Key".to_string());
+        let secret = domain::TotpSecret::generate();
+        let totp_secret =
+            TotpSecrets::new(&random_user.id, secret.encrypt(&encryption_key)?)
+                .insert(&database)
+                .await?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)?
+            .as_secs();
+        let code = secret.generate_code(now);
+
+        //-- Execute Function (Act)
+        let first = totp_secret.redeem_code(&code, &encryption_key, &database).await;
+        let second = totp_secret.redeem_code(&code, &encryption_key, &database).await;
+
+        //-- Checks (Assertions)
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(BackendError::TotpCodeInvalid)));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//-- ./src/database/user_roles/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete User Roles from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::UserRoles;
+
+impl UserRoles {
+    /// Unassign a Role from a user, returning the number of rows deleted
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Role is assigned to
+    /// * `role_id` - The Role being unassigned
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Delete a User Role from the database: ", skip(database))]
+    pub async fn delete(
+        user_id: &Uuid,
+        role_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE FROM user_roles
+                WHERE user_id = $1 AND role_id = $2
+            "#,
+            user_id,
+            role_id,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("User Roles deleted: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn unassign_role(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let random_role = database::Roles::mock_data()?;
+        random_role.insert(&database).await?;
+        UserRoles::new(&random_user.id, &random_role.id)
+            .insert(&database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let rows_affected =
+            UserRoles::delete(&random_user.id, &random_role.id, &database).await?;
+        let assigned_roles = database::Roles::index_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(rows_affected, 1);
+        assert!(assigned_roles.is_empty());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//-- ./src/database/user_roles/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a User Role into the database, returning a result with the User
+//! Roles Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::UserRoles;
+
+impl UserRoles {
+    /// Assign a Role to a user, returning the database instance. Assigning a
+    /// Role the user already holds returns the existing assignment.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The User Role instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new User Role into the database: ",
+        skip(self, database),
+        fields(
+            user_id = % self.user_id,
+            role_id = % self.role_id,
+        ),
+    )]
+    pub async fn insert(&self, database: &Pool<Postgres>) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            UserRoles,
+            r#"
+                INSERT INTO user_roles (user_id, role_id, created_on)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (user_id, role_id)
+                    DO UPDATE SET created_on = user_roles.created_on
+                RETURNING *
+            "#,
+            self.user_id,
+            self.role_id,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await
+        .map_err(|error| match error {
+            // The user or role is not in the database
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => {
+                BackendError::RoleInvalid("user or role does not exist".to_string())
+            }
+            _ => BackendError::Sqlx(error),
+        })?;
+
+        tracing::debug!("User Role database record inserted: {}", database_record.role_id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+    use uuid::Uuid;
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn assigning_twice_keeps_first_assignment(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let random_role = database::Roles::mock_data()?;
+        random_role.insert(&database).await?;
+        let user_role = UserRoles::new(&random_user.id, &random_role.id);
+
+        //-- Execute Function (Act)
+        let database_record = user_role.insert(&database).await?;
+        let mut reassigned = UserRoles::new(&random_user.id, &random_role.id);
+        reassigned.created_on += chrono::Duration::hours(1);
+        let reassigned = reassigned.insert(&database).await?;
+        let missing_role = UserRoles::new(&random_user.id, &Uuid::now_v7())
+            .insert(&database)
+            .await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, user_role);
+        assert_eq!(reassigned, user_role);
+        assert!(matches!(missing_role, Err(BackendError::RoleInvalid(_))));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Wrapper around User Roles database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::UserRoles;
+
+mod delete;
+mod insert;
+mod model;
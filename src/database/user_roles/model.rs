@@ -0,0 +1,35 @@
+//-- ./src/database/user_roles/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The User Roles database model
+//!
+//! Assigns a Role to a user, on top of the system role of their user role.
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Deserialize)]
+pub struct UserRoles {
+    pub user_id: Uuid,
+    pub role_id: Uuid,
+    pub created_on: DateTime<Utc>,
+}
+
+impl UserRoles {
+    /// Create a new User Role instance assigning the Role to the user
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the Role is assigned to
+    /// * `role_id` - The Role being assigned
+    /// ---
+    pub fn new(user_id: &Uuid, role_id: &Uuid) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            role_id: role_id.to_owned(),
+            created_on: Utc::now().round_subsecs(0),
+        }
+    }
+}
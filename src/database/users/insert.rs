@@ -31,6 +31,7 @@ impl Users {
             password_hash = % self.password_hash.as_ref(),
             is_active = % self.is_active,
             is_verified = % self.is_verified,
+            is_pending = % self.is_pending,
             created_on = % self.created_on,
         ),
     )]
@@ -50,10 +51,11 @@ impl Users {
                     role,
                     is_active,
                     is_verified,
+                    is_pending,
                     created_on
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-                RETURNING id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, created_on
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, is_pending, created_on
             "#,
             self.id,
             self.email.as_ref(),
@@ -62,10 +64,18 @@ impl Users {
             self.role.clone() as domain::UserRole,
             self.is_active,
             self.is_verified,
+            self.is_pending,
 			self.created_on,
         )
             .fetch_one(database)
-            .await?;
+            .await
+            .map_err(|error| match error {
+                // Email (or id) is already in the users table
+                sqlx::Error::Database(e) if e.is_unique_violation() => {
+                    BackendError::UserAlreadyExists(self.email.to_string())
+                }
+                _ => BackendError::Sqlx(error),
+            })?;
 
         tracing::debug!("User database records retrieved: {database_record:#?}");
 
@@ -102,4 +112,25 @@ mod tests {
         // -- Return
         Ok(())
     }
+
+    // Test inserting a duplicate email into the database
+    #[sqlx::test]
+    async fn duplicate_email_returns_error(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        // Generate a new user with the same email
+        let mut duplicate_user = Users::mock_data()?;
+        duplicate_user.email = random_user.email.clone();
+
+        //-- Execute Function (Act)
+        let error = duplicate_user.insert(&database).await.unwrap_err();
+
+        //-- Checks (Assertions)
+        assert!(matches!(error, BackendError::UserAlreadyExists(_)));
+
+        // -- Return
+        Ok(())
+    }
 }
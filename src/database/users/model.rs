@@ -20,6 +20,7 @@ pub struct Users {
     pub role: domain::UserRole,
     pub is_active: bool,
     pub is_verified: bool,
+    pub is_pending: bool,
     pub created_on: DateTime<Utc>,
 }
 
@@ -52,6 +53,7 @@ impl Users {
             role: random_user_role,
             is_active: random_is_active,
             is_verified: random_is_verified,
+            is_pending: false,
             created_on: random_created_on,
         })
     }
@@ -33,7 +33,7 @@ impl Users {
         let database_record = sqlx::query_as!(
 				Users,
 				r#"
-					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, created_on
+					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, is_pending, created_on
 					FROM users
 					WHERE id = $1
 				"#,
@@ -69,7 +69,7 @@ impl Users {
         let database_record = sqlx::query_as!(
 				Users,
 				r#"
-					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, created_on
+					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, is_pending, created_on
 					FROM users
 					WHERE email = $1
 				"#,
@@ -103,7 +103,7 @@ impl Users {
         let database_records = sqlx::query_as!(
 				Users,
 				r#"
-					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, created_on
+					SELECT id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, is_pending, created_on
 					FROM users
 					ORDER BY id
 					LIMIT $1 OFFSET $2
@@ -197,6 +197,12 @@ pub mod tests {
             // test_vec.push(random_user.insert(&database).await?);
         }
 
+        // Count every user in the database, as the migrations also add a
+        // default admin user
+        let user_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&database)
+            .await?;
+
         //-- Execute Function (Act)
         let random_limit = (1..random_count).fake::<i64>();
         let random_offset = (1..random_count).fake::<i64>();
@@ -205,7 +211,7 @@ pub mod tests {
 
         //-- Checks (Assertions)
         // Calculate the count less offset
-        let count_less_offset: i64 = random_count - random_offset;
+        let count_less_offset: i64 = user_count - random_offset;
 
         // Expected records based on offset and limit
         let expected_records = if count_less_offset < random_limit {
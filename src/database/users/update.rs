@@ -28,9 +28,9 @@ impl Users {
 			Users,
 			r#"
 				UPDATE users
-				SET email = $2, name = $3, password_hash = $4, role = $5, is_active = $6, is_verified = $7
+				SET email = $2, name = $3, password_hash = $4, role = $5, is_active = $6, is_verified = $7, is_pending = $8
 				WHERE id = $1
-				RETURNING id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, created_on
+				RETURNING id, email, name, password_hash, role as "role:domain::UserRole", is_active, is_verified, is_pending, created_on
 			"#,
 			self.id,
 			self.email.as_ref(),
@@ -39,6 +39,7 @@ impl Users {
 			self.role.clone() as domain::UserRole,
 			self.is_active,
 			self.is_verified,
+			self.is_pending,
 		)
             .fetch_one(database)
             .await?;
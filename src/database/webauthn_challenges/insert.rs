@@ -0,0 +1,100 @@
+//-- ./src/database/webauthn_challenges/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a WebAuthn Challenge into the database, returning a result with the
+//! WebAuthn Challenges Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::WebAuthnChallenges;
+
+impl WebAuthnChallenges {
+    /// Insert a WebAuthn Challenge into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The WebAuthn Challenge instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new WebAuthn Challenge into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            ceremony = % self.ceremony,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            WebAuthnChallenges,
+            r#"
+                INSERT INTO webauthn_challenges (
+                    id,
+                    user_id,
+                    ceremony,
+                    challenge_hash,
+                    expires_on,
+                    used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.ceremony,
+            self.challenge_hash,
+            self.expires_on,
+            self.used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("WebAuthn Challenge database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let (random_webauthn_challenge, _random_challenge) =
+            WebAuthnChallenges::mock_data(Some(&random_user.id))?;
+
+        //-- Execute Function (Act)
+        let database_record = random_webauthn_challenge.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_webauthn_challenge);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Wrapper around WebAuthn Challenges database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::{WebAuthnChallenges, WEBAUTHN_CHALLENGE_DURATION};
+
+mod insert;
+mod model;
+mod update;
@@ -0,0 +1,113 @@
+//-- ./src/database/webauthn_challenges/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The WebAuthn Challenges database model
+//! ---
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::{domain, webauthn};
+
+pub static WEBAUTHN_CHALLENGE_DURATION: i64 = 5 * 60; // 5 minutes as seconds
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct WebAuthnChallenges {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub ceremony: String,
+    pub challenge_hash: String,
+    pub expires_on: DateTime<Utc>,
+    pub used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl WebAuthnChallenges {
+    /// Create a new WebAuthn Challenge instance, storing only the keyed hash
+    /// of the challenge.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the challenge is for, or None for passwordless login
+    /// * `ceremony` - The WebAuthn ceremony the challenge is issued for
+    /// * `challenge` - The One Time Token sent to the client as the challenge
+    /// * `hash_key` - The configured token hash key
+    /// ---
+    pub fn new(
+        user_id: Option<&Uuid>,
+        ceremony: webauthn::Ceremony,
+        challenge: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user_id.copied();
+        let ceremony = ceremony.to_string();
+        let challenge_hash = challenge.keyed_hash(hash_key);
+        let created_on = Utc::now().round_subsecs(0);
+        let expires_on = created_on + Duration::seconds(WEBAUTHN_CHALLENGE_DURATION);
+
+        Self {
+            id,
+            user_id,
+            ceremony,
+            challenge_hash,
+            expires_on,
+            used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(
+        user_id: Option<&Uuid>,
+    ) -> Result<(Self, domain::OneTimeToken), crate::prelude::BackendError> {
+        let random_challenge = domain::OneTimeToken::generate();
+        let webauthn_challenge = Self::new(
+            user_id,
+            webauthn::Ceremony::Authentication,
+            &random_challenge,
+            &domain::OneTimeToken::mock_hash_key(),
+        );
+
+        Ok((webauthn_challenge, random_challenge))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_webauthn_challenge() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let random_challenge = domain::OneTimeToken::generate();
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+
+        //-- Execute Function (Act)
+        let webauthn_challenge = WebAuthnChallenges::new(
+            Some(&random_user.id),
+            webauthn::Ceremony::Registration,
+            &random_challenge,
+            &hash_key,
+        );
+
+        //-- Checks (Assertions)
+        assert_eq!(webauthn_challenge.user_id, Some(random_user.id));
+        assert_eq!(webauthn_challenge.ceremony, "registration");
+        assert_eq!(webauthn_challenge.challenge_hash, random_challenge.keyed_hash(&hash_key));
+        assert!(webauthn_challenge.expires_on > webauthn_challenge.created_on);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+//-- ./src/database/webauthn_challenges/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update WebAuthn Challenges in the database
+//! ---
+
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+
+use crate::{domain, prelude::*, webauthn};
+
+use super::WebAuthnChallenges;
+
+impl WebAuthnChallenges {
+    /// Redeem (mark as used) the unused and unexpired WebAuthn Challenge for
+    /// the ceremony, returning the WebAuthn Challenge or an sqlx RowNotFound
+    /// error if the challenge is unknown, used or expired.
+    ///
+    /// The update is done in a single query so a challenge cannot be redeemed
+    /// twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `challenge` - The challenge from the client data
+    /// * `hash_key` - The configured token hash key
+    /// * `ceremony` - The WebAuthn ceremony the challenge must be issued for
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Redeem a WebAuthn Challenge in the database: ",
+        skip(challenge, database)
+    )]
+    pub async fn redeem(
+        challenge: &domain::OneTimeToken,
+        hash_key: &Secret<String>,
+        ceremony: webauthn::Ceremony,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            WebAuthnChallenges,
+            r#"
+                UPDATE webauthn_challenges
+                SET used_on = NOW()
+                WHERE challenge_hash = $1
+                    AND ceremony = $2
+                    AND used_on IS NULL
+                    AND expires_on > NOW()
+                RETURNING *
+            "#,
+            challenge.keyed_hash(hash_key),
+            ceremony.to_string(),
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("WebAuthn Challenge database record redeemed: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn redeem_challenge_once(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let (random_webauthn_challenge, random_challenge) =
+            WebAuthnChallenges::mock_data(None)?;
+        random_webauthn_challenge.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = WebAuthnChallenges::redeem(
+            &random_challenge,
+            &hash_key,
+            webauthn::Ceremony::Authentication,
+            &database,
+        )
+        .await?;
+        let second_redeem = WebAuthnChallenges::redeem(
+            &random_challenge,
+            &hash_key,
+            webauthn::Ceremony::Authentication,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record.id, random_webauthn_challenge.id);
+        assert!(database_record.used_on.is_some());
+        assert!(second_redeem.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn wrong_ceremony_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let (random_webauthn_challenge, random_challenge) =
+            WebAuthnChallenges::mock_data(None)?;
+        random_webauthn_challenge.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = WebAuthnChallenges::redeem(
+            &random_challenge,
+            &hash_key,
+            webauthn::Ceremony::Registration,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn expired_challenge_is_not_redeemed(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let hash_key = domain::OneTimeToken::mock_hash_key();
+        let (mut random_webauthn_challenge, random_challenge) =
+            WebAuthnChallenges::mock_data(None)?;
+        random_webauthn_challenge.expires_on = Utc::now() - Duration::seconds(1);
+        random_webauthn_challenge.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = WebAuthnChallenges::redeem(
+            &random_challenge,
+            &hash_key,
+            webauthn::Ceremony::Authentication,
+            &database,
+        )
+        .await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
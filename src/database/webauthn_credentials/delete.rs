@@ -0,0 +1,94 @@
+//-- ./src/database/webauthn_credentials/delete.rs
+
+// #![allow(unused)] // For development only
+
+//! Delete WebAuthn Credentials from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::WebAuthnCredentials;
+
+impl WebAuthnCredentials {
+    /// Delete a WebAuthn Credential owned by a user from the database, so it
+    /// can no longer be used to log in, returning the number of rows deleted
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The WebAuthn Credential id
+    /// * `user_id` - The user that owns the WebAuthn Credential
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Delete a users WebAuthn Credential from the database: ",
+        skip(database)
+    )]
+    pub async fn delete_by_id_and_user_id(
+        id: &Uuid,
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<u64, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE FROM webauthn_credentials
+                WHERE id = $1 AND user_id = $2
+            "#,
+            id,
+            user_id,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("WebAuthn Credentials deleted: {rows_affected}");
+
+        Ok(rows_affected)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn delete_only_owned_webauthn_credential(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let random_webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?;
+        random_webauthn_credential.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let not_owned = WebAuthnCredentials::delete_by_id_and_user_id(
+            &random_webauthn_credential.id,
+            &Uuid::now_v7(),
+            &database,
+        )
+        .await?;
+        let owned = WebAuthnCredentials::delete_by_id_and_user_id(
+            &random_webauthn_credential.id,
+            &random_user.id,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(not_owned, 0);
+        assert_eq!(owned, 1);
+
+        //-- Return
+        Ok(())
+    }
+}
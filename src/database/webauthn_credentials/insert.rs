@@ -0,0 +1,124 @@
+//-- ./src/database/webauthn_credentials/insert.rs
+
+// #![allow(unused)] // For development only
+
+//! Insert a WebAuthn Credential into the database, returning a result with the
+//! WebAuthn Credentials Model
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::WebAuthnCredentials;
+
+impl WebAuthnCredentials {
+    /// Insert a WebAuthn Credential into the database, returning the database
+    /// instance created.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The WebAuthn Credential instance to be inserted in the database.
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Insert a new WebAuthn Credential into the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+            user_id = % self.user_id,
+        ),
+    )]
+    pub async fn insert(
+        &self,
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            WebAuthnCredentials,
+            r#"
+                INSERT INTO webauthn_credentials (
+                    id,
+                    user_id,
+                    credential_id,
+                    public_key,
+                    sign_count,
+                    name,
+                    last_used_on,
+                    created_on
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING *
+            "#,
+            self.id,
+            self.user_id,
+            self.credential_id,
+            self.public_key,
+            self.sign_count,
+            self.name,
+            self.last_used_on,
+            self.created_on,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("WebAuthn Credential database record inserted: {}", database_record.id);
+
+        Ok(database_record)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    // Test inserting into database
+    #[sqlx::test]
+    async fn create_database_record(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?;
+
+        //-- Execute Function (Act)
+        let database_record = random_webauthn_credential.insert(&database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_webauthn_credential);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn duplicate_credential_id_is_rejected(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?;
+        random_webauthn_credential.insert(&database).await?;
+
+        let mut duplicate_webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?;
+        duplicate_webauthn_credential.credential_id =
+            random_webauthn_credential.credential_id.to_owned();
+
+        //-- Execute Function (Act)
+        let database_record = duplicate_webauthn_credential.insert(&database).await;
+
+        //-- Checks (Assertions)
+        assert!(database_record.is_err());
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Wrapper around WebAuthn Credentials (passkeys) database tables
+
+// #![allow(unused)] // For development only
+
+pub use model::WebAuthnCredentials;
+
+mod delete;
+mod insert;
+mod model;
+mod read;
+mod update;
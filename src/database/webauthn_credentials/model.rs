@@ -0,0 +1,120 @@
+//-- ./src/database/webauthn_credentials/model.rs
+
+// #![allow(unused)] // For development only
+
+//! The WebAuthn Credentials (passkeys) database model
+//! ---
+
+use chrono::{DateTime, SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::webauthn;
+
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct WebAuthnCredentials {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub credential_id: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub sign_count: i64,
+    pub name: String,
+    pub last_used_on: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl WebAuthnCredentials {
+    /// Create a new WebAuthn Credential instance from a verified registration
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the credential belongs to
+    /// * `name` - A name for the user to recognise the credential by
+    /// * `credential` - The credential created by the authenticator
+    /// * `sign_count` - The authenticator signature counter at registration
+    /// ---
+    pub fn new(
+        user_id: &Uuid,
+        name: &str,
+        credential: &webauthn::AttestedCredential,
+        sign_count: u32,
+    ) -> Self {
+        let id = Uuid::now_v7();
+        let user_id = user_id.to_owned();
+        let credential_id = credential.credential_id.to_owned();
+        let public_key = credential.public_key.to_owned();
+        let name = name.to_owned();
+        let created_on = Utc::now().round_subsecs(0);
+
+        Self {
+            id,
+            user_id,
+            credential_id,
+            public_key,
+            sign_count: sign_count as i64,
+            name,
+            last_used_on: None,
+            created_on,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock_data(user_id: &Uuid) -> Result<Self, crate::prelude::BackendError> {
+        use fake::faker::lorem::en::Word;
+        use fake::Fake;
+        use p256::ecdsa::SigningKey;
+        use rand::RngCore;
+
+        let mut random_credential_id = vec![0u8; 32];
+        rand::thread_rng().fill_bytes(&mut random_credential_id);
+        let random_signing_key = SigningKey::random(&mut rand::thread_rng());
+        let random_name: String = Word().fake();
+
+        let credential = webauthn::AttestedCredential {
+            credential_id: random_credential_id,
+            public_key: random_signing_key
+                .verifying_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec(),
+        };
+
+        Ok(Self::new(user_id, &random_name, &credential, 0))
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn create_new_webauthn_credential() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        let credential = webauthn::AttestedCredential {
+            credential_id: vec![1, 2, 3],
+            public_key: vec![4, 5, 6],
+        };
+
+        //-- Execute Function (Act)
+        let webauthn_credential =
+            WebAuthnCredentials::new(&random_user.id, "Laptop", &credential, 3);
+
+        //-- Checks (Assertions)
+        assert_eq!(webauthn_credential.user_id, random_user.id);
+        assert_eq!(webauthn_credential.credential_id, vec![1, 2, 3]);
+        assert_eq!(webauthn_credential.public_key, vec![4, 5, 6]);
+        assert_eq!(webauthn_credential.sign_count, 3);
+        assert_eq!(webauthn_credential.name, "Laptop");
+        assert_eq!(webauthn_credential.last_used_on, None);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//-- ./src/database/webauthn_credentials/read.rs
+
+// #![allow(unused)] // For development only
+
+//! Read WebAuthn Credentials from the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+use super::WebAuthnCredentials;
+
+impl WebAuthnCredentials {
+    /// Get a WebAuthn Credential by the authenticator credential id, returning
+    /// an sqlx RowNotFound error if the credential is not registered.
+    ///
+    /// # Parameters
+    ///
+    /// * `credential_id` - The authenticator generated credential id
+    /// * `database` - An sqlx database pool that the thing will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Read WebAuthn Credential from the database: ",
+        skip_all
+    )]
+    pub async fn from_credential_id(
+        credential_id: &[u8],
+        database: &Pool<Postgres>,
+    ) -> Result<Self, BackendError> {
+        let database_record = sqlx::query_as!(
+            WebAuthnCredentials,
+            r#"
+                SELECT *
+                FROM webauthn_credentials
+                WHERE credential_id = $1
+            "#,
+            credential_id,
+        )
+        .fetch_one(database)
+        .await?;
+
+        tracing::debug!("WebAuthn Credential database record retrieved: {}", database_record.id);
+
+        Ok(database_record)
+    }
+
+    /// Get all the WebAuthn Credentials registered by a user
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user the WebAuthn Credentials belong to
+    /// * `database` - An sqlx database pool that the things will be searched in.
+    /// ---
+    #[tracing::instrument(
+        name = "Index WebAuthn Credentials for a user from the database: ",
+        skip(database)
+    )]
+    pub async fn index_by_user_id(
+        user_id: &Uuid,
+        database: &Pool<Postgres>,
+    ) -> Result<Vec<Self>, BackendError> {
+        let database_records = sqlx::query_as!(
+            WebAuthnCredentials,
+            r#"
+                SELECT *
+                FROM webauthn_credentials
+                WHERE user_id = $1
+                ORDER BY created_on
+            "#,
+            user_id,
+        )
+        .fetch_all(database)
+        .await?;
+
+        tracing::debug!(
+            "WebAuthn Credential database records retrieved: {}",
+            database_records.len()
+        );
+
+        Ok(database_records)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn get_webauthn_credential_by_credential_id(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let random_webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?;
+        random_webauthn_credential.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_record = WebAuthnCredentials::from_credential_id(
+            &random_webauthn_credential.credential_id,
+            &database,
+        )
+        .await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_record, random_webauthn_credential);
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn index_webauthn_credentials_for_user(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+        let other_user = database::Users::mock_data()?;
+        other_user.insert(&database).await?;
+
+        WebAuthnCredentials::mock_data(&random_user.id)?.insert(&database).await?;
+        WebAuthnCredentials::mock_data(&random_user.id)?.insert(&database).await?;
+        WebAuthnCredentials::mock_data(&other_user.id)?.insert(&database).await?;
+
+        //-- Execute Function (Act)
+        let database_records =
+            WebAuthnCredentials::index_by_user_id(&random_user.id, &database).await?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database_records.len(), 2);
+        assert!(database_records
+            .iter()
+            .all(|record| record.user_id == random_user.id));
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//-- ./src/database/webauthn_credentials/update.rs
+
+// #![allow(unused)] // For development only
+
+//! Update WebAuthn Credentials in the database
+//! ---
+
+use sqlx::{Pool, Postgres};
+
+use crate::prelude::*;
+
+use super::WebAuthnCredentials;
+
+impl WebAuthnCredentials {
+    /// Record a successful assertion with the authenticator signature counter,
+    /// returning false if the counter did not increase, which means the
+    /// credential may have been cloned.
+    ///
+    /// Authenticators that do not support a counter always return zero, so a
+    /// zero counter is accepted while the stored counter is also zero. The
+    /// check and update are done in a single query so concurrent requests
+    /// cannot reuse a counter.
+    ///
+    /// # Parameters
+    ///
+    /// * `self` - The WebAuthn Credential used to log in.
+    /// * `sign_count` - The signature counter from the authenticator data
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(
+        name = "Use a WebAuthn Credential in the database: ",
+        skip(self, database),
+        fields(
+            id = % self.id,
+        ),
+    )]
+    pub async fn use_sign_count(
+        &self,
+        sign_count: u32,
+        database: &Pool<Postgres>,
+    ) -> Result<bool, BackendError> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE webauthn_credentials
+                SET sign_count = $2, last_used_on = NOW()
+                WHERE id = $1 AND (sign_count < $2 OR (sign_count = 0 AND $2 = 0))
+            "#,
+            self.id,
+            sign_count as i64,
+        )
+        .execute(database)
+        .await?
+        .rows_affected();
+
+        tracing::debug!("WebAuthn Credential signature counter used: {rows_affected}");
+
+        Ok(rows_affected == 1)
+    }
+}
+
+//-- Unit Tests
+#[cfg(test)]
+mod tests {
+    use sqlx::{Pool, Postgres};
+
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible result and error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn sign_count_must_increase(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?
+            .insert(&database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let first = webauthn_credential.use_sign_count(1, &database).await?;
+        let same = webauthn_credential.use_sign_count(1, &database).await?;
+        let lower = webauthn_credential.use_sign_count(0, &database).await?;
+        let higher = webauthn_credential.use_sign_count(5, &database).await?;
+
+        //-- Checks (Assertions)
+        assert!(first);
+        assert!(!same);
+        assert!(!lower);
+        assert!(higher);
+
+        let database_record = WebAuthnCredentials::from_credential_id(
+            &webauthn_credential.credential_id,
+            &database,
+        )
+        .await?;
+        assert_eq!(database_record.sign_count, 5);
+        assert!(database_record.last_used_on.is_some());
+
+        //-- Return
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn zero_sign_count_is_accepted(database: Pool<Postgres>) -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_user = database::Users::mock_data()?;
+        random_user.insert(&database).await?;
+
+        let webauthn_credential = WebAuthnCredentials::mock_data(&random_user.id)?
+            .insert(&database)
+            .await?;
+
+        //-- Execute Function (Act)
+        let first = webauthn_credential.use_sign_count(0, &database).await?;
+        let second = webauthn_credential.use_sign_count(0, &database).await?;
+
+        //-- Checks (Assertions)
+        assert!(first);
+        assert!(second);
+
+        //-- Return
+        Ok(())
+    }
+}
@@ -8,32 +8,35 @@
 //! into a Token Claim
 //! ---
 
-use jsonwebtoken::{encode, EncodingKey, Header};
-use secrecy::{ExposeSecret, Secret};
+use jsonwebtoken::encode;
 use uuid::Uuid;
 
-use crate::{database, domain::token_claim::TokenType, prelude::*};
+use crate::{
+    configuration::ApplicationConfiguration, database, domain::token_claim::TokenType,
+    prelude::*,
+};
 
-use super::TokenClaim;
-
-pub static ACCESS_TOKEN_DURATION: u64 = 5 * 60; // 15 minutes as seconds
+use super::{Permission, ServiceScope, TokenClaim, TokenKeys};
 
 /// Access Token for authorising endpoint requests
 /// #[derive(Debug, Clone, Default, PartialEq)]
 #[derive(Debug, Clone, Default, PartialEq)]
-pub struct AccessToken(String);
+pub struct AccessToken {
+    token: String,
+    token_id: Uuid,
+}
 
 /// Get string reference of the Access Token
 impl AsRef<str> for AccessToken {
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.token
     }
 }
 
 /// Roll our own Display trait for Access Token
 impl std::fmt::Display for AccessToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+        self.token.fmt(f)
     }
 }
 
@@ -42,12 +45,14 @@ impl AccessToken {
     ///
     /// ## Parameters
     ///
-    /// * `secret`: Secret<String> containing the token encryption secret
+    /// * `token_keys`: The keys used to sign the token
+    /// * `config`: Application configuration with the token lifetime, issuer and audience
     /// * `user_id`: Uuid of the user that is going to use the Access Token
+    /// * `permissions`: The permissions held by the user
     /// ---
     #[tracing::instrument(
         name = "Generate a new Access Token for: ",
-        skip(secret),
+        skip(token_keys, config),
     // fields(
     // 	db_id = %self.id,
     // 	user_id = %self.user_id,
@@ -57,29 +62,109 @@ impl AccessToken {
     // )
     )]
     pub fn new(
-        secret: &Secret<String>,
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
         user: &database::Users,
+        permissions: &[Permission],
+    ) -> Result<Self, BackendError> {
+        Self::new_in_organisation(token_keys, config, user, permissions, None)
+    }
+
+    /// Parse a new Access Token for a user active in one of their
+    /// organisations, carrying the organisation and the role they hold in it
+    ///
+    /// ## Parameters
+    ///
+    /// * `token_keys`: The keys used to sign the token
+    /// * `config`: Application configuration with the token lifetime, issuer and audience
+    /// * `user_id`: Uuid of the user that is going to use the Access Token
+    /// * `permissions`: The permissions held by the user
+    /// * `membership`: The membership of the organisation the user is active in, if any
+    /// ---
+    #[tracing::instrument(
+        name = "Generate a new organisation Access Token for: ",
+        skip(token_keys, config)
+    )]
+    pub fn new_in_organisation(
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
+        user: &database::Users,
+        permissions: &[Permission],
+        membership: Option<&database::OrganisationMembers>,
     ) -> Result<Self, BackendError> {
         // Build the Access Token Claim
-        let token_claim = TokenClaim::new(secret, user, &TokenType::Access);
+        let mut token_claim = TokenClaim::new(
+            config,
+            user,
+            &TokenType::Access,
+            config.access_token_seconds,
+        )
+        .with_permissions(permissions);
+        if let Some(membership) = membership {
+            token_claim = token_claim.with_organisation(membership);
+        }
 
-        // Encode the Token Claim into a URL-safe hash encryption
+        // Encode the Token Claim with the current signing key
+        let signing_key = token_keys.signing_key()?;
         let token = encode(
-            &Header::default(),
+            &signing_key.header(),
             &token_claim,
-            &EncodingKey::from_secret(secret.expose_secret().as_bytes()),
+            signing_key.encoding_key(),
         )?;
+        let token_id = Uuid::parse_str(&token_claim.jti)?;
 
-        Ok(Self(token))
+        Ok(Self { token, token_id })
+    }
+
+    /// Issue a new Access Token to a service account, limited to the scopes
+    /// granted to the token
+    ///
+    /// ## Parameters
+    ///
+    /// * `token_keys`: The keys used to sign the token
+    /// * `config`: Application configuration with the token lifetime, issuer and audience
+    /// * `service_account`: The service account that is going to use the Access Token
+    /// * `scopes`: The scopes granted to the Access Token
+    /// ---
+    #[tracing::instrument(
+        name = "Generate a new service account Access Token for: ",
+        skip(token_keys, config)
+    )]
+    pub fn new_service_account(
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
+        service_account: &database::ServiceAccounts,
+        scopes: &[ServiceScope],
+    ) -> Result<Self, BackendError> {
+        let token_claim = TokenClaim::new_service_account(
+            config,
+            service_account,
+            scopes,
+            config.access_token_seconds,
+        );
+
+        let signing_key = token_keys.signing_key()?;
+        let token = encode(
+            &signing_key.header(),
+            &token_claim,
+            signing_key.encoding_key(),
+        )?;
+        let token_id = Uuid::parse_str(&token_claim.jti)?;
+
+        Ok(Self { token, token_id })
+    }
+
+    /// The token id (jti) of the Access Token, used to revoke it before it
+    /// expires
+    pub fn token_id(&self) -> Uuid {
+        self.token_id
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use rand::distributions::{Alphanumeric, DistString};
-
+    use crate::configuration::Configuration;
     use crate::database;
-    use crate::domain::TOKEN_ISSUER;
 
     // Bring module into test scope
     use super::*;
@@ -90,22 +175,131 @@ mod tests {
 
     #[tokio::test]
     async fn generate_new_access_token() -> Result<()> {
-        // Generate random secret string
-        let secret = Alphanumeric.sample_string(&mut rand::thread_rng(), 60);
-        let secret = Secret::new(secret);
+        // Load the mock token signing keys
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
 
         // Get a random user_id for subject
         let random_user = database::Users::mock_data()?;
 
-        let access_token = AccessToken::new(&secret, &random_user)?;
+        let access_token = AccessToken::new(
+            &token_keys,
+            &config,
+            &random_user,
+            &[Permission::UsersReadSelf],
+        )?;
 
         let token_claim =
-            TokenClaim::from_token(access_token.as_ref(), &secret)?;
+            TokenClaim::from_token(access_token.as_ref(), &token_keys, &config)?;
         // println!("{token_claim:#?}");
 
-        assert_eq!(token_claim.iss, TOKEN_ISSUER);
+        assert_eq!(token_claim.iss, config.token_issuer);
+        assert_eq!(token_claim.aud, config.token_audience);
+        assert_eq!(token_claim.exp - token_claim.iat, config.access_token_seconds);
         assert_eq!(token_claim.sub, random_user.id.to_string());
         assert_eq!(token_claim.jty, TokenType::Access.to_string());
+        assert!(!token_claim.is_service_account());
+        assert!(token_claim.has_permission(Permission::UsersReadSelf));
+        assert!(!token_claim.has_permission(Permission::UsersReadAny));
+
+        Ok(())
+    }
+
+    #[test]
+    fn organisation_access_token_carries_organisation() -> Result<()> {
+        // Load the mock token signing keys
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let random_user = database::Users::mock_data()?;
+        let membership = database::OrganisationMembers::new(
+            &Uuid::now_v7(),
+            &random_user.id,
+            crate::domain::OrganisationRole::Viewer,
+        );
+
+        let access_token = AccessToken::new_in_organisation(
+            &token_keys,
+            &config,
+            &random_user,
+            &[],
+            Some(&membership),
+        )?;
+        let user_access_token = AccessToken::new(&token_keys, &config, &random_user, &[])?;
+
+        let token_claim =
+            TokenClaim::from_token(access_token.as_ref(), &token_keys, &config)?;
+        let user_token_claim =
+            TokenClaim::from_token(user_access_token.as_ref(), &token_keys, &config)?;
+
+        assert_eq!(token_claim.organisation_id(), Some(membership.organisation_id));
+        assert_eq!(token_claim.jor, Some("viewer".to_string()));
+        assert_eq!(user_token_claim.organisation_id(), None);
+        assert_eq!(user_token_claim.jor, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn service_account_token_is_scoped() -> Result<()> {
+        // Load the mock token signing keys
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let (service_account, _client_secret) = database::ServiceAccounts::mock_data()?;
+
+        let access_token = AccessToken::new_service_account(
+            &token_keys,
+            &config,
+            &service_account,
+            &[ServiceScope::Users],
+        )?;
+
+        let token_claim =
+            TokenClaim::from_token(access_token.as_ref(), &token_keys, &config)?;
+
+        assert_eq!(token_claim.sub, service_account.id.to_string());
+        assert!(token_claim.is_service_account());
+        assert!(token_claim.has_scope(ServiceScope::Users));
+        assert!(!token_claim.has_scope(ServiceScope::Logins));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_key_id_returns_error() -> Result<()> {
+        // Sign with the mock keys, but under a key id the verifier does not know
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let other_token_keys = TokenKeys::new(vec![crate::domain::SigningKey::from_pem(
+            "other-ed25519",
+            crate::configuration::JwtAlgorithm::EdDSA,
+            include_bytes!("../../configuration/keys/development_ed25519_private.pem"),
+            include_bytes!("../../configuration/keys/development_ed25519_public.pem"),
+        )?]);
+        let random_user = database::Users::mock_data()?;
+
+        let access_token = AccessToken::new(&other_token_keys, &config, &random_user, &[])?;
+
+        let token_claim =
+            TokenClaim::from_token(access_token.as_ref(), &token_keys, &config);
+        assert!(token_claim.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_audience_returns_error() -> Result<()> {
+        // Issue the token for an audience the verifier does not accept
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let mut other_config = config.clone();
+        other_config.token_audience = "other-service".to_string();
+        let random_user = database::Users::mock_data()?;
+
+        let access_token = AccessToken::new(&token_keys, &other_config, &random_user, &[])?;
+
+        let token_claim =
+            TokenClaim::from_token(access_token.as_ref(), &token_keys, &config);
+        assert!(token_claim.is_err());
 
         Ok(())
     }
@@ -0,0 +1,197 @@
+//-- ./src/domain/api_key_list.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! API Keys that can authenticate requests
+//!
+//! The Access Token interceptor cannot wait on the database, so the API Key
+//! List caches, by prefix, the API keys of active users that have not expired
+//! along with the role and permissions of each user. The list is loaded from the database on
+//! start and reloaded in the background to pick up keys created or deleted by
+//! other instances. When a key authenticates a request the time is recorded,
+//! and written to the database on the next reload.
+//! ---
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use chrono::{DateTime, Utc};
+use secrecy::Secret;
+use uuid::Uuid;
+
+use crate::database;
+use crate::domain::{ApiKey, Permission, UserRole};
+
+/// API Key with the role and permissions of the user that owns it
+pub type UsableApiKey = (database::ApiKeys, UserRole, Vec<Permission>);
+
+/// In memory cache of usable API keys, keyed by prefix
+#[derive(Debug, Default)]
+pub struct ApiKeyList {
+    keys: RwLock<HashMap<String, UsableApiKey>>,
+    last_used: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+}
+
+impl ApiKeyList {
+    /// Create a new API Key List holding the API Keys and the role and
+    /// permissions of the user that owns each key
+    pub fn new(api_keys: Vec<UsableApiKey>) -> Self {
+        let api_key_list = Self::default();
+        api_key_list.replace(api_keys);
+
+        api_key_list
+    }
+
+    /// Replace the list with the API Keys
+    pub fn replace(&self, api_keys: Vec<UsableApiKey>) {
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        *keys = api_keys
+            .into_iter()
+            .map(|usable_api_key| (usable_api_key.0.prefix.to_owned(), usable_api_key))
+            .collect();
+    }
+
+    /// Replace the API Keys of a user, such as when the user is updated,
+    /// deleted or assigned roles. An empty list drops all the keys of the user.
+    pub fn replace_user(&self, user_id: &Uuid, api_keys: Vec<UsableApiKey>) {
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        keys.retain(|_, (api_key, ..)| api_key.user_id != *user_id);
+        keys.extend(
+            api_keys
+                .into_iter()
+                .map(|usable_api_key| (usable_api_key.0.prefix.to_owned(), usable_api_key)),
+        );
+    }
+
+    /// Add an API Key created on this instance
+    pub fn insert(
+        &self,
+        api_key: database::ApiKeys,
+        role: UserRole,
+        permissions: Vec<Permission>,
+    ) {
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        keys.insert(api_key.prefix.to_owned(), (api_key, role, permissions));
+    }
+
+    /// Drop an API Key deleted on this instance
+    pub fn remove(&self, api_key_id: &Uuid) {
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        keys.retain(|_, (api_key, ..)| api_key.id != *api_key_id);
+    }
+
+    /// Find the API Key record matching the presented key, returning it with
+    /// the role and permissions of its user if the secret matches the keyed
+    /// hash and it has not expired by `now`. The time is recorded as when the
+    /// key was last used.
+    pub fn authenticate(
+        &self,
+        api_key: &str,
+        hash_key: &Secret<String>,
+        now: DateTime<Utc>,
+    ) -> Option<UsableApiKey> {
+        let api_key = ApiKey::parse(api_key)?;
+
+        let usable_api_key = self
+            .keys
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(api_key.prefix())
+            .filter(|(api_key_record, ..)| {
+                api_key_record.verify_secret(&api_key, hash_key) && !api_key_record.is_expired(now)
+            })
+            .cloned()?;
+
+        self.last_used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(usable_api_key.0.id, now);
+
+        Some(usable_api_key)
+    }
+
+    /// Take the times API Keys were last used since the last call
+    pub fn take_last_used(&self) -> Vec<(Uuid, DateTime<Utc>)> {
+        let mut last_used = self.last_used.lock().unwrap_or_else(PoisonError::into_inner);
+
+        last_used.drain().collect()
+    }
+
+    /// The number of API keys in the list
+    pub fn len(&self) -> usize {
+        self.keys.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::domain::OneTimeToken;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn listed_key_authenticates() -> Result<()> {
+        let hash_key = OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        let (api_key_record, api_key) = database::ApiKeys::mock_data(&random_user)?;
+        let usable_api_key = (
+            api_key_record.clone(),
+            random_user.role.clone(),
+            vec![Permission::UsersReadSelf],
+        );
+        let api_key_list = ApiKeyList::new(vec![usable_api_key.clone()]);
+        let now = Utc::now();
+
+        let authenticated = api_key_list.authenticate(&api_key.to_string(), &hash_key, now);
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+        let other_hash_key_authenticated =
+            api_key_list.authenticate(&api_key.to_string(), &other_hash_key, now);
+
+        assert_eq!(authenticated, Some(usable_api_key));
+        assert!(other_hash_key_authenticated.is_none());
+        assert_eq!(api_key_list.take_last_used(), vec![(api_key_record.id, now)]);
+        assert!(api_key_list.take_last_used().is_empty());
+        assert!(api_key_list
+            .authenticate(&ApiKey::generate().to_string(), &hash_key, now)
+            .is_none());
+        assert!(api_key_list
+            .authenticate("not-an-api-key", &hash_key, now)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn expired_or_removed_key_does_not_authenticate() -> Result<()> {
+        let hash_key = OneTimeToken::mock_hash_key();
+        let random_user = database::Users::mock_data()?;
+        let (api_key_record, api_key) = database::ApiKeys::mock_data(&random_user)?;
+        let api_key_list = ApiKeyList::new(vec![(
+            api_key_record.clone(),
+            random_user.role,
+            vec![Permission::UsersReadSelf],
+        )]);
+
+        let expired = api_key_list
+            .authenticate(&api_key.to_string(), &hash_key, Utc::now() + Duration::days(31));
+        api_key_list.remove(&api_key_record.id);
+        let removed = api_key_list.authenticate(&api_key.to_string(), &hash_key, Utc::now());
+
+        assert!(expired.is_none());
+        assert!(removed.is_none());
+        assert!(api_key_list.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+//-- ./src/domain/id_token.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! OpenID Connect ID Token, telling a client who the user is
+//!
+//! ID Tokens are signed with the same key ring as Access Tokens, so clients can
+//! verify them with the published JWKS. The audience is the client id and the
+//! profile and email claims are only included for the scopes granted.
+//! ---
+
+use std::time::{Duration, SystemTime};
+
+use jsonwebtoken::{decode, decode_header, encode, errors::ErrorKind, Validation};
+
+use crate::{database, prelude::*};
+
+use super::TokenKeys;
+
+/// ID Token Claim
+///
+/// # References
+///
+/// * [OpenID Connect Core ID Token](https://openid.net/specs/openid-connect-core-1_0.html#IDToken)
+/// ---
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct IdTokenClaim {
+    pub iss: String, // Issuer, the OIDC provider issuer URL
+    pub sub: String, // Subject, the User Uuid
+    pub aud: String, // Audience, the client id
+    pub exp: u64, // Expiration time (as UTC timestamp)
+    pub iat: u64, // Issued at time (as UTC timestamp)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>, // Nonce from the authorization request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>, // Profile scope: the users name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>, // Email scope: the users email address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>, // Email scope: if the email is verified
+}
+
+/// Signed ID Token
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IdToken(String);
+
+/// Get string reference of the ID Token
+impl AsRef<str> for IdToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Roll our own Display trait for ID Token
+impl std::fmt::Display for IdToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IdToken {
+    /// Sign a new ID Token for the user, returning a Result with an IdToken or
+    /// BackEnd error
+    ///
+    /// ## Parameters
+    ///
+    /// * `token_keys`: The keys used to sign the token
+    /// * `issuer`: The OIDC provider issuer URL
+    /// * `client_id`: The client the ID Token is issued to
+    /// * `user`: The user that authenticated
+    /// * `scope`: The space separated scopes granted
+    /// * `nonce`: The nonce from the authorization request
+    /// * `duration`: Seconds until the ID Token expires
+    /// ---
+    #[tracing::instrument(
+        name = "Generate a new ID Token for: ",
+        skip(token_keys, user),
+        fields(user_id = %user.id)
+    )]
+    pub fn new(
+        token_keys: &TokenKeys,
+        issuer: &str,
+        client_id: &str,
+        user: &database::Users,
+        scope: &str,
+        nonce: Option<String>,
+        duration: u64,
+    ) -> Result<Self, BackendError> {
+        let now = SystemTime::now();
+        let issued_at_timestamp = now
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("valid timestamp")
+            .as_secs();
+        let expiration_timestamp = now
+            .checked_add(Duration::from_secs(duration))
+            .expect("valid time")
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("valid timestamp")
+            .as_secs();
+
+        // Only include the claims for the scopes granted
+        let scopes: Vec<&str> = scope.split_whitespace().collect();
+        let has_profile = scopes.contains(&"profile");
+        let has_email = scopes.contains(&"email");
+
+        let id_token_claim = IdTokenClaim {
+            iss: issuer.to_owned(),
+            sub: user.id.to_string(),
+            aud: client_id.to_owned(),
+            exp: expiration_timestamp,
+            iat: issued_at_timestamp,
+            nonce,
+            name: has_profile.then(|| user.name.to_string()),
+            email: has_email.then(|| user.email.to_string()),
+            email_verified: has_email.then_some(user.is_verified),
+        };
+
+        // Encode the ID Token Claim with the current signing key
+        let signing_key = token_keys.signing_key()?;
+        let token = encode(
+            &signing_key.header(),
+            &id_token_claim,
+            signing_key.encoding_key(),
+        )?;
+
+        Ok(Self(token))
+    }
+
+    /// Decode an ID Token into an ID Token Claim, validating the signature,
+    /// expiry, issuer and audience
+    ///
+    /// ## Parameters
+    ///
+    /// * `token`: The ID Token string to be decoded
+    /// * `token_keys`: The keys used to verify the token signature
+    /// * `issuer`: The OIDC provider issuer URL
+    /// * `client_id`: The client the ID Token was issued to
+    /// ---
+    pub fn decode(
+        token: &str,
+        token_keys: &TokenKeys,
+        issuer: &str,
+        client_id: &str,
+    ) -> Result<IdTokenClaim, BackendError> {
+        // Find the public key for the key id (kid) the token was signed with
+        let header = decode_header(token)?;
+        let verifying_key = header
+            .kid
+            .as_deref()
+            .and_then(|key_id| token_keys.verifying_key(key_id))
+            .ok_or(jsonwebtoken::errors::Error::from(ErrorKind::InvalidToken))?;
+
+        let mut validation = Validation::new(verifying_key.algorithm());
+        validation.set_issuer(&[issuer]);
+        validation.set_audience(&[client_id]);
+        validation.set_required_spec_claims(&["iss", "aud", "exp", "sub"]);
+
+        let id_token_claim =
+            decode::<IdTokenClaim>(token, verifying_key.decoding_key(), &validation)
+                .map(|data| data.claims)?;
+
+        Ok(id_token_claim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database;
+
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn id_token_has_scoped_claims() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+        let random_user = database::Users::mock_data()?;
+
+        let id_token = IdToken::new(
+            &token_keys,
+            "https://issuer.example.com",
+            "client",
+            &random_user,
+            "openid email",
+            Some("nonce".to_string()),
+            300,
+        )?;
+
+        let id_token_claim = IdToken::decode(
+            id_token.as_ref(),
+            &token_keys,
+            "https://issuer.example.com",
+            "client",
+        )?;
+
+        assert_eq!(id_token_claim.sub, random_user.id.to_string());
+        assert_eq!(id_token_claim.nonce.as_deref(), Some("nonce"));
+        assert_eq!(id_token_claim.email, Some(random_user.email.to_string()));
+        assert_eq!(id_token_claim.email_verified, Some(random_user.is_verified));
+        assert_eq!(id_token_claim.name, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_client_returns_error() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+        let random_user = database::Users::mock_data()?;
+
+        let id_token = IdToken::new(
+            &token_keys,
+            "https://issuer.example.com",
+            "client",
+            &random_user,
+            "openid",
+            None,
+            300,
+        )?;
+
+        let result = IdToken::decode(
+            id_token.as_ref(),
+            &token_keys,
+            "https://issuer.example.com",
+            "other-client",
+        );
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
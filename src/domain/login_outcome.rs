@@ -0,0 +1,91 @@
+//-- ./src/domain/login_outcome.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Login outcome domain
+//!
+//! Define the outcome of a login attempt, so failed attempts such as
+//! credential stuffing can be seen alongside successful logins.
+//! ---
+
+use crate::prelude::*;
+
+/// Allowable login attempt outcomes
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    sqlx::Type,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[sqlx(type_name = "login_outcome", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LoginOutcome {
+    /// Tokens were issued
+    #[default]
+    Success,
+    /// The password did not match the account
+    BadPassword,
+    /// The email address is not registered
+    UnknownEmail,
+    /// The password matched, but the account is not active
+    Inactive,
+    /// The account or client IP address is locked after failed attempts
+    Locked,
+    /// The MFA code or passkey assertion was not valid
+    MfaFailed,
+    /// The Refresh Token was not valid or its session is not active
+    InvalidToken,
+    /// A Refresh Token was used again after it had been rotated, so its
+    /// session family was revoked
+    TokenReuse,
+}
+
+impl LoginOutcome {
+    /// Is the outcome a failed guess at credentials, which counts towards
+    /// locking the account and client IP address
+    pub fn is_failed_guess(&self) -> bool {
+        matches!(
+            self,
+            LoginOutcome::BadPassword | LoginOutcome::UnknownEmail | LoginOutcome::MfaFailed
+        )
+    }
+
+    /// Parse a login outcome from a request message
+    pub fn parse(outcome: &str) -> Result<Self, BackendError> {
+        outcome
+            .parse()
+            .map_err(|_| BackendError::LoginOutcome(outcome.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcome_round_trips_as_string() -> Result<(), BackendError> {
+        for outcome in [
+            LoginOutcome::Success,
+            LoginOutcome::BadPassword,
+            LoginOutcome::UnknownEmail,
+            LoginOutcome::Inactive,
+            LoginOutcome::Locked,
+            LoginOutcome::MfaFailed,
+            LoginOutcome::InvalidToken,
+            LoginOutcome::TokenReuse,
+        ] {
+            assert_eq!(LoginOutcome::parse(&outcome.to_string())?, outcome);
+        }
+
+        assert_eq!(LoginOutcome::BadPassword.to_string(), "bad_password");
+        assert!(LoginOutcome::parse("not_an_outcome").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+//-- ./src/domains/mfa_token.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! JSON Web Token issued at login to users with MFA enabled
+//!
+//! The MFA Token proves the users password was verified, and is exchanged
+//! along with a valid MFA code for an Access Token and Refresh Token.
+//! ---
+
+use jsonwebtoken::encode;
+
+use crate::{
+    configuration::ApplicationConfiguration, database, domain::token_claim::TokenType,
+    prelude::*,
+};
+
+use super::{TokenClaim, TokenKeys};
+
+pub static MFA_TOKEN_DURATION: u64 = 5 * 60; // 5 minutes as seconds
+
+/// MFA Token for completing a login with an MFA code
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MfaToken(String);
+
+/// Get string reference of the MFA Token
+impl AsRef<str> for MfaToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Roll our own Display trait for MFA Token
+impl std::fmt::Display for MfaToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl MfaToken {
+    /// Generate a new MFA Token, returning a Result with an MfaToken or BackEnd error
+    ///
+    /// ## Parameters
+    ///
+    /// * `token_keys`: The keys used to sign the token
+    /// * `config`: Application configuration with the token issuer and audience
+    /// * `user`: The user whose password has been verified
+    /// * `refresh_token_seconds`: Refresh Token lifetime requested at login,
+    ///   used once the MFA Token is exchanged
+    /// ---
+    #[tracing::instrument(
+        name = "Generate a new MFA Token for: ",
+        skip(token_keys, config),
+    )]
+    pub fn new(
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
+        user: &database::Users,
+        refresh_token_seconds: u64,
+    ) -> Result<Self, BackendError> {
+        // Build the MFA Token Claim, carrying the requested Refresh Token lifetime
+        let mut token_claim =
+            TokenClaim::new(config, user, &TokenType::Mfa, MFA_TOKEN_DURATION);
+        token_claim.jrl = Some(refresh_token_seconds);
+
+        // Encode the Token Claim with the current signing key
+        let signing_key = token_keys.signing_key()?;
+        let token = encode(
+            &signing_key.header(),
+            &token_claim,
+            signing_key.encoding_key(),
+        )?;
+
+        Ok(Self(token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configuration::Configuration;
+    use crate::database;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn generate_new_mfa_token() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let random_user = database::Users::mock_data()?;
+
+        let mfa_token = MfaToken::new(
+            &token_keys,
+            &config,
+            &random_user,
+            config.remember_me_max_seconds,
+        )?;
+
+        let token_claim =
+            TokenClaim::from_token(mfa_token.as_ref(), &token_keys, &config)?;
+        assert_eq!(token_claim.sub, random_user.id.to_string());
+        assert_eq!(token_claim.jty, TokenType::Mfa.to_string());
+        assert_eq!(token_claim.jrl, Some(config.remember_me_max_seconds));
+
+        Ok(())
+    }
+}
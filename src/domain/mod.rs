@@ -6,18 +6,44 @@
 #![allow(unused)] // For beginning only.
 
 mod access_token;
+mod api_key;
+mod api_key_list;
 mod email_address;
+mod id_token;
+mod login_outcome;
+mod mfa_token;
+mod one_time_token;
+mod organisation_role;
 mod password_hash;
+mod permission;
+mod recovery_code;
 mod refresh_token;
+mod revocation_list;
+mod service_scope;
 mod token_claim;
+mod token_keys;
+mod totp_secret;
 mod user_name;
 mod user_role;
 
 // Re-export domain structs
 pub use access_token::AccessToken;
+pub use api_key::ApiKey;
+pub use api_key_list::{ApiKeyList, UsableApiKey};
 pub use email_address::EmailAddress;
+pub use id_token::{IdToken, IdTokenClaim};
+pub use login_outcome::LoginOutcome;
+pub use mfa_token::MfaToken;
+pub use one_time_token::OneTimeToken;
+pub use organisation_role::OrganisationRole;
 pub use password_hash::PasswordHash;
+pub use permission::Permission;
+pub use recovery_code::{RecoveryCode, RECOVERY_CODE_COUNT};
 pub use refresh_token::RefreshToken;
-pub use token_claim::{TokenClaim, TOKEN_ISSUER};
+pub use revocation_list::RevocationList;
+pub use service_scope::ServiceScope;
+pub use token_claim::{SubjectType, TokenClaim, TokenType};
+pub use token_keys::{SigningKey, TokenKeys};
+pub use totp_secret::{TotpSecret, TOTP_STEP};
 pub use user_name::UserName;
 pub use user_role::UserRole;
@@ -0,0 +1,72 @@
+//-- ./src/domain/organisation_role.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Organisation role domain
+//!
+//! Define the role a member holds in an organisation, such as a household
+//! sharing a ledger.
+//! ---
+
+use crate::prelude::*;
+
+/// Allowable organisation member roles
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    sqlx::Type,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[sqlx(type_name = "organisation_role", rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum OrganisationRole {
+    /// Manages the organisation, inviting and removing members
+    Owner,
+    /// Reads and writes the records shared by the organisation
+    #[default]
+    Member,
+    /// Only reads the records shared by the organisation
+    Viewer,
+}
+
+impl OrganisationRole {
+    /// Can members holding the role invite and remove other members
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, OrganisationRole::Owner)
+    }
+
+    /// Parse an organisation role from a request message
+    pub fn parse(role: &str) -> Result<Self, BackendError> {
+        role.parse()
+            .map_err(|_| BackendError::OrganisationRole(role.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_round_trips_as_string() -> Result<(), BackendError> {
+        for role in [
+            OrganisationRole::Owner,
+            OrganisationRole::Member,
+            OrganisationRole::Viewer,
+        ] {
+            assert_eq!(OrganisationRole::parse(&role.to_string())?, role);
+        }
+
+        assert_eq!(OrganisationRole::Viewer.to_string(), "viewer");
+        assert!(OrganisationRole::Owner.can_manage_members());
+        assert!(!OrganisationRole::Member.can_manage_members());
+        assert!(OrganisationRole::parse("admin").is_err());
+
+        Ok(())
+    }
+}
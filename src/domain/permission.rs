@@ -0,0 +1,169 @@
+//-- ./src/domain/permission.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Permission domain
+//!
+//! Permissions name an action on a resource, and whether it can be taken on
+//! the callers `self` or on `any` record, such as `users:read:self` or
+//! `sessions:revoke:any`. Roles grant sets of permissions to users, which are
+//! carried in the `scope` claim of Access Tokens and checked by each RPC.
+//! ---
+
+use strum::IntoEnumIterator;
+
+use crate::prelude::*;
+
+/// Actions a user can be permitted to take on users, sessions, logins and roles
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+pub enum Permission {
+    #[strum(serialize = "users:create:any")]
+    UsersCreateAny,
+    #[strum(serialize = "users:read:self")]
+    UsersReadSelf,
+    #[strum(serialize = "users:read:any")]
+    UsersReadAny,
+    #[strum(serialize = "users:update:self")]
+    UsersUpdateSelf,
+    #[strum(serialize = "users:update:any")]
+    UsersUpdateAny,
+    #[strum(serialize = "users:delete:any")]
+    UsersDeleteAny,
+    #[strum(serialize = "users:unlock:any")]
+    UsersUnlockAny,
+    #[strum(serialize = "sessions:read:self")]
+    SessionsReadSelf,
+    #[strum(serialize = "sessions:read:any")]
+    SessionsReadAny,
+    #[strum(serialize = "sessions:revoke:self")]
+    SessionsRevokeSelf,
+    #[strum(serialize = "sessions:revoke:any")]
+    SessionsRevokeAny,
+    #[strum(serialize = "sessions:delete:any")]
+    SessionsDeleteAny,
+    #[strum(serialize = "logins:create:any")]
+    LoginsCreateAny,
+    #[strum(serialize = "logins:read:self")]
+    LoginsReadSelf,
+    #[strum(serialize = "logins:read:any")]
+    LoginsReadAny,
+    #[strum(serialize = "logins:update:any")]
+    LoginsUpdateAny,
+    #[strum(serialize = "logins:delete:any")]
+    LoginsDeleteAny,
+    #[strum(serialize = "roles:assign:any")]
+    RolesAssignAny,
+}
+
+impl Permission {
+    /// Parse a permission, such as from a scope claim
+    pub fn parse(permission: &str) -> Result<Self, BackendError> {
+        permission
+            .parse()
+            .map_err(|_| BackendError::Permission(permission.to_string()))
+    }
+
+    /// Join a list of permissions into a space separated scope string
+    pub fn join(permissions: &[Self]) -> String {
+        permissions
+            .iter()
+            .map(|permission| permission.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The permission for taking the same action on only the callers own
+    /// records, if there is one
+    pub fn own(&self) -> Option<Self> {
+        match self {
+            Self::UsersReadAny => Some(Self::UsersReadSelf),
+            Self::UsersUpdateAny => Some(Self::UsersUpdateSelf),
+            Self::SessionsReadAny => Some(Self::SessionsReadSelf),
+            Self::SessionsRevokeAny => Some(Self::SessionsRevokeSelf),
+            Self::LoginsReadAny => Some(Self::LoginsReadSelf),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::configuration::Configuration;
+    use crate::database;
+    use crate::domain::TokenClaim;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn permissions_round_trip() -> Result<()> {
+        for permission in Permission::iter() {
+            assert_eq!(Permission::parse(&permission.to_string())?, permission);
+        }
+        assert!(Permission::parse("users:read:everyone").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn self_permissions_only_act_on_own_records() -> Result<()> {
+        let config = Configuration::parse()?;
+        let random_user = database::Users::mock_data()?;
+        let token_claim = TokenClaim::new(
+            &config.application,
+            &random_user,
+            &crate::domain::TokenType::Access,
+            60,
+        )
+        .with_permissions(&[Permission::UsersReadSelf]);
+
+        assert!(token_claim
+            .authorise(Permission::UsersReadAny, Some(&random_user.id))
+            .is_ok());
+        assert!(token_claim
+            .authorise(Permission::UsersReadAny, Some(&Uuid::now_v7()))
+            .is_err());
+        assert!(token_claim.authorise(Permission::UsersReadAny, None).is_err());
+        assert!(token_claim
+            .authorise(Permission::UsersUpdateAny, Some(&random_user.id))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn any_permissions_act_on_anyone() -> Result<()> {
+        let config = Configuration::parse()?;
+        let random_user = database::Users::mock_data()?;
+        let permissions = Permission::iter().collect::<Vec<_>>();
+        let token_claim = TokenClaim::new(
+            &config.application,
+            &random_user,
+            &crate::domain::TokenType::Access,
+            60,
+        )
+        .with_permissions(&permissions);
+
+        for permission in Permission::iter() {
+            assert!(token_claim.authorise(permission, None).is_ok());
+        }
+
+        Ok(())
+    }
+}
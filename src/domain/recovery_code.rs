@@ -0,0 +1,123 @@
+//-- ./src/domain/recovery_code.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Single use MFA recovery code, used in place of a TOTP code when the user
+//! has lost their authenticator app
+//!
+//! Only the keyed hash of the code is stored in the database, so a database
+//! dump cannot be used to complete an MFA challenge, or to check guesses at
+//! the code without the hash key.
+//! ---
+
+use hmac::{Hmac, Mac};
+use rand::seq::SliceRandom;
+use secrecy::{ExposeSecret, Secret};
+use sha2::Sha256;
+
+/// Characters used in a recovery code, without look alike characters such as
+/// 0, o, 1, l and i, so codes are easy to copy from paper.
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Number of characters in a recovery code, excluding the separator
+const RECOVERY_CODE_LENGTH: usize = 10;
+
+/// Number of recovery codes generated for a user at a time
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Single use MFA recovery code
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryCode(String);
+
+impl RecoveryCode {
+    /// Generate a new random Recovery Code
+    pub fn generate() -> Self {
+        let mut rng = rand::thread_rng();
+        let code = (0..RECOVERY_CODE_LENGTH)
+            .map(|_| *RECOVERY_CODE_ALPHABET.choose(&mut rng).unwrap() as char)
+            .collect();
+
+        Self(code)
+    }
+
+    /// Generate a full set of new Recovery Codes
+    pub fn generate_set() -> Vec<Self> {
+        (0..RECOVERY_CODE_COUNT).map(|_| Self::generate()).collect()
+    }
+
+    /// Keyed hash of the code for storing in, or looking up from, the
+    /// database, in the same way as One Time Tokens
+    ///
+    /// ## Parameters
+    ///
+    /// * `hash_key`: The configured token hash key
+    /// ---
+    pub fn hash(&self, hash_key: &Secret<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(hash_key.expose_secret().as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(self.0.as_bytes());
+
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+}
+
+/// Make a Recovery Code from a String, such as a request message, ignoring
+/// case, whitespace and the separator shown to the user.
+impl From<String> for RecoveryCode {
+    fn from(value: String) -> Self {
+        let code = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+
+        Self(code)
+    }
+}
+
+/// Display the code split in two, such as `abcde-fghjk`, for readability
+impl std::fmt::Display for RecoveryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (first, second) = self.0.split_at(self.0.len() / 2);
+        write!(f, "{first}-{second}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_set_is_unique() {
+        let codes = RecoveryCode::generate_set();
+
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        for (index, code) in codes.iter().enumerate() {
+            assert_eq!(code.0.len(), RECOVERY_CODE_LENGTH);
+            assert!(!codes[index + 1..].contains(code));
+        }
+    }
+
+    #[test]
+    fn displayed_code_parses_to_same_hash() {
+        let code = RecoveryCode::generate();
+        let displayed = code.to_string();
+        let hash_key = Secret::new("Super_Secret_Key".to_string());
+
+        assert_eq!(displayed.len(), RECOVERY_CODE_LENGTH + 1);
+        assert_eq!(RecoveryCode::from(displayed.clone()).hash(&hash_key), code.hash(&hash_key));
+        assert_eq!(
+            RecoveryCode::from(format!(" {} ", displayed.to_uppercase())).hash(&hash_key),
+            code.hash(&hash_key)
+        );
+    }
+
+    #[test]
+    fn hash_is_keyed() {
+        let code = RecoveryCode::generate();
+        let hash_key = Secret::new("Super_Secret_Key".to_string());
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+
+        assert_ne!(code.hash(&hash_key), code.hash(&other_hash_key));
+    }
+}
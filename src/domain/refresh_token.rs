@@ -8,16 +8,19 @@
 //! into a Token Claim
 //! ---
 
-use jsonwebtoken::{encode, EncodingKey, Header};
+use hmac::{Hmac, Mac};
+use jsonwebtoken::encode;
 use secrecy::{ExposeSecret, Secret};
 use serde::Deserialize;
+use sha2::Sha256;
 use uuid::Uuid;
 
-use crate::{database, domain::token_claim::TokenType, prelude::*};
+use crate::{
+    configuration::ApplicationConfiguration, database, domain::token_claim::TokenType,
+    prelude::*,
+};
 
-use super::TokenClaim;
-
-pub static REFRESH_TOKEN_DURATION: u64 = 2 * 60 * 60; // 2 hours as seconds
+use super::{TokenClaim, TokenKeys};
 
 /// Refresh Token for authorising a new Access Token
 // #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
@@ -49,27 +52,84 @@ impl RefreshToken {
     ///
     /// ## Parameters
     ///
-    /// * `secret`: Secret<String> containing the token encryption secret
+    /// * `token_keys`: The keys used to sign the token
+    /// * `config`: Application configuration with the token issuer and audience
     /// * `user_id`: Uuid of the user that is going to use the Access Token
+    /// * `lifetime_seconds`: Seconds until the Refresh Token expires
     /// ---
     #[tracing::instrument(
         name = "Generate a new Refresh Token for: "
-        skip(secret)
+        skip(token_keys, config)
     )]
     pub fn new(
-        secret: &Secret<String>,
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
         user: &database::Users,
+        lifetime_seconds: u64,
     ) -> Result<Self, BackendError> {
         // Build the Access Token Claim
-        let token_claim = TokenClaim::new(secret, user, &TokenType::Refresh);
+        let token_claim =
+            TokenClaim::new(config, user, &TokenType::Refresh, lifetime_seconds);
 
-        // Encode the Token Claim into a URL-safe hash encryption
+        // Encode the Token Claim with the current signing key
+        let signing_key = token_keys.signing_key()?;
         let token = encode(
-            &Header::default(),
+            &signing_key.header(),
             &token_claim,
-            &EncodingKey::from_secret(secret.expose_secret().as_bytes()),
+            signing_key.encoding_key(),
         )?;
 
         Ok(Self(token))
     }
+
+    /// Keyed hash of the token for storing in, or looking up from, the
+    /// database, so a database dump cannot be used to refresh a session
+    ///
+    /// ## Parameters
+    ///
+    /// * `hash_key`: The configured token hash key
+    /// ---
+    pub fn hash(&self, hash_key: &Secret<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(hash_key.expose_secret().as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(self.0.as_bytes());
+
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configuration::Configuration;
+    use crate::database;
+    use crate::domain::TokenKeys;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn hash_is_keyed() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+        let config = Configuration::parse()?.application;
+        let random_user = database::Users::mock_data()?;
+        let refresh_token = RefreshToken::new(
+            &token_keys,
+            &config,
+            &random_user,
+            config.refresh_token_seconds,
+        )?;
+
+        let hash_key = Secret::new("Super_Secret_Key".to_string());
+        let other_hash_key = Secret::new("Other_Secret_Key".to_string());
+
+        let parsed_token = RefreshToken::from(refresh_token.to_string());
+        assert_eq!(refresh_token.hash(&hash_key), parsed_token.hash(&hash_key));
+        assert_ne!(refresh_token.hash(&hash_key), refresh_token.hash(&other_hash_key));
+        assert_eq!(refresh_token.hash(&hash_key).len(), 64);
+
+        Ok(())
+    }
 }
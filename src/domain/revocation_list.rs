@@ -0,0 +1,125 @@
+//-- ./src/domain/revocation_list.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Access Tokens revoked before they expire
+//!
+//! The Access Token interceptor cannot wait on the database, so the Revocation
+//! List caches the token ids (jti) of revoked Access Tokens in memory. The list
+//! is loaded from the database on start and reloaded in the background to pick
+//! up tokens revoked by other instances. A token only needs to stay in the list
+//! until it expires, as an expired token is already rejected.
+//! ---
+
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::database;
+
+/// In memory cache of revoked Access Token ids and when each token expires
+#[derive(Debug, Default)]
+pub struct RevocationList {
+    tokens: RwLock<HashMap<Uuid, DateTime<Utc>>>,
+}
+
+impl RevocationList {
+    /// Create a new Revocation List holding the Revoked Tokens
+    pub fn new(revoked_tokens: &[database::RevokedTokens]) -> Self {
+        let revocation_list = Self::default();
+        revocation_list.extend(revoked_tokens);
+
+        revocation_list
+    }
+
+    /// Add the Revoked Tokens to the list
+    pub fn extend(&self, revoked_tokens: &[database::RevokedTokens]) {
+        let mut tokens = self.tokens.write().unwrap_or_else(PoisonError::into_inner);
+        tokens.extend(
+            revoked_tokens
+                .iter()
+                .map(|revoked_token| (revoked_token.id, revoked_token.expires_on)),
+        );
+    }
+
+    /// Replace the list with the Revoked Tokens, keeping any revoked tokens
+    /// that have not expired in case they are not yet in the database
+    pub fn replace(&self, revoked_tokens: &[database::RevokedTokens]) {
+        self.prune(Utc::now());
+        self.extend(revoked_tokens);
+    }
+
+    /// Drop tokens that have expired by `now`, returning the number dropped
+    pub fn prune(&self, now: DateTime<Utc>) -> usize {
+        let mut tokens = self.tokens.write().unwrap_or_else(PoisonError::into_inner);
+        let count = tokens.len();
+        tokens.retain(|_, expires_on| *expires_on > now);
+
+        count - tokens.len()
+    }
+
+    /// Check if the token id (jti) of an Access Token has been revoked
+    pub fn is_revoked(&self, token_id: &str) -> bool {
+        let Ok(token_id) = Uuid::parse_str(token_id) else {
+            return false;
+        };
+
+        self.tokens
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(&token_id)
+    }
+
+    /// The number of revoked tokens in the list
+    pub fn len(&self) -> usize {
+        self.tokens.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn revoked_token_is_revoked() -> Result<()> {
+        let revoked_token = database::RevokedTokens::mock_data(&Uuid::now_v7())?;
+        let revocation_list = RevocationList::new(std::slice::from_ref(&revoked_token));
+
+        assert!(revocation_list.is_revoked(&revoked_token.id.to_string()));
+        assert!(!revocation_list.is_revoked(&Uuid::now_v7().to_string()));
+        assert!(!revocation_list.is_revoked("not-a-token-id"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn prune_drops_expired_tokens() -> Result<()> {
+        let revoked_token = database::RevokedTokens::mock_data(&Uuid::now_v7())?;
+        let mut expired_token = database::RevokedTokens::mock_data(&Uuid::now_v7())?;
+        expired_token.expires_on = Utc::now() - Duration::minutes(1);
+        let revocation_list =
+            RevocationList::new(&[revoked_token.clone(), expired_token.clone()]);
+
+        let pruned = revocation_list.prune(Utc::now());
+
+        assert_eq!(pruned, 1);
+        assert_eq!(revocation_list.len(), 1);
+        assert!(revocation_list.is_revoked(&revoked_token.id.to_string()));
+        assert!(!revocation_list.is_revoked(&expired_token.id.to_string()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//-- ./src/domain/service_scope.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Service account scope domain
+//!
+//! Service accounts are not users, so they have no role. Instead each service
+//! account is granted scopes naming the admin RPC services its Access Tokens
+//! can call.
+//! ---
+
+use crate::domain::Permission;
+use crate::prelude::*;
+
+/// Admin RPC services a service account can be granted access to
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    strum::Display,
+    strum::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum ServiceScope {
+    /// The `Logins` service
+    Logins,
+    /// The `OidcClients` service
+    OidcClients,
+    /// The `Sessions` service
+    Sessions,
+    /// The `SigningKeys` service
+    SigningKeys,
+    /// The `Authentication` service `Introspect` and `Revoke` endpoints
+    Tokens,
+    /// The `Users` service
+    Users,
+}
+
+impl ServiceScope {
+    /// Parse a service scope from a request message
+    pub fn parse(scope: &str) -> Result<Self, BackendError> {
+        scope
+            .parse()
+            .map_err(|_| BackendError::ServiceScope(scope.to_string()))
+    }
+
+    /// Parse a list of scopes, returning them sorted without duplicates
+    pub fn parse_list<S: AsRef<str>>(scopes: &[S]) -> Result<Vec<Self>, BackendError> {
+        let mut scopes = scopes
+            .iter()
+            .map(|scope| Self::parse(scope.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        scopes.sort();
+        scopes.dedup();
+
+        Ok(scopes)
+    }
+
+    /// The permissions the scope grants on the `Users`, `Sessions` and `Logins`
+    /// services. Service accounts cannot delete records or assign roles, so a
+    /// leaked client secret cannot grant admin or remove an audit trail.
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Self::Users => &[
+                Permission::UsersCreateAny,
+                Permission::UsersReadAny,
+                Permission::UsersUpdateAny,
+                Permission::UsersUnlockAny,
+            ],
+            Self::Sessions => &[Permission::SessionsReadAny, Permission::SessionsRevokeAny],
+            Self::Logins => &[Permission::LoginsCreateAny, Permission::LoginsReadAny],
+            Self::OidcClients | Self::SigningKeys | Self::Tokens => &[],
+        }
+    }
+
+    /// Join a list of scopes into a space separated scope string, as used in
+    /// the Access Token `scope` claim
+    pub fn join(scopes: &[Self]) -> String {
+        scopes
+            .iter()
+            .map(|scope| scope.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_list_is_parsed_sorted_and_deduplicated() -> Result<(), BackendError> {
+        let scopes = ServiceScope::parse_list(&["users", "logins", "users"])?;
+
+        assert_eq!(scopes, vec![ServiceScope::Logins, ServiceScope::Users]);
+        assert_eq!(ServiceScope::join(&scopes), "logins users");
+
+        Ok(())
+    }
+
+    #[test]
+    fn scopes_never_grant_role_assignment_or_deletes() -> Result<(), BackendError> {
+        let scopes = ServiceScope::parse_list(&["logins", "sessions", "users"])?;
+
+        for scope in scopes {
+            assert!(!scope.permissions().contains(&Permission::RolesAssignAny));
+            assert!(!scope.permissions().contains(&Permission::UsersDeleteAny));
+            assert!(!scope.permissions().contains(&Permission::SessionsDeleteAny));
+            assert!(!scope.permissions().contains(&Permission::LoginsDeleteAny));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_scope_is_error() {
+        assert!(matches!(
+            ServiceScope::parse("service_accounts"),
+            Err(BackendError::ServiceScope(_))
+        ));
+    }
+}
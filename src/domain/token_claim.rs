@@ -13,21 +13,18 @@
 //! * [DefGuard/defguard](https://github.com/DefGuard/defguard/blob/main/src/auth/mod.rs
 //! * [JSON Web Token (JWT)(https://www.rfc-editor.org/rfc/rfc7519#section-4.1.3)
 
-use jsonwebtoken::{
-    decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
-};
-use secrecy::{ExposeSecret, Secret};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, decode_header, Validation};
 use std::time::Duration;
 use std::time::SystemTime;
 use strum::Display;
 use uuid::Uuid;
 
+use crate::configuration::ApplicationConfiguration;
 use crate::database;
+use crate::domain::{Permission, ServiceScope, TokenKeys, UserRole};
 use crate::prelude::*;
 
-// TODO: Add to config
-pub static TOKEN_ISSUER: &str = "Authentication Microservice";
-
 /// Token Types
 //TODO: Impellent own Display trait
 #[derive(Debug, Clone, Default, PartialEq, Display)]
@@ -35,6 +32,22 @@ pub enum TokenType {
     #[default]
     Access,
     Refresh,
+    /// Short lived token exchanged, with a valid MFA code, for Access and
+    /// Refresh tokens
+    Mfa,
+    /// Claim built for a request authenticated with an API key, which is
+    /// never encoded into a token
+    ApiKey,
+}
+
+/// Token subject types, set in the `jst` claim of tokens that are not issued
+/// to users
+#[derive(Debug, Clone, Default, PartialEq, Display)]
+pub enum SubjectType {
+    #[default]
+    User,
+    /// Service account authenticated with the client credentials grant
+    Service,
 }
 
 impl rand::distributions::Distribution<TokenType> for rand::distributions::Standard {
@@ -59,13 +72,23 @@ impl rand::distributions::Distribution<TokenType> for rand::distributions::Stand
 pub struct TokenClaim {
     pub iss: String, // Optional.  Issuer of the JWT.
     pub sub: String, // Optional. Subject (whom token refers to)
-    // aud: String,         // Optional. The JWT intended recipient or audience.
+    pub aud: String, // Optional. The JWT intended recipient or audience.
     pub exp: u64, // Required (validate_exp defaults to true in validation). Expiration time (as UTC timestamp)
     pub nbf: u64, // Optional. Not Before (as UTC timestamp). Identifies the time before which JWT can not be accepted into processing.
     pub iat: u64, // Optional. Identifies the time at which the JWT was issued. This can be used to establish the age of the JWT or the exact time the token was generated.
     pub jti: String, // (JWT ID): Unique identifier; this can be used to prevent the JWT from being used more than once.
     pub jty: String, // Custom. Identify the token as access or refresh
     pub jur: String, // Custom: Add user role (authorisation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jrl: Option<u64>, // Custom: Refresh Token lifetime requested at login, carried by MFA Tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jst: Option<String>, // Custom: Subject type, set to Service for service account tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>, // Optional. Space separated permissions of a user, or scopes granted to a service account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>, // Custom: Organisation the user is active in, for records shared by its members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jor: Option<String>, // Custom: Role the user holds in the active organisation
 }
 
 impl TokenClaim {
@@ -79,32 +102,53 @@ impl TokenClaim {
     ///
     /// # Parameters
     ///
-    /// * `secret`: The secret string wrapped in a Secret for encoding token
+    /// * `config`: Application configuration with the token issuer and audience
     /// * `subject`: The recipient of the token, or who will use the token during a request. This is the User Uuid.
     /// * `token_type`: token_claim::Kind, will the new claim be an access or refresh token
+    /// * `duration`: Seconds until the token claim expires
     /// ---
     pub fn new(
-        secret: &Secret<String>,
+        config: &ApplicationConfiguration,
         user: &database::Users,
         token_type: &TokenType,
+        duration: u64,
     ) -> Self {
-        // Secret used to encode and decode tokens
-        let secret = secret.to_owned();
+        Self::new_for_subject(config, user.id, &user.role, token_type, duration)
+    }
 
-        // Set JWT issuer
-        let issuer = TOKEN_ISSUER.to_owned();
+    /// Carry the permissions held by the user in the scope claim
+    pub fn with_permissions(mut self, permissions: &[Permission]) -> Self {
+        self.scope = Some(Permission::join(permissions));
+
+        self
+    }
 
-        let user_id = user.id.to_string();
+    /// Carry the organisation the user is active in, and the role they hold in
+    /// it, in the org and jor claims
+    pub fn with_organisation(mut self, membership: &database::OrganisationMembers) -> Self {
+        self.org = Some(membership.organisation_id.to_string());
+        self.jor = Some(membership.role.to_string());
+
+        self
+    }
+
+    /// Build a Token Claim for the subject id and role
+    fn new_for_subject(
+        config: &ApplicationConfiguration,
+        subject: Uuid,
+        role: &UserRole,
+        token_type: &TokenType,
+        duration: u64,
+    ) -> Self {
+        // Set JWT issuer and audience
+        let issuer = config.token_issuer.to_owned();
+        let audience = config.token_audience.to_owned();
+
+        let user_id = subject.to_string();
 
         // System Time now
         let now = SystemTime::now();
 
-        // Match duration against token kind
-        let duration = match token_type {
-            TokenType::Access => super::access_token::ACCESS_TOKEN_DURATION,
-            TokenType::Refresh => super::refresh_token::REFRESH_TOKEN_DURATION,
-        };
-
         // Token claim will expire at what System Time
         let expiration_timestamp = now
             .checked_add(Duration::from_secs(duration))
@@ -130,19 +174,172 @@ impl TokenClaim {
 
         let token_type = token_type.to_string();
 
-        let user_role = user.role.to_string();
+        let user_role = role.to_string();
 
         Self {
             iss: issuer,
             sub: user_id,
-            // aud,
+            aud: audience,
             exp: expiration_timestamp,
             nbf: not_before_timestamp,
             iat: issued_at_timestamp,
             jti: token_id,
             jty: token_type,
             jur: user_role,
+            jrl: None,
+            jst: None,
+            scope: None,
+            org: None,
+            jor: None,
+        }
+    }
+
+    /// Create a new Access Token Claim for a service account, with the subject
+    /// type set to Service and the scopes it has been granted
+    ///
+    /// # Parameters
+    ///
+    /// * `config`: Application configuration with the token issuer and audience
+    /// * `service_account`: The service account the token is issued to
+    /// * `scopes`: The scopes granted to the token
+    /// * `duration`: Seconds until the token claim expires
+    /// ---
+    pub fn new_service_account(
+        config: &ApplicationConfiguration,
+        service_account: &database::ServiceAccounts,
+        scopes: &[ServiceScope],
+        duration: u64,
+    ) -> Self {
+        // Service accounts have no user role, so are given the least privileged
+        let mut token_claim = Self::new_for_subject(
+            config,
+            service_account.id,
+            &UserRole::Guest,
+            &TokenType::Access,
+            duration,
+        );
+        token_claim.jst = Some(SubjectType::Service.to_string());
+        token_claim.scope = Some(ServiceScope::join(scopes));
+
+        token_claim
+    }
+
+    /// Build the Token Claim for a request authenticated with an API key. The
+    /// subject is the user that owns the key, the token id (jti) is the API
+    /// key id, and the scope holds the user permissions and the services the
+    /// key is limited to.
+    /// The claim is never encoded, so it expires with the request.
+    ///
+    /// # Parameters
+    ///
+    /// * `config`: Application configuration with the token issuer and audience
+    /// * `api_key`: The API key that authenticated the request
+    /// * `role`: The role of the user that owns the API key
+    /// * `permissions`: The permissions held by the user that owns the API key
+    /// ---
+    pub fn new_api_key(
+        config: &ApplicationConfiguration,
+        api_key: &database::ApiKeys,
+        role: &UserRole,
+        permissions: &[Permission],
+    ) -> Self {
+        let mut token_claim =
+            Self::new_for_subject(config, api_key.user_id, role, &TokenType::ApiKey, 0);
+        token_claim.jti = api_key.id.to_string();
+
+        // Carry the permissions of the user, and any services the API key is
+        // limited to
+        let mut scope = permissions
+            .iter()
+            .map(|permission| permission.to_string())
+            .collect::<Vec<_>>();
+        scope.extend(api_key.scopes.iter().cloned());
+        token_claim.scope = Some(scope.join(" "));
+
+        token_claim
+    }
+
+    /// Is the request authenticated with an API key rather than a token
+    pub fn is_api_key(&self) -> bool {
+        self.jty == TokenType::ApiKey.to_string()
+    }
+
+    /// Is the token issued to a service account rather than a user
+    pub fn is_service_account(&self) -> bool {
+        self.jst.as_deref() == Some(SubjectType::Service.to_string().as_str())
+    }
+
+    /// Has the token been granted the service scope
+    pub fn has_scope(&self, scope: ServiceScope) -> bool {
+        let scope = scope.to_string();
+        self.scope
+            .as_deref()
+            .is_some_and(|scopes| scopes.split_whitespace().any(|granted| granted == scope))
+    }
+
+    /// The organisation the token is active in, if any
+    pub fn organisation_id(&self) -> Option<Uuid> {
+        self.org
+            .as_deref()
+            .and_then(|organisation_id| Uuid::parse_str(organisation_id).ok())
+    }
+
+    /// Get the Token Claim the Access Token interceptor added to the request
+    /// extensions
+    pub fn from_request_extensions(
+        request_extensions: &tonic::Extensions,
+    ) -> Result<&Self, BackendError> {
+        request_extensions
+            .get::<Self>()
+            .ok_or(BackendError::Static("Token Claim not found in request extension."))
+    }
+
+    /// Has the token been granted the permission. Service accounts are
+    /// granted the permissions of the service scopes they hold.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        if self.is_service_account() {
+            return self.scope.as_deref().is_some_and(|scopes| {
+                scopes
+                    .split_whitespace()
+                    .filter_map(|granted| ServiceScope::parse(granted).ok())
+                    .any(|scope| scope.permissions().contains(&permission))
+            });
+        }
+
+        let permission = permission.to_string();
+        self.scope
+            .as_deref()
+            .is_some_and(|scopes| scopes.split_whitespace().any(|granted| granted == permission))
+    }
+
+    /// Check the token can take the action, either on any record with the
+    /// permission, or on a record the subject owns with its `self` counterpart
+    ///
+    /// ## Parameters
+    ///
+    /// * `permission`: The permission to take the action on any record
+    /// * `owner_id`: The user that owns the record, if any
+    /// ---
+    pub fn authorise(
+        &self,
+        permission: Permission,
+        owner_id: Option<&Uuid>,
+    ) -> Result<(), BackendError> {
+        if self.has_permission(permission) {
+            return Ok(());
+        }
+
+        let is_owner = owner_id.is_some_and(|owner_id| owner_id.to_string() == self.sub);
+        let has_own_permission = permission
+            .own()
+            .is_some_and(|own_permission| self.has_permission(own_permission));
+        if is_owner && has_own_permission {
+            return Ok(());
         }
+
+        tracing::error!("Permission denied for {}: {permission}", self.sub);
+
+        Err(BackendError::PermissionDenied(permission.to_string()))
     }
 
     /// Decode a Token into to Token Claim
@@ -150,28 +347,40 @@ impl TokenClaim {
     /// ## Parameters
     ///
     /// * `token` [String]: The Token string to be decoded into a Token Claim.
-    /// * `secret`: Secret<String> containing the token encryption secret
+    /// * `token_keys`: The keys used to verify the token signature
+    /// * `config`: Application configuration with the token issuer and audience
     /// ---
     pub fn from_token(
         token: &str,
-        secret: &Secret<String>,
+        token_keys: &TokenKeys,
+        config: &ApplicationConfiguration,
     ) -> Result<Self, BackendError> {
-        // By default, automatically validate the expiration (exp) claim
-        let mut validation = Validation::default();
+        // Find the public key for the key id (kid) the token was signed with
+        let header = decode_header(token)?;
+        let verifying_key = header
+            .kid
+            .as_deref()
+            .and_then(|key_id| token_keys.verifying_key(key_id))
+            .ok_or(jsonwebtoken::errors::Error::from(ErrorKind::InvalidToken))?;
+
+        // By default, automatically validate the expiration (exp) claim. Only
+        // accept the algorithm of the key.
+        let mut validation = Validation::new(verifying_key.algorithm());
 
-        // Issuer (iss) of token to validate against
-        validation.set_issuer(&[TOKEN_ISSUER]);
+        // Issuer (iss) and audience (aud) of token to validate against
+        validation.set_issuer(&[&config.token_issuer]);
+        validation.set_audience(&[&config.token_audience]);
 
         // Validate Not before (nbf) claim
         validation.validate_nbf = true;
 
         // What is going to be validated against
-        validation.set_required_spec_claims(&["iss", "exp", "nbf"]);
+        validation.set_required_spec_claims(&["iss", "aud", "exp", "nbf"]);
 
         // Decode Access Token into a Token Claim
         let token_claim = decode::<TokenClaim>(
-            &token,
-            &DecodingKey::from_secret(secret.expose_secret().as_bytes()),
+            token,
+            verifying_key.decoding_key(),
             &validation,
         )
         .map(|data| data.claims)?;
@@ -0,0 +1,536 @@
+//-- ./src/domain/token_keys.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Asymmetric keys used to sign and verify JSON Web Tokens
+//!
+//! Tokens are signed with a private key and verified with the public key,
+//! which is published as a JSON Web Key Set (JWKS) so other services can verify
+//! tokens without holding a signing secret.
+//!
+//! The Token Keys are a key ring, so signing keys can be rotated without
+//! invalidating outstanding tokens. One key signs new tokens, while every key
+//! that has not been retired verifies tokens by the key id (kid) in the token
+//! header.
+//!
+//! # References
+//!
+//! * [JSON Web Key (JWK)](https://www.rfc-editor.org/rfc/rfc7517)
+//! * [CFRG Elliptic Curve Signatures in JOSE](https://www.rfc-editor.org/rfc/rfc8037)
+//! ---
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet,
+    KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+    RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header};
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use rsa::pkcs8::spki::{ObjectIdentifier, SubjectPublicKeyInfoRef};
+use rsa::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
+use rsa::traits::PublicKeyParts;
+use secrecy::Secret;
+
+use crate::configuration::{JwtAlgorithm, JwtConfiguration};
+use crate::prelude::*;
+
+/// Object identifier of an Ed25519 public key
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// Object identifier of an RSA public key
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// DER encoded SubjectPublicKeyInfo header of an Ed25519 public key, which is
+/// followed by the 32 byte key
+const ED25519_SPKI_HEADER: [u8; 12] =
+    [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+
+/// Bits in a generated RSA key
+const RSA_KEY_BITS: usize = 2048;
+
+/// A key pair used to sign and verify JSON Web Tokens
+#[derive(Clone)]
+pub struct SigningKey {
+    key_id: String,
+    algorithm: JwtAlgorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    jwk: Jwk,
+    signs_from: DateTime<Utc>,
+    retires_on: Option<DateTime<Utc>>,
+}
+
+/// Only show the key id, algorithm and schedule, never the keys
+impl std::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("key_id", &self.key_id)
+            .field("algorithm", &self.algorithm)
+            .field("signs_from", &self.signs_from)
+            .field("retires_on", &self.retires_on)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SigningKey {
+    /// Build a signing key from PEM encoded keys, that signs tokens straight
+    /// away and is never retired
+    ///
+    /// # Parameters
+    ///
+    /// * `key_id`: Key id added to token headers and the JWKS
+    /// * `algorithm`: The algorithm the keys are used with
+    /// * `private_key`: PKCS#8 PEM private key used to sign tokens
+    /// * `public_key`: PEM public key used to verify tokens
+    /// ---
+    pub fn from_pem(
+        key_id: &str,
+        algorithm: JwtAlgorithm,
+        private_key: &[u8],
+        public_key: &[u8],
+    ) -> Result<Self, BackendError> {
+        let (encoding_key, decoding_key) = match algorithm {
+            JwtAlgorithm::EdDSA => (
+                EncodingKey::from_ed_pem(private_key)?,
+                DecodingKey::from_ed_pem(public_key)?,
+            ),
+            JwtAlgorithm::RS256 => (
+                EncodingKey::from_rsa_pem(private_key)?,
+                DecodingKey::from_rsa_pem(public_key)?,
+            ),
+        };
+
+        let jwk = public_jwk(key_id, algorithm, public_key)?;
+
+        Ok(Self {
+            key_id: key_id.to_string(),
+            algorithm,
+            encoding_key,
+            decoding_key,
+            jwk,
+            signs_from: DateTime::UNIX_EPOCH,
+            retires_on: None,
+        })
+    }
+
+    /// Set when the key starts signing tokens, and when it stops verifying them
+    pub fn with_schedule(
+        mut self,
+        signs_from: DateTime<Utc>,
+        retires_on: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.signs_from = signs_from;
+        self.retires_on = retires_on;
+
+        self
+    }
+
+    /// Generate a new PEM encoded key pair for the algorithm, returning the
+    /// PKCS#8 private key and the public key
+    ///
+    /// # Parameters
+    ///
+    /// * `algorithm`: The algorithm the keys will be used with
+    /// ---
+    pub fn generate_pem(
+        algorithm: JwtAlgorithm,
+    ) -> Result<(Secret<String>, String), BackendError> {
+        let key_error = |error: &dyn std::fmt::Display| BackendError::TokenKey(error.to_string());
+
+        match algorithm {
+            JwtAlgorithm::EdDSA => {
+                let random = ring::rand::SystemRandom::new();
+                let private_key = Ed25519KeyPair::generate_pkcs8(&random)
+                    .map_err(|error| key_error(&error))?;
+                let key_pair = Ed25519KeyPair::from_pkcs8(private_key.as_ref())
+                    .map_err(|error| key_error(&error))?;
+                let public_key =
+                    [&ED25519_SPKI_HEADER[..], key_pair.public_key().as_ref()].concat();
+
+                Ok((
+                    Secret::new(pem::encode(&pem::Pem::new("PRIVATE KEY", private_key.as_ref()))),
+                    pem::encode(&pem::Pem::new("PUBLIC KEY", public_key)),
+                ))
+            }
+            JwtAlgorithm::RS256 => {
+                let private_key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), RSA_KEY_BITS)
+                    .map_err(|error| key_error(&error))?;
+                let public_key = rsa::RsaPublicKey::from(&private_key)
+                    .to_public_key_pem(LineEnding::LF)
+                    .map_err(|error| key_error(&error))?;
+                let private_key = private_key
+                    .to_pkcs8_pem(LineEnding::LF)
+                    .map_err(|error| key_error(&error))?;
+
+                Ok((Secret::new(private_key.to_string()), public_key))
+            }
+        }
+    }
+
+    /// Read a PEM key file
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the PEM file
+    /// ---
+    pub fn read_pem(path: &str) -> Result<Vec<u8>, BackendError> {
+        std::fs::read(path)
+            .map_err(|error| BackendError::TokenKey(format!("{}: {}", path, error)))
+    }
+
+    /// Key id of the key
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// The JSON Web Token algorithm the key is used with
+    pub fn algorithm(&self) -> Algorithm {
+        match self.algorithm {
+            JwtAlgorithm::EdDSA => Algorithm::EdDSA,
+            JwtAlgorithm::RS256 => Algorithm::RS256,
+        }
+    }
+
+    /// When the key starts signing tokens
+    pub fn signs_from(&self) -> DateTime<Utc> {
+        self.signs_from
+    }
+
+    /// When the key stops verifying tokens, if it has been retired
+    pub fn retires_on(&self) -> Option<DateTime<Utc>> {
+        self.retires_on
+    }
+
+    /// Has the key been retired at the time
+    pub fn is_retired(&self, now: DateTime<Utc>) -> bool {
+        self.retires_on.is_some_and(|retires_on| retires_on <= now)
+    }
+
+    /// Token header with the algorithm and key id of the key
+    pub fn header(&self) -> Header {
+        let mut header = Header::new(self.algorithm());
+        header.kid = Some(self.key_id.clone());
+
+        header
+    }
+
+    /// Private key used to sign tokens
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    /// Public key used to verify tokens
+    pub fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+}
+
+/// The key ring of signing keys, shared across services and replaced when the
+/// keys are rotated or reloaded from the database
+#[derive(Default)]
+pub struct TokenKeys {
+    keys: RwLock<Vec<Arc<SigningKey>>>,
+}
+
+/// Only show the keys in the ring
+impl std::fmt::Debug for TokenKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenKeys").field("keys", &self.keys()).finish()
+    }
+}
+
+impl TokenKeys {
+    /// Create a new key ring holding the signing keys
+    pub fn new(keys: Vec<SigningKey>) -> Self {
+        let token_keys = Self::default();
+        token_keys.replace(keys);
+
+        token_keys
+    }
+
+    /// Create a key ring holding only the signing key in the configuration PEM
+    /// files
+    ///
+    /// # Parameters
+    ///
+    /// * `config`: The JSON Web Token configuration
+    /// ---
+    pub fn from_config(config: &JwtConfiguration) -> Result<Self, BackendError> {
+        let private_key = SigningKey::read_pem(&config.private_key_path)?;
+        let public_key = SigningKey::read_pem(&config.public_key_path)?;
+        let signing_key =
+            SigningKey::from_pem(&config.key_id, config.algorithm, &private_key, &public_key)?;
+
+        Ok(Self::new(vec![signing_key]))
+    }
+
+    /// Replace the keys in the ring
+    pub fn replace(&self, keys: Vec<SigningKey>) {
+        let keys = keys.into_iter().map(Arc::new).collect();
+        *self.keys.write().unwrap_or_else(PoisonError::into_inner) = keys;
+    }
+
+    /// Every key in the ring, including retired keys
+    pub fn keys(&self) -> Vec<Arc<SigningKey>> {
+        self.keys.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// The key new tokens are signed with, which is the most recently promoted
+    /// key that has not been retired
+    pub fn signing_key(&self) -> Result<Arc<SigningKey>, BackendError> {
+        let now = Utc::now();
+
+        self.keys()
+            .into_iter()
+            .filter(|key| key.signs_from <= now && !key.is_retired(now))
+            .max_by_key(|key| key.signs_from)
+            .ok_or(BackendError::TokenKey("no signing key is active".to_string()))
+    }
+
+    /// The key that verifies tokens with the key id, if it has not been retired
+    pub fn verifying_key(&self, key_id: &str) -> Option<Arc<SigningKey>> {
+        let now = Utc::now();
+
+        self.keys()
+            .into_iter()
+            .find(|key| key.key_id == key_id && !key.is_retired(now))
+    }
+
+    /// The public keys that have not been retired as a JSON Web Key Set,
+    /// including keys waiting to be promoted
+    pub fn jwks(&self) -> JwkSet {
+        let now = Utc::now();
+
+        JwkSet {
+            keys: self
+                .keys()
+                .iter()
+                .filter(|key| !key.is_retired(now))
+                .map(|key| key.jwk.clone())
+                .collect(),
+        }
+    }
+
+    /// Load the development Ed25519 keys for unit testing
+    #[cfg(test)]
+    pub fn mock_data() -> Result<Self, BackendError> {
+        let signing_key = SigningKey::from_pem(
+            "mock-ed25519",
+            JwtAlgorithm::EdDSA,
+            include_bytes!("../../configuration/keys/development_ed25519_private.pem"),
+            include_bytes!("../../configuration/keys/development_ed25519_public.pem"),
+        )?;
+
+        Ok(Self::new(vec![signing_key]))
+    }
+}
+
+/// Build the JSON Web Key for the PEM encoded public key
+fn public_jwk(
+    key_id: &str,
+    algorithm: JwtAlgorithm,
+    public_key: &[u8],
+) -> Result<Jwk, BackendError> {
+    let public_key = pem::parse(public_key)
+        .map_err(|error| BackendError::TokenKey(error.to_string()))?;
+    let public_key_info = SubjectPublicKeyInfoRef::try_from(public_key.contents())
+        .map_err(|error| BackendError::TokenKey(error.to_string()))?;
+
+    let (key_algorithm, algorithm_parameters) =
+        match (algorithm, public_key_info.algorithm.oid) {
+            (JwtAlgorithm::EdDSA, oid) if oid == ED25519_OID => {
+                let x = public_key_info.subject_public_key.raw_bytes();
+                let parameters = OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(x),
+                };
+
+                (KeyAlgorithm::EdDSA, AlgorithmParameters::OctetKeyPair(parameters))
+            }
+            (JwtAlgorithm::RS256, oid) if oid == RSA_OID => {
+                let rsa_public_key = rsa::RsaPublicKey::try_from(public_key_info)
+                    .map_err(|error| BackendError::TokenKey(error.to_string()))?;
+                let parameters = RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(rsa_public_key.n().to_bytes_be()),
+                    e: URL_SAFE_NO_PAD.encode(rsa_public_key.e().to_bytes_be()),
+                };
+
+                (KeyAlgorithm::RS256, AlgorithmParameters::RSA(parameters))
+            }
+            _ => {
+                return Err(BackendError::TokenKey(format!(
+                    "public key is not a {} key",
+                    algorithm
+                )))
+            }
+        };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(key_id.to_string()),
+            ..Default::default()
+        },
+        algorithm: algorithm_parameters,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use secrecy::ExposeSecret;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// Generate a new signing key
+    fn generate_key(key_id: &str) -> Result<SigningKey> {
+        let (private_key, public_key) = SigningKey::generate_pem(JwtAlgorithm::EdDSA)?;
+
+        Ok(SigningKey::from_pem(
+            key_id,
+            JwtAlgorithm::EdDSA,
+            private_key.expose_secret().as_bytes(),
+            public_key.as_bytes(),
+        )?)
+    }
+
+    #[test]
+    fn ed25519_keys_publish_jwk() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+
+        let jwks = token_keys.jwks();
+
+        assert_eq!(jwks.keys.len(), 1);
+        let jwk = &jwks.keys[0];
+        assert_eq!(jwk.common.key_id.as_deref(), Some("mock-ed25519"));
+        match &jwk.algorithm {
+            AlgorithmParameters::OctetKeyPair(parameters) => {
+                assert_eq!(parameters.curve, EllipticCurve::Ed25519);
+                assert_eq!(URL_SAFE_NO_PAD.decode(&parameters.x)?.len(), 32);
+            }
+            _ => panic!("Expected an octet key pair"),
+        }
+
+        // The published key verifies tokens
+        DecodingKey::from_jwk(jwk)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn rsa_keys_publish_jwk() -> Result<()> {
+        let signing_key = SigningKey::from_pem(
+            "mock-rsa",
+            JwtAlgorithm::RS256,
+            include_bytes!("../../configuration/keys/development_rsa_private.pem"),
+            include_bytes!("../../configuration/keys/development_rsa_public.pem"),
+        )?;
+        let token_keys = TokenKeys::new(vec![signing_key]);
+
+        let jwks = token_keys.jwks();
+
+        let jwk = &jwks.keys[0];
+        assert_eq!(token_keys.signing_key()?.algorithm(), Algorithm::RS256);
+        assert_eq!(jwk.common.key_algorithm, Some(KeyAlgorithm::RS256));
+        match &jwk.algorithm {
+            AlgorithmParameters::RSA(parameters) => {
+                assert_eq!(parameters.e, "AQAB");
+            }
+            _ => panic!("Expected an RSA key"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatched_algorithm_is_error() -> Result<()> {
+        let signing_key = SigningKey::from_pem(
+            "mock-rsa",
+            JwtAlgorithm::EdDSA,
+            include_bytes!("../../configuration/keys/development_rsa_private.pem"),
+            include_bytes!("../../configuration/keys/development_rsa_public.pem"),
+        );
+
+        assert!(signing_key.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn header_has_key_id() -> Result<()> {
+        let token_keys = TokenKeys::mock_data()?;
+
+        let header = token_keys.signing_key()?.header();
+
+        assert_eq!(header.kid.as_deref(), Some("mock-ed25519"));
+        assert_eq!(header.alg, Algorithm::EdDSA);
+        assert!(token_keys.verifying_key("mock-ed25519").is_some());
+        assert!(token_keys.verifying_key("unknown").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn generated_ed25519_key_signs_tokens() -> Result<()> {
+        let signing_key = generate_key("generated")?;
+
+        let token = jsonwebtoken::encode(
+            &signing_key.header(),
+            &serde_json::json!({ "sub": "generated", "exp": u64::MAX }),
+            signing_key.encoding_key(),
+        )?;
+        let token_claim = jsonwebtoken::decode::<serde_json::Value>(
+            &token,
+            signing_key.decoding_key(),
+            &jsonwebtoken::Validation::new(Algorithm::EdDSA),
+        )?;
+
+        assert_eq!(token_claim.claims["sub"], "generated");
+
+        Ok(())
+    }
+
+    #[test]
+    fn ring_signs_with_latest_promoted_key() -> Result<()> {
+        let now = Utc::now();
+        let retired = generate_key("retired")?
+            .with_schedule(now - Duration::hours(3), Some(now - Duration::hours(1)));
+        let previous = generate_key("previous")?
+            .with_schedule(now - Duration::hours(2), Some(now + Duration::hours(1)));
+        let current = generate_key("current")?.with_schedule(now - Duration::hours(1), None);
+        let pending = generate_key("pending")?.with_schedule(now + Duration::hours(1), None);
+
+        let token_keys = TokenKeys::new(vec![retired, previous, current, pending]);
+
+        // The most recently promoted key signs
+        assert_eq!(token_keys.signing_key()?.key_id(), "current");
+
+        // Retired keys no longer verify, while keys being retired still do
+        assert!(token_keys.verifying_key("retired").is_none());
+        assert!(token_keys.verifying_key("previous").is_some());
+        assert!(token_keys.verifying_key("pending").is_some());
+
+        // Pending keys are published before they sign
+        let key_ids: Vec<String> = token_keys
+            .jwks()
+            .keys
+            .into_iter()
+            .filter_map(|jwk| jwk.common.key_id)
+            .collect();
+        assert_eq!(key_ids, vec!["previous", "current", "pending"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//-- ./src/email/message.rs
+
+//! Email message handed to an email sender for delivery
+//! ---
+
+use lettre::message::{Mailbox, MultiPart};
+
+use crate::domain;
+use crate::prelude::*;
+
+/// An email message to be delivered
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailMessage {
+    /// Who the email is being sent to
+    pub to: domain::EmailAddress,
+
+    /// Email subject line
+    pub subject: String,
+
+    /// Plain text body of the email
+    pub text_body: String,
+
+    /// HTML body of the email
+    pub html_body: String,
+}
+
+impl EmailMessage {
+    /// Build a new email message
+    pub fn new(
+        to: &domain::EmailAddress,
+        subject: impl Into<String>,
+        text_body: impl Into<String>,
+        html_body: impl Into<String>,
+    ) -> Self {
+        Self {
+            to: to.to_owned(),
+            subject: subject.into(),
+            text_body: text_body.into(),
+            html_body: html_body.into(),
+        }
+    }
+
+    /// Build a MIME message, with plain text and HTML alternatives, ready for
+    /// delivery.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - The mailbox the email is sent from
+    /// ---
+    pub fn to_mime(&self, from: &Mailbox) -> Result<lettre::Message, BackendError> {
+        let message = lettre::Message::builder()
+            .from(from.to_owned())
+            .to(self.to.as_ref().parse()?)
+            .subject(&self.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                self.text_body.to_owned(),
+                self.html_body.to_owned(),
+            ))?;
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::faker::internet::en::SafeEmail;
+    use fake::Fake;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn mime_message_has_both_bodies() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let random_email: String = SafeEmail().fake();
+        let to = domain::EmailAddress::parse(random_email)?;
+        let message = EmailMessage::new(&to, "Subject", "Plain text", "<p>HTML</p>");
+        let from: Mailbox = "Authentication <no-reply@localhost>".parse()?;
+
+        //-- Execute Function (Act)
+        let mime = message.to_mime(&from)?;
+        let formatted = String::from_utf8(mime.formatted())?;
+
+        //-- Checks (Assertions)
+        assert!(formatted.contains(&format!("To: {}", to)));
+        assert!(formatted.contains("Subject: Subject"));
+        assert!(formatted.contains("Content-Type: text/plain"));
+        assert!(formatted.contains("Content-Type: text/html"));
+
+        Ok(())
+    }
+}
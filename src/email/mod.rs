@@ -0,0 +1,55 @@
+//-- ./src/email/mod.rs
+
+//! Outbound email delivery
+//!
+//! Services hand an `EmailMessage` to an `EmailSender` backend, so the flows
+//! that need to email users do not care how the message is delivered. The
+//! backend is selected in the email configuration.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use crate::configuration::{EmailBackend, EmailConfiguration};
+use crate::prelude::*;
+
+pub use message::EmailMessage;
+pub use smtp_sender::SmtpSender;
+pub use spool_sender::SpoolSender;
+pub use template::EmailTemplate;
+pub(crate) use template::{escape_html, render};
+pub use tracing_sender::TracingSender;
+
+mod message;
+mod smtp_sender;
+mod spool_sender;
+mod template;
+mod tracing_sender;
+
+/// Backend for delivering email messages
+#[tonic::async_trait]
+pub trait EmailSender: Send + Sync {
+    /// Deliver the email message
+    async fn send(&self, message: EmailMessage) -> Result<(), BackendError>;
+}
+
+/// Build the email sender for the configured backend
+///
+/// # Parameters
+///
+/// * `config` - The email configuration
+/// ---
+pub fn sender_from_config(
+    config: &EmailConfiguration,
+) -> Result<Arc<dyn EmailSender>, BackendError> {
+    let email_sender: Arc<dyn EmailSender> = match config.backend {
+        EmailBackend::Tracing => Arc::new(TracingSender),
+        EmailBackend::Spool => Arc::new(SpoolSender::new(config)?),
+        EmailBackend::Smtp => Arc::new(SmtpSender::new(config)?),
+    };
+
+    tracing::info!("Email backend: {}", config.backend);
+
+    Ok(email_sender)
+}
@@ -0,0 +1,77 @@
+//-- ./src/email/smtp_sender.rs
+
+//! Email sender that delivers messages through an SMTP server
+//! ---
+
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use secrecy::ExposeSecret;
+
+use crate::configuration::{EmailConfiguration, SmtpTls};
+use crate::prelude::*;
+
+use super::{EmailMessage, EmailSender};
+
+/// Email sender that uses an SMTP server
+#[derive(Clone)]
+pub struct SmtpSender {
+    /// Pooled connection to the SMTP server
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+
+    /// Mailbox emails are sent from
+    from: Mailbox,
+}
+
+impl SmtpSender {
+    /// Build a new SMTP sender from the email configuration. The connection
+    /// is not opened until the first email is sent.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - The email configuration with the SMTP server settings
+    /// ---
+    pub fn new(config: &EmailConfiguration) -> Result<Self, BackendError> {
+        let smtp = &config.smtp;
+
+        let transport_builder = match smtp.tls {
+            SmtpTls::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp.host)
+            }
+            SmtpTls::Starttls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)?
+            }
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host)?,
+        }
+        .port(smtp.port);
+
+        // Only authenticate when a username is configured
+        let transport_builder = if smtp.username.is_empty() {
+            transport_builder
+        } else {
+            transport_builder.credentials(Credentials::new(
+                smtp.username.to_owned(),
+                smtp.password.expose_secret().to_owned(),
+            ))
+        };
+
+        let transport = transport_builder.build();
+        let from = config.from.parse()?;
+
+        Ok(Self { transport, from })
+    }
+}
+
+#[tonic::async_trait]
+impl EmailSender for SmtpSender {
+    #[tracing::instrument(name = "Send email message over SMTP: ", skip_all)]
+    async fn send(&self, message: EmailMessage) -> Result<(), BackendError> {
+        let mime = message.to_mime(&self.from)?;
+
+        let response = self.transport.send(mime).await?;
+
+        tracing::debug!("SMTP server response: {:?}", response.code());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//-- ./src/email/spool_sender.rs
+
+//! Email sender that writes messages as `.eml` files into a spool directory
+//!
+//! Used in development and integration testing, where the spooled emails can
+//! be opened in a mail client or read back by the test harness.
+//! ---
+
+use std::path::PathBuf;
+
+use lettre::message::Mailbox;
+use uuid::Uuid;
+
+use crate::configuration::EmailConfiguration;
+use crate::prelude::*;
+
+use super::{EmailMessage, EmailSender};
+
+/// Email sender that writes messages to a spool directory
+#[derive(Debug, Clone)]
+pub struct SpoolSender {
+    /// Directory the `.eml` files are written to
+    directory: PathBuf,
+
+    /// Mailbox emails are sent from
+    from: Mailbox,
+}
+
+impl SpoolSender {
+    /// Build a new spool sender from the email configuration
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - The email configuration with the spool directory
+    /// ---
+    pub fn new(config: &EmailConfiguration) -> Result<Self, BackendError> {
+        let directory = PathBuf::from(&config.spool.directory);
+        let from = config.from.parse()?;
+
+        Ok(Self { directory, from })
+    }
+}
+
+#[tonic::async_trait]
+impl EmailSender for SpoolSender {
+    #[tracing::instrument(name = "Spool email message: ", skip_all)]
+    async fn send(&self, message: EmailMessage) -> Result<(), BackendError> {
+        let mime = message.to_mime(&self.from)?;
+
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        // Time ordered file names keep the spool in the order sent. Write to a
+        // temporary file first so readers never see a partial message.
+        let file_name = Uuid::now_v7().to_string();
+        let temporary_path = self.directory.join(format!("{file_name}.tmp"));
+        let spool_path = self.directory.join(format!("{file_name}.eml"));
+
+        tokio::fs::write(&temporary_path, mime.formatted()).await?;
+        tokio::fs::rename(&temporary_path, &spool_path).await?;
+
+        tracing::debug!("Email spooled to: {}", spool_path.display());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::faker::internet::en::SafeEmail;
+    use fake::Fake;
+
+    use crate::configuration::Configuration;
+    use crate::domain;
+
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[tokio::test]
+    async fn message_is_written_to_spool() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let directory = std::env::temp_dir().join(Uuid::now_v7().to_string());
+        let mut config = Configuration::parse()?.email;
+        config.spool.directory = directory.to_string_lossy().to_string();
+        let spool_sender = SpoolSender::new(&config)?;
+
+        let random_email: String = SafeEmail().fake();
+        let to = domain::EmailAddress::parse(random_email)?;
+        let message = EmailMessage::new(&to, "Subject", "Plain text", "<p>HTML</p>");
+
+        //-- Execute Function (Act)
+        spool_sender.send(message).await?;
+
+        //-- Checks (Assertions)
+        let mut entries = std::fs::read_dir(&directory)?;
+        let spool_file = entries.next().unwrap()?.path();
+        assert_eq!(spool_file.extension().unwrap(), "eml");
+        assert!(entries.next().is_none());
+
+        let contents = std::fs::read_to_string(&spool_file)?;
+        assert!(contents.contains(&format!("To: {}", to)));
+
+        std::fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+}
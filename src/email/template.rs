@@ -0,0 +1,255 @@
+//-- ./src/email/template.rs
+
+//! Templated email bodies for each type of message sent to users
+//!
+//! Each template has a plain text and HTML body, found in `./templates`.
+//! Placeholders in the form `{{ name }}` are replaced when the template is
+//! rendered, with values escaped in the HTML body.
+//! ---
+
+use crate::domain;
+
+use super::EmailMessage;
+
+/// The types of email sent to users
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmailTemplate {
+    /// Password reset token, expiring in minutes
+    PasswordReset {
+        token: domain::OneTimeToken,
+        expires_in_minutes: i64,
+    },
+    /// Email address verification token, expiring in hours
+    EmailVerification {
+        token: domain::OneTimeToken,
+        expires_in_hours: i64,
+    },
+    /// Email change confirmation token sent to the new address, expiring in
+    /// hours
+    EmailChange {
+        token: domain::OneTimeToken,
+        expires_in_hours: i64,
+    },
+    /// Notice sent to the current address when a change of email address is
+    /// requested
+    EmailChangeNotice { new_email: domain::EmailAddress },
+    /// Organisation invitation token, expiring in days
+    OrganisationInvitation {
+        token: domain::OneTimeToken,
+        organisation: String,
+        role: domain::OrganisationRole,
+        expires_in_days: i64,
+    },
+}
+
+impl EmailTemplate {
+    /// Email subject line for the template
+    pub fn subject(&self) -> &'static str {
+        match self {
+            EmailTemplate::PasswordReset { .. } => "Reset your password",
+            EmailTemplate::EmailVerification { .. } => "Verify your email address",
+            EmailTemplate::EmailChange { .. } => "Confirm your new email address",
+            EmailTemplate::EmailChangeNotice { .. } => "Your email address is being changed",
+            EmailTemplate::OrganisationInvitation { .. } => "You have been invited to an organisation",
+        }
+    }
+
+    /// Raw plain text and HTML templates
+    fn templates(&self) -> (&'static str, &'static str) {
+        match self {
+            EmailTemplate::PasswordReset { .. } => (
+                include_str!("templates/password_reset.txt"),
+                include_str!("templates/password_reset.html"),
+            ),
+            EmailTemplate::EmailVerification { .. } => (
+                include_str!("templates/email_verification.txt"),
+                include_str!("templates/email_verification.html"),
+            ),
+            EmailTemplate::EmailChange { .. } => (
+                include_str!("templates/email_change.txt"),
+                include_str!("templates/email_change.html"),
+            ),
+            EmailTemplate::EmailChangeNotice { .. } => (
+                include_str!("templates/email_change_notice.txt"),
+                include_str!("templates/email_change_notice.html"),
+            ),
+            EmailTemplate::OrganisationInvitation { .. } => (
+                include_str!("templates/organisation_invitation.txt"),
+                include_str!("templates/organisation_invitation.html"),
+            ),
+        }
+    }
+
+    /// Values substituted into the template placeholders
+    fn values(&self) -> Vec<(&'static str, String)> {
+        match self {
+            EmailTemplate::PasswordReset {
+                token,
+                expires_in_minutes,
+            } => vec![
+                ("token", token.to_string()),
+                ("expires_in", expires_in_minutes.to_string()),
+            ],
+            EmailTemplate::EmailVerification {
+                token,
+                expires_in_hours,
+            }
+            | EmailTemplate::EmailChange {
+                token,
+                expires_in_hours,
+            } => vec![
+                ("token", token.to_string()),
+                ("expires_in", expires_in_hours.to_string()),
+            ],
+            EmailTemplate::EmailChangeNotice { new_email } => {
+                vec![("new_email", new_email.to_string())]
+            }
+            EmailTemplate::OrganisationInvitation {
+                token,
+                organisation,
+                role,
+                expires_in_days,
+            } => vec![
+                ("token", token.to_string()),
+                ("organisation", organisation.to_owned()),
+                ("role", role.to_string()),
+                ("expires_in", expires_in_days.to_string()),
+            ],
+        }
+    }
+
+    /// Render the plain text body
+    pub fn text_body(&self) -> String {
+        let (text, _html) = self.templates();
+
+        render(text, &self.values(), |value| value.to_string())
+    }
+
+    /// Render the HTML body
+    pub fn html_body(&self) -> String {
+        let (_text, html) = self.templates();
+
+        render(html, &self.values(), escape_html)
+    }
+
+    /// Render the template into an email message to the given address
+    ///
+    /// # Parameters
+    ///
+    /// * `to` - The email address the message is sent to
+    /// ---
+    pub fn message(&self, to: &domain::EmailAddress) -> EmailMessage {
+        EmailMessage::new(to, self.subject(), self.text_body(), self.html_body())
+    }
+}
+
+/// Replace the `{{ name }}` placeholders in a template
+pub(crate) fn render(
+    template: &str,
+    values: &[(&str, String)],
+    escape: impl Fn(&str) -> String,
+) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |rendered, (name, value)| {
+            rendered.replace(&format!("{{{{ {name} }}}}"), &escape(value))
+        })
+}
+
+/// Escape characters with special meaning in HTML
+pub(crate) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_reset_renders_token() {
+        let token = domain::OneTimeToken::generate();
+        let template = EmailTemplate::PasswordReset {
+            token: token.clone(),
+            expires_in_minutes: 60,
+        };
+
+        let text_body = template.text_body();
+        let html_body = template.html_body();
+
+        assert!(text_body.contains(token.as_ref()));
+        assert!(text_body.contains("60 minutes"));
+        assert!(html_body.contains(token.as_ref()));
+        assert!(!text_body.contains("{{"));
+        assert!(!html_body.contains("{{"));
+    }
+
+    #[test]
+    fn email_verification_renders_token() {
+        let token = domain::OneTimeToken::generate();
+        let template = EmailTemplate::EmailVerification {
+            token: token.clone(),
+            expires_in_hours: 24,
+        };
+
+        let text_body = template.text_body();
+        let html_body = template.html_body();
+
+        assert!(text_body.contains(token.as_ref()));
+        assert!(text_body.contains("24 hours"));
+        assert!(html_body.contains(token.as_ref()));
+        assert!(!text_body.contains("{{"));
+        assert!(!html_body.contains("{{"));
+    }
+
+    #[test]
+    fn email_change_notice_renders_new_email() -> Result<(), crate::prelude::BackendError> {
+        let new_email = domain::EmailAddress::mock_data()?;
+        let template = EmailTemplate::EmailChangeNotice {
+            new_email: new_email.clone(),
+        };
+
+        let text_body = template.text_body();
+        let html_body = template.html_body();
+
+        assert!(text_body.contains(new_email.as_ref()));
+        assert!(!text_body.contains("{{"));
+        assert!(!html_body.contains("{{"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn organisation_invitation_renders_organisation() {
+        let token = domain::OneTimeToken::generate();
+        let template = EmailTemplate::OrganisationInvitation {
+            token: token.clone(),
+            organisation: "Smith & Jones".to_string(),
+            role: domain::OrganisationRole::Viewer,
+            expires_in_days: 7,
+        };
+
+        let text_body = template.text_body();
+        let html_body = template.html_body();
+
+        assert!(text_body.contains(token.as_ref()));
+        assert!(text_body.contains("Smith & Jones as a viewer"));
+        assert!(text_body.contains("7 days"));
+        assert!(html_body.contains("Smith &amp; Jones"));
+        assert!(!text_body.contains("{{"));
+        assert!(!html_body.contains("{{"));
+    }
+
+    #[test]
+    fn html_values_are_escaped() {
+        let values = [("token", "<script>".to_string())];
+
+        let rendered = render("{{ token }}", &values, escape_html);
+
+        assert_eq!(rendered, "&lt;script&gt;");
+    }
+}
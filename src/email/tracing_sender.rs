@@ -0,0 +1,29 @@
+//-- ./src/email/tracing_sender.rs
+
+//! Email sender that writes messages to the tracing log instead of sending them
+//!
+//! Useful in development when there is no mail server to hand.
+//! ---
+
+use crate::prelude::*;
+
+use super::{EmailMessage, EmailSender};
+
+/// Email sender that logs messages
+#[derive(Debug, Clone, Default)]
+pub struct TracingSender;
+
+#[tonic::async_trait]
+impl EmailSender for TracingSender {
+    #[tracing::instrument(name = "Log email message: ", skip_all)]
+    async fn send(&self, message: EmailMessage) -> Result<(), BackendError> {
+        tracing::info!(
+            "Email to: {}, subject: {}\n{}",
+            message.to,
+            message.subject,
+            message.text_body
+        );
+
+        Ok(())
+    }
+}
@@ -29,6 +29,9 @@ pub enum BackendError {
     #[error("Email format is invalid: {0}")]
     EmailFormatInvalid(String),
 
+    #[error("New email address is the same as the current one")]
+    EmailUnchanged,
+
     #[error("Name format is invalid: {0}")]
     UserNameFormatInvalid(String),
 
@@ -44,6 +47,78 @@ pub enum BackendError {
     #[error("User role does not exist.")]
     UserRole,
 
+    #[error("Login outcome does not exist: {0}")]
+    LoginOutcome(String),
+
+    #[error("Service account scope does not exist: {0}")]
+    ServiceScope(String),
+
+    #[error("API key is invalid: {0}")]
+    ApiKeyInvalid(String),
+
+    #[error("API keys cannot be used to manage API keys")]
+    ApiKeyNotAllowed,
+
+    #[error("Permission does not exist: {0}")]
+    Permission(String),
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Role is invalid: {0}")]
+    RoleInvalid(String),
+
+    #[error("Role already exists: {0}")]
+    RoleAlreadyExists(String),
+
+    #[error("System roles cannot be {0}")]
+    SystemRole(&'static str),
+
+    #[error("Request is not authenticated with a session")]
+    NoCurrentSession,
+
+    #[error("Organisation role does not exist: {0}")]
+    OrganisationRole(String),
+
+    #[error("Organisation is invalid: {0}")]
+    OrganisationInvalid(String),
+
+    #[error("User is not a member of the organisation")]
+    NotOrganisationMember,
+
+    #[error("Organisations must keep at least one owner")]
+    LastOrganisationOwner,
+
+    #[error("User already exists: {0}")]
+    UserAlreadyExists(String),
+
+    #[error("User registration is closed")]
+    RegistrationClosed,
+
+    #[error("Too many failed login attempts, try again later")]
+    LoginLocked,
+
+    #[error("Remember me seconds must be between {0} and {1}")]
+    RememberMeOutOfBounds(u64, u64),
+
+    #[error("TOTP is already enabled")]
+    TotpAlreadyEnabled,
+
+    #[error("TOTP enrolment has not been started")]
+    TotpNotEnrolled,
+
+    #[error("TOTP code is invalid")]
+    TotpCodeInvalid,
+
+    #[error("TOTP secret could not be encrypted or decrypted")]
+    TotpSecretEncryption,
+
+    #[error("WebAuthn verification failed: {0}")]
+    WebAuthn(String),
+
+    #[error("Token signing key is invalid: {0}")]
+    TokenKey(String),
+
     //-- External errors
     /// Derive IO errors
     #[error(transparent)]
@@ -91,12 +166,75 @@ pub enum BackendError {
     #[error(transparent)]
     Chrono(#[from] chrono::ParseError),
 
+    #[error("totp: {0}")]
+    TotpUrl(#[from] totp_rs::TotpUrlError),
+
+    // Email address, message and transport errors
+    #[error("email address: {0}")]
+    EmailAddress(#[from] lettre::address::AddressError),
+
+    #[error("email message: {0}")]
+    EmailMessage(#[from] lettre::error::Error),
+
+    #[error("smtp: {0}")]
+    Smtp(#[from] lettre::transport::smtp::Error),
+
 }
 
 impl From<BackendError> for tonic::Status {
     fn from(backend_error: BackendError) -> tonic::Status {
         match backend_error {
             BackendError::AuthenticationError(m) => tonic::Status::unauthenticated(m),
+            BackendError::UserAlreadyExists(_) => {
+                tonic::Status::already_exists("User already exists!")
+            }
+            BackendError::RoleAlreadyExists(_) => {
+                tonic::Status::already_exists(backend_error.to_string())
+            }
+            BackendError::SystemRole(_) => {
+                tonic::Status::failed_precondition(backend_error.to_string())
+            }
+            BackendError::RegistrationClosed => {
+                tonic::Status::permission_denied("Registration is closed!")
+            }
+            BackendError::PermissionDenied(_) => {
+                tonic::Status::permission_denied(backend_error.to_string())
+            }
+            BackendError::NotOrganisationMember => {
+                tonic::Status::permission_denied(backend_error.to_string())
+            }
+            BackendError::ApiKeyNotAllowed => {
+                tonic::Status::permission_denied(backend_error.to_string())
+            }
+            BackendError::LoginLocked => {
+                tonic::Status::resource_exhausted(backend_error.to_string())
+            }
+            BackendError::TotpAlreadyEnabled
+            | BackendError::TotpNotEnrolled
+            | BackendError::NoCurrentSession
+            | BackendError::LastOrganisationOwner => {
+                tonic::Status::failed_precondition(backend_error.to_string())
+            }
+            BackendError::TotpCodeInvalid
+            | BackendError::WebAuthn(_)
+            | BackendError::RememberMeOutOfBounds(..) => {
+                tonic::Status::invalid_argument(backend_error.to_string())
+            }
+            BackendError::EmailIsEmpty
+            | BackendError::EmailUnchanged
+            | BackendError::LoginOutcome(_)
+            | BackendError::ServiceScope(_)
+            | BackendError::Permission(_)
+            | BackendError::ApiKeyInvalid(_)
+            | BackendError::RoleInvalid(_)
+            | BackendError::OrganisationRole(_)
+            | BackendError::OrganisationInvalid(_)
+            | BackendError::AddressParse(_)
+            | BackendError::EmailFormatInvalid(_)
+            | BackendError::UserNameFormatInvalid(_)
+            | BackendError::PasswordFormatInvalid => {
+                tonic::Status::invalid_argument(backend_error.to_string())
+            }
             // BackendError::EmailFormatInvalid(_) => {
             //     Status::invalid_argument(format!("{:?}", backend_error))
             // }
@@ -7,8 +7,10 @@ pub use error::BackendError;
 pub mod configuration;
 pub mod database;
 pub mod domain;
+pub mod email;
 mod error;
 pub mod middleware;
+pub mod oidc;
 pub mod prelude;
 pub mod router;
 pub mod rpc;
@@ -16,3 +18,4 @@ pub mod services;
 pub mod startup;
 pub mod telemetry;
 pub mod utils;
+pub mod webauthn;
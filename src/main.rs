@@ -6,8 +6,10 @@
 mod configuration;
 mod database;
 mod domain;
+mod email;
 mod error;
 mod middleware;
+mod oidc;
 mod prelude;
 mod router;
 mod rpc;
@@ -15,6 +17,7 @@ mod services;
 mod startup;
 mod telemetry;
 mod utils;
+mod webauthn;
 
 // use configuration::Configuration;
 
@@ -26,7 +29,7 @@ use crate::prelude::*;
 #[tokio::main]
 async fn main() -> Result<(), BackendError> {
     // Start tracing
-    let _telemetry = telemetry::init()?;
+    telemetry::init()?;
 
     // Parse configuration files
     let config = Configuration::parse()?;
@@ -3,15 +3,198 @@
 // #![allow(unused)] // For beginning only.
 
 use std::str::FromStr;
+use std::sync::Arc;
 
-use secrecy::Secret;
+use chrono::Utc;
 
-use crate::{domain, prelude::*};
+use crate::{configuration::Configuration, domain, prelude::*};
 
 /// Check
 #[derive(Clone)]
 pub struct AccessTokenInterceptor {
-    pub(crate) token_secret: Secret<String>,
+    /// Keys used to verify the Access Token signature
+    pub(crate) token_keys: Arc<domain::TokenKeys>,
+    /// Configuration with the token issuer and audience to validate against
+    pub(crate) config: Arc<Configuration>,
+    /// Access Tokens revoked before they expire
+    pub(crate) revocation_list: Arc<domain::RevocationList>,
+    /// API keys accepted in the `api_key` metadata instead of an Access Token
+    pub(crate) api_key_list: Arc<domain::ApiKeyList>,
+    /// Only allow requests from users with the Admin role
+    pub(crate) admin_only: bool,
+    /// Allow requests from service accounts granted this scope. Service
+    /// accounts are rejected when there is no scope.
+    pub(crate) service_scope: Option<domain::ServiceScope>,
+}
+
+impl AccessTokenInterceptor {
+    /// Copy the interceptor, also allowing service accounts granted the scope
+    pub(crate) fn with_service_scope(&self, service_scope: domain::ServiceScope) -> Self {
+        Self {
+            service_scope: Some(service_scope),
+            ..self.clone()
+        }
+    }
+
+    /// Decode and validate the Access Token into a Token Claim
+    fn access_token_claim(
+        &self,
+        access_token: &tonic::metadata::MetadataValue<tonic::metadata::Ascii>,
+    ) -> Result<domain::TokenClaim, BackendError> {
+        // Convert Ascii to a string reference
+        let access_token = access_token.to_str().map_err(|_| {
+            tracing::error!("Access Token is invalid!");
+            // Return error
+            BackendError::AuthenticationError(
+                "Authentication Failed! No valid auth token.".to_string(),
+            )
+        })?;
+
+        // Using the Token Keys decode the Access Token into a Token Claim. This also
+        // validates the token expiration, not before, issuer and audience.
+        let access_token_claim = domain::TokenClaim::from_token(
+            access_token,
+            &self.token_keys,
+            &self.config.application,
+        )
+        .map_err(|_| {
+            tracing::error!("Access Token is invalid!");
+            // Return error
+            BackendError::AuthenticationError(
+                "Authentication Failed! No valid auth token.".to_string(),
+            )
+        })?;
+
+        // Only Access Tokens authorise requests, not Refresh or MFA Tokens
+        if access_token_claim.jty != domain::TokenType::Access.to_string() {
+            tracing::error!("Token is not an Access Token!");
+            return Err(BackendError::AuthenticationError(
+                "Authentication Failed! No valid auth token.".to_string(),
+            ));
+        }
+
+        // Revoked Access Tokens cannot be used, even before they expire
+        if self.revocation_list.is_revoked(&access_token_claim.jti) {
+            tracing::error!("Access Token has been revoked!");
+            return Err(BackendError::AuthenticationError(
+                "Authentication Failed! No valid auth token.".to_string(),
+            ));
+        }
+
+        // Service accounts have no role, so can only call the services
+        // they have been granted the scope for
+        if access_token_claim.is_service_account() {
+            self.check_service_scope(&access_token_claim)?;
+
+            tracing::info!(
+                "Access Token authenticated for service account: {}",
+                access_token_claim.sub
+            );
+
+            return Ok(access_token_claim);
+        }
+
+        tracing::info!(
+            "Access Token authenticated for user: {}",
+            access_token_claim.sub
+        );
+
+        self.check_user_role(&access_token_claim)?;
+
+        Ok(access_token_claim)
+    }
+
+    /// Look up the API Key in the API Key List, building a Token Claim for the
+    /// user that owns the key
+    fn api_key_claim(
+        &self,
+        api_key: &tonic::metadata::MetadataValue<tonic::metadata::Ascii>,
+    ) -> Result<domain::TokenClaim, BackendError> {
+        let hash_key = &self.config.jwt.token_hash_key;
+        let (api_key, user_role, permissions) = api_key
+            .to_str()
+            .ok()
+            .and_then(|api_key| self.api_key_list.authenticate(api_key, hash_key, Utc::now()))
+            .ok_or_else(|| {
+                tracing::error!("API Key is invalid!");
+                BackendError::AuthenticationError(
+                    "Authentication Failed! No valid auth token.".to_string(),
+                )
+            })?;
+
+        let api_key_claim = domain::TokenClaim::new_api_key(
+            &self.config.application,
+            &api_key,
+            &user_role,
+            &permissions,
+        );
+
+        // API keys limited to some services can only call those services, while
+        // API keys without scopes have the same access as their user
+        if !api_key.scopes.is_empty() {
+            self.check_service_scope(&api_key_claim)?;
+        }
+
+        tracing::info!(
+            "API Key {} authenticated for user: {}",
+            api_key.id,
+            api_key_claim.sub
+        );
+
+        self.check_user_role(&api_key_claim)?;
+
+        Ok(api_key_claim)
+    }
+
+    /// Check the Token Claim has been granted the scope of the interceptor
+    fn check_service_scope(&self, token_claim: &domain::TokenClaim) -> Result<(), BackendError> {
+        let has_scope = self
+            .service_scope
+            .is_some_and(|scope| token_claim.has_scope(scope));
+        if !has_scope {
+            tracing::error!("Request without service scope: {}", &token_claim.sub);
+            return Err(BackendError::AuthenticationError(
+                "Service account scope required!".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Authenticate the request metadata with an Access Token, or else an API
+    /// key, returning the Token Claim
+    pub(crate) fn authenticate(
+        &self,
+        metadata: &tonic::metadata::MetadataMap,
+    ) -> Result<domain::TokenClaim, BackendError> {
+        match (metadata.get("access_token"), metadata.get("api_key")) {
+            (Some(access_token), _) => self.access_token_claim(access_token),
+            (None, Some(api_key)) => self.api_key_claim(api_key),
+            (None, None) => {
+                tracing::error!("Access Token not in request header");
+                Err(BackendError::AuthenticationError(
+                    "Authentication Failed! No valid auth token.".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// Check the user role in the Token Claim can call the endpoint
+    fn check_user_role(&self, token_claim: &domain::TokenClaim) -> Result<(), BackendError> {
+        // Parse Token Claim user role into domain type
+        let requester_role = domain::UserRole::from_str(&token_claim.jur)?;
+
+        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
+        // for endpoints that require admin
+        if self.admin_only && requester_role != domain::UserRole::Admin {
+            tracing::error!("User request admin endpoint: {}", &token_claim.sub);
+            return Err(BackendError::AuthenticationError(
+                "Admin access required!".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl tonic::service::Interceptor for AccessTokenInterceptor {
@@ -21,70 +204,19 @@ impl tonic::service::Interceptor for AccessTokenInterceptor {
     ) -> Result<tonic::Request<()>, tonic::Status> {
         // let remote_address = request::remote_addr();
 
-        // Unwrap the .get() option
-        match request.metadata().get("access_token") {
-            Some(access_token) => {
-                // Convert Ascii to a string reference
-                let access_token = access_token.to_str().map_err(|_| {
-                    tracing::error!("Access Token is invalid!");
-                    // Return error
-                    BackendError::AuthenticationError(
-                        "Authentication Failed! No valid auth token.".to_string(),
-                    )
-                })?;
-
-                // Using the Token Secret decode the Access Token into a Token Claim. This also
-                // validates the token expiration, not before and Issuer.
-                let access_token_claim =
-                    domain::TokenClaim::from_token(access_token, &self.token_secret)
-                        .map_err(|_| {
-                            tracing::error!("Access Token is invalid!");
-                            // Return error
-                            BackendError::AuthenticationError(
-                                "Authentication Failed! No valid auth token."
-                                    .to_string(),
-                            )
-                        })?;
-
-                tracing::info!(
-                    "Access Token authenticated for user: {}",
-                    access_token_claim.sub
-                );
-
-                // Parse Token Claim user role into domain type
-                let requester_role =
-                    domain::UserRole::from_str(&access_token_claim.jur)?;
-
-                // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-                // All endpoints in the authentication microservice require admin so we check it here
-                if requester_role != domain::UserRole::Admin {
-                    tracing::error!(
-                        "User request admin endpoint: {}",
-                        &access_token_claim.sub
-                    );
-                    return Err(tonic::Status::unauthenticated(
-                        "Admin access required!",
-                    ));
-                }
-
-                // Add access token claim to request
-                // let (request_metadata, request_extensions, request_message) = request.into_parts();
-
-                // let access_token_claim = request_extensions.get::<domain::TokenClaim>().ok_or(
-                //     BackendError::Static("Token Claim not found in request extension."),
-                // )?;
-
-                // Add Access token to the Tonic request extension for reference in services
-                request.extensions_mut().insert(access_token_claim);
-
-                Ok(request)
-            }
-            None => {
-                tracing::error!("Access Token not in request header");
-                Err(tonic::Status::unauthenticated(
-                    "Authentication Failed! No valid auth token.",
-                ))
-            }
-        }
+        // Requests are authenticated with an Access Token, or else an API key
+        let access_token_claim = self.authenticate(request.metadata())?;
+
+        // Add access token claim to request
+        // let (request_metadata, request_extensions, request_message) = request.into_parts();
+
+        // let access_token_claim = request_extensions.get::<domain::TokenClaim>().ok_or(
+        //     BackendError::Static("Token Claim not found in request extension."),
+        // )?;
+
+        // Add Access token to the Tonic request extension for reference in services
+        request.extensions_mut().insert(access_token_claim);
+
+        Ok(request)
     }
 }
@@ -0,0 +1,541 @@
+//-- ./src/oidc/authorize.rs
+
+//! OIDC authorize endpoint
+//!
+//! Shows the sign in form for a valid authorization request, then checks the
+//! users password, and second factor when enabled, before redirecting back to
+//! the client with a single use authorization code. The second factor is a
+//! TOTP or Recovery Code, or a passkey, depending on what the user has set up.
+//!
+//! Requests with an unknown client or unregistered redirect URI are shown an
+//! error page, as redirecting them could send the user to an attacker. Other
+//! invalid requests are redirected back to the client with the error.
+//!
+//! The sign in form carries a CSRF token, an HMAC of the browsers CSRF cookie
+//! and the authorization request, so a form posted from another site, or for
+//! a different request, is rejected.
+//! ---
+
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, Query, State};
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Form;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use secrecy::ExposeSecret;
+use sha2::Sha256;
+use url::Url;
+use uuid::Uuid;
+
+use crate::rpc::proto::FinishWebAuthnLoginRequest;
+use crate::{database, domain, email, prelude::*};
+
+use super::{OidcError, OidcState, SUPPORTED_SCOPES};
+
+/// Name of the cookie holding the browsers CSRF secret
+const CSRF_COOKIE: &str = "oidc_csrf";
+
+/// Authorization request parameters, from the query string or sign in form
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct AuthorizeParams {
+    response_type: Option<String>,
+    client_id: Option<String>,
+    redirect_uri: Option<String>,
+    scope: Option<String>,
+    state: Option<String>,
+    nonce: Option<String>,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<String>,
+}
+
+/// Sign in form, posted with the authorization request parameters
+#[derive(Debug, serde::Deserialize)]
+pub struct SignInForm {
+    #[serde(flatten)]
+    params: AuthorizeParams,
+    csrf_token: Option<String>,
+    email: Option<String>,
+    password: Option<String>,
+    mfa_token: Option<String>,
+    mfa_code: Option<String>,
+    // The passkey assertion from navigator.credentials.get(), base64url encoded
+    credential_id: Option<String>,
+    client_data_json: Option<String>,
+    authenticator_data: Option<String>,
+    signature: Option<String>,
+}
+
+/// A validated authorization request
+#[derive(Debug)]
+struct AuthorizationRequest {
+    client: database::OidcClients,
+    redirect_uri: String,
+    scope: String,
+    state: Option<String>,
+    nonce: Option<String>,
+    code_challenge: String,
+    /// CSRF token for the sign in forms, set once the request is validated
+    csrf_token: String,
+}
+
+/// Authorization request errors, either shown to the user or sent to the client
+pub enum AuthorizeError {
+    /// The client or redirect URI cannot be trusted, so show an error page
+    Page(String),
+    /// Redirect the error back to the client
+    Redirect(Url),
+    /// The request could not be completed
+    Server(OidcError),
+}
+
+impl IntoResponse for AuthorizeError {
+    fn into_response(self) -> Response {
+        match self {
+            AuthorizeError::Page(message) => {
+                let body = format!(
+                    "<!DOCTYPE html><html><body><p>{}</p></body></html>",
+                    email::escape_html(&message)
+                );
+                (StatusCode::BAD_REQUEST, Html(body)).into_response()
+            }
+            AuthorizeError::Redirect(url) => Redirect::to(url.as_str()).into_response(),
+            AuthorizeError::Server(error) => error.into_response(),
+        }
+    }
+}
+
+impl From<BackendError> for AuthorizeError {
+    fn from(backend_error: BackendError) -> Self {
+        AuthorizeError::Server(backend_error.into())
+    }
+}
+
+/// Get an optional parameter, treating an empty value as missing
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+/// Decode a base64url form field, where an invalid value is left empty to fail
+/// verification
+fn decode_field(value: Option<String>) -> Vec<u8> {
+    value
+        .and_then(|value| URL_SAFE_NO_PAD.decode(value).ok())
+        .unwrap_or_default()
+}
+
+/// Build the client redirect URI with the query parameters appended
+fn redirect_url(redirect_uri: &str, params: &[(&str, Option<&str>)]) -> Url {
+    // Registered redirect URIs are parsed when the client is created
+    let mut url = Url::parse(redirect_uri).expect("registered redirect URI is a URL");
+    {
+        let mut query = url.query_pairs_mut();
+        for (name, value) in params {
+            if let Some(value) = value {
+                query.append_pair(name, value);
+            }
+        }
+    }
+    url
+}
+
+/// Validate the authorization request parameters
+async fn validate(
+    state: &OidcState,
+    params: AuthorizeParams,
+) -> Result<AuthorizationRequest, AuthorizeError> {
+    //-- 1. The client and redirect URI must be registered before anything is
+    // sent to the redirect URI
+    let client_id = non_empty(params.client_id)
+        .and_then(|client_id| Uuid::try_parse(&client_id).ok())
+        .ok_or_else(|| AuthorizeError::Page("Unknown client".to_string()))?;
+
+    let client = match database::OidcClients::from_id(&client_id, state.database_ref()).await {
+        Ok(client) if client.is_active => client,
+        _ => return Err(AuthorizeError::Page("Unknown client".to_string())),
+    };
+
+    let redirect_uri = non_empty(params.redirect_uri)
+        .filter(|redirect_uri| client.has_redirect_uri(redirect_uri))
+        .ok_or_else(|| {
+            AuthorizeError::Page("Redirect URI is not registered for the client".to_string())
+        })?;
+
+    let client_state = non_empty(params.state);
+    let redirect_error = |error: &str, description: &str| {
+        AuthorizeError::Redirect(redirect_url(
+            &redirect_uri,
+            &[
+                ("error", Some(error)),
+                ("error_description", Some(description)),
+                ("state", client_state.as_deref()),
+            ],
+        ))
+    };
+
+    //-- 2. Only the authorization code flow is supported
+    if params.response_type.as_deref() != Some("code") {
+        return Err(redirect_error(
+            "unsupported_response_type",
+            "Response type must be code",
+        ));
+    }
+
+    //-- 3. The openid scope is required, and unsupported scopes are dropped
+    let requested_scope = params.scope.unwrap_or_default();
+    let requested_scopes: Vec<&str> = requested_scope.split_whitespace().collect();
+    if !requested_scopes.contains(&"openid") {
+        return Err(redirect_error("invalid_scope", "Scope must include openid"));
+    }
+    let scope = SUPPORTED_SCOPES
+        .iter()
+        .filter(|scope| requested_scopes.contains(scope))
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    //-- 4. Every client must prove the request with a PKCE S256 code challenge
+    let code_challenge = non_empty(params.code_challenge)
+        .filter(|_| params.code_challenge_method.as_deref() == Some("S256"))
+        .ok_or_else(|| {
+            redirect_error("invalid_request", "PKCE S256 code challenge is required")
+        })?;
+
+    Ok(AuthorizationRequest {
+        client,
+        redirect_uri: redirect_uri.to_owned(),
+        scope,
+        state: client_state.to_owned(),
+        nonce: non_empty(params.nonce),
+        code_challenge,
+        csrf_token: String::new(),
+    })
+}
+
+/// Get the CSRF secret from the request cookies
+fn csrf_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, value)| *name == CSRF_COOKIE && !value.is_empty())
+        .map(|(_, value)| value.to_string())
+}
+
+/// HMAC of the CSRF secret and authorization request, so the CSRF token is
+/// only valid for the browser and request it was issued to
+fn csrf_mac(state: &OidcState, csrf_secret: &str, request: &AuthorizationRequest) -> Hmac<Sha256> {
+    let key = state.config_ref().jwt.token_hash_key.expose_secret();
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length");
+
+    let client_id = request.client.id.to_string();
+    let fields = [
+        "oidc_csrf",
+        csrf_secret,
+        &client_id,
+        &request.redirect_uri,
+        &request.scope,
+        request.state.as_deref().unwrap_or_default(),
+        request.nonce.as_deref().unwrap_or_default(),
+        &request.code_challenge,
+    ];
+    for field in fields {
+        mac.update(field.as_bytes());
+        mac.update(&[0]);
+    }
+
+    mac
+}
+
+/// Render a sign in page for the authorization request
+fn render_page(
+    status: StatusCode,
+    template: &str,
+    request: &AuthorizationRequest,
+    values: &[(&'static str, String)],
+) -> Response {
+    let mut all_values = vec![
+        ("client_name", request.client.name.to_owned()),
+        ("client_id", request.client.id.to_string()),
+        ("redirect_uri", request.redirect_uri.to_owned()),
+        ("scope", request.scope.to_owned()),
+        ("state", request.state.to_owned().unwrap_or_default()),
+        ("nonce", request.nonce.to_owned().unwrap_or_default()),
+        ("code_challenge", request.code_challenge.to_owned()),
+        ("csrf_token", request.csrf_token.to_owned()),
+    ];
+    all_values.extend_from_slice(values);
+
+    let body = email::render(template, &all_values, email::escape_html);
+
+    (status, Html(body)).into_response()
+}
+
+/// Render the email and password sign in page
+fn sign_in_page(
+    status: StatusCode,
+    request: &AuthorizationRequest,
+    email: &str,
+    message: &str,
+) -> Response {
+    render_page(
+        status,
+        include_str!("templates/sign_in.html"),
+        request,
+        &[("email", email.to_string()), ("message", message.to_string())],
+    )
+}
+
+/// Render the second factor page, carrying the MFA Token that proves the
+/// password was verified. Only the factors the user has set up are shown, with
+/// a new WebAuthn challenge when they have a passkey.
+async fn mfa_page(
+    state: &OidcState,
+    status: StatusCode,
+    request: &AuthorizationRequest,
+    user: &database::Users,
+    mfa_token: &str,
+    message: &str,
+) -> Result<Response, AuthorizeError> {
+    let totp_enabled = database::TotpSecrets::from_user_id(&user.id, state.database_ref())
+        .await?
+        .is_some_and(|totp_secret| totp_secret.is_enabled);
+
+    // Users with only passkeys can still sign in with a Recovery Code
+    let code_enabled = totp_enabled
+        || database::RecoveryCodes::count_unused_by_user_id(&user.id, state.database_ref())
+            .await?
+            > 0;
+
+    let allow_credential_ids =
+        database::WebAuthnCredentials::index_by_user_id(&user.id, state.database_ref())
+            .await?
+            .iter()
+            .map(|credential| URL_SAFE_NO_PAD.encode(&credential.credential_id))
+            .collect::<Vec<_>>();
+
+    let webauthn_challenge = match allow_credential_ids.is_empty() {
+        true => String::new(),
+        false => {
+            let challenge = state.authentication.web_authn_challenge(Some(&user.id)).await?;
+            URL_SAFE_NO_PAD.encode(challenge.as_ref())
+        }
+    };
+
+    let hidden = |is_hidden: bool| if is_hidden { "hidden" } else { "" }.to_string();
+
+    Ok(render_page(
+        status,
+        include_str!("templates/mfa.html"),
+        request,
+        &[
+            ("mfa_token", mfa_token.to_string()),
+            ("message", message.to_string()),
+            ("code_hidden", hidden(!code_enabled)),
+            ("passkey_hidden", hidden(allow_credential_ids.is_empty())),
+            ("webauthn_challenge", webauthn_challenge),
+            ("rp_id", state.config_ref().webauthn.rp_id.to_owned()),
+            ("allow_credential_ids", allow_credential_ids.join(" ")),
+        ],
+    ))
+}
+
+/// Message shown when signing in fails
+fn sign_in_failed_message(error: &BackendError) -> &'static str {
+    match error {
+        BackendError::LoginLocked => "Too many failed attempts, try again later.",
+        _ => "Sign in failed, check your details and try again.",
+    }
+}
+
+/// Show the sign in form for a valid authorization request
+#[tracing::instrument(name = "OIDC Authorize Request: ", skip_all)]
+pub async fn authorize(
+    State(state): State<OidcState>,
+    headers: HeaderMap,
+    Query(params): Query<AuthorizeParams>,
+) -> Result<Response, AuthorizeError> {
+    let mut request = validate(&state, params).await?;
+
+    // Keep the browsers CSRF secret, so sign in forms open in other tabs stay valid
+    let csrf_secret =
+        csrf_cookie(&headers).unwrap_or_else(|| domain::OneTimeToken::generate().to_string());
+    request.csrf_token =
+        URL_SAFE_NO_PAD.encode(csrf_mac(&state, &csrf_secret, &request).finalize().into_bytes());
+
+    let secure = if state.issuer().starts_with("https://") { "; Secure" } else { "" };
+    let cookie = format!("{CSRF_COOKIE}={csrf_secret}; Path=/; HttpOnly; SameSite=Lax{secure}");
+
+    let mut response = sign_in_page(StatusCode::OK, &request, "", "");
+    response.headers_mut().insert(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).expect("CSRF cookie is a valid header value"),
+    );
+
+    Ok(response)
+}
+
+/// Check the users credentials, redirecting back to the client with an
+/// authorization code once they have signed in
+#[tracing::instrument(name = "OIDC Sign In Request: ", skip_all, fields(
+    src_address = %address,
+))]
+pub async fn sign_in(
+    State(state): State<OidcState>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Form(form): Form<SignInForm>,
+) -> Result<Response, AuthorizeError> {
+    let mut request = validate(&state, form.params).await?;
+
+    //-- 0. The form must be posted by the browser the sign in page was shown to
+    let csrf_secret = csrf_cookie(&headers)
+        .ok_or_else(|| AuthorizeError::Page("Sign in has expired, sign in again".to_string()))?;
+    let csrf_mac = csrf_mac(&state, &csrf_secret, &request);
+    let csrf_token = non_empty(form.csrf_token)
+        .and_then(|csrf_token| URL_SAFE_NO_PAD.decode(csrf_token).ok())
+        .unwrap_or_default();
+    if csrf_mac.clone().verify_slice(&csrf_token).is_err() {
+        tracing::error!("Sign in form CSRF token is invalid");
+        return Err(AuthorizeError::Page(
+            "Sign in has expired, sign in again".to_string(),
+        ));
+    }
+    request.csrf_token = URL_SAFE_NO_PAD.encode(csrf_mac.finalize().into_bytes());
+    let login_ip = address.ip();
+    let authentication = &state.authentication;
+
+    let user = match non_empty(form.mfa_token) {
+        //-- 1a. Second step, the MFA Token proves the password was verified
+        Some(mfa_token) => {
+            let user = match authentication.decode_mfa_token(&mfa_token) {
+                Ok((user_id, _refresh_token_seconds)) => {
+                    database::Users::from_user_id(&user_id, state.database_ref()).await?
+                }
+                Err(error) => {
+                    tracing::error!("MFA Token is invalid: {error}");
+                    return Ok(sign_in_page(
+                        StatusCode::UNAUTHORIZED,
+                        &request,
+                        "",
+                        "Sign in has expired, sign in again.",
+                    ));
+                }
+            };
+
+            // Verify the passkey assertion when one was posted, otherwise the code
+            let verified = match non_empty(form.credential_id) {
+                Some(credential_id) => {
+                    let request_message = FinishWebAuthnLoginRequest {
+                        mfa_token: Some(mfa_token.to_owned()),
+                        credential_id: decode_field(Some(credential_id)),
+                        client_data_json: decode_field(form.client_data_json),
+                        authenticator_data: decode_field(form.authenticator_data),
+                        signature: decode_field(form.signature),
+                    };
+                    authentication
+                        .verify_web_authn_login(&request_message, login_ip)
+                        .await
+                        .map(|_| ())
+                }
+                None => {
+                    let mfa_code = form.mfa_code.unwrap_or_default();
+                    authentication.verify_mfa_code(&user, &mfa_code, login_ip).await
+                }
+            };
+
+            match verified {
+                Ok(()) => user,
+                Err(error @ (BackendError::AuthenticationError(_) | BackendError::LoginLocked)) => {
+                    return mfa_page(
+                        &state,
+                        StatusCode::UNAUTHORIZED,
+                        &request,
+                        &user,
+                        &mfa_token,
+                        sign_in_failed_message(&error),
+                    )
+                    .await;
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+        //-- 1b. First step, check the email and password
+        None => {
+            let email = form.email.unwrap_or_default();
+            let password = form.password.unwrap_or_default();
+            let user = match authentication.verify_password(&email, password, login_ip).await {
+                Ok(user) => user,
+                Err(error @ (BackendError::AuthenticationError(_) | BackendError::LoginLocked)) => {
+                    return Ok(sign_in_page(
+                        StatusCode::UNAUTHORIZED,
+                        &request,
+                        &email,
+                        sign_in_failed_message(&error),
+                    ));
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            // Users with MFA enabled must also use a second factor
+            if authentication.is_mfa_required(&user).await? {
+                let config = state.config_ref();
+                let mfa_token = domain::MfaToken::new(
+                    state.token_keys_ref(),
+                    &config.application,
+                    &user,
+                    config.application.refresh_token_seconds,
+                )?;
+                tracing::info!("MFA required for user: {}", user.id);
+
+                return mfa_page(&state, StatusCode::OK, &request, &user, mfa_token.as_ref(), "")
+                    .await;
+            }
+
+            user
+        }
+    };
+
+    // The user has fully authenticated, so clear the failed attempts
+    authentication.clear_account_lockout(&user.id).await?;
+
+    //-- 2. Issue a single use authorization code to the client
+    let code = domain::OneTimeToken::generate();
+    let authorization_code = database::OidcAuthorizationCodes::new(
+        &request.client.id,
+        &user.id,
+        &code,
+        &state.config_ref().jwt.token_hash_key,
+        &request.redirect_uri,
+        &request.scope,
+        request.nonce.to_owned(),
+        &request.code_challenge,
+        state.config_ref().oidc.authorization_code_seconds,
+    )
+    .insert(state.database_ref())
+    .await?;
+
+    tracing::info!(
+        "Authorization code {} issued to client {} for user {}",
+        authorization_code.id,
+        request.client.id,
+        user.id
+    );
+
+    //-- 3. Redirect back to the client with the code
+    let url = redirect_url(
+        &request.redirect_uri,
+        &[
+            ("code", Some(code.as_ref())),
+            ("state", request.state.as_deref()),
+        ],
+    );
+
+    Ok(Redirect::to(url.as_str()).into_response())
+}
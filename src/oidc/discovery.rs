@@ -0,0 +1,44 @@
+//-- ./src/oidc/discovery.rs
+
+//! OIDC discovery document and the JSON Web Key Set it points clients to
+//! ---
+
+use axum::extract::State;
+use axum::Json;
+
+use super::{OidcState, SUPPORTED_SCOPES};
+
+/// The OpenID Provider metadata, telling clients where the endpoints are and
+/// what the provider supports
+pub async fn openid_configuration(State(state): State<OidcState>) -> Json<serde_json::Value> {
+    let issuer = state.issuer();
+    let algorithms: Vec<String> = state
+        .token_keys_ref()
+        .keys()
+        .iter()
+        .map(|signing_key| format!("{:?}", signing_key.algorithm()))
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    Json(serde_json::json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{issuer}/authorize"),
+        "token_endpoint": format!("{issuer}/token"),
+        "userinfo_endpoint": format!("{issuer}/userinfo"),
+        "jwks_uri": format!("{issuer}/jwks"),
+        "response_types_supported": ["code"],
+        "grant_types_supported": ["authorization_code", "refresh_token"],
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": algorithms,
+        "scopes_supported": SUPPORTED_SCOPES,
+        "token_endpoint_auth_methods_supported": ["none", "client_secret_post"],
+        "code_challenge_methods_supported": ["S256"],
+        "claims_supported": ["sub", "iss", "aud", "exp", "iat", "nonce", "name", "email", "email_verified"],
+    }))
+}
+
+/// The public keys ID Tokens and Access Tokens are verified with
+pub async fn jwks(State(state): State<OidcState>) -> Json<jsonwebtoken::jwk::JwkSet> {
+    Json(state.token_keys_ref().jwks())
+}
@@ -0,0 +1,94 @@
+//-- ./src/oidc/error.rs
+
+//! OAuth 2.0 error responses from the OIDC endpoints
+//!
+//! Errors are returned as a JSON body with the error code and a description,
+//! as described in RFC 6749 section 5.2.
+//! ---
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+use crate::prelude::*;
+
+/// OAuth 2.0 error response
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcError {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+    /// OAuth 2.0 error code
+    pub error: &'static str,
+    /// Human readable description of the error
+    pub description: String,
+}
+
+impl OidcError {
+    /// The request is missing, or has an invalid, parameter
+    pub fn invalid_request(description: &str) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_request", description)
+    }
+
+    /// The client is unknown or failed to authenticate
+    pub fn invalid_client() -> Self {
+        Self::new(
+            StatusCode::UNAUTHORIZED,
+            "invalid_client",
+            "Client authentication failed",
+        )
+    }
+
+    /// The authorization code or refresh token is invalid, expired or used
+    pub fn invalid_grant(description: &str) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_grant", description)
+    }
+
+    /// The grant type is not supported by the token endpoint
+    pub fn unsupported_grant_type() -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            "unsupported_grant_type",
+            "Grant type must be authorization_code or refresh_token",
+        )
+    }
+
+    /// The bearer Access Token is missing, invalid, expired or revoked
+    pub fn invalid_token() -> Self {
+        Self::new(
+            StatusCode::UNAUTHORIZED,
+            "invalid_token",
+            "Access Token is not active",
+        )
+    }
+
+    fn new(status: StatusCode, error: &'static str, description: &str) -> Self {
+        Self {
+            status,
+            error,
+            description: description.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for OidcError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": self.error,
+            "error_description": self.description,
+        });
+
+        (self.status, Json(body)).into_response()
+    }
+}
+
+/// Backend errors are logged, and not revealed to the client
+impl From<BackendError> for OidcError {
+    fn from(backend_error: BackendError) -> Self {
+        tracing::error!("OIDC request failed: {backend_error}");
+        Self::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "server_error",
+            "The request could not be completed",
+        )
+    }
+}
@@ -0,0 +1,107 @@
+//-- ./src/oidc/mod.rs
+
+//! OpenID Connect provider
+//!
+//! HTTP endpoints for clients to sign users in with the OpenID Connect
+//! authorization code flow. Clients must prove the authorization request with a
+//! PKCE S256 code challenge, and confidential clients must also authenticate
+//! with their client secret. Users sign in with the same password, lockout and
+//! MFA checks as the `Login` RPC.
+//!
+//! # References
+//!
+//! * [OpenID Connect Core](https://openid.net/specs/openid-connect-core-1_0.html)
+//! * [OpenID Connect Discovery](https://openid.net/specs/openid-connect-discovery-1_0.html)
+//! * [RFC 7636 PKCE](https://datatracker.ietf.org/doc/html/rfc7636)
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use axum::routing::get;
+use sqlx::{Pool, Postgres};
+
+use crate::configuration::Configuration;
+use crate::{domain, services};
+
+pub use error::OidcError;
+
+mod authorize;
+mod discovery;
+mod error;
+mod token;
+mod userinfo;
+
+/// Scopes the provider supports, of which `openid` is required
+pub const SUPPORTED_SCOPES: [&str; 3] = ["openid", "profile", "email"];
+
+/// Shared state for the OIDC endpoints
+#[derive(Clone)]
+pub struct OidcState {
+    /// Database Arc reference
+    database: Arc<Pool<Postgres>>,
+    /// Configuration Arc reference
+    config: Arc<Configuration>,
+    /// Token signing keys Arc reference
+    token_keys: Arc<domain::TokenKeys>,
+    /// Authentication service for checking credentials and issuing tokens
+    authentication: Arc<services::AuthenticationService>,
+}
+
+impl OidcState {
+    /// Initiate the OIDC endpoint state
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        token_keys: Arc<domain::TokenKeys>,
+        authentication: Arc<services::AuthenticationService>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            token_keys,
+            authentication,
+        }
+    }
+
+    /// Shorthand reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Shorthand reference to config
+    fn config_ref(&self) -> &Configuration {
+        &self.config
+    }
+
+    /// Shorthand reference to the token signing keys
+    fn token_keys_ref(&self) -> &domain::TokenKeys {
+        &self.token_keys
+    }
+
+    /// The issuer URL, without a trailing slash so endpoint paths can be appended
+    fn issuer(&self) -> &str {
+        self.config.oidc.issuer.trim_end_matches('/')
+    }
+}
+
+/// Build the OIDC endpoint router
+pub fn router(state: OidcState) -> axum::Router {
+    axum::Router::new()
+        .route(
+            "/.well-known/openid-configuration",
+            get(discovery::openid_configuration),
+        )
+        .route("/jwks", get(discovery::jwks))
+        .route(
+            "/authorize",
+            get(authorize::authorize).post(authorize::sign_in),
+        )
+        .route("/token", axum::routing::post(token::token))
+        .route(
+            "/userinfo",
+            get(userinfo::userinfo).post(userinfo::userinfo),
+        )
+        .with_state(state)
+}
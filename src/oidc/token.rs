@@ -0,0 +1,238 @@
+//-- ./src/oidc/token.rs
+
+//! OIDC token endpoint
+//!
+//! Exchanges a single use authorization code, with the PKCE code verifier, for
+//! an Access Token, Refresh Token and ID Token. Refresh Tokens issued here are
+//! bound to the client, which must authenticate to refresh them, and are
+//! rotated in the same way as the `Refresh` RPC.
+//! ---
+
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, State};
+use axum::{Form, Json};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{database, domain, prelude::*, services};
+
+use super::{OidcError, OidcState};
+
+/// Token request form
+#[derive(Debug, serde::Deserialize)]
+pub struct TokenRequest {
+    grant_type: Option<String>,
+    code: Option<String>,
+    redirect_uri: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    code_verifier: Option<String>,
+    refresh_token: Option<String>,
+}
+
+/// Token response body
+#[derive(Debug, serde::Serialize)]
+pub struct TokenResponse {
+    access_token: String,
+    token_type: &'static str,
+    expires_in: u64,
+    refresh_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+/// Authenticate the client with its id and any client secret. Confidential
+/// clients must send their secret with every grant.
+async fn authenticate_client(
+    state: &OidcState,
+    client_id: Option<&str>,
+    client_secret: Option<&str>,
+) -> Result<database::OidcClients, OidcError> {
+    let client_id = client_id
+        .and_then(|client_id| Uuid::try_parse(client_id).ok())
+        .ok_or_else(OidcError::invalid_client)?;
+    let client = database::OidcClients::from_id(&client_id, state.database_ref())
+        .await
+        .map_err(|_| OidcError::invalid_client())?;
+    let hash_key = &state.config_ref().jwt.token_hash_key;
+    if !client.is_active || !client.verify_secret(client_secret, hash_key) {
+        return Err(OidcError::invalid_client());
+    }
+
+    Ok(client)
+}
+
+/// Check the PKCE code verifier hashes to the S256 code challenge
+fn verify_code_challenge(code_verifier: &str, code_challenge: &str) -> bool {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes())) == code_challenge
+}
+
+/// Exchange an authorization code or Refresh Token for tokens
+#[tracing::instrument(name = "OIDC Token Request: ", skip_all, fields(
+    src_address = %address,
+))]
+pub async fn token(
+    State(state): State<OidcState>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    Form(request): Form<TokenRequest>,
+) -> Result<Json<TokenResponse>, OidcError> {
+    match request.grant_type.as_deref() {
+        Some("authorization_code") => authorization_code_grant(&state, address, request).await,
+        Some("refresh_token") => refresh_token_grant(&state, address, request).await,
+        _ => Err(OidcError::unsupported_grant_type()),
+    }
+    .map(Json)
+}
+
+/// Exchange an authorization code for an Access, Refresh and ID Token
+async fn authorization_code_grant(
+    state: &OidcState,
+    address: SocketAddr,
+    request: TokenRequest,
+) -> Result<TokenResponse, OidcError> {
+    let code = request
+        .code
+        .ok_or_else(|| OidcError::invalid_request("Missing code"))?;
+    let code_verifier = request
+        .code_verifier
+        .ok_or_else(|| OidcError::invalid_request("Missing code_verifier"))?;
+
+    //-- 1. Redeem the code, which can only be used once
+    let authorization_code = database::OidcAuthorizationCodes::redeem(
+        &domain::OneTimeToken::from(code),
+        &state.config_ref().jwt.token_hash_key,
+        state.database_ref(),
+    )
+    .await
+    .map_err(|_| OidcError::invalid_grant("Authorization code is invalid, expired or used"))?;
+
+    //-- 2. Authenticate the client the code was issued to
+    let client = authenticate_client(
+        state,
+        request.client_id.as_deref(),
+        request.client_secret.as_deref(),
+    )
+    .await?;
+    if client.id != authorization_code.client_id {
+        return Err(OidcError::invalid_client());
+    }
+
+    //-- 3. The redirect URI and PKCE code verifier must match the request
+    if request.redirect_uri.as_deref() != Some(authorization_code.redirect_uri.as_str()) {
+        return Err(OidcError::invalid_grant("Redirect URI does not match"));
+    }
+    if !verify_code_challenge(&code_verifier, &authorization_code.code_challenge) {
+        return Err(OidcError::invalid_grant("Code verifier does not match"));
+    }
+
+    //-- 4. The user must still be active
+    let user = database::Users::from_user_id(&authorization_code.user_id, state.database_ref())
+        .await
+        .map_err(|_| OidcError::invalid_grant("User is not active"))?;
+    if !user.is_active {
+        return Err(OidcError::invalid_grant("User is not active"));
+    }
+
+    //-- 5. Issue the tokens, with the Session bound to the client
+    let config = state.config_ref();
+    let tokens = state
+        .authentication
+        .issue_client_tokens(
+            &user,
+            address.ip(),
+            config.application.refresh_token_seconds,
+            Some(client.id),
+        )
+        .await?;
+    let id_token = domain::IdToken::new(
+        state.token_keys_ref(),
+        state.issuer(),
+        &client.id.to_string(),
+        &user,
+        &authorization_code.scope,
+        authorization_code.nonce,
+        config.application.access_token_seconds,
+    )?;
+
+    tracing::info!("Tokens issued to client {} for user {}", client.id, user.id);
+
+    Ok(TokenResponse {
+        access_token: tokens.access_token,
+        token_type: "Bearer",
+        expires_in: config.application.access_token_seconds,
+        refresh_token: tokens.refresh_token,
+        id_token: Some(id_token.to_string()),
+        scope: Some(authorization_code.scope),
+    })
+}
+
+/// Exchange a Refresh Token for a new Access and Refresh Token, which the
+/// client must have been issued
+async fn refresh_token_grant(
+    state: &OidcState,
+    address: SocketAddr,
+    request: TokenRequest,
+) -> Result<TokenResponse, OidcError> {
+    let refresh_token = request
+        .refresh_token
+        .ok_or_else(|| OidcError::invalid_request("Missing refresh_token"))?;
+
+    //-- 1. Authenticate the client
+    let client = authenticate_client(
+        state,
+        request.client_id.as_deref(),
+        request.client_secret.as_deref(),
+    )
+    .await?;
+
+    //-- 2. Rotate the Session, which must have been issued to the client
+    let tokens = state
+        .authentication
+        .refresh_session(
+            &refresh_token,
+            address.ip(),
+            services::OrganisationContext::Keep,
+            Some(client.id),
+        )
+        .await
+        .map_err(|error| match error {
+            BackendError::AuthenticationError(_)
+            | BackendError::Sqlx(sqlx::Error::RowNotFound) => {
+                OidcError::invalid_grant("Refresh Token is invalid, expired or revoked")
+            }
+            error => error.into(),
+        })?;
+
+    Ok(TokenResponse {
+        access_token: tokens.access_token,
+        token_type: "Bearer",
+        expires_in: state.config_ref().application.access_token_seconds,
+        refresh_token: tokens.refresh_token,
+        id_token: None,
+        scope: None,
+    })
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+
+    // Bring module functions into test scope
+    use super::*;
+
+    #[test]
+    fn code_verifier_matches_challenge() {
+        // Arrange: example from RFC 7636 Appendix B
+        let code_verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let code_challenge = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+        // Act & Assertions
+        assert!(verify_code_challenge(code_verifier, code_challenge));
+        assert!(!verify_code_challenge("wrong-verifier", code_challenge));
+    }
+}
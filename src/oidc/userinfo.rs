@@ -0,0 +1,55 @@
+//-- ./src/oidc/userinfo.rs
+
+//! OIDC userinfo endpoint
+//!
+//! Returns the claims about the user of a bearer Access Token.
+//! ---
+
+use axum::extract::State;
+use axum::http::{header, HeaderMap};
+use axum::Json;
+
+use crate::domain;
+use crate::services::TokenSubject;
+
+use super::{OidcError, OidcState};
+
+/// Userinfo response body
+#[derive(Debug, serde::Serialize)]
+pub struct UserinfoResponse {
+    sub: String,
+    name: String,
+    email: String,
+    email_verified: bool,
+}
+
+/// Return the claims for the user of an active bearer Access Token
+#[tracing::instrument(name = "OIDC Userinfo Request: ", skip_all)]
+pub async fn userinfo(
+    State(state): State<OidcState>,
+    headers: HeaderMap,
+) -> Result<Json<UserinfoResponse>, OidcError> {
+    let access_token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(OidcError::invalid_token)?;
+
+    // Refresh Tokens cannot be used as bearer tokens, and service accounts have
+    // no user claims
+    let user = match state.authentication.active_token(access_token).await? {
+        Some((token_claim, TokenSubject::User(user), _session))
+            if token_claim.jty == domain::TokenType::Access.to_string() =>
+        {
+            user
+        }
+        _ => return Err(OidcError::invalid_token()),
+    };
+
+    Ok(Json(UserinfoResponse {
+        sub: user.id.to_string(),
+        name: user.name.to_string(),
+        email: user.email.to_string(),
+        email_verified: user.is_verified,
+    }))
+}
@@ -14,11 +14,22 @@ use sqlx::Postgres;
 use tonic::transport::{server::Router, Server};
 
 use crate::configuration::Configuration;
+use crate::domain;
+use crate::email;
 use crate::middleware;
+use crate::oidc;
 use crate::prelude::*;
+use crate::rpc::proto::api_keys_server::ApiKeysServer;
 use crate::rpc::proto::authentication_server::AuthenticationServer;
 use crate::rpc::proto::logins_server::LoginsServer;
+use crate::rpc::proto::me_server::MeServer;
+use crate::rpc::proto::mfa_server::MfaServer;
+use crate::rpc::proto::oidc_clients_server::OidcClientsServer;
+use crate::rpc::proto::organisations_server::OrganisationsServer;
+use crate::rpc::proto::roles_server::RolesServer;
+use crate::rpc::proto::service_accounts_server::ServiceAccountsServer;
 use crate::rpc::proto::sessions_server::SessionsServer;
+use crate::rpc::proto::signing_keys_server::SigningKeysServer;
 use crate::rpc::proto::users_server::UsersServer;
 use crate::rpc::proto::utilities_server::UtilitiesServer;
 use crate::services;
@@ -28,6 +39,9 @@ use crate::services;
 pub fn get_router(
     database: Pool<Postgres>,
     config: Configuration,
+    token_keys: Arc<domain::TokenKeys>,
+    revocation_list: Arc<domain::RevocationList>,
+    api_key_list: Arc<domain::ApiKeyList>,
 ) -> Result<Router, BackendError> {
     // Wraps our database pool in an Atomic Reference Counted (ARC).
     // Each instance of the backend will get a pointer to the pool instead of getting a raw copy.
@@ -36,40 +50,72 @@ pub fn get_router(
     // Wrap config in an Atomic Reference Counted (ARC).
     let config = Arc::new(config);
 
-    // Wrap token_secret string in a Secret
-    let token_secret = config.application.token_secret.clone();
+    // Backend for delivering emails to users
+    let email_sender = email::sender_from_config(&config.email)?;
 
     // Intercept request and verify Access Token
-    let access_token_interceptor =
-        middleware::AccessTokenInterceptor { token_secret };
+    let access_token_interceptor = middleware::AccessTokenInterceptor {
+        token_keys: Arc::clone(&token_keys),
+        config: Arc::clone(&config),
+        revocation_list: Arc::clone(&revocation_list),
+        api_key_list: Arc::clone(&api_key_list),
+        admin_only: true,
+        service_scope: None,
+    };
+
+    // Intercept request and verify Access Token for endpoints any user can use,
+    // or that check the permissions in the Access Token for each RPC
+    let user_access_token_interceptor = middleware::AccessTokenInterceptor {
+        token_keys: Arc::clone(&token_keys),
+        config: Arc::clone(&config),
+        revocation_list: Arc::clone(&revocation_list),
+        api_key_list: Arc::clone(&api_key_list),
+        admin_only: false,
+        service_scope: None,
+    };
 
     // Build Utilities server
-    let utilities_service = services::UtilitiesService::new(Arc::clone(&config));
+    let utilities_service =
+        services::UtilitiesService::new(Arc::clone(&config), Arc::clone(&token_keys));
     
     let utilities_server = UtilitiesServer::new(utilities_service);
 
     // Build Authentication server
-    let authentication_service =
-        services::AuthenticationService::new(Arc::clone(&database), Arc::clone(&config));
+    let authentication_service = services::AuthenticationService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&email_sender),
+        Arc::clone(&token_keys),
+        Arc::clone(&revocation_list),
+        access_token_interceptor.with_service_scope(domain::ServiceScope::Tokens),
+    );
+    let authentication_service = Arc::new(authentication_service);
     
-    let authentication_server = AuthenticationServer::new(authentication_service);
+    let authentication_server = AuthenticationServer::from_arc(Arc::clone(&authentication_service));
 
     // Build Users server
-    let users_service =
-        services::UsersService::new(Arc::clone(&database), Arc::clone(&config));
+    let users_service = services::UsersService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&revocation_list),
+        Arc::clone(&api_key_list),
+    );
     
     let users_server = UsersServer::with_interceptor(
         users_service,
-        access_token_interceptor.clone(),
+        user_access_token_interceptor.with_service_scope(domain::ServiceScope::Users),
     );
 
     // Build Sessions server
-    let sessions_service =
-        services::SessionsService::new(Arc::clone(&database), Arc::clone(&config));
+    let sessions_service = services::SessionsService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&revocation_list),
+    );
     
     let sessions_server = SessionsServer::with_interceptor(
         sessions_service,
-        access_token_interceptor.clone(),
+        user_access_token_interceptor.with_service_scope(domain::ServiceScope::Sessions),
     );
 
     // Build Logins Tokens server
@@ -78,9 +124,93 @@ pub fn get_router(
 
     let logins_server = LoginsServer::with_interceptor(
         logins_service,
-        access_token_interceptor,
+        user_access_token_interceptor.with_service_scope(domain::ServiceScope::Logins),
     );
 
+    // Build Signing Keys server
+    let signing_keys_service = services::SigningKeysService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        token_keys,
+    );
+
+    let signing_keys_server = SigningKeysServer::with_interceptor(
+        signing_keys_service,
+        access_token_interceptor.with_service_scope(domain::ServiceScope::SigningKeys),
+    );
+
+    // Build OIDC Clients server
+    let oidc_clients_service =
+        services::OidcClientsService::new(Arc::clone(&database), Arc::clone(&config));
+
+    let oidc_clients_server = OidcClientsServer::with_interceptor(
+        oidc_clients_service,
+        access_token_interceptor.with_service_scope(domain::ServiceScope::OidcClients),
+    );
+
+    // Build Service Accounts server, which only admins can use so service
+    // accounts cannot grant themselves more scopes
+    let service_accounts_service = services::ServiceAccountsService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&revocation_list),
+    );
+
+    let service_accounts_server = ServiceAccountsServer::with_interceptor(
+        service_accounts_service,
+        access_token_interceptor.clone(),
+    );
+
+    // Build Roles server, which only admins can use so users cannot grant
+    // themselves more permissions
+    let roles_service =
+        services::RolesService::new(Arc::clone(&database), Arc::clone(&api_key_list));
+
+    let roles_server = RolesServer::with_interceptor(roles_service, access_token_interceptor);
+
+    // Build Me server, for users to manage their own account
+    let me_service = services::MeService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&authentication_service),
+        revocation_list,
+    );
+
+    let me_server = MeServer::with_interceptor(me_service, user_access_token_interceptor.clone());
+
+    // Build Organisations server, for users to share records with the members
+    // of their organisations
+    let organisations_service = services::OrganisationsService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&authentication_service),
+    );
+
+    let organisations_server = OrganisationsServer::with_interceptor(
+        organisations_service,
+        user_access_token_interceptor.clone(),
+    );
+
+    // Build MFA server
+    let mfa_service = services::MfaService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        Arc::clone(&authentication_service),
+    );
+
+    let mfa_server =
+        MfaServer::with_interceptor(mfa_service, user_access_token_interceptor.clone());
+
+    // Build API Keys server, for users to manage their own API keys
+    let api_keys_service = services::ApiKeysService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        api_key_list,
+    );
+
+    let api_keys_server =
+        ApiKeysServer::with_interceptor(api_keys_service, user_access_token_interceptor);
+
     // Build reflections server
     let reflections_server = services::ReflectionsService::new();
 
@@ -92,7 +222,56 @@ pub fn get_router(
         .add_service(authentication_server)
         .add_service(users_server)
         .add_service(sessions_server)
-        .add_service(logins_server);
+        .add_service(logins_server)
+        .add_service(signing_keys_server)
+        .add_service(oidc_clients_server)
+        .add_service(service_accounts_server)
+        .add_service(roles_server)
+        .add_service(me_server)
+        .add_service(organisations_server)
+        .add_service(mfa_server)
+        .add_service(api_keys_server);
 
     Ok(router)
 }
+
+/// Build the OpenID Connect provider HTTP router, which signs users in with the
+/// same Authentication service as the RPC endpoints
+pub fn get_oidc_router(
+    database: Pool<Postgres>,
+    config: Configuration,
+    token_keys: Arc<domain::TokenKeys>,
+    revocation_list: Arc<domain::RevocationList>,
+    api_key_list: Arc<domain::ApiKeyList>,
+) -> Result<axum::Router, BackendError> {
+    let database = Arc::new(database);
+    let config = Arc::new(config);
+    let email_sender = email::sender_from_config(&config.email)?;
+
+    let token_interceptor = middleware::AccessTokenInterceptor {
+        token_keys: Arc::clone(&token_keys),
+        config: Arc::clone(&config),
+        revocation_list: Arc::clone(&revocation_list),
+        api_key_list,
+        admin_only: true,
+        service_scope: Some(domain::ServiceScope::Tokens),
+    };
+
+    let authentication_service = services::AuthenticationService::new(
+        Arc::clone(&database),
+        Arc::clone(&config),
+        email_sender,
+        Arc::clone(&token_keys),
+        revocation_list,
+        token_interceptor,
+    );
+
+    let state = oidc::OidcState::new(
+        database,
+        config,
+        token_keys,
+        Arc::new(authentication_service),
+    );
+
+    Ok(oidc::router(state))
+}
@@ -0,0 +1,315 @@
+//-- ./src/services/api_keys.rs
+
+//! RPC service for API Keys endpoint
+//!
+//! Endpoints act on the API keys of the user authenticated by the request
+//! Access Token, so users can create and delete the long lived keys they
+//! authenticate scripts with.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::prelude::*;
+use crate::rpc::proto::api_keys_server::ApiKeys;
+use crate::rpc::proto::{
+    ApiKeysCreateRequest, ApiKeysCreateResponse, ApiKeysDeleteRequest, ApiKeysDeleteResponse,
+    ApiKeysIndexRequest, ApiKeysIndexResponse, ApiKeysReadRequest, ApiKeysResponse,
+    ApiKeysUpdateRequest,
+};
+use crate::{database, domain};
+
+/// API Keys service containing a database pool and the shared API key list
+pub struct ApiKeysService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+    api_key_list: Arc<domain::ApiKeyList>,
+}
+
+impl ApiKeysService {
+    /// Create a new ApiKeysService passing in the Arc for the Sqlx database
+    /// pool, configuration and API key list
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        api_key_list: Arc<domain::ApiKeyList>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            api_key_list,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Load the API Keys in the database that can authenticate requests
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Load API key list: ", skip_all)]
+    pub async fn load_api_key_list(
+        database: &Pool<Postgres>,
+    ) -> Result<domain::ApiKeyList, BackendError> {
+        let api_keys = database::ApiKeys::index_usable(None, database).await?;
+
+        Ok(domain::ApiKeyList::new(api_keys))
+    }
+
+    /// Write when API Keys were last used to the database and reload the API
+    /// key list on the configured refresh interval, so keys created or deleted
+    /// by other instances are picked up
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_list` - The shared API key list
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    pub async fn refresh_api_key_list(
+        api_key_list: Arc<domain::ApiKeyList>,
+        database: Pool<Postgres>,
+        config: Arc<Configuration>,
+    ) {
+        let period = std::time::Duration::from_secs(config.jwt.refresh_seconds.max(1));
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        loop {
+            interval.tick().await;
+
+            for (id, last_used_on) in api_key_list.take_last_used() {
+                if let Err(error) =
+                    database::ApiKeys::update_last_used_on(&id, &last_used_on, &database).await
+                {
+                    tracing::error!("Unable to update API key last used: {error}");
+                }
+            }
+
+            match database::ApiKeys::index_usable(None, &database).await {
+                Ok(api_keys) => api_key_list.replace(api_keys),
+                Err(error) => tracing::error!("Unable to reload API key list: {error}"),
+            }
+        }
+    }
+
+    /// Reload the API Keys of a user into the API key list, such as when the
+    /// user is deactivated, deleted or their roles change
+    ///
+    /// # Parameters
+    ///
+    /// * `user_id` - The user that owns the API Keys
+    /// * `api_key_list` - The shared API key list
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    pub async fn reload_user_api_keys(
+        user_id: &Uuid,
+        api_key_list: &domain::ApiKeyList,
+        database: &Pool<Postgres>,
+    ) -> Result<(), BackendError> {
+        let api_keys = database::ApiKeys::index_usable(Some(user_id), database).await?;
+        api_key_list.replace_user(user_id, api_keys);
+
+        Ok(())
+    }
+
+    /// Get the requesting user id from the Token Claim added to the request
+    /// extensions by the Access Token interceptor. API keys cannot be used to
+    /// manage API keys, so a leaked key cannot create more.
+    fn requester_id(request_extensions: &tonic::Extensions) -> Result<Uuid, BackendError> {
+        let access_token_claim = request_extensions
+            .get::<domain::TokenClaim>()
+            .ok_or(BackendError::Static("Token Claim not found in request extension."))?;
+
+        if access_token_claim.is_api_key() {
+            tracing::error!("API key used to manage API keys: {}", access_token_claim.jti);
+            return Err(BackendError::ApiKeyNotAllowed);
+        }
+
+        Ok(Uuid::parse_str(&access_token_claim.sub)?)
+    }
+}
+
+/// Parse an API key id from a request message
+fn parse_id(id: &str) -> Result<Uuid, BackendError> {
+    Uuid::parse_str(id).map_err(|_| {
+        tracing::error!("Unable to parse API key id to UUID!");
+        BackendError::Generic("Unable to parse API key id to UUID!".to_string())
+    })
+}
+
+/// Check an API key name from a request message is not empty
+fn parse_name(name: &str) -> Result<&str, BackendError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(BackendError::ApiKeyInvalid("name must not be empty".to_string()));
+    }
+
+    Ok(name)
+}
+
+impl From<database::ApiKeys> for ApiKeysResponse {
+    /// Convert from database::ApiKeys to proto::ApiKeysResponse, never
+    /// including the secret hash
+    fn from(value: database::ApiKeys) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            prefix: value.prefix,
+            scopes: value.scopes,
+            expires_on: value.expires_on.map(|expires_on| expires_on.to_string()),
+            last_used_on: value.last_used_on.map(|last_used_on| last_used_on.to_string()),
+            created_on: value.created_on.to_string(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ApiKeys for ApiKeysService {
+    /// Handle rpc requests to create an API key for the requesting user,
+    /// returning the key this one time
+    #[tracing::instrument(name = "Create API Key Request: ", skip_all)]
+    async fn create(
+        &self,
+        request: Request<ApiKeysCreateRequest>,
+    ) -> Result<Response<ApiKeysCreateResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        let name = parse_name(&request_message.name)?;
+        let scopes = domain::ServiceScope::parse_list(&request_message.scopes)?;
+
+        let expires_on = request_message
+            .expires_on
+            .map(|expires_on| expires_on.parse::<DateTime<Utc>>())
+            .transpose()
+            .map_err(|_| {
+                BackendError::ApiKeyInvalid("expiry must be an RFC 3339 timestamp".to_string())
+            })?;
+        if expires_on.is_some_and(|expires_on| expires_on <= Utc::now()) {
+            return Err(
+                BackendError::ApiKeyInvalid("expiry must be in the future".to_string()).into(),
+            );
+        }
+
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        let api_key = domain::ApiKey::generate();
+        let database_record = database::ApiKeys::new(
+            &user,
+            name,
+            &scopes,
+            expires_on,
+            &api_key,
+            &self.config.jwt.token_hash_key,
+        )
+        .insert(self.database_ref())
+        .await?;
+        tracing::info!("API key created: {}", database_record.id);
+
+        // Let the API key be used on this instance straight away
+        let permissions =
+            database::Roles::permissions_for_user(&user.id, self.database_ref()).await?;
+        self.api_key_list.insert(database_record.clone(), user.role, permissions);
+
+        Ok(Response::new(ApiKeysCreateResponse {
+            api_key: Some(database_record.into()),
+            key: api_key.to_string(),
+        }))
+    }
+
+    /// Handle rpc requests to read an API key of the requesting user
+    #[tracing::instrument(name = "Read API Key Request: ", skip_all)]
+    async fn read(
+        &self,
+        request: Request<ApiKeysReadRequest>,
+    ) -> Result<Response<ApiKeysResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions)?;
+        let id = parse_id(&request_message.id)?;
+
+        let database_record =
+            database::ApiKeys::from_id_and_user_id(&id, &user_id, self.database_ref()).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to get an index of the requesting users API keys
+    #[tracing::instrument(name = "Index API Keys Request: ", skip_all)]
+    async fn index(
+        &self,
+        request: Request<ApiKeysIndexRequest>,
+    ) -> Result<Response<ApiKeysIndexResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        let database_records = database::ApiKeys::index_user_id(
+            &user_id,
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let api_keys = database_records
+            .into_iter()
+            .map(|api_key| api_key.into())
+            .collect();
+
+        Ok(Response::new(ApiKeysIndexResponse { api_keys }))
+    }
+
+    /// Handle rpc requests to rename an API key of the requesting user
+    #[tracing::instrument(name = "Update API Key Request: ", skip_all)]
+    async fn update(
+        &self,
+        request: Request<ApiKeysUpdateRequest>,
+    ) -> Result<Response<ApiKeysResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions)?;
+        let id = parse_id(&request_message.id)?;
+        let name = parse_name(&request_message.name)?;
+
+        let database_record =
+            database::ApiKeys::update_name(&id, &user_id, name, self.database_ref()).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to delete an API key of the requesting user, so it
+    /// can no longer be used
+    #[tracing::instrument(name = "Delete API Key Request: ", skip_all)]
+    async fn delete(
+        &self,
+        request: Request<ApiKeysDeleteRequest>,
+    ) -> Result<Response<ApiKeysDeleteResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions)?;
+        let id = parse_id(&request_message.id)?;
+
+        let rows_affected =
+            database::ApiKeys::delete_by_id_and_user_id(&id, &user_id, self.database_ref())
+                .await?;
+
+        // Other instances drop the API key on their next reload
+        if rows_affected > 0 {
+            self.api_key_list.remove(&id);
+            tracing::info!("API key deleted: {id}");
+        }
+
+        Ok(Response::new(ApiKeysDeleteResponse {
+            rows_affected: rows_affected as i64,
+        }))
+    }
+}
@@ -4,23 +4,30 @@
 
 // #![allow(unused)] // For development only
 
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::IpAddr;
 use std::sync::Arc;
 
+use chrono::Utc;
 use secrecy::Secret;
 use sqlx::{Pool, Postgres};
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
-use crate::configuration::Configuration;
+use crate::configuration::{Configuration, RegistrationPolicy};
+use crate::email::{EmailMessage, EmailSender, EmailTemplate};
 use crate::prelude::*;
 use crate::rpc::proto::authentication_server::Authentication;
 use crate::rpc::proto::{
-    LoginRequest, LogoutRequest, LogoutResponse, RefreshRequest, RegisterRequest,
-    ResetPasswordRequest, ResetPasswordResponse, TokenResponse,
-    UpdatePasswordRequest,
+    BeginWebAuthnLoginRequest, BeginWebAuthnLoginResponse, ClientCredentialsRequest,
+    ClientCredentialsResponse,
+    ConfirmEmailChangeRequest, ConfirmEmailChangeResponse, ConfirmPasswordResetRequest,
+    FinishWebAuthnLoginRequest, IntrospectRequest, IntrospectResponse, LoginMfaRequest, LoginRequest, LoginResponse, LogoutRequest, LogoutResponse, RefreshRequest, RegisterRequest,
+    RegisterResponse, ResetPasswordRequest, ResetPasswordResponse, RevokeRequest, RevokeResponse,
+    SendVerificationEmailRequest, SendVerificationEmailResponse, SwitchOrganisationRequest,
+    TokenResponse,
+    UpdatePasswordRequest, VerifyEmailRequest, VerifyEmailResponse,
 };
-use crate::{database, domain};
+use crate::{database, domain, middleware, services, webauthn};
 
 // use crate::rpc::proto::authentication_server::Authentication;
 // use crate::rpc::proto::LoginRequest;
@@ -31,12 +38,83 @@ pub struct AuthenticationService {
     database: Arc<Pool<Postgres>>,
     /// Configuration Arc reference
     config: Arc<Configuration>,
+    /// Email sender Arc reference
+    email_sender: Arc<dyn EmailSender>,
+    /// Token signing keys Arc reference
+    token_keys: Arc<domain::TokenKeys>,
+    /// Revoked Access Tokens Arc reference
+    revocation_list: Arc<domain::RevocationList>,
+    /// Authenticates the admins and service accounts that introspect and
+    /// revoke tokens, as the other endpoints do not need an Access Token
+    token_interceptor: middleware::AccessTokenInterceptor,
+}
+
+/// Reset password response message, which is the same whether or not the
+/// email address is registered so the endpoint cannot enumerate users.
+const RESET_PASSWORD_MESSAGE: &str =
+    "If the email address is registered, a password reset token has been sent.";
+
+/// Send verification email response message, which is the same whether or not
+/// the email address is registered so the endpoint cannot enumerate users.
+const SEND_VERIFICATION_EMAIL_MESSAGE: &str =
+    "If the email address is registered and unverified, a verification token has been sent.";
+
+/// The subject an active token was issued to
+#[derive(Debug)]
+pub(crate) enum TokenSubject {
+    /// A user, who signed in or created an API key
+    User(database::Users),
+    /// A service account, with the client credentials grant
+    ServiceAccount(database::ServiceAccounts),
+}
+
+impl TokenSubject {
+    /// The user or service account id
+    pub(crate) fn id(&self) -> Uuid {
+        match self {
+            TokenSubject::User(user) => user.id,
+            TokenSubject::ServiceAccount(service_account) => service_account.id,
+        }
+    }
+
+    /// The user role, as service accounts have none
+    pub(crate) fn role(&self) -> Option<String> {
+        match self {
+            TokenSubject::User(user) => Some(user.role.to_string()),
+            TokenSubject::ServiceAccount(_) => None,
+        }
+    }
+}
+
+/// The organisation a rotated Session is active in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrganisationContext {
+    /// Stay in the organisation of the Session, while the user is a member
+    Keep,
+    /// Switch to an organisation the user is a member of
+    Switch(Uuid),
+    /// Leave the organisation, for the users own account
+    Clear,
 }
 
 impl AuthenticationService {
     /// Initiate a new Authentication Service
-    pub fn new(database: Arc<Pool<Postgres>>, config: Arc<Configuration>) -> Self {
-        Self { database, config }
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        email_sender: Arc<dyn EmailSender>,
+        token_keys: Arc<domain::TokenKeys>,
+        revocation_list: Arc<domain::RevocationList>,
+        token_interceptor: middleware::AccessTokenInterceptor,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            email_sender,
+            token_keys,
+            revocation_list,
+            token_interceptor,
+        }
     }
 
     /// Shorthand reference to database pool
@@ -48,112 +126,1103 @@ impl AuthenticationService {
     fn config_ref(&self) -> &Configuration {
         &self.config
     }
-}
 
-#[tonic::async_trait]
-impl Authentication for AuthenticationService {
-    #[tracing::instrument(name = "Authenticate Request: ", skip_all, fields(
-        src_address=%request.remote_addr().unwrap(),
-    ))]
-    async fn login(
+    /// Shorthand reference to the token signing keys
+    fn token_keys_ref(&self) -> &domain::TokenKeys {
+        &self.token_keys
+    }
+
+    /// Shorthand reference to the key stored tokens are hashed with
+    fn hash_key_ref(&self) -> &Secret<String> {
+        &self.config.jwt.token_hash_key
+    }
+
+    /// Revoke the Access Tokens issued with the Sessions in scope, so they
+    /// cannot be used until they expire
+    async fn revoke_access_tokens(
         &self,
-        request: Request<LoginRequest>,
-    ) -> Result<Response<TokenResponse>, Status> {
-        let socket_address = request.remote_addr().unwrap();
+        scope: database::RevocationScope,
+    ) -> Result<usize, BackendError> {
+        services::SessionsService::revoke_access_tokens(
+            scope,
+            &self.revocation_list,
+            self.database_ref(),
+            self.config_ref(),
+        )
+        .await
+    }
 
-        // let login_ip: Ipv4Addr = login_ip.;
+    /// Decode an Access or Refresh Token and check it is still active, returning
+    /// the Token Claim, the user or service account and for Refresh Tokens the
+    /// Session, or `None` if the token is not active
+    pub(crate) async fn active_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<(domain::TokenClaim, TokenSubject, Option<database::Sessions>)>, BackendError>
+    {
+        // Invalid and expired tokens, or tokens for another audience, are not active
+        let token_claim = match domain::TokenClaim::from_token(
+            token,
+            self.token_keys_ref(),
+            &self.config_ref().application,
+        ) {
+            Ok(token_claim) => token_claim,
+            Err(_) => return Ok(None),
+        };
 
-        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
-        let (_request_metadata, _request_extensions, request_message) =
-            request.into_parts();
+        // The user or service account must still exist and be active
+        let Ok(subject_id) = Uuid::try_parse(&token_claim.sub) else {
+            return Ok(None);
+        };
+        let subject = if token_claim.is_service_account() {
+            match database::ServiceAccounts::from_id(&subject_id, self.database_ref()).await {
+                Ok(service_account) if service_account.is_active => {
+                    TokenSubject::ServiceAccount(service_account)
+                }
+                _ => return Ok(None),
+            }
+        } else {
+            match database::Users::from_user_id(&subject_id, self.database_ref()).await {
+                Ok(user) if user.is_active => TokenSubject::User(user),
+                _ => return Ok(None),
+            }
+        };
 
-        // Parse the request email string into an EmailAddress
-        let request_email = domain::EmailAddress::parse(&request_message.email)
-            .map_err(|_| {
-                BackendError::AuthenticationError(
-                    "Authentication failed!".to_string(),
+        // Access Tokens must not be revoked, and Refresh Tokens must have an
+        // active Session. MFA Tokens are never active.
+        let session = if token_claim.jty == domain::TokenType::Access.to_string() {
+            if self.revocation_list.is_revoked(&token_claim.jti) {
+                return Ok(None);
+            }
+            None
+        } else if token_claim.jty == domain::TokenType::Refresh.to_string() {
+            match database::Sessions::from_token(
+                token,
+                self.hash_key_ref(),
+                self.database_ref(),
+            )
+            .await
+            {
+                Ok(session) if session.is_active => Some(session),
+                _ => return Ok(None),
+            }
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some((token_claim, subject, session)))
+    }
+
+    /// Send an email in the background, so the response time does not reveal
+    /// whether the email address is registered
+    pub(crate) fn send_email(&self, message: EmailMessage) {
+        let email_sender = Arc::clone(&self.email_sender);
+        tokio::spawn(async move {
+            if let Err(error) = email_sender.send(message).await {
+                tracing::error!("Unable to send email: {error}");
+            }
+        });
+    }
+
+    /// Build a new Access Token for the user, carrying the permissions of the
+    /// roles they hold and the organisation they are active in, if any
+    async fn access_token(
+        &self,
+        user: &database::Users,
+        membership: Option<&database::OrganisationMembers>,
+    ) -> Result<domain::AccessToken, BackendError> {
+        let permissions =
+            database::Roles::permissions_for_user(&user.id, self.database_ref()).await?;
+
+        domain::AccessToken::new_in_organisation(
+            self.token_keys_ref(),
+            &self.config_ref().application,
+            user,
+            &permissions,
+            membership,
+        )
+    }
+
+    /// Record the login and issue a new Access Token and Session (Refresh
+    /// Token) for the user, with the Refresh Token lifetime in seconds.
+    pub(crate) async fn issue_tokens(
+        &self,
+        user: &database::Users,
+        login_ip: IpAddr,
+        refresh_token_seconds: u64,
+    ) -> Result<TokenResponse, BackendError> {
+        self.issue_client_tokens(user, login_ip, refresh_token_seconds, None)
+            .await
+    }
+
+    /// Record the login and issue a new Access Token and Session (Refresh
+    /// Token) for the user, with the Session bound to the OIDC client the
+    /// tokens are issued to, if any.
+    pub(crate) async fn issue_client_tokens(
+        &self,
+        user: &database::Users,
+        login_ip: IpAddr,
+        refresh_token_seconds: u64,
+        oidc_client_id: Option<Uuid>,
+    ) -> Result<TokenResponse, BackendError> {
+        let token_keys = self.token_keys_ref();
+        let app_config = &self.config_ref().application;
+
+        // Build a new database Login
+        let login = database::Logins::new(&user.id, Some(login_ip));
+
+        // Insert Login into the database
+        let login = login.insert(self.database_ref()).await?;
+
+        tracing::debug!("Login added to the database: {}", login.id);
+
+        // Build a new Access Token
+        let access_token = self.access_token(user, None).await?;
+
+        tracing::debug!("Using Access Token: {}", access_token);
+
+        // Build a new Refresh Token and Session
+        let refresh_token = domain::RefreshToken::new(
+            token_keys,
+            app_config,
+            user,
+            refresh_token_seconds,
+        )?;
+        let session = database::Sessions::new(
+            user,
+            &access_token,
+            &refresh_token,
+            self.hash_key_ref(),
+        )
+        .with_oidc_client(oidc_client_id);
+
+        // Insert Session into the database
+        let session = session.insert(self.database_ref()).await?;
+
+        tracing::debug!("Session added to the database: {}", session.id);
+
+        Ok(TokenResponse {
+            access_token: access_token.to_string(),
+            refresh_token: refresh_token.to_string(),
+        })
+    }
+
+    /// Record a login attempt and its outcome alongside the users successful
+    /// logins. Failed guesses at credentials are counted against the client IP
+    /// address and, if the user is known, the user account.
+    async fn record_login_attempt(
+        &self,
+        user_id: Option<&Uuid>,
+        login_ip: IpAddr,
+        outcome: domain::LoginOutcome,
+    ) -> Result<(), BackendError> {
+        let login = database::Logins::new_attempt(user_id, Some(login_ip), outcome)
+            .insert(self.database_ref())
+            .await?;
+        tracing::debug!("Login attempt added to the database: {} {}", login.id, outcome);
+
+        if !outcome.is_failed_guess() {
+            return Ok(());
+        }
+
+        let lockout_config = &self.config_ref().lockout;
+
+        let ip_lockout = database::LoginLockouts::record_failure(
+            database::LockoutScope::IpAddress,
+            &login_ip.to_string(),
+            lockout_config,
+            self.database_ref(),
+        )
+        .await?;
+        if ip_lockout.is_locked() {
+            tracing::warn!(
+                "Client IP address locked after {} failed login attempts: {}",
+                ip_lockout.failed_attempts,
+                login_ip
+            );
+        }
+
+        if let Some(user_id) = user_id {
+            let account_lockout = database::LoginLockouts::record_failure(
+                database::LockoutScope::Account,
+                &user_id.to_string(),
+                lockout_config,
+                self.database_ref(),
+            )
+            .await?;
+            if account_lockout.is_locked() {
+                tracing::warn!(
+                    "User account locked after {} failed login attempts: {}",
+                    account_lockout.failed_attempts,
+                    user_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the Login Lockout for a client IP address, returning a LoginLocked
+    /// error and recording the attempt if the IP address is locked.
+    async fn check_ip_lockout(
+        &self,
+        login_ip: IpAddr,
+    ) -> Result<Option<database::LoginLockouts>, BackendError> {
+        let ip_lockout = database::LoginLockouts::from_subject(
+            database::LockoutScope::IpAddress,
+            &login_ip.to_string(),
+            self.database_ref(),
+        )
+        .await?;
+
+        if ip_lockout.as_ref().is_some_and(|lockout| lockout.is_locked()) {
+            tracing::error!("Client IP address is locked: {}", login_ip);
+            self.record_login_attempt(None, login_ip, domain::LoginOutcome::Locked)
+                .await?;
+            return Err(BackendError::LoginLocked);
+        }
+
+        Ok(ip_lockout)
+    }
+
+    /// Get the Login Lockout for a user account, returning a LoginLocked error
+    /// and recording the attempt if the account is locked.
+    async fn check_account_lockout(
+        &self,
+        user_id: &Uuid,
+        login_ip: IpAddr,
+    ) -> Result<Option<database::LoginLockouts>, BackendError> {
+        let account_lockout = database::LoginLockouts::from_subject(
+            database::LockoutScope::Account,
+            &user_id.to_string(),
+            self.database_ref(),
+        )
+        .await?;
+
+        if account_lockout.as_ref().is_some_and(|lockout| lockout.is_locked()) {
+            tracing::error!("User account is locked: {}", user_id);
+            self.record_login_attempt(Some(user_id), login_ip, domain::LoginOutcome::Locked)
+                .await?;
+            return Err(BackendError::LoginLocked);
+        }
+
+        Ok(account_lockout)
+    }
+
+    /// Clear the failed attempts on a user account once the user has fully
+    /// authenticated, including any second factor. Clearing them after only
+    /// the password would let anyone with it reset the count between MFA
+    /// code guesses.
+    pub(crate) async fn clear_account_lockout(&self, user_id: &Uuid) -> Result<(), BackendError> {
+        database::LoginLockouts::delete_by_subject(
+            database::LockoutScope::Account,
+            &user_id.to_string(),
+            self.database_ref(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Verify a WebAuthn assertion for a login with the credential, checking
+    /// the challenge, any MFA Token, the signature and the signature counter,
+    /// returning the Refresh Token lifetime requested with any MFA Token.
+    async fn verify_web_authn_assertion(
+        &self,
+        webauthn_credential: &database::WebAuthnCredentials,
+        request_message: &FinishWebAuthnLoginRequest,
+    ) -> Result<u64, BackendError> {
+        let relying_party = webauthn::RelyingParty::new(&self.config_ref().webauthn);
+
+        // The client data must be for our origin and a challenge we issued
+        let client_data = relying_party.client_data(
+            &request_message.client_data_json,
+            webauthn::Ceremony::Authentication,
+        )?;
+
+        let webauthn_challenge = database::WebAuthnChallenges::redeem(
+            &client_data.challenge,
+            self.hash_key_ref(),
+            webauthn::Ceremony::Authentication,
+            self.database_ref(),
+        )
+        .await
+        .map_err(|_| BackendError::WebAuthn("challenge is not valid".to_string()))?;
+
+        // The challenge and any MFA Token must be issued to the credential user
+        let mfa_token = request_message
+            .mfa_token
+            .as_deref()
+            .map(|mfa_token| self.decode_mfa_token(mfa_token))
+            .transpose()?;
+        let mfa_user_id = mfa_token.map(|(user_id, _)| user_id);
+
+        if webauthn_challenge.user_id != mfa_user_id
+            || mfa_user_id.is_some_and(|user_id| user_id != webauthn_credential.user_id)
+        {
+            return Err(BackendError::WebAuthn(
+                "challenge was not issued for this credential".to_string(),
+            ));
+        }
+
+        // A passkey used as the only factor must also verify the user, such as
+        // with a PIN or biometric
+        let authenticator_data = relying_party.verify_assertion(
+            &webauthn_credential.public_key,
+            &request_message.client_data_json,
+            &request_message.authenticator_data,
+            &request_message.signature,
+            mfa_user_id.is_none(),
+        )?;
+
+        // The signature counter must increase, or the credential may be cloned
+        if !webauthn_credential
+            .use_sign_count(authenticator_data.sign_count, self.database_ref())
+            .await?
+        {
+            return Err(BackendError::WebAuthn(
+                "signature counter did not increase".to_string(),
+            ));
+        }
+
+        // Passwordless logins have no MFA Token, so use the configured lifetime
+        let refresh_token_seconds = mfa_token
+            .map(|(_, refresh_token_seconds)| refresh_token_seconds)
+            .unwrap_or(self.config_ref().application.refresh_token_seconds);
+
+        Ok(refresh_token_seconds)
+    }
+
+    /// Store a new WebAuthn login challenge, bound to the user when the
+    /// passkey is used as a second factor.
+    pub(crate) async fn web_authn_challenge(
+        &self,
+        user_id: Option<&Uuid>,
+    ) -> Result<domain::OneTimeToken, BackendError> {
+        let challenge = domain::OneTimeToken::generate();
+        let webauthn_challenge = database::WebAuthnChallenges::new(
+            user_id,
+            webauthn::Ceremony::Authentication,
+            &challenge,
+            self.hash_key_ref(),
+        )
+        .insert(self.database_ref())
+        .await?;
+        tracing::debug!("WebAuthn Challenge added to the database: {}", webauthn_challenge.id);
+
+        Ok(challenge)
+    }
+
+    /// Verify a WebAuthn login assertion, where failures count towards locking
+    /// the account, returning the active user and the Refresh Token lifetime.
+    pub(crate) async fn verify_web_authn_login(
+        &self,
+        request_message: &FinishWebAuthnLoginRequest,
+        login_ip: IpAddr,
+    ) -> Result<(database::Users, u64), BackendError> {
+        let authentication_failed =
+            || BackendError::AuthenticationError("Authentication Failed!".to_string());
+
+        // Get the credential used
+        let webauthn_credential = match database::WebAuthnCredentials::from_credential_id(
+            &request_message.credential_id,
+            self.database_ref(),
+        )
+        .await
+        {
+            Ok(webauthn_credential) => webauthn_credential,
+            Err(_) => {
+                tracing::error!("WebAuthn Credential not found in database!");
+                self.record_login_attempt(None, login_ip, domain::LoginOutcome::MfaFailed)
+                    .await?;
+                return Err(authentication_failed());
+            }
+        };
+        let user_id = webauthn_credential.user_id;
+
+        self.check_account_lockout(&user_id, login_ip).await?;
+
+        // Verify the assertion, failures count towards locking the account
+        let refresh_token_seconds = match self
+            .verify_web_authn_assertion(&webauthn_credential, request_message)
+            .await
+        {
+            Ok(refresh_token_seconds) => refresh_token_seconds,
+            Err(error) => {
+                tracing::error!("WebAuthn assertion rejected: {error}");
+                self.record_login_attempt(
+                    Some(&user_id),
+                    login_ip,
+                    domain::LoginOutcome::MfaFailed,
                 )
-            })?;
+                .await?;
+                return Err(authentication_failed());
+            }
+        };
+
+        // Get the user, who must still be active
+        let user = database::Users::from_user_id(&user_id, self.database_ref())
+            .await
+            .map_err(|_| authentication_failed())?;
+
+        if !user.is_active {
+            tracing::error!("User is not active: {}", user.id);
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::Inactive,
+            )
+            .await?;
+            return Err(authentication_failed());
+        }
+
+        tracing::info!("WebAuthn assertion verified for user: {}", user.id);
+
+        Ok((user, refresh_token_seconds))
+    }
+
+    /// Decode an MFA Token, which proves the users password was verified,
+    /// returning the user id it was issued to and the Refresh Token lifetime
+    /// requested at login.
+    pub(crate) fn decode_mfa_token(&self, mfa_token: &str) -> Result<(Uuid, u64), BackendError> {
+        let token_keys = self.token_keys_ref();
+        let app_config = &self.config_ref().application;
+        let mfa_token_claim =
+            domain::TokenClaim::from_token(mfa_token, token_keys, app_config).map_err(
+                |_| {
+                    tracing::error!("MFA Token is invalid!");
+                    BackendError::AuthenticationError("Authentication Failed!".to_string())
+                },
+            )?;
+
+        if mfa_token_claim.jty != domain::TokenType::Mfa.to_string() {
+            tracing::error!("Token is not an MFA Token!");
+            return Err(BackendError::AuthenticationError(
+                "Authentication Failed!".to_string(),
+            ));
+        }
+
+        let refresh_token_seconds = mfa_token_claim
+            .jrl
+            .unwrap_or(app_config.refresh_token_seconds)
+            .min(app_config.remember_me_max_seconds);
+
+        Ok((Uuid::parse_str(&mfa_token_claim.sub)?, refresh_token_seconds))
+    }
+
+    /// Rotate the Session for a Refresh Token, issuing new Access and Refresh
+    /// Tokens. A Refresh Token that has already been rotated is being reused,
+    /// so the whole refresh token family is revoked.
+    ///
+    /// The new Session is active in the organisation given by the context.
+    /// Switching to an organisation the user is not a member of fails before
+    /// the Session is rotated, while a kept organisation the user has since
+    /// been removed from is dropped.
+    ///
+    /// Sessions issued to an OIDC client can only be refreshed by that client
+    /// once it has authenticated, and other Sessions only without a client.
+    pub(crate) async fn refresh_session(
+        &self,
+        refresh_token: &str,
+        login_ip: IpAddr,
+        organisation: OrganisationContext,
+        oidc_client_id: Option<Uuid>,
+    ) -> Result<TokenResponse, BackendError> {
+        //-- 1. Get & Validate  the Refresh Token Claim
+        // Get the keys used to verify the token signature
+        let token_keys = self.token_keys_ref();
+        let app_config = &self.config_ref().application;
+
+        // Using the Token Keys decode the token into a Token Claim
+        // This also validates the token expiration, not before, issuer and audience
+        let refresh_token_claim =
+            match domain::TokenClaim::from_token(refresh_token, token_keys, app_config) {
+                Ok(refresh_token_claim) => refresh_token_claim,
+                Err(_) => {
+                    tracing::error!("Refresh Token is invalid!");
+                    self.record_login_attempt(
+                        None,
+                        login_ip,
+                        domain::LoginOutcome::InvalidToken,
+                    )
+                    .await?;
+                    return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+                }
+            };
+
+        //-- 2. Check Session status in database
+        let session = database::Sessions::from_token(
+            refresh_token,
+            self.hash_key_ref(),
+            self.database_ref(),
+        )
+        .await?;
+
+        if session.oidc_client_id != oidc_client_id {
+            tracing::error!("Session was not issued to the client: {oidc_client_id:?}");
+            self.record_login_attempt(
+                Some(&session.user_id),
+                login_ip,
+                domain::LoginOutcome::InvalidToken,
+            )
+            .await?;
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+        }
+
+        // Deactivated users cannot refresh, even if their Session was not revoked
+        let user_id = Uuid::try_parse(&refresh_token_claim.sub).map_err(|_| {
+            tracing::error!("Unable to parse Uuid");
+            BackendError::AuthenticationError("Authentication Failed!".to_string())
+        })?;
+
+        let user =
+            database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        if !user.is_active {
+            tracing::error!("User is not active: {}", user.id);
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::Inactive,
+            )
+            .await?;
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+        }
+
+        // Find the membership of the organisation the new Session is active in
+        let organisation_id = match organisation {
+            OrganisationContext::Keep => session.organisation_id,
+            OrganisationContext::Switch(organisation_id) => Some(organisation_id),
+            OrganisationContext::Clear => None,
+        };
+        let membership = match organisation_id {
+            Some(organisation_id) => {
+                database::OrganisationMembers::from_ids(
+                    &organisation_id,
+                    &session.user_id,
+                    self.database_ref(),
+                )
+                .await?
+            }
+            None => None,
+        };
+        if session.is_active
+            && matches!(organisation, OrganisationContext::Switch(_))
+            && membership.is_none()
+        {
+            tracing::error!("User is not a member of organisation: {organisation_id:?}");
+            return Err(BackendError::NotOrganisationMember);
+        }
+
+        //-- 3. Rotate the Session, which only succeeds once per Refresh Token
+        if !session.revoke_if_active(self.database_ref()).await? {
+            // A revoked Session in a family that is still active has already
+            // been rotated, so the Refresh Token has been stolen and replayed
+            // by either the attacker or the user. Revoke the family so neither
+            // can continue to use it.
+            let revoked = session.revoke_family(self.database_ref()).await?;
+            self.revoke_access_tokens(database::RevocationScope::Family(session.family_id))
+                .await?;
+
+            let outcome = match revoked {
+                0 => {
+                    tracing::error!("Session is not active");
+                    domain::LoginOutcome::InvalidToken
+                }
+                _ => {
+                    tracing::warn!(
+                        "Rotated Refresh Token reused, revoked session family {} for user {}",
+                        session.family_id,
+                        session.user_id
+                    );
+                    domain::LoginOutcome::TokenReuse
+                }
+            };
+
+            self.record_login_attempt(Some(&session.user_id), login_ip, outcome)
+                .await?;
+
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+        }
+
+        tracing::info!("Session is active.");
+
+        // Record the refresh alongside the users logins
+        database::Logins::new(&user.id, Some(login_ip))
+            .insert(self.database_ref())
+            .await?;
+
+        //-- 4. Generate new Access and Refresh Tokens
+        // Build an Access Token
+        let access_token = self.access_token(&user, membership.as_ref()).await?;
+
+        tracing::debug!("Using Access Token: {}", access_token);
+
+        // Build a new Refresh Token and the next Session in its family, keeping
+        // the lifetime asked for at login
+        let refresh_token_seconds = (refresh_token_claim.exp - refresh_token_claim.iat)
+            .min(app_config.remember_me_max_seconds);
+        let refresh_token = domain::RefreshToken::new(
+            token_keys,
+            app_config,
+            &user,
+            refresh_token_seconds,
+        )?;
+        let session = session
+            .rotate(&user, &access_token, &refresh_token, self.hash_key_ref())
+            .with_organisation(membership.map(|membership| membership.organisation_id));
+
+        // Add Session to database
+        let session = session.insert(self.database_ref()).await?;
+
+        tracing::debug!("Session added to the database: {}", session.id);
+
+        //-- 5. Return new Access Token and Refresh Token
+        // Build Authenticate Response with the token
+        let response = TokenResponse {
+            access_token: access_token.to_string(),
+            refresh_token: refresh_token.to_string(),
+        };
+
+        Ok(response)
+    }
+
+    /// Check a users email and password, returning the active user. Client IP
+    /// addresses and accounts with too many failed attempts are locked, and
+    /// repeated failed attempts are slowed down.
+    pub(crate) async fn verify_password(
+        &self,
+        email: &str,
+        password: String,
+        login_ip: IpAddr,
+    ) -> Result<database::Users, BackendError> {
+        let lockout_config = &self.config_ref().lockout;
+
+        let authentication_failed =
+            || BackendError::AuthenticationError("Authentication Failed!".to_string());
+
+        // Client IP addresses with too many failed attempts cannot try any account
+        let ip_lockout = self.check_ip_lockout(login_ip).await?;
+
+        // Slow down repeated failed attempts from the client IP address
+        let ip_delay = ip_lockout
+            .map(|lockout| lockout.delay(lockout_config))
+            .unwrap_or_default();
+        tokio::time::sleep(ip_delay).await;
+
+        // Parse the request email string into an EmailAddress
+        let request_email = match domain::EmailAddress::parse(email) {
+            Ok(request_email) => request_email,
+            Err(_) => {
+                self.record_login_attempt(None, login_ip, domain::LoginOutcome::UnknownEmail)
+                    .await?;
+                return Err(authentication_failed());
+            }
+        };
 
         tracing::debug!("Request email: {}", request_email.as_ref());
 
         // Get the user from the database using the request email, so we can verify password hash
         let user =
-            database::Users::from_user_email(&request_email, self.database_ref())
-                .await
-                .map_err(|_| {
+            match database::Users::from_user_email(&request_email, self.database_ref()).await {
+                Ok(user) => user,
+                Err(_) => {
                     tracing::error!(
                         "User email not found in database: {}",
                         request_email.as_ref()
                     );
-                    BackendError::AuthenticationError(
-                        "Authentication Failed!".to_string(),
+                    self.record_login_attempt(
+                        None,
+                        login_ip,
+                        domain::LoginOutcome::UnknownEmail,
                     )
-                })?;
+                    .await?;
+                    return Err(authentication_failed());
+                }
+            };
 
         tracing::debug!("User retrieved from the database: {}", user.id);
 
-        // Wrap the Token Secret string in a Secret
-        let token_secret = self.config.application.token_secret.clone();
+        // Locked accounts cannot log in until the lock expires or an admin unlocks them
+        let account_lockout = self.check_account_lockout(&user.id, login_ip).await?;
 
-        // Wrap request password in a Secret
-        let password_secret = Secret::new(request_message.password);
+        // Slow down repeated failed attempts on the account
+        let account_delay = account_lockout
+            .map(|lockout| lockout.delay(lockout_config))
+            .unwrap_or_default();
+        tokio::time::sleep(account_delay.saturating_sub(ip_delay)).await;
 
         // Check password against stored hash
-        match user.password_hash.verify_password(&password_secret)? {
-            true => {
-                tracing::info!("Password verified.");
+        if !user.password_hash.verify_password(&Secret::new(password))? {
+            tracing::error!("Password verification failed.");
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::BadPassword,
+            )
+            .await?;
+            return Err(authentication_failed());
+        }
+
+        tracing::info!("Password verified.");
+
+        // Inactive users, such as those pending registration, cannot log in
+        if !user.is_active {
+            tracing::error!("User is not active: {}", user.id);
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::Inactive,
+            )
+            .await?;
+            return Err(authentication_failed());
+        }
+
+        Ok(user)
+    }
+
+    /// Check the password of a signed in user before a change to their account,
+    /// such as their email address. Wrong passwords count towards locking the
+    /// client IP address and the account, in the same way as logging in.
+    pub(crate) async fn verify_user_password(
+        &self,
+        user: &database::Users,
+        password: String,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        self.check_ip_lockout(login_ip).await?;
+        self.check_account_lockout(&user.id, login_ip).await?;
+
+        if !user.password_hash.verify_password(&Secret::new(password))? {
+            tracing::error!("Password verification failed: {}", user.id);
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::BadPassword,
+            )
+            .await?;
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Check a TOTP or Recovery Code for an active user logging in with MFA
+    /// enabled. Failed codes count towards locking the account.
+    pub(crate) async fn verify_mfa_code(
+        &self,
+        user: &database::Users,
+        code: &str,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        let authentication_failed =
+            || BackendError::AuthenticationError("Authentication Failed!".to_string());
+
+        // The user must still be active
+        if !user.is_active {
+            tracing::error!("User is not active: {}", user.id);
+            self.record_login_attempt(
+                Some(&user.id),
+                login_ip,
+                domain::LoginOutcome::Inactive,
+            )
+            .await?;
+            return Err(authentication_failed());
+        }
+
+        self.check_mfa_code(&user.id, code, login_ip).await
+    }
+
+    /// Check a TOTP or Recovery Code for a user, which can only be used once,
+    /// such as to log in or to change their MFA settings. Users with only
+    /// passkeys can still use their Recovery Codes. Failed codes count towards
+    /// locking the account, as TOTP codes can be guessed.
+    pub(crate) async fn check_mfa_code(
+        &self,
+        user_id: &Uuid,
+        code: &str,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        self.check_account_lockout(user_id, login_ip).await?;
+
+        let totp_secret = database::TotpSecrets::from_user_id(user_id, self.database_ref())
+            .await?
+            .filter(|totp_secret| totp_secret.is_enabled);
+
+        // Check the TOTP or Recovery Code, which can only be used once
+        let result = match totp_secret {
+            Some(totp_secret) => {
+                totp_secret
+                    .redeem_mfa_code(
+                        code,
+                        &self.config_ref().mfa.encryption_key,
+                        self.hash_key_ref(),
+                        self.database_ref(),
+                    )
+                    .await
+            }
+            None => {
+                let recovery_code = domain::RecoveryCode::from(code.to_owned());
+                database::RecoveryCodes::redeem(
+                    user_id,
+                    &recovery_code,
+                    self.hash_key_ref(),
+                    self.database_ref(),
+                )
+                .await
+                .map(|recovery_code| {
+                    tracing::warn!("Recovery Code {} used by user: {}", recovery_code.id, user_id)
+                })
+            }
+        };
+
+        if let Err(error) = result {
+            tracing::error!("MFA code verification failed: {error}");
+            self.record_login_attempt(Some(user_id), login_ip, domain::LoginOutcome::MfaFailed)
+                .await?;
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()));
+        }
 
-                // Get the ip address from the request socket
-                let login_ip = socket_address.ip();
+        tracing::info!("MFA code verified for user: {}", user_id);
 
-                // IpAddress is an enum with two types, so we need to handle both IP cases
-                let login_ip = match login_ip {
-                    IpAddr::V4(ipv4) => Some(ipv4),
-                    IpAddr::V6(ipv6) => None,
-                };
+        Ok(())
+    }
 
-                // Build a new database Login
-                let login = database::Logins::new(&user.id, login_ip);
+    /// Check if the user must complete a second factor to log in, which is
+    /// when they have enabled TOTP or registered a WebAuthn Credential.
+    pub(crate) async fn is_mfa_required(&self, user: &database::Users) -> Result<bool, BackendError> {
+        let totp_secret =
+            database::TotpSecrets::from_user_id(&user.id, self.database_ref()).await?;
+        if totp_secret.is_some_and(|totp_secret| totp_secret.is_enabled) {
+            return Ok(true);
+        }
 
-                // Insert Login into the database
-                let login = login.insert(self.database_ref()).await?;
+        let webauthn_credentials =
+            database::WebAuthnCredentials::index_by_user_id(&user.id, self.database_ref())
+                .await?;
+
+        Ok(!webauthn_credentials.is_empty())
+    }
 
-                tracing::debug!("Login added to the database: {}", login.id);
+    /// Issue a new email verification token for the user, revoking any
+    /// outstanding tokens, and email it to the user.
+    async fn send_email_verification(
+        &self,
+        user: &database::Users,
+    ) -> Result<(), BackendError> {
+        // Revoke outstanding verification tokens so only the latest is valid
+        database::EmailVerifications::revoke_user_id(&user.id, self.database_ref())
+            .await?;
+
+        // Insert the new verification into the database
+        let token = domain::OneTimeToken::generate();
+        let email_verification =
+            database::EmailVerifications::new(user, &token, self.hash_key_ref());
+        let email_verification =
+            email_verification.insert(self.database_ref()).await?;
+        tracing::debug!(
+            "Email Verification added to the database: {}",
+            email_verification.id
+        );
+
+        // Email the token to the user
+        let template = EmailTemplate::EmailVerification {
+            token,
+            expires_in_hours: database::EMAIL_VERIFICATION_DURATION / 60 / 60,
+        };
+        self.send_email(template.message(&user.email));
 
-                // Build a new Access Token
-                let access_token = domain::AccessToken::new(&token_secret, &user)?;
+        Ok(())
+    }
+}
 
-                tracing::debug!("Using Access Token: {}", access_token);
+#[tonic::async_trait]
+impl Authentication for AuthenticationService {
+    #[tracing::instrument(name = "Authenticate Request: ", skip_all, fields(
+        src_address=%request.remote_addr().unwrap(),
+    ))]
+    async fn login(
+        &self,
+        request: Request<LoginRequest>,
+    ) -> Result<Response<LoginResponse>, Status> {
+        let socket_address = request.remote_addr().unwrap();
+        let login_ip = socket_address.ip();
 
-                // Build a new Session
-                let session =
-                    database::Sessions::new(&user, &token_secret)?;
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        // Remember me must be within the configured Refresh Token lifetimes
+        let refresh_token_seconds = self
+            .config_ref()
+            .application
+            .refresh_token_lifetime(request_message.remember_me_seconds)?;
+
+        // Check the password, which also applies the lockout and delay
+        let user = self
+            .verify_password(&request_message.email, request_message.password, login_ip)
+            .await?;
+
+        // Users with MFA enabled must exchange an MFA Token and a valid code, or
+        // passkey assertion, for their tokens
+        if self.is_mfa_required(&user).await? {
+            let mfa_token = domain::MfaToken::new(
+                self.token_keys_ref(),
+                &self.config_ref().application,
+                &user,
+                refresh_token_seconds,
+            )?;
+            tracing::info!("MFA required for user: {}", user.id);
+
+            let response = LoginResponse {
+                access_token: None,
+                refresh_token: None,
+                mfa_token: Some(mfa_token.to_string()),
+            };
+
+            return Ok(Response::new(response));
+        }
+
+        self.clear_account_lockout(&user.id).await?;
+
+        let tokens = self
+            .issue_tokens(&user, login_ip, refresh_token_seconds)
+            .await?;
+
+        // Build Authenticate Response with the token
+        let response = LoginResponse {
+            access_token: Some(tokens.access_token),
+            refresh_token: Some(tokens.refresh_token),
+            mfa_token: None,
+        };
+
+        // Send Response
+        Ok(Response::new(response))
+    }
+
+    #[tracing::instrument(name = "Authenticate MFA Request: ", skip_all, fields(
+        src_address=%request.remote_addr().unwrap(),
+    ))]
+    async fn login_mfa(
+        &self,
+        request: Request<LoginMfaRequest>,
+    ) -> Result<Response<TokenResponse>, Status> {
+        let socket_address = request.remote_addr().unwrap();
+        let login_ip = socket_address.ip();
 
-                // Insert Session into the database
-                let refresh_token =
-                    session.insert(self.database_ref()).await?;
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        let authentication_failed =
+            || BackendError::AuthenticationError("Authentication Failed!".to_string());
+
+        //-- 1. Decode the MFA Token, which proves the password was verified
+        let (user_id, refresh_token_seconds) =
+            match self.decode_mfa_token(&request_message.mfa_token) {
+                Ok(mfa_token) => mfa_token,
+                Err(error) => {
+                    self.record_login_attempt(
+                        None,
+                        login_ip,
+                        domain::LoginOutcome::InvalidToken,
+                    )
+                    .await?;
+                    return Err(error.into());
+                }
+            };
+
+        //-- 2. Get the user, who must still be active with MFA enabled
+        let user = database::Users::from_user_id(&user_id, self.database_ref())
+            .await
+            .map_err(|_| authentication_failed())?;
+
+        //-- 3. Check the TOTP or Recovery Code, which can only be used once
+        self.verify_mfa_code(&user, &request_message.code, login_ip)
+            .await?;
+        self.clear_account_lockout(&user.id).await?;
+
+        //-- 4. Issue the tokens
+        let response = self
+            .issue_tokens(&user, login_ip, refresh_token_seconds)
+            .await?;
+
+        Ok(Response::new(response))
+    }
+
+    #[tracing::instrument(name = "Begin WebAuthn Login Request: ", skip_all)]
+    async fn begin_web_authn_login(
+        &self,
+        request: Request<BeginWebAuthnLoginRequest>,
+    ) -> Result<Response<BeginWebAuthnLoginResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 1. As a second factor the challenge is bound to the MFA Token user,
+        // passwordless challenges are bound to the credential used
+        let (user_id, allow_credential_ids) = match request_message.mfa_token {
+            Some(mfa_token) => {
+                let (user_id, _) = self.decode_mfa_token(&mfa_token)?;
+                let allow_credential_ids = database::WebAuthnCredentials::index_by_user_id(
+                    &user_id,
+                    self.database_ref(),
+                )
+                .await?
+                .into_iter()
+                .map(|credential| credential.credential_id)
+                .collect();
+
+                (Some(user_id), allow_credential_ids)
+            }
+            None => (None, Vec::new()),
+        };
+
+        //-- 2. Store the challenge
+        let challenge = self.web_authn_challenge(user_id.as_ref()).await?;
+
+        let response_message = BeginWebAuthnLoginResponse {
+            challenge: challenge.as_ref().as_bytes().to_vec(),
+            rp_id: self.config_ref().webauthn.rp_id.to_owned(),
+            allow_credential_ids,
+        };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Finish WebAuthn Login Request: ", skip_all, fields(
+        src_address=%request.remote_addr().unwrap(),
+    ))]
+    async fn finish_web_authn_login(
+        &self,
+        request: Request<FinishWebAuthnLoginRequest>,
+    ) -> Result<Response<TokenResponse>, Status> {
+        let socket_address = request.remote_addr().unwrap();
+        let login_ip = socket_address.ip();
+
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
 
-                tracing::debug!("Session added to the database: {}", refresh_token.id);
-                tracing::debug!("Using Refresh Token: {}", refresh_token.refresh_token);
+        //-- 1. Verify the assertion for an active user
+        let (user, refresh_token_seconds) = self
+            .verify_web_authn_login(&request_message, login_ip)
+            .await?;
+        self.clear_account_lockout(&user.id).await?;
 
-                // Build Authenticate Response with the token
-                let response = TokenResponse {
-                    access_token: access_token.to_string(),
-                    refresh_token: refresh_token.refresh_token.to_string(),
-                };
+        //-- 2. Issue the tokens
+        let response = self
+            .issue_tokens(&user, login_ip, refresh_token_seconds)
+            .await?;
 
-                // Send Response
-                Ok(Response::new(response))
-            }
-            false => {
-                tracing::error!("Password verification failed.");
-                Err(Status::unauthenticated("Authentication Failed!"))
-            }
-        }
+        Ok(Response::new(response))
     }
 
-    /// Get a new Access Token using the Refresh Token that has a longer life
+    /// Get a new Access Token using the Refresh Token that has a longer life.
+    ///
+    /// Each refresh rotates the Refresh Token within its session family, so
+    /// sessions on other devices are not affected. Using a rotated Refresh
+    /// Token again revokes the whole family.
     #[tracing::instrument(
         name = "Refresh Access Token Request: ",
         skip(self, request)
@@ -162,89 +1231,59 @@ impl Authentication for AuthenticationService {
         &self,
         request: Request<RefreshRequest>,
     ) -> Result<Response<TokenResponse>, Status> {
+        let socket_address = request.remote_addr().unwrap();
+        let login_ip = socket_address.ip();
+
         // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
         let (_request_metadata, _request_extensions, request_message) =
             request.into_parts();
 
         //-- 1. Get the Refresh Token
         // Get the RefreshAuthenticationRequest from inside the Tonic Request
-        // let request = request.into_inner();
         let refresh_token = request_message.refresh_token;
 
-        //-- 2. Get & Validate  the Refresh Token Claim
-        // Get the Token Secret from config and wrap it in a Secret to help limit leaks
-        let token_secret = &self.config_ref().application.token_secret;
-        let token_secret = token_secret.to_owned();
-
-        // Using the Token Secret decode the token into a Token Claim
-        // This also validates the token expiration, not before and Issuer
-        let refresh_token_claim = domain::TokenClaim::from_token(
-            &refresh_token,
-            &token_secret,
-        )
-        .map_err(|_| {
-            tracing::error!("Refresh Token is invalid!");
-            BackendError::AuthenticationError("Authentication Failed!".to_string())
-        })?;
-
-        //-- 3. Check Session status in database
-        let session =
-            database::Sessions::from_token(&refresh_token, self.database_ref())
-                .await?;
-
-        match session.is_active {
-            true => {
-                tracing::info!("Session is active.");
-
-                //-- 4. Void all Sessions for associated user ID
-                session
-                    .revoke_associated(self.database_ref())
-                    .await?;
-
-                let user_id =
-                    Uuid::try_parse(&refresh_token_claim.sub).map_err(|_| {
-                        tracing::error!("Unable to parse Uuid");
-                        BackendError::AuthenticationError(
-                            "Authentication Failed!".to_string(),
-                        )
-                    })?;
+        //-- 2. Rotate the Session, issuing new Access and Refresh Tokens
+        let response = self
+            .refresh_session(&refresh_token, login_ip, OrganisationContext::Keep, None)
+            .await?;
 
-                let user =
-                    database::Users::from_user_id(&user_id, self.database_ref())
-                        .await?;
-
-                //-- 5. Generate new Access and Refresh Tokens
-                // Build an Access Token
-                let access_token = domain::AccessToken::new(&token_secret, &user)?;
-
-                tracing::debug!("Using Access Token: {}", access_token);
+        // Send Response
+        Ok(Response::new(response))
+    }
 
-                // Build a Session
-                let session =
-                    database::Sessions::new(&user, &token_secret)?;
+    /// Switch the organisation the users Session is active in, rotating the
+    /// Refresh Token so the new Access Token carries the organisation.
+    #[tracing::instrument(name = "Switch Organisation Request: ", skip(self, request))]
+    async fn switch_organisation(
+        &self,
+        request: Request<SwitchOrganisationRequest>,
+    ) -> Result<Response<TokenResponse>, Status> {
+        let socket_address = request.remote_addr().unwrap();
+        let login_ip = socket_address.ip();
 
-                // Add Session to database
-                let refresh_token =
-                    session.insert(self.database_ref()).await?;
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
 
-                tracing::debug!("Using Refresh Token: {}", refresh_token.refresh_token);
+        //-- 1. Parse the organisation to switch to, if any
+        let organisation = match request_message.organisation_id {
+            Some(organisation_id) => {
+                let organisation_id = Uuid::parse_str(&organisation_id).map_err(|_| {
+                    tracing::error!("Unable to parse organisation id to UUID!");
+                    BackendError::OrganisationInvalid("id is not a UUID".to_string())
+                })?;
+                OrganisationContext::Switch(organisation_id)
+            }
+            None => OrganisationContext::Clear,
+        };
 
-                //-- 5. Send new Access Token and Refresh Token
-                // Build Authenticate Response with the token
-                let response = TokenResponse {
-                    access_token: access_token.to_string(),
-                    refresh_token: refresh_token.refresh_token.to_string(),
-                };
+        //-- 2. Rotate the Session into the organisation
+        let response = self
+            .refresh_session(&request_message.refresh_token, login_ip, organisation, None)
+            .await?;
 
-                // Send Response
-                Ok(Response::new(response))
-            }
-            false => {
-                tracing::error!("Session is not active");
-                Err(Status::unauthenticated("Authentication Failed!"))
-            }
-        }
-        //
+        // Send Response
+        Ok(Response::new(response))
     }
 
     #[tracing::instrument(name = "Update Password Request: ", skip(self, request))]
@@ -271,21 +1310,33 @@ impl Authentication for AuthenticationService {
             })?;
         tracing::debug!("Using Access Token: {}", access_token);
 
-        // Get the Token Secret from config and wrap it in a Secret to help limit leaks
-        let token_secret = &self.config_ref().application.token_secret;
-        let token_secret = token_secret.to_owned();
+        // Get the keys used to verify the token signature
+        let token_keys = self.token_keys_ref();
+        let app_config = &self.config_ref().application;
 
-        // Using the Token Secret decode the Access Token into a Token Claim. This also
-        // validates the token expiration, not before and Issuer.
+        // Using the Token Keys decode the Access Token into a Token Claim. This also
+        // validates the token expiration, not before, issuer and audience.
         let access_token_claim =
-            domain::TokenClaim::from_token(&access_token, &token_secret).map_err(
-                |_| {
+            domain::TokenClaim::from_token(access_token, token_keys, app_config)
+                .map_err(|_| {
                     tracing::error!("Access Token is invalid!");
-                    return BackendError::AuthenticationError(
+                    BackendError::AuthenticationError(
                         "Authentication Failed!".to_string(),
-                    );
-                },
-            )?;
+                    )
+                })?;
+
+        // Only Access Tokens can change the password, not Refresh or MFA Tokens,
+        // so the password alone cannot be used to skip the MFA challenge
+        if access_token_claim.jty != domain::TokenType::Access.to_string() {
+            tracing::error!("Token is not an Access Token!");
+            return Err(Status::unauthenticated("Authentication Failed!"));
+        }
+
+        // Revoked Access Tokens cannot be used to change the password
+        if self.revocation_list.is_revoked(&access_token_claim.jti) {
+            tracing::error!("Access Token has been revoked!");
+            return Err(Status::unauthenticated("Authentication Failed!"));
+        }
         // tracing::debug!("Decoded Access Token Claim: {}", access_token_claim);
 
         //-- 2. Get user from database and check status
@@ -293,9 +1344,9 @@ impl Authentication for AuthenticationService {
         // Parse token claim user_id string into a UUID
         let user_id: Uuid = access_token_claim.sub.parse().map_err(|_| {
             tracing::error!("Unable to parse user id to UUID!");
-            return BackendError::AuthenticationError(
+            BackendError::AuthenticationError(
                 "Authentication Failed!".to_string(),
-            );
+            )
         })?;
 
         // Get the user from the database using the token claim user_id, so we
@@ -304,20 +1355,20 @@ impl Authentication for AuthenticationService {
             .await
             .map_err(|_| {
                 tracing::error!("User id not found in database: {}", user_id);
-                return BackendError::AuthenticationError(
+                BackendError::AuthenticationError(
                     "Authentication Failed!".to_string(),
-                );
+                )
             })?;
 
         // Check user is active
-        if user.is_active == false {
+        if !user.is_active {
             tracing::error!("User is not active: {}", user_id);
             return Err(Status::unauthenticated("Authentication Failed!"));
         }
         tracing::debug!("User is active in the database: {}", user.id);
 
         // Check user email is verified
-        if user.is_verified == false {
+        if !user.is_verified {
             tracing::error!("User email is not verified: {}", user_id);
             return Err(Status::unauthenticated("Authentication Failed!"));
         }
@@ -325,7 +1376,7 @@ impl Authentication for AuthenticationService {
 
         //-- 4. Verify existing/original password
         let original_password = Secret::new(request_message.password_original);
-        if user.password_hash.verify_password(&original_password)? == false {
+        if !user.password_hash.verify_password(&original_password)? {
             tracing::error!("Original password is incorrect");
             return Err(Status::unauthenticated("Authentication Failed!"));
         }
@@ -335,55 +1386,471 @@ impl Authentication for AuthenticationService {
         let new_password = Secret::new(request_message.password_new);
         let new_password_hash = domain::PasswordHash::parse(new_password)?;
         user.password_hash = new_password_hash;
-        user.update(&self.database_ref());
+        let user = user.update(self.database_ref()).await?;
         tracing::debug!("Users password updated in the database: {}", user.id);
 
         // Build an new Access Token
-        let access_token = domain::AccessToken::new(&token_secret, &user)?;
+        let access_token = self.access_token(&user, None).await?;
         tracing::debug!("Using Access Token: {}", access_token);
 
-        // Build a new session instance
-        let session = database::Sessions::new(&user, &token_secret)?;
+        // Build a new Refresh Token and session instance
+        let refresh_token = domain::RefreshToken::new(
+            token_keys,
+            app_config,
+            &user,
+            app_config.refresh_token_seconds,
+        )?;
+        let session = database::Sessions::new(
+            &user,
+            &access_token,
+            &refresh_token,
+            self.hash_key_ref(),
+        );
 
-        // Revoke sessions associated with the user before adding new one to the database
+        // Revoke sessions associated with the user, and their Access Tokens,
+        // before adding new one to the database
         // TODO: When do we clean up (delete) the database
         let _rows_affected =
             session.revoke_associated(self.database_ref()).await?;
+        self.revoke_access_tokens(database::RevocationScope::User(user.id))
+            .await?;
 
         // Add new Session to the database
         let session = session.insert(self.database_ref()).await?;
-        tracing::debug!("Using Refresh Token: {}", session.refresh_token);
+        tracing::debug!("Session added to the database: {}", session.id);
 
         // Build Token Response message with the token
         let response_message = TokenResponse {
             access_token: access_token.to_string(),
-            refresh_token: session.refresh_token.to_string(),
+            refresh_token: refresh_token.to_string(),
         };
 
         // Send Response
         Ok(Response::new(response_message))
     }
 
+    /// Issue a single use password reset token and email it to the user
     #[tracing::instrument(name = "Reset Password Request: ", skip(self, request))]
     async fn reset_password(
         &self,
         request: Request<ResetPasswordRequest>,
     ) -> Result<Response<ResetPasswordResponse>, Status> {
         //-- 0. Break the request up into its parts
-        let (metadata, _extensions, request_message) = request.into_parts();
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        // The response is the same whatever happens below
+        let response_message = ResetPasswordResponse {
+            message: RESET_PASSWORD_MESSAGE.to_string(),
+        };
+
+        //-- 1. Get the user from the database using the request email
+        let user = match domain::EmailAddress::parse(&request_message.email) {
+            Ok(email) => {
+                database::Users::from_user_email(&email, self.database_ref()).await
+            }
+            Err(error) => Err(error),
+        };
+
+        let user = match user {
+            Ok(user) if user.is_active => user,
+            _ => {
+                tracing::info!("Password reset requested for unknown or inactive user");
+                return Ok(Response::new(response_message));
+            }
+        };
+
+        //-- 2. Revoke outstanding reset tokens and issue a new one
+        database::PasswordResets::revoke_user_id(&user.id, self.database_ref())
+            .await?;
+
+        let token = domain::OneTimeToken::generate();
+        let password_reset = database::PasswordResets::new(&user.id, &token, self.hash_key_ref());
+        let password_reset = password_reset.insert(self.database_ref()).await?;
+        tracing::debug!("Password Reset added to the database: {}", password_reset.id);
+
+        //-- 3. Email the token to the user
+        let template = EmailTemplate::PasswordReset {
+            token,
+            expires_in_minutes: database::PASSWORD_RESET_DURATION / 60,
+        };
+        self.send_email(template.message(&user.email));
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Confirm a password reset with the emailed token and a new password
+    #[tracing::instrument(
+        name = "Confirm Password Reset Request: ",
+        skip(self, request)
+    )]
+    async fn confirm_password_reset(
+        &self,
+        request: Request<ConfirmPasswordResetRequest>,
+    ) -> Result<Response<ResetPasswordResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 1. Parse the new password, before the token is redeemed
+        let new_password = Secret::new(request_message.password);
+        let new_password_hash = domain::PasswordHash::parse(new_password)?;
+
+        //-- 2. Redeem the reset token
+        let token = domain::OneTimeToken::from(request_message.token);
+        let password_reset =
+            database::PasswordResets::redeem(&token, self.hash_key_ref(), self.database_ref())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Password reset token is invalid!");
+                    BackendError::AuthenticationError(
+                        "Authentication Failed!".to_string(),
+                    )
+                })?;
+
+        //-- 3. Update the users password in the database
+        let mut user = database::Users::from_user_id(
+            &password_reset.user_id,
+            self.database_ref(),
+        )
+        .await?;
+        user.password_hash = new_password_hash;
+        let user = user.update(self.database_ref()).await?;
+        tracing::info!("Users password reset in the database: {}", user.id);
 
-        unimplemented!()
+        //-- 4. Revoke all of the users Sessions and remaining reset tokens
+        let rows_affected =
+            database::Sessions::revoke_user_id(&user.id, self.database_ref())
+                .await?;
+        tracing::debug!("Sessions revoked after password reset: {rows_affected}");
+        self.revoke_access_tokens(database::RevocationScope::User(user.id))
+            .await?;
+
+        database::PasswordResets::revoke_user_id(&user.id, self.database_ref())
+            .await?;
+
+        // Build Reset Password Response message
+        let response_message = ResetPasswordResponse {
+            message: "Password has been reset.".to_string(),
+        };
+
+        // Send Response
+        Ok(Response::new(response_message))
     }
 
+    /// Register a new user, returning tokens if the sign up policy allows
+    /// them to log in straight away
     #[tracing::instrument(name = "Register User Request: ", skip(self, request))]
     async fn register(
         &self,
         request: Request<RegisterRequest>,
-    ) -> Result<Response<TokenResponse>, Status> {
+    ) -> Result<Response<RegisterResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 1. Check the sign up policy allows self registration, unless the
+        // user has been invited to an organisation
+        let policy = self.config_ref().registration.policy;
+        if policy == RegistrationPolicy::Closed && request_message.invitation_token.is_none() {
+            tracing::error!("Registration request while registration is closed");
+            return Err(BackendError::RegistrationClosed.into());
+        }
+
+        //-- 2. Parse the request message into domain types
+        let email = domain::EmailAddress::parse(request_message.email)?;
+        let name = domain::UserName::parse(request_message.name)?;
+        let password = Secret::new(request_message.password);
+        let password_hash = domain::PasswordHash::parse(password)?;
+
+        //-- 3. Redeem the organisation invitation sent to the email address
+        let invitation = match request_message.invitation_token {
+            Some(invitation_token) => {
+                // Check first, so an invitation is not used up by a duplicate
+                if database::Users::from_user_email(&email, self.database_ref())
+                    .await
+                    .is_ok()
+                {
+                    return Err(BackendError::UserAlreadyExists(email.to_string()).into());
+                }
+
+                let token = domain::OneTimeToken::from(invitation_token);
+                let invitation = database::OrganisationInvitations::redeem(
+                    &token,
+                    self.hash_key_ref(),
+                    &email,
+                    self.database_ref(),
+                )
+                .await
+                .map_err(|_| {
+                    tracing::error!("Organisation invitation token is invalid!");
+                    BackendError::AuthenticationError("Authentication Failed!".to_string())
+                })?;
+                Some(invitation)
+            }
+            None => None,
+        };
+
+        //-- 4. Insert the new user into the database
+        // Self registered users always start with the user role and an
+        // unverified email address. Invited users have shown they own the
+        // email address, so are active and verified. Users left inactive are
+        // pending until they verify their email address.
+        let is_invited = invitation.is_some();
+        let is_active = is_invited || policy.is_active();
+        let user = database::Users {
+            id: Uuid::now_v7(),
+            email,
+            name,
+            password_hash,
+            role: domain::UserRole::User,
+            is_active,
+            is_verified: is_invited,
+            is_pending: !is_active,
+            created_on: Utc::now(),
+        };
+
+        // Duplicate emails are returned as a user already exists error
+        let user = user.insert(self.database_ref()).await?;
+        tracing::info!("User registered with policy {policy}: {}", user.id);
+
+        // Add the invited user to the organisation, or email the new user a
+        // token to verify their email address
+        let membership = match invitation {
+            Some(invitation) => {
+                let membership = database::OrganisationMembers::new(
+                    &invitation.organisation_id,
+                    &user.id,
+                    invitation.role,
+                )
+                .insert(self.database_ref())
+                .await?;
+                tracing::info!(
+                    "Invited user joined organisation: {}",
+                    membership.organisation_id
+                );
+                Some(membership)
+            }
+            None => {
+                self.send_email_verification(&user).await?;
+                None
+            }
+        };
+
+        //-- 5. Build tokens if the user can log in straight away, active in the
+        // organisation they were invited to
+        let (access_token, refresh_token) = if user.is_active {
+            let token_keys = self.token_keys_ref();
+            let app_config = &self.config_ref().application;
+
+            // Build a new Access Token
+            let access_token = self.access_token(&user, membership.as_ref()).await?;
+            tracing::debug!("Using Access Token: {}", access_token);
+
+            // Build a new Refresh Token and Session, and insert it into the database
+            let refresh_token = domain::RefreshToken::new(
+                token_keys,
+                app_config,
+                &user,
+                app_config.refresh_token_seconds,
+            )?;
+            let session = database::Sessions::new(
+                &user,
+                &access_token,
+                &refresh_token,
+                self.hash_key_ref(),
+            )
+            .with_organisation(membership.map(|membership| membership.organisation_id));
+            let session = session.insert(self.database_ref()).await?;
+            tracing::debug!("Session added to the database: {}", session.id);
+
+            (
+                Some(access_token.to_string()),
+                Some(refresh_token.to_string()),
+            )
+        } else {
+            (None, None)
+        };
+
+        // Build Register Response message
+        let response_message = RegisterResponse {
+            user_id: user.id.to_string(),
+            is_active: user.is_active,
+            is_verified: user.is_verified,
+            access_token,
+            refresh_token,
+        };
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Email the user a token to verify their email address, throttling
+    /// repeated requests
+    #[tracing::instrument(
+        name = "Send Verification Email Request: ",
+        skip(self, request)
+    )]
+    async fn send_verification_email(
+        &self,
+        request: Request<SendVerificationEmailRequest>,
+    ) -> Result<Response<SendVerificationEmailResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        // The response is the same whatever happens below
+        let response_message = SendVerificationEmailResponse {
+            message: SEND_VERIFICATION_EMAIL_MESSAGE.to_string(),
+        };
+
+        //-- 1. Get the user from the database using the request email
+        let user = match domain::EmailAddress::parse(&request_message.email) {
+            Ok(email) => {
+                database::Users::from_user_email(&email, self.database_ref()).await
+            }
+            Err(error) => Err(error),
+        };
+
+        // Users an admin has deactivated are not sent verification emails
+        let user = match user {
+            Ok(user) if !user.is_verified && (user.is_active || user.is_pending) => user,
+            _ => {
+                tracing::info!("Verification requested for unknown, verified or inactive user");
+                return Ok(Response::new(response_message));
+            }
+        };
+
+        //-- 2. Throttle repeated requests
+        let latest = database::EmailVerifications::latest_from_user_id(
+            &user.id,
+            self.database_ref(),
+        )
+        .await?;
+
+        if latest.is_some_and(|email_verification| email_verification.is_throttled()) {
+            tracing::info!("Verification email throttled for user: {}", user.id);
+            return Ok(Response::new(response_message));
+        }
+
+        //-- 3. Issue and email a new verification token
+        self.send_email_verification(&user).await?;
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Verify the users email address with the emailed token
+    #[tracing::instrument(name = "Verify Email Request: ", skip(self, request))]
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<VerifyEmailResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 1. Redeem the verification token
+        let token = domain::OneTimeToken::from(request_message.token);
+        let email_verification =
+            database::EmailVerifications::redeem(&token, self.hash_key_ref(), self.database_ref())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Email verification token is invalid!");
+                    BackendError::AuthenticationError(
+                        "Authentication Failed!".to_string(),
+                    )
+                })?;
+
+        //-- 2. Get the user and check the token was for their current email
+        let mut user = database::Users::from_user_id(
+            &email_verification.user_id,
+            self.database_ref(),
+        )
+        .await?;
+
+        if user.email != email_verification.email {
+            tracing::error!("Email verification is for a previous email: {}", user.id);
+            return Err(Status::unauthenticated("Authentication Failed!"));
+        }
+
+        //-- 3. Set the user as verified in the database
+        // Users pending from registration are activated once verified, but
+        // not users an admin has deactivated
+        if user.is_pending {
+            user.is_active = true;
+            user.is_pending = false;
+        }
+        user.is_verified = true;
+        let user = user.update(self.database_ref()).await?;
+        tracing::info!("User email verified: {}", user.id);
+
+        // Build Verify Email Response message
+        let response_message = VerifyEmailResponse {
+            user_id: user.id.to_string(),
+            is_active: user.is_active,
+            is_verified: user.is_verified,
+        };
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Confirm a change of email address with the token sent to the new address
+    #[tracing::instrument(
+        name = "Confirm Email Change Request: ",
+        skip(self, request)
+    )]
+    async fn confirm_email_change(
+        &self,
+        request: Request<ConfirmEmailChangeRequest>,
+    ) -> Result<Response<ConfirmEmailChangeResponse>, Status> {
         //-- 0. Break the request up into its parts
-        let (metadata, _extensions, request_message) = request.into_parts();
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 1. Redeem the email change token
+        let token = domain::OneTimeToken::from(request_message.token);
+        let email_change =
+            database::EmailChanges::redeem(&token, self.hash_key_ref(), self.database_ref())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Email change token is invalid!");
+                    BackendError::AuthenticationError("Authentication Failed!".to_string())
+                })?;
+
+        //-- 2. Check the new address has not been registered since
+        if database::Users::from_user_email(&email_change.email, self.database_ref())
+            .await
+            .is_ok()
+        {
+            tracing::error!("Email change to a registered email: {}", email_change.user_id);
+            return Err(BackendError::UserAlreadyExists(email_change.email.to_string()).into());
+        }
+
+        //-- 3. Swap the users email address in the database
+        // Confirming the token verifies the new address, while verification
+        // tokens sent to the previous address can no longer be used
+        let mut user =
+            database::Users::from_user_id(&email_change.user_id, self.database_ref())
+                .await?;
+        user.email = email_change.email;
+        user.is_verified = true;
+        let user = user.update(self.database_ref()).await?;
+        database::EmailVerifications::revoke_user_id(&user.id, self.database_ref())
+            .await?;
+        tracing::info!("User email changed: {}", user.id);
+
+        // Build Confirm Email Change Response message
+        let response_message = ConfirmEmailChangeResponse {
+            user_id: user.id.to_string(),
+            email: user.email.to_string(),
+            is_verified: user.is_verified,
+        };
 
-        unimplemented!()
+        // Send Response
+        Ok(Response::new(response_message))
     }
 
     /// Revoke all Sessions in the database
@@ -401,15 +1868,15 @@ impl Authentication for AuthenticationService {
         let refresh_token = request_message.refresh_token;
 
         //-- 2. Get & Validate  the Refresh Token Claim
-        // Get the Token Secret from config and wrap it in a Secret to help limit leaks
-        let token_secret = &self.config_ref().application.token_secret;
-        let token_secret = token_secret.to_owned();
+        // Get the keys used to verify the token signature
+        let token_keys = self.token_keys_ref();
 
-        // Using the Token Secret decode the token into a Token Claim
-        // This also validates the token expiration, not before and Issuer
+        // Using the Token Keys decode the token into a Token Claim
+        // This also validates the token expiration, not before, issuer and audience
         let refresh_token_claim = domain::TokenClaim::from_token(
             &refresh_token,
-            &token_secret,
+            token_keys,
+            &self.config_ref().application,
         )
         .map_err(|_| {
             tracing::error!("Refresh Token is invalid!");
@@ -417,18 +1884,201 @@ impl Authentication for AuthenticationService {
         })?;
 
         //-- 3. Get Session from database
-        let session =
-            database::Sessions::from_token(&refresh_token, self.database_ref())
-                .await?;
+        let session = database::Sessions::from_token(
+            &refresh_token,
+            self.hash_key_ref(),
+            self.database_ref(),
+        )
+        .await?;
 
-        // Revoke all Sessions associated with user_id
+        // Revoke all Sessions associated with user_id, and their Access Tokens
         let rows_affected = session
             .revoke_associated(self.database_ref())
             .await? as i64;
+        self.revoke_access_tokens(database::RevocationScope::User(session.user_id))
+            .await?;
 
         // Build Tonic response message
         let response_message = LogoutResponse { rows_affected };
 
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+    /// Introspect an Access or Refresh Token (RFC 7662), returning whether it is
+    /// active along with its subject, role, expiry and token type. Only admins
+    /// and service accounts with the `tokens` scope can introspect tokens.
+    #[tracing::instrument(name = "Introspect Request: ", skip(self, request))]
+    async fn introspect(
+        &self,
+        request: Request<IntrospectRequest>,
+    ) -> Result<Response<IntrospectResponse>, Status> {
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 0. Authenticate the requester
+        self.token_interceptor.authenticate(&request_metadata)?;
+
+        //-- 1. Check the token is active
+        let response_message = match self.active_token(&request_message.token).await? {
+            Some((token_claim, subject, _session)) => IntrospectResponse {
+                active: true,
+                sub: Some(subject.id().to_string()),
+                role: subject.role(),
+                exp: Some(token_claim.exp),
+                token_type: Some(token_claim.jty),
+            },
+            None => {
+                tracing::info!("Introspected token is not active");
+                IntrospectResponse {
+                    active: false,
+                    ..Default::default()
+                }
+            }
+        };
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Revoke an Access or Refresh Token (RFC 7009). Revoking a Refresh Token
+    /// revokes its refresh token family and their Access Tokens. Only admins
+    /// and service accounts with the `tokens` scope can revoke tokens.
+    #[tracing::instrument(name = "Revoke Request: ", skip(self, request))]
+    async fn revoke(
+        &self,
+        request: Request<RevokeRequest>,
+    ) -> Result<Response<RevokeResponse>, Status> {
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        //-- 0. Authenticate the requester
+        self.token_interceptor.authenticate(&request_metadata)?;
+
+        //-- 1. Check the token is active, as inactive tokens need no revoking
+        let rows_affected = match self.active_token(&request_message.token).await? {
+            // Refresh Tokens revoke the Sessions in the family and their Access Tokens
+            Some((_token_claim, _subject, Some(session))) => {
+                let rows_affected = session.revoke_family(self.database_ref()).await?;
+                self.revoke_access_tokens(database::RevocationScope::Family(
+                    session.family_id,
+                ))
+                .await?;
+                rows_affected as i64
+            }
+            // Access Tokens are added to the revocation list
+            Some((token_claim, subject, None)) => {
+                let token_id = Uuid::try_parse(&token_claim.jti)
+                    .map_err(BackendError::from)?;
+                let expires_on = chrono::DateTime::from_timestamp(token_claim.exp as i64, 0)
+                    .unwrap_or_else(Utc::now);
+                let revoked_token =
+                    database::RevokedTokens::new(&token_id, &subject.id(), expires_on)
+                        .insert(self.database_ref())
+                        .await?;
+                self.revocation_list.extend(&[revoked_token]);
+                1
+            }
+            None => {
+                tracing::info!("Revoked token is not active");
+                0
+            }
+        };
+
+        // Build Tonic response message
+        let response_message = RevokeResponse { rows_affected };
+
+        // Send Response
+        Ok(Response::new(response_message))
+    }
+
+    /// Issue an Access Token to a service account with the OAuth 2.0 client
+    /// credentials grant, limited to the requested scopes
+    #[tracing::instrument(name = "Client Credentials Request: ", skip(self, request))]
+    async fn client_credentials(
+        &self,
+        request: Request<ClientCredentialsRequest>,
+    ) -> Result<Response<ClientCredentialsResponse>, Status> {
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (_request_metadata, _request_extensions, request_message) =
+            request.into_parts();
+
+        let authentication_failed =
+            || BackendError::AuthenticationError("Authentication Failed!".to_string());
+
+        //-- 1. Authenticate the service account with its client id and secret
+        let client_id = Uuid::try_parse(&request_message.client_id).map_err(|_| {
+            tracing::error!("Unable to parse client id to UUID!");
+            authentication_failed()
+        })?;
+        let service_account =
+            match database::ServiceAccounts::from_id(&client_id, self.database_ref()).await {
+                Ok(service_account) => service_account,
+                Err(BackendError::Sqlx(sqlx::Error::RowNotFound)) => {
+                    tracing::error!("Service account not found: {client_id}");
+                    return Err(authentication_failed().into());
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+        if !service_account.verify_secret(&request_message.client_secret, self.hash_key_ref()) {
+            tracing::error!("Service account secret is incorrect: {client_id}");
+            return Err(authentication_failed().into());
+        }
+        if !service_account.is_active {
+            tracing::error!("Service account is not active: {client_id}");
+            return Err(authentication_failed().into());
+        }
+
+        //-- 2. Only grant scopes the service account has been granted
+        let granted_scopes = service_account.granted_scopes()?;
+        let scopes = match request_message
+            .scope
+            .filter(|scope| !scope.trim().is_empty())
+        {
+            Some(scope) => {
+                let requested_scopes = domain::ServiceScope::parse_list(
+                    &scope.split_whitespace().collect::<Vec<_>>(),
+                )?;
+                if !requested_scopes
+                    .iter()
+                    .all(|scope| granted_scopes.contains(scope))
+                {
+                    return Err(Status::permission_denied(
+                        "Scope has not been granted to the service account",
+                    ));
+                }
+                requested_scopes
+            }
+            None => granted_scopes,
+        };
+
+        //-- 3. Issue an Access Token to the service account
+        let app_config = &self.config_ref().application;
+        let access_token = domain::AccessToken::new_service_account(
+            self.token_keys_ref(),
+            app_config,
+            &service_account,
+            &scopes,
+        )?;
+
+        // Record the Access Token, so it is revoked if the service account is disabled
+        let expires_on =
+            Utc::now() + chrono::Duration::seconds(app_config.access_token_seconds as i64);
+        service_account
+            .insert_access_token(&access_token.token_id(), expires_on, self.database_ref())
+            .await?;
+
+        tracing::info!("Access Token issued to service account: {}", service_account.id);
+
+        // Build Tonic response message
+        let response_message = ClientCredentialsResponse {
+            access_token: access_token.to_string(),
+            expires_in: app_config.access_token_seconds,
+            scope: domain::ServiceScope::join(&scopes),
+        };
+
         // Send Response
         Ok(Response::new(response_message))
     }
@@ -7,6 +7,7 @@
 
 // #![allow(unused)] // For development only
 
+use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -54,15 +55,17 @@ impl From<database::Logins> for LoginsResponse {
     /// Convert from database::Logins to proto::LoginsResponse
     fn from(value: database::Logins) -> Self {
         let id = value.id.to_string();
-        let user_id = value.user_id.to_string();
+        let user_id = value.user_id.map(|user_id| user_id.to_string());
         let login_on = value.login_on.to_string();
-        let login_ip = value.login_ip;
+        let login_ip_address = value.login_ip.map(|login_ip| login_ip.to_string());
+        let outcome = value.outcome.to_string();
 
         Self {
             id,
             user_id,
             login_on,
-            login_ip,
+            login_ip_address,
+            outcome,
         }
     }
 }
@@ -74,15 +77,25 @@ impl TryFrom<LoginsCreateRequest> for database::Logins {
     /// Try to convert from proto::LoginCreateRequest to a database:Logins
     fn try_from(value: LoginsCreateRequest) -> Result<Self, Self::Error> {
         let id = Uuid::now_v7();
-        let user_id = Uuid::parse_str(value.user_id.as_str())?;
+        let user_id = Some(Uuid::parse_str(value.user_id.as_str())?);
         let login_on = Utc::now();
-        let login_ip = value.login_ip;
+        let login_ip = value
+            .login_ip_address
+            .map(|login_ip| login_ip.parse::<IpAddr>())
+            .transpose()?;
+        let outcome = value
+            .outcome
+            .as_deref()
+            .map(domain::LoginOutcome::parse)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Self {
             id,
             user_id,
             login_on,
             login_ip,
+            outcome,
         })
     }
 }
@@ -94,15 +107,25 @@ impl TryFrom<LoginsUpdateRequest> for database::Logins {
     /// Try to convert from proto::LoginCreateRequest to a database:Logins
     fn try_from(value: LoginsUpdateRequest) -> Result<Self, Self::Error> {
         let id = Uuid::parse_str(&value.id)?;
-        let user_id = Uuid::parse_str(&value.user_id)?;
+        let user_id = Some(Uuid::parse_str(&value.user_id)?);
         let login_on: DateTime<Utc> = value.login_on.parse()?;
-        let login_ip = value.login_ip;
+        let login_ip = value
+            .login_ip_address
+            .map(|login_ip| login_ip.parse::<IpAddr>())
+            .transpose()?;
+        let outcome = value
+            .outcome
+            .as_deref()
+            .map(domain::LoginOutcome::parse)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Self {
             id,
             user_id,
             login_on,
             login_ip,
+            outcome,
         })
     }
 }
@@ -118,6 +141,10 @@ impl Logins for LoginsService {
         // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
+
+        // Check the caller can create logins
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::LoginsCreateAny, None)?;
         // println!("{request_message:#?}");
 
         // Convert the LoginsCreateRequest into a database::Logins
@@ -149,15 +176,19 @@ impl Logins for LoginsService {
         // Parse response login id string into a Uuid
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse login id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse login id to UUID!".to_string(),
-            );
+            )
         })?;
 
         // Retrieve database for request login id
         let database_record =
             database::Logins::from_id(&id, self.database_ref()).await?;
 
+        // Users can read their own logins, or any login with the permission
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::LoginsReadAny, database_record.user_id.as_ref())?;
+
         // Convert the database record into a LoginsResponse message
         let response_message: LoginsResponse = database_record.into();
 
@@ -175,6 +206,10 @@ impl Logins for LoginsService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
+        // Check the caller can read every login
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::LoginsReadAny, None)?;
+
         // TODO: Why does this need to be i64, could we use i32
         // Offset, where to start the records from
         let offset: i64 = request_message.offset.into();
@@ -182,9 +217,16 @@ impl Logins for LoginsService {
         // The number of users to be returned
         let limit: i64 = request_message.limit.into();
 
+        // Only return logins with the outcome, if one is requested
+        let outcome = request_message
+            .outcome
+            .as_deref()
+            .map(domain::LoginOutcome::parse)
+            .transpose()?;
+
         // Query the database
         let database_records =
-            database::Logins::index(&limit, &offset, self.database_ref()).await?;
+            database::Logins::index(&limit, &offset, outcome, self.database_ref()).await?;
 
         // Convert database::Users into User Response within the vector
         let logins: Vec<LoginsResponse> = database_records
@@ -207,6 +249,10 @@ impl Logins for LoginsService {
         // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
+
+        // Check the caller can update logins
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::LoginsUpdateAny, None)?;
         // println!("{request_message:#?} request");
 
         // Convert the LoginsCreateRequest into a database::Logins
@@ -235,12 +281,16 @@ impl Logins for LoginsService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
+        // Check the caller can delete logins
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::LoginsDeleteAny, None)?;
+
         // Parse response login id string into a Uuid
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse login id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse login id to UUID!".to_string(),
-            );
+            )
         })?;
 
         // Retrieve database for request login id
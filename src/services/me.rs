@@ -0,0 +1,356 @@
+//-- ./src/services/me.rs
+
+//! RPC service for Me endpoint
+//!
+//! Endpoints act on the user authenticated by the request Access Token, so
+//! users can read and update their own profile, change their email address,
+//! sign out their own devices and browse their own login history without
+//! admin access.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::email::EmailTemplate;
+use crate::prelude::*;
+use crate::rpc::proto::me_server::Me;
+use crate::rpc::proto::{
+    Empty, LoginsIndexResponse, MeChangeEmailRequest, MeChangeEmailResponse, MeIndexRequest,
+    MeRevokeSessionRequest, MeSessionResponse, MeSessionsResponse, MeUpdateRequest,
+    SessionsRevokeResponse, UserResponse,
+};
+use crate::services::{AuthenticationService, SessionsService};
+use crate::{database, domain};
+
+/// Change email response message, which is the same whether or not the new
+/// email address is registered so the endpoint cannot enumerate users.
+const CHANGE_EMAIL_MESSAGE: &str =
+    "If the email address can be used, a confirmation token has been sent to it.";
+
+/// Me service containing a database pool, the Authentication service used to
+/// check passwords and send emails, and the shared revocation list
+pub struct MeService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+    authentication: Arc<AuthenticationService>,
+    revocation_list: Arc<domain::RevocationList>,
+}
+
+impl MeService {
+    /// Create a new MeService passing in the Arc for the Sqlx database pool,
+    /// configuration, Authentication service and revocation list
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        authentication: Arc<AuthenticationService>,
+        revocation_list: Arc<domain::RevocationList>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            authentication,
+            revocation_list,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Get the requesting user id from the Token Claim added to the request
+    /// extensions by the Access Token interceptor, checking the user has been
+    /// granted the permission to take the action on their own records. API
+    /// keys cannot be used to manage the users own account.
+    fn requester_id(
+        request_extensions: &tonic::Extensions,
+        permission: domain::Permission,
+    ) -> Result<Uuid, BackendError> {
+        let access_token_claim = domain::TokenClaim::from_request_extensions(request_extensions)?;
+
+        if access_token_claim.is_api_key() {
+            tracing::error!("API key used to manage account: {}", access_token_claim.jti);
+            return Err(BackendError::ApiKeyNotAllowed);
+        }
+
+        if !access_token_claim.has_permission(permission) {
+            tracing::error!("Permission denied for {}: {permission}", access_token_claim.sub);
+            return Err(BackendError::PermissionDenied(permission.to_string()));
+        }
+
+        Ok(Uuid::parse_str(&access_token_claim.sub)?)
+    }
+
+    /// Get the Session the request Access Token was issued with, if any
+    async fn current_session(
+        &self,
+        request_extensions: &tonic::Extensions,
+    ) -> Result<Option<database::Sessions>, BackendError> {
+        let access_token_claim = domain::TokenClaim::from_request_extensions(request_extensions)?;
+
+        let Ok(access_token_id) = Uuid::parse_str(&access_token_claim.jti) else {
+            return Ok(None);
+        };
+
+        database::Sessions::from_access_token_id(&access_token_id, self.database_ref()).await
+    }
+
+    /// Shorthand for revoking the Access Tokens of the Sessions in scope
+    async fn revoke_scope(
+        &self,
+        scope: database::RevocationScope,
+    ) -> Result<usize, BackendError> {
+        SessionsService::revoke_access_tokens(
+            scope,
+            &self.revocation_list,
+            self.database_ref(),
+            &self.config,
+        )
+        .await
+    }
+}
+
+#[tonic::async_trait]
+impl Me for MeService {
+    /// Handle rpc requests to read the requesting users profile
+    #[tracing::instrument(name = "Read Me Request: ", skip_all)]
+    async fn read(&self, request: Request<Empty>) -> Result<Response<UserResponse>, Status> {
+        let (_request_metadata, request_extensions, _request_message) = request.into_parts();
+        let user_id = Self::requester_id(&request_extensions, domain::Permission::UsersReadSelf)?;
+
+        let database_record = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to update the requesting users profile
+    #[tracing::instrument(name = "Update Me Request: ", skip_all)]
+    async fn update(
+        &self,
+        request: Request<MeUpdateRequest>,
+    ) -> Result<Response<UserResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::UsersUpdateSelf)?;
+
+        let name = domain::UserName::parse(request_message.name)?;
+
+        let mut user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+        user.name = name;
+        let database_record = user.update(self.database_ref()).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to get an index of the requesting users active
+    /// Sessions, one for each device they are signed in on
+    #[tracing::instrument(name = "Index Me Sessions Request: ", skip_all)]
+    async fn sessions(
+        &self,
+        request: Request<MeIndexRequest>,
+    ) -> Result<Response<MeSessionsResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::SessionsReadSelf)?;
+
+        let current_family_id = self
+            .current_session(&request_extensions)
+            .await?
+            .map(|session| session.family_id);
+
+        let database_records = database::Sessions::index_active_from_user_id(
+            &user_id,
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let sessions = database_records
+            .into_iter()
+            .map(|session| MeSessionResponse {
+                id: session.id.to_string(),
+                family_id: session.family_id.to_string(),
+                created_on: session.created_on.to_string(),
+                is_current: Some(session.family_id) == current_family_id,
+            })
+            .collect();
+
+        Ok(Response::new(MeSessionsResponse { sessions }))
+    }
+
+    /// Handle rpc requests to sign out one of the requesting users devices,
+    /// revoking every Session in the refresh token family and their Access
+    /// Tokens
+    #[tracing::instrument(name = "Revoke Me Session Request: ", skip_all)]
+    async fn revoke_session(
+        &self,
+        request: Request<MeRevokeSessionRequest>,
+    ) -> Result<Response<SessionsRevokeResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::SessionsRevokeSelf)?;
+
+        let id = Uuid::parse_str(&request_message.id).map_err(|_| {
+            tracing::error!("Unable to parse Session id to UUID!");
+            BackendError::Generic("Unable to parse Session id to UUID!".to_string())
+        })?;
+
+        // Only the requesting users own Sessions can be revoked
+        let session = database::Sessions::from_id(&id, self.database_ref()).await?;
+        if session.user_id != user_id {
+            tracing::error!("Session {id} is not owned by user: {user_id}");
+            return Err(BackendError::PermissionDenied(
+                domain::Permission::SessionsRevokeSelf.to_string(),
+            )
+            .into());
+        }
+
+        let rows_affected = session.revoke_family(self.database_ref()).await? as i64;
+        self.revoke_scope(database::RevocationScope::Family(session.family_id))
+            .await?;
+
+        Ok(Response::new(SessionsRevokeResponse { rows_affected }))
+    }
+
+    /// Handle rpc requests to sign out every device of the requesting user
+    /// other than the one making the request
+    #[tracing::instrument(name = "Revoke Other Me Sessions Request: ", skip_all)]
+    async fn revoke_other_sessions(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<SessionsRevokeResponse>, Status> {
+        let (_request_metadata, request_extensions, _request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::SessionsRevokeSelf)?;
+
+        // Without a current Session there is no device to keep signed in
+        let session = self
+            .current_session(&request_extensions)
+            .await?
+            .ok_or(BackendError::NoCurrentSession)?;
+
+        let rows_affected = session.revoke_other_families(self.database_ref()).await? as i64;
+        self.revoke_scope(database::RevocationScope::OtherFamilies(
+            user_id,
+            session.family_id,
+        ))
+        .await?;
+
+        Ok(Response::new(SessionsRevokeResponse { rows_affected }))
+    }
+
+    /// Handle rpc requests to get an index of the requesting users Logins
+    #[tracing::instrument(name = "Index Me Logins Request: ", skip_all)]
+    async fn logins(
+        &self,
+        request: Request<MeIndexRequest>,
+    ) -> Result<Response<LoginsIndexResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::LoginsReadSelf)?;
+
+        let database_records = database::Logins::index_user(
+            &user_id,
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let logins = database_records
+            .into_iter()
+            .map(|login| login.into())
+            .collect();
+
+        Ok(Response::new(LoginsIndexResponse { logins }))
+    }
+
+    /// Handle rpc requests to change the requesting users email address,
+    /// sending a confirmation token to the new address and a notice to the
+    /// current one. The email address is not changed until the token is
+    /// confirmed.
+    #[tracing::instrument(name = "Change Me Email Request: ", skip_all)]
+    async fn change_email(
+        &self,
+        request: Request<MeChangeEmailRequest>,
+    ) -> Result<Response<MeChangeEmailResponse>, Status> {
+        let login_ip = request
+            .remote_addr()
+            .ok_or(BackendError::Static("Client address not found in request."))?
+            .ip();
+
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let user_id =
+            Self::requester_id(&request_extensions, domain::Permission::UsersUpdateSelf)?;
+
+        // The response is the same whether or not a token is sent below
+        let response_message = MeChangeEmailResponse {
+            message: CHANGE_EMAIL_MESSAGE.to_string(),
+        };
+
+        //-- 1. Verify the users current password
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+        if !user.is_active {
+            tracing::error!("User is not active: {}", user.id);
+            return Err(BackendError::AuthenticationError("Authentication Failed!".to_string()).into());
+        }
+        self.authentication
+            .verify_user_password(&user, request_message.password, login_ip)
+            .await?;
+
+        //-- 2. Check the new email address
+        let new_email = domain::EmailAddress::parse(request_message.new_email)?;
+        if new_email == user.email {
+            return Err(BackendError::EmailUnchanged.into());
+        }
+
+        if database::Users::from_user_email(&new_email, self.database_ref())
+            .await
+            .is_ok()
+        {
+            tracing::info!("Email change requested to a registered email: {}", user.id);
+            return Ok(Response::new(response_message));
+        }
+
+        //-- 3. Throttle repeated requests
+        let latest =
+            database::EmailChanges::latest_from_user_id(&user.id, self.database_ref()).await?;
+
+        if latest.is_some_and(|email_change| email_change.is_throttled()) {
+            tracing::info!("Email change throttled for user: {}", user.id);
+            return Ok(Response::new(response_message));
+        }
+
+        //-- 4. Revoke outstanding changes so only the latest can be confirmed
+        database::EmailChanges::revoke_user_id(&user.id, self.database_ref()).await?;
+
+        let token = domain::OneTimeToken::generate();
+        let email_change = database::EmailChanges::new(
+            &user,
+            &new_email,
+            &token,
+            &self.config.jwt.token_hash_key,
+        );
+        let email_change = email_change.insert(self.database_ref()).await?;
+        tracing::debug!("Email Change added to the database: {}", email_change.id);
+
+        //-- 5. Email the token to the new address, and a notice to the current one
+        let template = EmailTemplate::EmailChange {
+            token,
+            expires_in_hours: database::EMAIL_CHANGE_DURATION / 60 / 60,
+        };
+        self.authentication.send_email(template.message(&new_email));
+
+        let template = EmailTemplate::EmailChangeNotice { new_email };
+        self.authentication.send_email(template.message(&user.email));
+
+        Ok(Response::new(response_message))
+    }
+}
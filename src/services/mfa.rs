@@ -0,0 +1,544 @@
+//-- ./src/services/mfa.rs
+
+//! RPC service for Multi-factor Authentication endpoints
+//!
+//! Endpoints act on the user authenticated by the request Access Token, so
+//! users can manage their own TOTP enrolment and WebAuthn (passkey)
+//! credentials.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::prelude::*;
+use crate::rpc::proto::mfa_server::Mfa;
+use crate::rpc::proto::{
+    BeginTotpEnrolmentRequest, BeginTotpEnrolmentResponse,
+    BeginWebAuthnRegistrationRequest, BeginWebAuthnRegistrationResponse,
+    ConfirmTotpEnrolmentRequest, ConfirmTotpEnrolmentResponse,
+    DeleteWebAuthnCredentialRequest, DeleteWebAuthnCredentialResponse, DisableTotpRequest,
+    FinishWebAuthnRegistrationRequest, ListWebAuthnCredentialsRequest,
+    RecoveryCodesRemainingRequest, RecoveryCodesRemainingResponse, RecoveryCodesResponse,
+    RegenerateRecoveryCodesRequest, TotpStatusResponse, WebAuthnCredentialResponse,
+    WebAuthnCredentialsResponse,
+};
+use crate::services::AuthenticationService;
+use crate::{database, domain, webauthn};
+
+/// MFA service containing a database pool
+pub struct MfaService {
+    /// Database Arc reference
+    database: Arc<Pool<Postgres>>,
+    /// Configuration Arc reference
+    config: Arc<Configuration>,
+    /// Authentication service, for checking codes against the login lockouts
+    authentication: Arc<AuthenticationService>,
+}
+
+impl MfaService {
+    /// Initiate a new MFA Service
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        authentication: Arc<AuthenticationService>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            authentication,
+        }
+    }
+
+    /// Shorthand reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Shorthand reference to config
+    fn config_ref(&self) -> &Configuration {
+        &self.config
+    }
+
+    /// Get the requesting user id from the Access Token Claim added to the
+    /// request extensions by the Access Token interceptor. API keys cannot be
+    /// used to manage MFA, so a leaked key cannot enrol a factor and take
+    /// over the account.
+    fn requester_id(
+        request_extensions: &tonic::Extensions,
+    ) -> Result<Uuid, BackendError> {
+        let access_token_claim = request_extensions
+            .get::<domain::TokenClaim>()
+            .ok_or(BackendError::Static("Token Claim not found in request extension."))?;
+
+        if access_token_claim.is_api_key() {
+            tracing::error!("API key used to manage MFA: {}", access_token_claim.jti);
+            return Err(BackendError::ApiKeyNotAllowed);
+        }
+
+        Ok(Uuid::parse_str(&access_token_claim.sub)?)
+    }
+
+    /// Get the client IP address of the request, which failed codes are
+    /// counted against
+    fn client_ip<T>(request: &Request<T>) -> Result<IpAddr, BackendError> {
+        let socket_address = request
+            .remote_addr()
+            .ok_or(BackendError::Static("Client address not found in request."))?;
+
+        Ok(socket_address.ip())
+    }
+
+    /// Check the TOTP or Recovery Code for the user, recording the code as
+    /// used. Wrong codes count towards locking the account in the same way as
+    /// logging in, so they cannot be guessed.
+    async fn verify_code(
+        &self,
+        user_id: &Uuid,
+        code: &str,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        self.authentication
+            .check_mfa_code(user_id, code, login_ip)
+            .await
+            .map_err(|error| match error {
+                BackendError::AuthenticationError(_) => BackendError::TotpCodeInvalid,
+                error => error,
+            })
+    }
+
+    /// Check the TOTP or Recovery Code against the users enabled TOTP Secret
+    async fn verify_enabled_code(
+        &self,
+        user_id: &Uuid,
+        code: &str,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        database::TotpSecrets::from_user_id(user_id, self.database_ref())
+            .await?
+            .filter(|totp_secret| totp_secret.is_enabled)
+            .ok_or(BackendError::TotpNotEnrolled)?;
+
+        self.verify_code(user_id, code, login_ip).await
+    }
+
+    /// Check the users password, or a TOTP or Recovery Code when they have one,
+    /// before changing their passkeys, so a stolen Access Token cannot add or
+    /// remove them. Both count towards locking the account.
+    async fn verify_password_or_code(
+        &self,
+        user: &database::Users,
+        password: Option<String>,
+        code: Option<String>,
+        login_ip: IpAddr,
+    ) -> Result<(), BackendError> {
+        match (password, code) {
+            (_, Some(code)) => self.verify_code(&user.id, &code, login_ip).await,
+            (Some(password), None) => {
+                self.authentication
+                    .verify_user_password(user, password, login_ip)
+                    .await
+            }
+            (None, None) => Err(BackendError::AuthenticationError(
+                "Password or code required!".to_string(),
+            )),
+        }
+    }
+
+    /// Generate a new set of Recovery Codes for the user, replacing any unused
+    /// codes, returning the codes to be shown to the user.
+    async fn replace_recovery_codes(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<String>, BackendError> {
+        let recovery_codes = domain::RecoveryCode::generate_set();
+        database::RecoveryCodes::replace_user_id(
+            user_id,
+            &recovery_codes,
+            &self.config_ref().jwt.token_hash_key,
+            self.database_ref(),
+        )
+        .await?;
+
+        Ok(recovery_codes.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl From<database::WebAuthnCredentials> for WebAuthnCredentialResponse {
+    /// Convert from database::WebAuthnCredentials to proto::WebAuthnCredentialResponse,
+    /// never including the public key
+    fn from(value: database::WebAuthnCredentials) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            credential_id: value.credential_id,
+            last_used_on: value.last_used_on.map(|last_used_on| last_used_on.to_string()),
+            created_on: value.created_on.to_string(),
+            recovery_codes: Vec::new(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Mfa for MfaService {
+    #[tracing::instrument(name = "Begin TOTP enrolment: ", skip_all)]
+    async fn begin_totp_enrolment(
+        &self,
+        request: Request<BeginTotpEnrolmentRequest>,
+    ) -> Result<Response<BeginTotpEnrolmentResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, _request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        //-- 1. Enrolment cannot be restarted once TOTP is enabled
+        let existing = database::TotpSecrets::from_user_id(&user.id, self.database_ref())
+            .await?;
+        if existing.is_some_and(|totp_secret| totp_secret.is_enabled) {
+            return Err(BackendError::TotpAlreadyEnabled.into());
+        }
+
+        //-- 2. Store a new pending secret, replacing any previous pending secret
+        let secret = domain::TotpSecret::generate();
+        let secret_encrypted = secret.encrypt(&self.config_ref().mfa.encryption_key)?;
+        let totp_secret = database::TotpSecrets::new(&user.id, secret_encrypted)
+            .insert(self.database_ref())
+            .await?;
+        tracing::debug!("TOTP Secret added to the database: {}", totp_secret.id);
+
+        //-- 3. Return the secret for the users authenticator app
+        let otpauth_uri =
+            secret.otpauth_uri(&self.config_ref().mfa.issuer, user.email.as_ref())?;
+
+        let response_message = BeginTotpEnrolmentResponse {
+            secret: secret.to_base32(),
+            otpauth_uri,
+        };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Confirm TOTP enrolment: ", skip_all)]
+    async fn confirm_totp_enrolment(
+        &self,
+        request: Request<ConfirmTotpEnrolmentRequest>,
+    ) -> Result<Response<ConfirmTotpEnrolmentResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        //-- 1. Get the pending TOTP Secret
+        let totp_secret = database::TotpSecrets::from_user_id(&user_id, self.database_ref())
+            .await?
+            .ok_or(BackendError::TotpNotEnrolled)?;
+
+        if totp_secret.is_enabled {
+            return Err(BackendError::TotpAlreadyEnabled.into());
+        }
+
+        //-- 2. Check the code proves the authenticator app has the secret
+        let secret = domain::TotpSecret::decrypt(
+            &totp_secret.secret_encrypted,
+            &self.config_ref().mfa.encryption_key,
+        )?;
+
+        let step = secret
+            .verify(&request_message.code)
+            .ok_or(BackendError::TotpCodeInvalid)?;
+
+        //-- 3. Enable TOTP for the user
+        let totp_secret = totp_secret.enable(step, self.database_ref()).await?;
+        tracing::info!("TOTP enabled for user: {}", user_id);
+
+        //-- 4. Issue Recovery Codes in case the user loses their authenticator app
+        let recovery_codes = self.replace_recovery_codes(&user_id).await?;
+
+        let response_message = ConfirmTotpEnrolmentResponse {
+            is_enabled: totp_secret.is_enabled,
+            recovery_codes,
+        };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Disable TOTP: ", skip_all)]
+    async fn disable_totp(
+        &self,
+        request: Request<DisableTotpRequest>,
+    ) -> Result<Response<TotpStatusResponse>, Status> {
+        let login_ip = Self::client_ip(&request)?;
+
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        //-- 1. A valid code is required, so a stolen Access Token cannot disable TOTP
+        self.verify_enabled_code(&user_id, &request_message.code, login_ip).await?;
+
+        //-- 2. Remove the TOTP Secret and unused Recovery Codes
+        database::TotpSecrets::delete_by_user_id(&user_id, self.database_ref()).await?;
+        database::RecoveryCodes::delete_unused_by_user_id(&user_id, self.database_ref())
+            .await?;
+        tracing::info!("TOTP disabled for user: {}", user_id);
+
+        let response_message = TotpStatusResponse { is_enabled: false };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Regenerate Recovery Codes: ", skip_all)]
+    async fn regenerate_recovery_codes(
+        &self,
+        request: Request<RegenerateRecoveryCodesRequest>,
+    ) -> Result<Response<RecoveryCodesResponse>, Status> {
+        let login_ip = Self::client_ip(&request)?;
+
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        //-- 1. A valid code is required, so a stolen Access Token cannot read new codes.
+        // Users with only passkeys use one of their Recovery Codes.
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+        if !self.authentication.is_mfa_required(&user).await? {
+            return Err(BackendError::TotpNotEnrolled.into());
+        }
+        self.verify_code(&user_id, &request_message.code, login_ip).await?;
+
+        //-- 2. Replace the unused Recovery Codes
+        let recovery_codes = self.replace_recovery_codes(&user_id).await?;
+        tracing::info!("Recovery Codes regenerated for user: {}", user_id);
+
+        let response_message = RecoveryCodesResponse { recovery_codes };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Count remaining Recovery Codes: ", skip_all)]
+    async fn recovery_codes_remaining(
+        &self,
+        request: Request<RecoveryCodesRemainingRequest>,
+    ) -> Result<Response<RecoveryCodesRemainingResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, _request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        //-- 1. Count the unused Recovery Codes
+        let remaining =
+            database::RecoveryCodes::count_unused_by_user_id(&user_id, self.database_ref())
+                .await?;
+
+        let response_message = RecoveryCodesRemainingResponse { remaining };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Begin WebAuthn registration: ", skip_all)]
+    async fn begin_web_authn_registration(
+        &self,
+        request: Request<BeginWebAuthnRegistrationRequest>,
+    ) -> Result<Response<BeginWebAuthnRegistrationResponse>, Status> {
+        let login_ip = Self::client_ip(&request)?;
+
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        //-- 1. The password or a code is required, so a stolen Access Token cannot add a passkey
+        self.verify_password_or_code(
+            &user,
+            request_message.password,
+            request_message.code,
+            login_ip,
+        )
+        .await?;
+
+        //-- 2. Store a new challenge bound to the user
+        let challenge = domain::OneTimeToken::generate();
+        let webauthn_challenge = database::WebAuthnChallenges::new(
+            Some(&user.id),
+            webauthn::Ceremony::Registration,
+            &challenge,
+            &self.config_ref().jwt.token_hash_key,
+        )
+        .insert(self.database_ref())
+        .await?;
+        tracing::debug!("WebAuthn Challenge added to the database: {}", webauthn_challenge.id);
+
+        //-- 3. Exclude credentials the user has already registered
+        let exclude_credential_ids =
+            database::WebAuthnCredentials::index_by_user_id(&user.id, self.database_ref())
+                .await?
+                .into_iter()
+                .map(|credential| credential.credential_id)
+                .collect();
+
+        let webauthn_config = &self.config_ref().webauthn;
+        let response_message = BeginWebAuthnRegistrationResponse {
+            challenge: challenge.as_ref().as_bytes().to_vec(),
+            rp_id: webauthn_config.rp_id.to_owned(),
+            rp_name: webauthn_config.rp_name.to_owned(),
+            user_handle: user.id.as_bytes().to_vec(),
+            user_name: user.email.to_string(),
+            exclude_credential_ids,
+        };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Finish WebAuthn registration: ", skip_all)]
+    async fn finish_web_authn_registration(
+        &self,
+        request: Request<FinishWebAuthnRegistrationRequest>,
+    ) -> Result<Response<WebAuthnCredentialResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+        let relying_party = webauthn::RelyingParty::new(&self.config_ref().webauthn);
+
+        //-- 1. The client data must be for our origin and a challenge we issued the user
+        let client_data = relying_party
+            .client_data(&request_message.client_data_json, webauthn::Ceremony::Registration)?;
+
+        let webauthn_challenge = database::WebAuthnChallenges::redeem(
+            &client_data.challenge,
+            &self.config_ref().jwt.token_hash_key,
+            webauthn::Ceremony::Registration,
+            self.database_ref(),
+        )
+        .await
+        .map_err(|_| BackendError::WebAuthn("challenge is not valid".to_string()))?;
+
+        if webauthn_challenge.user_id != Some(user_id) {
+            tracing::error!("WebAuthn Challenge was issued to another user: {}", user_id);
+            return Err(BackendError::WebAuthn("challenge is not valid".to_string()).into());
+        }
+
+        //-- 2. Verify the attestation object and extract the new credential
+        let (credential, sign_count) =
+            relying_party.verify_registration(&request_message.attestation_object)?;
+
+        //-- 3. Store the credential for the user
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+        let is_first_factor = !self.authentication.is_mfa_required(&user).await?;
+
+        let name = match request_message.name.trim() {
+            "" => "Passkey",
+            name => name,
+        };
+        let webauthn_credential =
+            database::WebAuthnCredentials::new(&user_id, name, &credential, sign_count)
+                .insert(self.database_ref())
+                .await?;
+        tracing::info!(
+            "WebAuthn Credential {} registered for user: {}",
+            webauthn_credential.id,
+            user_id
+        );
+
+        //-- 4. Issue Recovery Codes with the first second factor, so users with only
+        // passkeys can still log in if they lose them
+        let mut response_message = WebAuthnCredentialResponse::from(webauthn_credential);
+        if is_first_factor {
+            response_message.recovery_codes = self.replace_recovery_codes(&user_id).await?;
+        }
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "List WebAuthn credentials: ", skip_all)]
+    async fn list_web_authn_credentials(
+        &self,
+        request: Request<ListWebAuthnCredentialsRequest>,
+    ) -> Result<Response<WebAuthnCredentialsResponse>, Status> {
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, _request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+
+        //-- 1. Get the users credentials
+        let credentials =
+            database::WebAuthnCredentials::index_by_user_id(&user_id, self.database_ref())
+                .await?
+                .into_iter()
+                .map(WebAuthnCredentialResponse::from)
+                .collect();
+
+        let response_message = WebAuthnCredentialsResponse { credentials };
+
+        Ok(Response::new(response_message))
+    }
+
+    #[tracing::instrument(name = "Delete WebAuthn credential: ", skip_all)]
+    async fn delete_web_authn_credential(
+        &self,
+        request: Request<DeleteWebAuthnCredentialRequest>,
+    ) -> Result<Response<DeleteWebAuthnCredentialResponse>, Status> {
+        let login_ip = Self::client_ip(&request)?;
+
+        //-- 0. Break the request up into its parts
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        let user_id = Self::requester_id(&request_extensions)?;
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+        let id = Uuid::parse_str(&request_message.id)
+            .map_err(|_| BackendError::WebAuthn("credential id is not valid".to_string()))?;
+
+        //-- 1. The password or a code is required, so a stolen Access Token cannot remove
+        // a passkey
+        self.verify_password_or_code(
+            &user,
+            request_message.password,
+            request_message.code,
+            login_ip,
+        )
+        .await?;
+
+        //-- 2. Remove the credential
+        let rows_affected = database::WebAuthnCredentials::delete_by_id_and_user_id(
+            &id,
+            &user_id,
+            self.database_ref(),
+        )
+        .await?;
+
+        //-- 3. Remove the unused Recovery Codes once the user has no second factor left
+        if rows_affected > 0 {
+            tracing::info!("WebAuthn Credential {} deleted for user: {}", id, user_id);
+
+            if !self.authentication.is_mfa_required(&user).await? {
+                database::RecoveryCodes::delete_unused_by_user_id(&user_id, self.database_ref())
+                    .await?;
+            }
+        }
+
+        let response_message = DeleteWebAuthnCredentialResponse {
+            rows_affected: rows_affected as i64,
+        };
+
+        Ok(Response::new(response_message))
+    }
+}
@@ -3,16 +3,33 @@
 #![allow(unused)] // For beginning only.
 
 // Flatten module exports
-pub use authentication::AuthenticationService;
+pub use api_keys::ApiKeysService;
+pub use authentication::{AuthenticationService, OrganisationContext};
+pub(crate) use authentication::TokenSubject;
 pub use logins::LoginsService;
+pub use me::MeService;
+pub use mfa::MfaService;
+pub use oidc_clients::OidcClientsService;
+pub use organisations::OrganisationsService;
 pub use reflections::ReflectionsService;
+pub use roles::RolesService;
+pub use service_accounts::ServiceAccountsService;
 pub use sessions::SessionsService;
+pub use signing_keys::SigningKeysService;
 pub use users::UsersService;
 pub use utilities::UtilitiesService;
 
+mod api_keys;
 mod authentication;
 mod logins;
+mod me;
+mod mfa;
+mod oidc_clients;
+mod organisations;
 mod reflections;
+mod roles;
+mod service_accounts;
 mod sessions;
+mod signing_keys;
 mod users;
 mod utilities;
@@ -0,0 +1,154 @@
+//-- ./src/rpc/oidc_clients.rs
+
+//! RPC service for OIDC Clients endpoint
+//!
+//! Registers the OpenID Connect clients allowed to sign users in through the
+//! OIDC provider endpoints.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::rpc::proto::oidc_clients_server::OidcClients;
+use crate::rpc::proto::{
+    OidcClientsCreateRequest, OidcClientsCreateResponse, OidcClientsDeleteRequest,
+    OidcClientsDeleteResponse, OidcClientsIndexRequest, OidcClientsIndexResponse,
+    OidcClientsResponse,
+};
+use crate::{database, domain, prelude::*};
+
+/// OIDC Clients service containing a database pool
+pub struct OidcClientsService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+}
+
+impl OidcClientsService {
+    /// Create a new OidcClientsService passing in the Arc for the Sqlx database
+    /// pool and configuration
+    pub fn new(database: Arc<Pool<Postgres>>, config: Arc<Configuration>) -> Self {
+        Self { database, config }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+}
+
+impl From<database::OidcClients> for OidcClientsResponse {
+    /// Convert from database::OidcClients to proto::OidcClientsResponse, never
+    /// including the client secret hash
+    fn from(value: database::OidcClients) -> Self {
+        Self {
+            id: value.id.to_string(),
+            is_confidential: value.is_confidential(),
+            name: value.name,
+            redirect_uris: value.redirect_uris,
+            is_active: value.is_active,
+            created_on: value.created_on.to_string(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl OidcClients for OidcClientsService {
+    /// Handle rpc requests to register an OIDC Client, returning the client
+    /// secret of confidential clients this one time
+    #[tracing::instrument(name = "Create OIDC Client Request: ", skip_all)]
+    async fn create(
+        &self,
+        request: Request<OidcClientsCreateRequest>,
+    ) -> Result<Response<OidcClientsCreateResponse>, Status> {
+        let request_message = request.into_inner();
+
+        if request_message.name.trim().is_empty() {
+            return Err(Status::invalid_argument("Client name must not be empty"));
+        }
+
+        // Redirect URIs must be absolute URLs, as authorization responses are
+        // appended to them as query parameters
+        if request_message.redirect_uris.is_empty() {
+            return Err(Status::invalid_argument(
+                "At least one redirect URI is required",
+            ));
+        }
+        for redirect_uri in &request_message.redirect_uris {
+            match url::Url::parse(redirect_uri) {
+                Ok(url) if url.fragment().is_none() => {}
+                _ => {
+                    return Err(Status::invalid_argument(format!(
+                        "Redirect URI is not a valid URL: {redirect_uri}"
+                    )))
+                }
+            }
+        }
+
+        let client_secret = request_message
+            .is_confidential
+            .then(domain::OneTimeToken::generate);
+
+        let oidc_client = database::OidcClients::new(
+            request_message.name.trim(),
+            request_message.redirect_uris,
+            client_secret.as_ref(),
+            &self.config.jwt.token_hash_key,
+        );
+        let database_record = oidc_client.insert(self.database_ref()).await?;
+        tracing::info!("OIDC Client registered: {}", database_record.id);
+
+        Ok(Response::new(OidcClientsCreateResponse {
+            client: Some(database_record.into()),
+            client_secret: client_secret.map(|client_secret| client_secret.to_string()),
+        }))
+    }
+
+    /// Handle rpc requests to get an index of the OIDC Clients
+    #[tracing::instrument(name = "Index OIDC Clients Request: ", skip_all)]
+    async fn index(
+        &self,
+        request: Request<OidcClientsIndexRequest>,
+    ) -> Result<Response<OidcClientsIndexResponse>, Status> {
+        let request_message = request.into_inner();
+
+        let database_records = database::OidcClients::index(
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let clients = database_records
+            .into_iter()
+            .map(|oidc_client| oidc_client.into())
+            .collect();
+
+        Ok(Response::new(OidcClientsIndexResponse { clients }))
+    }
+
+    /// Handle rpc requests to delete an OIDC Client, along with any
+    /// authorization codes issued to it
+    #[tracing::instrument(name = "Delete OIDC Client Request: ", skip_all)]
+    async fn delete(
+        &self,
+        request: Request<OidcClientsDeleteRequest>,
+    ) -> Result<Response<OidcClientsDeleteResponse>, Status> {
+        let request_message = request.into_inner();
+
+        let id = Uuid::parse_str(&request_message.id).map_err(|_| {
+            tracing::error!("Unable to parse OIDC Client id to UUID!");
+            BackendError::Generic("Unable to parse OIDC Client id to UUID!".to_string())
+        })?;
+
+        let rows_affected =
+            database::OidcClients::delete_by_id(&id, self.database_ref()).await? as i64;
+
+        Ok(Response::new(OidcClientsDeleteResponse { rows_affected }))
+    }
+}
@@ -0,0 +1,345 @@
+//-- ./src/services/organisations.rs
+
+//! RPC service for Organisations endpoint
+//!
+//! Users create organisations, such as a household, and invite other users to
+//! share its records. Access to an organisation comes from membership rather
+//! than permissions, with owners managing its members.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::email::EmailTemplate;
+use crate::prelude::*;
+use crate::rpc::proto::organisations_server::Organisations;
+use crate::rpc::proto::{
+    Empty, OrganisationMemberResponse, OrganisationResponse,
+    OrganisationsAcceptInvitationRequest, OrganisationsCreateRequest, OrganisationsIndexResponse,
+    OrganisationsInviteRequest, OrganisationsInviteResponse, OrganisationsMembersRequest,
+    OrganisationsMembersResponse, OrganisationsRemoveMemberRequest,
+    OrganisationsRemoveMemberResponse,
+};
+use crate::services::AuthenticationService;
+use crate::{database, domain};
+
+/// Organisations service containing a database pool and the Authentication
+/// service used to send emails
+pub struct OrganisationsService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+    authentication: Arc<AuthenticationService>,
+}
+
+impl OrganisationsService {
+    /// Create a new OrganisationsService passing in the Arc for the Sqlx
+    /// database pool, configuration and Authentication service
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        authentication: Arc<AuthenticationService>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            authentication,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Get the membership of the requesting user in the organisation, which
+    /// must exist for the user to take any action on it
+    async fn requester_membership(
+        &self,
+        organisation_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<database::OrganisationMembers, BackendError> {
+        database::OrganisationMembers::from_ids(organisation_id, user_id, self.database_ref())
+            .await?
+            .ok_or_else(|| {
+                tracing::error!("User {user_id} is not a member of: {organisation_id}");
+                BackendError::NotOrganisationMember
+            })
+    }
+
+    /// Build the response for an organisation and the membership of the
+    /// requesting user
+    fn organisation_response(
+        organisation: database::Organisations,
+        membership: &database::OrganisationMembers,
+        access_token_claim: &domain::TokenClaim,
+    ) -> OrganisationResponse {
+        OrganisationResponse {
+            is_active: access_token_claim.organisation_id() == Some(organisation.id),
+            id: organisation.id.to_string(),
+            name: organisation.name,
+            role: membership.role.to_string(),
+            created_on: organisation.created_on.to_string(),
+        }
+    }
+}
+
+/// Get the requesting user id from the Token Claim added to the request
+/// extensions by the Access Token interceptor. API keys cannot be used to
+/// manage organisations, so a leaked key cannot invite or remove members.
+fn requester_id(access_token_claim: &domain::TokenClaim) -> Result<Uuid, BackendError> {
+    if access_token_claim.is_api_key() {
+        tracing::error!("API key used to manage organisations: {}", access_token_claim.jti);
+        return Err(BackendError::ApiKeyNotAllowed);
+    }
+
+    Ok(Uuid::parse_str(&access_token_claim.sub)?)
+}
+
+/// Parse an organisation or user id from a request message
+fn parse_id(id: &str) -> Result<Uuid, BackendError> {
+    Uuid::parse_str(id).map_err(|_| {
+        tracing::error!("Unable to parse id to UUID!");
+        BackendError::OrganisationInvalid("id is not a UUID".to_string())
+    })
+}
+
+/// Check an organisation name from a request message is not empty
+fn parse_name(name: &str) -> Result<&str, BackendError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(BackendError::OrganisationInvalid("name must not be empty".to_string()));
+    }
+
+    Ok(name)
+}
+
+#[tonic::async_trait]
+impl Organisations for OrganisationsService {
+    /// Handle rpc requests to create an organisation, owned by the requesting
+    /// user
+    #[tracing::instrument(name = "Create Organisation Request: ", skip_all)]
+    async fn create(
+        &self,
+        request: Request<OrganisationsCreateRequest>,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let name = parse_name(&request_message.name)?;
+
+        let (organisation, membership) = database::Organisations::new(name)
+            .insert_with_owner(&user_id, self.database_ref())
+            .await?;
+        tracing::info!("Organisation created: {}", organisation.id);
+
+        Ok(Response::new(Self::organisation_response(
+            organisation,
+            &membership,
+            access_token_claim,
+        )))
+    }
+
+    /// Handle rpc requests to get an index of the organisations the requesting
+    /// user is a member of
+    #[tracing::instrument(name = "Index Organisations Request: ", skip_all)]
+    async fn index(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<OrganisationsIndexResponse>, Status> {
+        let (_request_metadata, request_extensions, _request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let memberships: HashMap<Uuid, database::OrganisationMembers> =
+            database::OrganisationMembers::index_user_id(&user_id, self.database_ref())
+                .await?
+                .into_iter()
+                .map(|membership| (membership.organisation_id, membership))
+                .collect();
+        let database_records =
+            database::Organisations::index_user_id(&user_id, self.database_ref()).await?;
+
+        let organisations = database_records
+            .into_iter()
+            .filter_map(|organisation| {
+                let membership = memberships.get(&organisation.id)?;
+                Some(Self::organisation_response(
+                    organisation,
+                    membership,
+                    access_token_claim,
+                ))
+            })
+            .collect();
+
+        Ok(Response::new(OrganisationsIndexResponse { organisations }))
+    }
+
+    /// Handle rpc requests to list the members of an organisation the
+    /// requesting user is a member of
+    #[tracing::instrument(name = "Index Organisation Members Request: ", skip_all)]
+    async fn members(
+        &self,
+        request: Request<OrganisationsMembersRequest>,
+    ) -> Result<Response<OrganisationsMembersResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let organisation_id = parse_id(&request_message.organisation_id)?;
+        self.requester_membership(&organisation_id, &user_id).await?;
+
+        let database_records = database::OrganisationMembers::index_organisation_id_with_users(
+            &organisation_id,
+            self.database_ref(),
+        )
+        .await?;
+
+        let members = database_records
+            .into_iter()
+            .map(|(member, user)| OrganisationMemberResponse {
+                user_id: user.id.to_string(),
+                name: user.name.to_string(),
+                email: user.email.to_string(),
+                role: member.role.to_string(),
+                created_on: member.created_on.to_string(),
+            })
+            .collect();
+
+        Ok(Response::new(OrganisationsMembersResponse { members }))
+    }
+
+    /// Handle rpc requests to invite a user to an organisation by email,
+    /// which only owners can do
+    #[tracing::instrument(name = "Invite Organisation Member Request: ", skip_all)]
+    async fn invite(
+        &self,
+        request: Request<OrganisationsInviteRequest>,
+    ) -> Result<Response<OrganisationsInviteResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let organisation_id = parse_id(&request_message.organisation_id)?;
+        let email = domain::EmailAddress::parse(request_message.email)?;
+        let role = domain::OrganisationRole::parse(request_message.role.trim())?;
+
+        let requester = self.requester_membership(&organisation_id, &user_id).await?;
+        if !requester.role.can_manage_members() {
+            tracing::error!("User {user_id} cannot invite members to: {organisation_id}");
+            return Err(BackendError::PermissionDenied("organisation owner required".to_string()).into());
+        }
+
+        let organisation =
+            database::Organisations::from_id(&organisation_id, self.database_ref()).await?;
+
+        let token = domain::OneTimeToken::generate();
+        let invitation = database::OrganisationInvitations::new(
+            &organisation_id,
+            &email,
+            role,
+            &token,
+            &self.config.jwt.token_hash_key,
+        )
+        .insert(self.database_ref())
+        .await?;
+        tracing::info!("Organisation invitation created: {}", invitation.id);
+
+        let template = EmailTemplate::OrganisationInvitation {
+            token,
+            organisation: organisation.name,
+            role,
+            expires_in_days: database::ORGANISATION_INVITATION_DURATION / 60 / 60 / 24,
+        };
+        self.authentication.send_email(template.message(&email));
+
+        Ok(Response::new(OrganisationsInviteResponse {
+            id: invitation.id.to_string(),
+            expires_on: invitation.expires_on.to_string(),
+        }))
+    }
+
+    /// Handle rpc requests to accept an invitation sent to the requesting
+    /// users email address, joining the organisation
+    #[tracing::instrument(name = "Accept Organisation Invitation Request: ", skip_all)]
+    async fn accept_invitation(
+        &self,
+        request: Request<OrganisationsAcceptInvitationRequest>,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let user = database::Users::from_user_id(&user_id, self.database_ref()).await?;
+
+        let token = domain::OneTimeToken::from(request_message.token);
+        let invitation = database::OrganisationInvitations::redeem(
+            &token,
+            &self.config.jwt.token_hash_key,
+            &user.email,
+            self.database_ref(),
+        )
+        .await
+        .map_err(|_| {
+            tracing::error!("Organisation invitation token is invalid!");
+            BackendError::AuthenticationError("Authentication Failed!".to_string())
+        })?;
+
+        // Users that are already members keep the role they hold
+        let membership =
+            database::OrganisationMembers::new(&invitation.organisation_id, &user.id, invitation.role)
+                .insert(self.database_ref())
+                .await?;
+        tracing::info!("User {} joined organisation: {}", user.id, membership.organisation_id);
+
+        let organisation =
+            database::Organisations::from_id(&membership.organisation_id, self.database_ref())
+                .await?;
+
+        Ok(Response::new(Self::organisation_response(
+            organisation,
+            &membership,
+            access_token_claim,
+        )))
+    }
+
+    /// Handle rpc requests to remove a member from an organisation. Owners can
+    /// remove any member, while other members can only remove themselves.
+    #[tracing::instrument(name = "Remove Organisation Member Request: ", skip_all)]
+    async fn remove_member(
+        &self,
+        request: Request<OrganisationsRemoveMemberRequest>,
+    ) -> Result<Response<OrganisationsRemoveMemberResponse>, Status> {
+        let (_request_metadata, request_extensions, request_message) = request.into_parts();
+        let access_token_claim = domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        let user_id = requester_id(access_token_claim)?;
+
+        let organisation_id = parse_id(&request_message.organisation_id)?;
+        let member_id = parse_id(&request_message.user_id)?;
+
+        let requester = self.requester_membership(&organisation_id, &user_id).await?;
+        if member_id != user_id && !requester.role.can_manage_members() {
+            tracing::error!("User {user_id} cannot remove members from: {organisation_id}");
+            return Err(BackendError::PermissionDenied("organisation owner required".to_string()).into());
+        }
+
+        // The last owner is refused once the owner rows are locked
+        let rows_affected = database::OrganisationMembers::delete(
+            &organisation_id,
+            &member_id,
+            self.database_ref(),
+        )
+        .await? as i64;
+
+        tracing::info!("User {member_id} removed from organisation: {organisation_id}");
+
+        Ok(Response::new(OrganisationsRemoveMemberResponse { rows_affected }))
+    }
+}
@@ -5,12 +5,11 @@ use crate::rpc::proto::FILE_DESCRIPTOR_SET as REFLECTIONS_DESCRIPTOR_SET;
 pub struct ReflectionsService {}
 
 impl ReflectionsService {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> ServerReflectionServer<impl ServerReflection> {
-        let service = tonic_reflection::server::Builder::configure()
+        tonic_reflection::server::Builder::configure()
             .register_encoded_file_descriptor_set(REFLECTIONS_DESCRIPTOR_SET)
             .build()
-            .expect("ERROR: Building gRPC reflection service");
-
-        service
+            .expect("ERROR: Building gRPC reflection service")
     }
 }
\ No newline at end of file
@@ -0,0 +1,287 @@
+//-- ./src/services/roles.rs
+
+//! RPC service for Roles endpoint
+//!
+//! Manages the roles that grant permissions to users, and assigns them to
+//! users on top of the system role of their user role.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::rpc::proto::roles_server::Roles;
+use crate::rpc::proto::{
+    RolesAssignRequest, RolesCreateRequest, RolesDeleteRequest, RolesDeleteResponse,
+    RolesIndexRequest, RolesIndexResponse, RolesReadRequest, RolesResponse, RolesUpdateRequest,
+    RolesUserRequest, RolesUserResponse,
+};
+use crate::services::ApiKeysService;
+use crate::{database, domain, prelude::*};
+
+/// Roles service containing a database pool and the shared API key list
+pub struct RolesService {
+    database: Arc<Pool<Postgres>>,
+    api_key_list: Arc<domain::ApiKeyList>,
+}
+
+impl RolesService {
+    /// Create a new RolesService passing in the Arc for the Sqlx database pool
+    /// and API key list
+    pub fn new(database: Arc<Pool<Postgres>>, api_key_list: Arc<domain::ApiKeyList>) -> Self {
+        Self {
+            database,
+            api_key_list,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Reload every API key in the API key list, after the permissions of a
+    /// role held by any number of users change
+    async fn reload_api_keys(&self) -> Result<(), BackendError> {
+        let api_keys = database::ApiKeys::index_usable(None, self.database_ref()).await?;
+        self.api_key_list.replace(api_keys);
+
+        Ok(())
+    }
+
+    /// Build the response listing the roles and permissions a user holds
+    async fn user_response(&self, user_id: &Uuid) -> Result<RolesUserResponse, BackendError> {
+        let user = database::Users::from_user_id(user_id, self.database_ref()).await?;
+        let roles = database::Roles::index_user_id(user_id, self.database_ref()).await?;
+        let permissions =
+            database::Roles::permissions_for_user(user_id, self.database_ref()).await?;
+
+        Ok(RolesUserResponse {
+            user_id: user.id.to_string(),
+            user_role: user.role.to_string(),
+            roles: roles.into_iter().map(|role| role.into()).collect(),
+            permissions: permissions
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+        })
+    }
+}
+
+/// Parse a role or user id from a request message
+fn parse_id(id: &str) -> Result<Uuid, BackendError> {
+    Uuid::parse_str(id).map_err(|_| {
+        tracing::error!("Unable to parse id to UUID!");
+        BackendError::Generic("Unable to parse id to UUID!".to_string())
+    })
+}
+
+/// Check a role name from a request message is not empty
+fn parse_name(name: &str) -> Result<&str, BackendError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(BackendError::RoleInvalid("name must not be empty".to_string()));
+    }
+
+    Ok(name)
+}
+
+/// Parse the permissions from a request message
+fn parse_permissions(permissions: &[String]) -> Result<Vec<domain::Permission>, BackendError> {
+    permissions
+        .iter()
+        .map(|permission| domain::Permission::parse(permission.trim()))
+        .collect()
+}
+
+impl From<database::Roles> for RolesResponse {
+    /// Convert from database::Roles to proto::RolesResponse
+    fn from(value: database::Roles) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            description: value.description,
+            permissions: value.permissions,
+            is_system: value.is_system,
+            created_on: value.created_on.to_string(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Roles for RolesService {
+    /// Handle rpc requests to create a custom role
+    #[tracing::instrument(name = "Create Role Request: ", skip_all)]
+    async fn create(
+        &self,
+        request: Request<RolesCreateRequest>,
+    ) -> Result<Response<RolesResponse>, Status> {
+        let request_message = request.into_inner();
+
+        let name = parse_name(&request_message.name)?;
+        let permissions = parse_permissions(&request_message.permissions)?;
+
+        let role = database::Roles::new(name, request_message.description.trim(), &permissions);
+        let database_record = role.insert(self.database_ref()).await?;
+        tracing::info!("Role created: {}", database_record.id);
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to read a role
+    #[tracing::instrument(name = "Read Role Request: ", skip_all)]
+    async fn read(
+        &self,
+        request: Request<RolesReadRequest>,
+    ) -> Result<Response<RolesResponse>, Status> {
+        let request_message = request.into_inner();
+        let id = parse_id(&request_message.id)?;
+
+        let database_record = database::Roles::from_id(&id, self.database_ref()).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to get an index of the roles
+    #[tracing::instrument(name = "Index Roles Request: ", skip_all)]
+    async fn index(
+        &self,
+        request: Request<RolesIndexRequest>,
+    ) -> Result<Response<RolesIndexResponse>, Status> {
+        let request_message = request.into_inner();
+
+        let database_records = database::Roles::index(
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let roles = database_records
+            .into_iter()
+            .map(|role| role.into())
+            .collect();
+
+        Ok(Response::new(RolesIndexResponse { roles }))
+    }
+
+    /// Handle rpc requests to update the name, description and permissions of
+    /// a role
+    #[tracing::instrument(name = "Update Role Request: ", skip_all)]
+    async fn update(
+        &self,
+        request: Request<RolesUpdateRequest>,
+    ) -> Result<Response<RolesResponse>, Status> {
+        let request_message = request.into_inner();
+        let id = parse_id(&request_message.id)?;
+        let name = parse_name(&request_message.name)?;
+        let permissions = parse_permissions(&request_message.permissions)?;
+
+        let mut role = database::Roles::from_id(&id, self.database_ref()).await?;
+
+        // System roles are held through the user role of the same name
+        if role.is_system && role.name != name {
+            return Err(BackendError::SystemRole("renamed").into());
+        }
+
+        role.name = name.to_owned();
+        role.description = request_message.description.trim().to_owned();
+        role.permissions = permissions
+            .iter()
+            .map(|permission| permission.to_string())
+            .collect();
+        let database_record = role.update(self.database_ref()).await?;
+        tracing::info!("Role updated: {}", database_record.id);
+
+        self.reload_api_keys().await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+
+    /// Handle rpc requests to delete a custom role, unassigning it from every
+    /// user
+    #[tracing::instrument(name = "Delete Role Request: ", skip_all)]
+    async fn delete(
+        &self,
+        request: Request<RolesDeleteRequest>,
+    ) -> Result<Response<RolesDeleteResponse>, Status> {
+        let request_message = request.into_inner();
+        let id = parse_id(&request_message.id)?;
+
+        let role = database::Roles::from_id(&id, self.database_ref()).await?;
+        if role.is_system {
+            return Err(BackendError::SystemRole("deleted").into());
+        }
+
+        let rows_affected = database::Roles::delete_by_id(&id, self.database_ref()).await?;
+        tracing::info!("Role deleted: {id}");
+
+        self.reload_api_keys().await?;
+
+        Ok(Response::new(RolesDeleteResponse {
+            rows_affected: rows_affected as i64,
+        }))
+    }
+
+    /// Handle rpc requests to assign a role to a user
+    #[tracing::instrument(name = "Assign Role Request: ", skip_all)]
+    async fn assign(
+        &self,
+        request: Request<RolesAssignRequest>,
+    ) -> Result<Response<RolesUserResponse>, Status> {
+        let request_message = request.into_inner();
+        let user_id = parse_id(&request_message.user_id)?;
+        let role_id = parse_id(&request_message.role_id)?;
+
+        database::UserRoles::new(&user_id, &role_id)
+            .insert(self.database_ref())
+            .await?;
+        tracing::info!("Role {role_id} assigned to user: {user_id}");
+
+        ApiKeysService::reload_user_api_keys(&user_id, &self.api_key_list, self.database_ref())
+            .await?;
+
+        Ok(Response::new(self.user_response(&user_id).await?))
+    }
+
+    /// Handle rpc requests to unassign a role from a user
+    #[tracing::instrument(name = "Unassign Role Request: ", skip_all)]
+    async fn unassign(
+        &self,
+        request: Request<RolesAssignRequest>,
+    ) -> Result<Response<RolesUserResponse>, Status> {
+        let request_message = request.into_inner();
+        let user_id = parse_id(&request_message.user_id)?;
+        let role_id = parse_id(&request_message.role_id)?;
+
+        let rows_affected =
+            database::UserRoles::delete(&user_id, &role_id, self.database_ref()).await?;
+        if rows_affected > 0 {
+            tracing::info!("Role {role_id} unassigned from user: {user_id}");
+            ApiKeysService::reload_user_api_keys(
+                &user_id,
+                &self.api_key_list,
+                self.database_ref(),
+            )
+            .await?;
+        }
+
+        Ok(Response::new(self.user_response(&user_id).await?))
+    }
+
+    /// Handle rpc requests to list the roles and permissions a user holds
+    #[tracing::instrument(name = "User Roles Request: ", skip_all)]
+    async fn user(
+        &self,
+        request: Request<RolesUserRequest>,
+    ) -> Result<Response<RolesUserResponse>, Status> {
+        let request_message = request.into_inner();
+        let user_id = parse_id(&request_message.user_id)?;
+
+        Ok(Response::new(self.user_response(&user_id).await?))
+    }
+}
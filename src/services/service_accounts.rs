@@ -0,0 +1,190 @@
+//-- ./src/rpc/service_accounts.rs
+
+//! RPC service for Service Accounts endpoint
+//!
+//! Registers the service accounts, such as background jobs, that are issued
+//! Access Tokens with the client credentials grant instead of logging in as a
+//! user.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::rpc::proto::service_accounts_server::ServiceAccounts;
+use crate::rpc::proto::{
+    ServiceAccountsCreateRequest, ServiceAccountsDisableRequest, ServiceAccountsIndexRequest,
+    ServiceAccountsIndexResponse, ServiceAccountsResponse, ServiceAccountsRotateRequest,
+    ServiceAccountsSecretResponse,
+};
+use crate::{database, domain, prelude::*};
+
+/// Service Accounts service containing a database pool
+pub struct ServiceAccountsService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+    /// Revoked Access Tokens, so disabling a service account revokes its tokens
+    revocation_list: Arc<domain::RevocationList>,
+}
+
+impl ServiceAccountsService {
+    /// Create a new ServiceAccountsService passing in the Arc for the Sqlx
+    /// database pool, configuration and the revocation list
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        revocation_list: Arc<domain::RevocationList>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            revocation_list,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+}
+
+/// Parse a service account id from a request message
+fn parse_id(id: &str) -> Result<Uuid, BackendError> {
+    Uuid::parse_str(id).map_err(|_| {
+        tracing::error!("Unable to parse service account id to UUID!");
+        BackendError::Generic("Unable to parse service account id to UUID!".to_string())
+    })
+}
+
+impl From<database::ServiceAccounts> for ServiceAccountsResponse {
+    /// Convert from database::ServiceAccounts to proto::ServiceAccountsResponse,
+    /// never including the client secret hash
+    fn from(value: database::ServiceAccounts) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            scopes: value.scopes,
+            is_active: value.is_active,
+            secret_rotated_on: value
+                .secret_rotated_on
+                .map(|secret_rotated_on| secret_rotated_on.to_string()),
+            created_on: value.created_on.to_string(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ServiceAccounts for ServiceAccountsService {
+    /// Handle rpc requests to create a service account, returning its client
+    /// secret this one time
+    #[tracing::instrument(name = "Create Service Account Request: ", skip_all)]
+    async fn create(
+        &self,
+        request: Request<ServiceAccountsCreateRequest>,
+    ) -> Result<Response<ServiceAccountsSecretResponse>, Status> {
+        let request_message = request.into_inner();
+
+        if request_message.name.trim().is_empty() {
+            return Err(Status::invalid_argument(
+                "Service account name must not be empty",
+            ));
+        }
+
+        let scopes = domain::ServiceScope::parse_list(&request_message.scopes)?;
+        if scopes.is_empty() {
+            return Err(Status::invalid_argument("At least one scope is required"));
+        }
+
+        let client_secret = domain::OneTimeToken::generate();
+        let service_account = database::ServiceAccounts::new(
+            request_message.name.trim(),
+            &scopes,
+            &client_secret,
+            &self.config.jwt.token_hash_key,
+        );
+        let database_record = service_account.insert(self.database_ref()).await?;
+        tracing::info!("Service account created: {}", database_record.id);
+
+        Ok(Response::new(ServiceAccountsSecretResponse {
+            service_account: Some(database_record.into()),
+            client_secret: client_secret.to_string(),
+        }))
+    }
+
+    /// Handle rpc requests to get an index of the service accounts
+    #[tracing::instrument(name = "Index Service Accounts Request: ", skip_all)]
+    async fn index(
+        &self,
+        request: Request<ServiceAccountsIndexRequest>,
+    ) -> Result<Response<ServiceAccountsIndexResponse>, Status> {
+        let request_message = request.into_inner();
+
+        let database_records = database::ServiceAccounts::index(
+            &request_message.limit,
+            &request_message.offset,
+            self.database_ref(),
+        )
+        .await?;
+
+        let service_accounts = database_records
+            .into_iter()
+            .map(|service_account| service_account.into())
+            .collect();
+
+        Ok(Response::new(ServiceAccountsIndexResponse { service_accounts }))
+    }
+
+    /// Handle rpc requests to replace the client secret of a service account,
+    /// returning the new client secret this one time
+    #[tracing::instrument(name = "Rotate Service Account Secret Request: ", skip_all)]
+    async fn rotate(
+        &self,
+        request: Request<ServiceAccountsRotateRequest>,
+    ) -> Result<Response<ServiceAccountsSecretResponse>, Status> {
+        let request_message = request.into_inner();
+        let id = parse_id(&request_message.id)?;
+
+        let client_secret = domain::OneTimeToken::generate();
+        let database_record = database::ServiceAccounts::rotate_secret(
+            &id,
+            &client_secret,
+            &self.config.jwt.token_hash_key,
+            self.database_ref(),
+        )
+        .await?;
+        tracing::info!("Service account secret rotated: {}", database_record.id);
+
+        Ok(Response::new(ServiceAccountsSecretResponse {
+            service_account: Some(database_record.into()),
+            client_secret: client_secret.to_string(),
+        }))
+    }
+
+    /// Handle rpc requests to disable a service account, so it can no longer
+    /// be issued Access Tokens, revoking the Access Tokens it has been issued
+    #[tracing::instrument(name = "Disable Service Account Request: ", skip_all)]
+    async fn disable(
+        &self,
+        request: Request<ServiceAccountsDisableRequest>,
+    ) -> Result<Response<ServiceAccountsResponse>, Status> {
+        let request_message = request.into_inner();
+        let id = parse_id(&request_message.id)?;
+
+        let database_record =
+            database::ServiceAccounts::update_is_active(&id, false, self.database_ref())
+                .await?;
+        tracing::info!("Service account disabled: {}", database_record.id);
+
+        let revoked_tokens =
+            database::RevokedTokens::revoke_service_account(&id, self.database_ref()).await?;
+        self.revocation_list.extend(&revoked_tokens);
+        tracing::info!("Service account Access Tokens revoked: {}", revoked_tokens.len());
+
+        Ok(Response::new(database_record.into()))
+    }
+}
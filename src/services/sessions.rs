@@ -20,17 +20,27 @@ use crate::{database, domain};
 use crate::configuration::Configuration;
 use crate::prelude::BackendError;
 
-/// User service containing a database pool
+/// User service containing a database pool and the shared revocation list
 // #[derive(Debug)]
 pub struct SessionsService {
     database: Arc<Pool<Postgres>>,
     config: Arc<Configuration>,
+    revocation_list: Arc<domain::RevocationList>,
 }
 
 impl SessionsService {
-    /// Create a new UserService passing in the Arc for the Sqlx database pool
-    pub fn new(database: Arc<Pool<Postgres>>, config: Arc<Configuration>) -> Self {
-        Self { database, config }
+    /// Create a new UserService passing in the Arc for the Sqlx database pool,
+    /// configuration and revocation list
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        revocation_list: Arc<domain::RevocationList>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            revocation_list,
+        }
     }
 
     /// Shorthand for reference to database pool
@@ -42,6 +52,112 @@ impl SessionsService {
     fn config_ref(&self) -> &Configuration {
         &self.config
     }
+
+    /// Load the Access Tokens revoked in the database that have not expired
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An Sqlx database connection pool
+    /// ---
+    #[tracing::instrument(name = "Load revocation list: ", skip_all)]
+    pub async fn load_revocation_list(
+        database: &Pool<Postgres>,
+    ) -> Result<domain::RevocationList, BackendError> {
+        let revoked_tokens = database::RevokedTokens::index_unexpired(database).await?;
+
+        Ok(domain::RevocationList::new(&revoked_tokens))
+    }
+
+    /// Delete expired Revoked Tokens, and the expired Access Tokens issued to
+    /// service accounts, then reload the revocation list from the database on
+    /// the configured refresh interval, so tokens revoked by other instances
+    /// are rejected
+    ///
+    /// # Parameters
+    ///
+    /// * `revocation_list` - The shared revocation list
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    pub async fn refresh_revocation_list(
+        revocation_list: Arc<domain::RevocationList>,
+        database: Pool<Postgres>,
+        config: Arc<Configuration>,
+    ) {
+        let period = std::time::Duration::from_secs(config.jwt.refresh_seconds.max(1));
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = database::RevokedTokens::delete_expired(&database).await {
+                tracing::error!("Unable to delete expired revoked tokens: {error}");
+            }
+
+            if let Err(error) =
+                database::ServiceAccounts::delete_expired_access_tokens(&database).await
+            {
+                tracing::error!("Unable to delete expired service account tokens: {error}");
+            }
+
+            match database::RevokedTokens::index_unexpired(&database).await {
+                Ok(revoked_tokens) => revocation_list.replace(&revoked_tokens),
+                Err(error) => tracing::error!("Unable to reload revocation list: {error}"),
+            }
+        }
+    }
+
+    /// Revoke the Access Tokens issued with the Sessions in scope, adding them
+    /// to the database and the revocation list, returning the number revoked
+    ///
+    /// # Parameters
+    ///
+    /// * `scope` - Which Sessions to revoke the Access Tokens of
+    /// * `revocation_list` - The shared revocation list
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    pub async fn revoke_access_tokens(
+        scope: database::RevocationScope,
+        revocation_list: &domain::RevocationList,
+        database: &Pool<Postgres>,
+        config: &Configuration,
+    ) -> Result<usize, BackendError> {
+        let revoked_tokens = database::RevokedTokens::revoke_sessions(
+            scope,
+            config.application.access_token_seconds,
+            database,
+        )
+        .await?;
+        revocation_list.extend(&revoked_tokens);
+
+        tracing::info!("Access Tokens revoked for {scope:?}: {}", revoked_tokens.len());
+
+        Ok(revoked_tokens.len())
+    }
+
+    /// Get the user that owns the Session, if it exists, for checking
+    /// permissions that only allow acting on the callers own Sessions
+    async fn session_owner(&self, id: &Uuid) -> Option<Uuid> {
+        database::Sessions::from_id(id, self.database_ref())
+            .await
+            .ok()
+            .map(|session| session.user_id)
+    }
+
+    /// Shorthand for revoking the Access Tokens of the Sessions in scope
+    async fn revoke_scope(
+        &self,
+        scope: database::RevocationScope,
+    ) -> Result<usize, BackendError> {
+        Self::revoke_access_tokens(
+            scope,
+            &self.revocation_list,
+            self.database_ref(),
+            self.config_ref(),
+        )
+        .await
+    }
 }
 
 impl From<database::Sessions> for SessionsResponse {
@@ -49,16 +165,16 @@ impl From<database::Sessions> for SessionsResponse {
     fn from(value: database::Sessions) -> Self {
         let id = value.id.to_string();
         let user_id = value.user_id.to_string();
-        let refresh_token = value.refresh_token.to_string();
+        let family_id = value.family_id.to_string();
         let is_active = value.is_active;
         let created_on = value.created_on.to_string();
 
         Self {
             id,
             user_id,
-            refresh_token,
             is_active,
             created_on,
+            family_id,
         }
     }
 }
@@ -78,13 +194,17 @@ impl Sessions for SessionsService {
         // Parse the request message string into a Uuid
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse Session id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
         let database_record = database::Sessions::from_id(&id, self.database_ref()).await?;
 
+        // Users can read their own Sessions, or any Session with the permission
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsReadAny, Some(&database_record.user_id))?;
+
         // Convert the database record into a LoginsResponse message
         let response_message: SessionsResponse = database_record.into();
         // println!("{response_message:#?}");
@@ -102,12 +222,16 @@ impl Sessions for SessionsService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
+        // Check the caller can read every Session
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsReadAny, None)?;
+
         // TODO: Why does this need to be i64, could we use i32
         // Offset, where to start the records from
-        let offset: i64 = request_message.offset.into();
+        let offset: i64 = request_message.offset;
 
         // The number of users to be returned
-        let limit: i64 = request_message.limit.into();
+        let limit: i64 = request_message.limit;
 
         // Query the database
         let database_records =
@@ -138,15 +262,22 @@ impl Sessions for SessionsService {
         // Parse the request message string into a Uuid
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse Session id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
-        // Revoke Session in database based on database row PK (id)
+        // Users can revoke their own Sessions, or any Session with the permission
+        let owner_id = self.session_owner(&id).await;
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsRevokeAny, owner_id.as_ref())?;
+
+        // Revoke Session in database based on database row PK (id), and its
+        // Access Token
         let rows_affected =
             database::Sessions::revoke_by_id(&id, self.database_ref()).await?
                 as i64;
+        self.revoke_scope(database::RevocationScope::Session(id)).await?;
 
         // Build Session Response message
         let response_message = SessionsRevokeResponse { rows_affected };
@@ -171,15 +302,21 @@ impl Sessions for SessionsService {
         // Parse the request message string into a Uuid
         let user_id = Uuid::parse_str(&request_message.user_id).map_err(|_| {
             tracing::error!("Unable to parse User id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
-        // Revoke Sessions in database based on database row PK (id)
+        // Users can revoke their own Sessions, or any users Sessions with the permission
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsRevokeAny, Some(&user_id))?;
+
+        // Revoke Sessions in database based on database row PK (id), and their
+        // Access Tokens
         let rows_affected =
             database::Sessions::revoke_user_id(&user_id, self.database_ref())
                 .await? as i64;
+        self.revoke_scope(database::RevocationScope::User(user_id)).await?;
 
         // Build Sessions Response message
         let response_message = SessionsRevokeResponse { rows_affected };
@@ -201,9 +338,15 @@ impl Sessions for SessionsService {
         let (_request_metadata, request_extensions, _request_message) =
             request.into_parts();
 
-        // Revoke (set is_active = false) all Access Tokens in the database
+        // Check the caller can revoke every Session
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsRevokeAny, None)?;
+
+        // Revoke (set is_active = false) all Sessions in the database, and
+        // their Access Tokens
         let rows_affected =
             database::Sessions::revoke_all(self.database_ref()).await? as i64;
+        self.revoke_scope(database::RevocationScope::All).await?;
 
         // Build Session Response message
         let response_message = SessionsRevokeResponse { rows_affected };
@@ -222,15 +365,22 @@ impl Sessions for SessionsService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
+        // Check the caller can delete Sessions
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsDeleteAny, None)?;
+
         // Parse the request message string into a Uuid
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse Sessionid to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
-        // Revoke Session in database based on database row PK (id)
+        // Revoke the Access Token before the Session is deleted
+        self.revoke_scope(database::RevocationScope::Session(id)).await?;
+
+        // Delete Session in database based on database row PK (id)
         let rows_affected =
             database::Sessions::delete_by_id(&id, self.database_ref()).await?
                 as i64;
@@ -255,15 +405,22 @@ impl Sessions for SessionsService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
+        // Check the caller can delete Sessions
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsDeleteAny, None)?;
+
         // Parse the request message string into a Uuid
         let user_id = Uuid::parse_str(&request_message.user_id).map_err(|_| {
             tracing::error!("Unable to parse User id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
-        // Revoke Session in database based on database row PK (id)
+        // Revoke the Access Tokens before the Sessions are deleted
+        self.revoke_scope(database::RevocationScope::User(user_id)).await?;
+
+        // Delete Sessions in database based on the user id
         let rows_affected = database::Sessions::delete_all_user(
             &user_id,
             self.database_ref(),
@@ -290,7 +447,14 @@ impl Sessions for SessionsService {
         let (_request_metadata, request_extensions, _request_message) =
             request.into_parts();
 
-        // Revoke (set is_active = false) all Access Tokens in the database
+        // Check the caller can delete Sessions
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::SessionsDeleteAny, None)?;
+
+        // Revoke the Access Tokens before the Sessions are deleted
+        self.revoke_scope(database::RevocationScope::All).await?;
+
+        // Delete all Sessions in the database
         let rows_affected =
             database::Sessions::delete_all(self.database_ref()).await? as i64;
 
@@ -0,0 +1,232 @@
+//-- ./src/rpc/signing_keys.rs
+
+//! RPC service for Signing Keys endpoint
+//!
+//! Manages the key ring used to sign and verify tokens. Keys are stored in the
+//! database, so every instance of the service shares the same key ring, and
+//! each instance reloads the key ring on the configured refresh interval.
+//! ---
+
+// #![allow(unused)] // For development only
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use sqlx::{Pool, Postgres};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+use crate::configuration::Configuration;
+use crate::rpc::proto::signing_keys_server::SigningKeys;
+use crate::rpc::proto::{
+    Empty, SigningKeysIndexResponse, SigningKeysResponse, SigningKeysRotateRequest,
+};
+use crate::{database, domain, prelude::*};
+
+/// Signing Keys service containing a database pool and the shared key ring
+pub struct SigningKeysService {
+    database: Arc<Pool<Postgres>>,
+    config: Arc<Configuration>,
+    token_keys: Arc<domain::TokenKeys>,
+}
+
+impl SigningKeysService {
+    /// Create a new SigningKeysService passing in the Arc for the Sqlx database
+    /// pool, configuration and key ring
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        token_keys: Arc<domain::TokenKeys>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            token_keys,
+        }
+    }
+
+    /// Shorthand for reference to database pool
+    fn database_ref(&self) -> &Pool<Postgres> {
+        &self.database
+    }
+
+    /// Shorthand for reference to Configuration instance
+    fn config_ref(&self) -> &Configuration {
+        &self.config
+    }
+
+    /// Load the key ring from the database, importing the configured PEM key as
+    /// the first signing key when the database has no keys
+    ///
+    /// # Parameters
+    ///
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    #[tracing::instrument(name = "Load signing keys: ", skip_all)]
+    pub async fn load_token_keys(
+        database: &Pool<Postgres>,
+        config: &Configuration,
+    ) -> Result<domain::TokenKeys, BackendError> {
+        if database::SigningKeys::index(database).await?.is_empty() {
+            let private_key = domain::SigningKey::read_pem(&config.jwt.private_key_path)?;
+            let public_key = domain::SigningKey::read_pem(&config.jwt.public_key_path)?;
+
+            let signing_key = database::SigningKeys::new(
+                &config.jwt.key_id,
+                config.jwt.algorithm,
+                &String::from_utf8_lossy(&private_key).to_string().into(),
+                &String::from_utf8_lossy(&public_key),
+                chrono::DateTime::UNIX_EPOCH,
+                &config.jwt.encryption_key,
+            )?;
+
+            // Another instance starting at the same time may have imported it
+            if let Err(error) = signing_key.insert(database).await {
+                tracing::warn!("Configured signing key was not imported: {error}");
+            }
+        }
+
+        let token_keys = domain::TokenKeys::default();
+        Self::reload_token_keys(&token_keys, database, config).await?;
+
+        Ok(token_keys)
+    }
+
+    /// Replace the keys in the key ring with the keys in the database that have
+    /// not been retired
+    ///
+    /// # Parameters
+    ///
+    /// * `token_keys` - The key ring to be reloaded
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    pub async fn reload_token_keys(
+        token_keys: &domain::TokenKeys,
+        database: &Pool<Postgres>,
+        config: &Configuration,
+    ) -> Result<(), BackendError> {
+        let signing_keys = database::SigningKeys::index_active(database)
+            .await?
+            .iter()
+            .map(|signing_key| signing_key.to_signing_key(&config.jwt.encryption_key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        token_keys.replace(signing_keys);
+
+        Ok(())
+    }
+
+    /// Reload the key ring on the configured refresh interval, so keys rotated
+    /// by other instances are picked up
+    ///
+    /// # Parameters
+    ///
+    /// * `token_keys` - The key ring to be reloaded
+    /// * `database` - An Sqlx database connection pool
+    /// * `config` - The service configuration
+    /// ---
+    pub async fn refresh_token_keys(
+        token_keys: Arc<domain::TokenKeys>,
+        database: Pool<Postgres>,
+        config: Arc<Configuration>,
+    ) {
+        let period = std::time::Duration::from_secs(config.jwt.refresh_seconds.max(1));
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = Self::reload_token_keys(&token_keys, &database, &config).await {
+                tracing::error!("Unable to reload signing keys: {error}");
+            }
+        }
+    }
+}
+
+impl From<database::SigningKeys> for SigningKeysResponse {
+    /// Convert from database::SigningKeys to proto::SigningKeysResponse, never
+    /// including the private key
+    fn from(value: database::SigningKeys) -> Self {
+        Self {
+            id: value.id.to_string(),
+            key_id: value.key_id,
+            algorithm: value.algorithm.to_string(),
+            signs_from: value.signs_from.to_string(),
+            retires_on: value.retires_on.map(|retires_on| retires_on.to_string()),
+            created_on: value.created_on.to_string(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl SigningKeys for SigningKeysService {
+    /// Handle rpc requests to index every signing key, including retired keys
+    #[tracing::instrument(name = "Index Signing Keys Request: ", skip_all)]
+    async fn index(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<SigningKeysIndexResponse>, Status> {
+        let database_records = database::SigningKeys::index(self.database_ref()).await?;
+
+        let signing_keys = database_records
+            .into_iter()
+            .map(|signing_key| signing_key.into())
+            .collect();
+
+        Ok(Response::new(SigningKeysIndexResponse { signing_keys }))
+    }
+
+    /// Handle rpc requests to rotate the signing key. The new key is published
+    /// straight away and signs tokens once promoted, while older keys are
+    /// retired once every token they could have signed has expired.
+    #[tracing::instrument(name = "Rotate Signing Key Request: ", skip_all)]
+    async fn rotate(
+        &self,
+        request: Request<SigningKeysRotateRequest>,
+    ) -> Result<Response<SigningKeysResponse>, Status> {
+        let request_message = request.into_inner();
+        let config = self.config_ref();
+
+        let promotion_seconds = request_message
+            .promotion_seconds
+            .unwrap_or(config.jwt.promotion_seconds);
+        if promotion_seconds < 0 {
+            return Err(Status::invalid_argument("Promotion seconds must not be negative"));
+        }
+
+        // Generate the new key with the configured algorithm
+        let (private_key, public_key) =
+            domain::SigningKey::generate_pem(config.jwt.algorithm)?;
+        let key_id = Uuid::now_v7().to_string();
+        let signs_from = Utc::now() + Duration::seconds(promotion_seconds);
+
+        let signing_key = database::SigningKeys::new(
+            &key_id,
+            config.jwt.algorithm,
+            &private_key,
+            &public_key,
+            signs_from,
+            &config.jwt.encryption_key,
+        )?;
+
+        // Older keys verify tokens until the longest lived token signed before
+        // the promotion has expired, so nobody is logged out
+        let retires_on = signing_key.signs_from
+            + Duration::seconds(config.application.remember_me_max_seconds as i64);
+        database::SigningKeys::retire_before(
+            &signing_key.signs_from,
+            &retires_on,
+            self.database_ref(),
+        )
+        .await?;
+
+        let database_record = signing_key.insert(self.database_ref()).await?;
+        tracing::info!("Signing key rotated: {}", database_record.key_id);
+
+        Self::reload_token_keys(&self.token_keys, self.database_ref(), config).await?;
+
+        Ok(Response::new(database_record.into()))
+    }
+}
@@ -22,21 +22,36 @@ use crate::rpc::proto::users_server::Users;
 //TODO: Refactor Proto function names
 use crate::rpc::proto::{
     CreateUserRequest, DeleteUserRequest, DeleteUserResponse, ReadUserRequest,
-    UpdateUserRequest, UserIndexRequest, UserIndexResponse, UserResponse,
+    UnlockUserRequest, UnlockUserResponse, UpdateUserRequest, UserIndexRequest,
+    UserIndexResponse, UserResponse,
 };
-use crate::{database, domain};
+use crate::{database, domain, services};
 
-/// User service containing a database pool
+/// User service containing a database pool, the shared revocation list and
+/// the shared API key list
 // #[derive(Debug)]
 pub struct UsersService {
     database: Arc<Pool<Postgres>>,
     config: Arc<Configuration>,
+    revocation_list: Arc<domain::RevocationList>,
+    api_key_list: Arc<domain::ApiKeyList>,
 }
 
 impl UsersService {
-    /// Create a new UserService passing in the Arc for the Sqlx database pool
-    pub fn new(database: Arc<Pool<Postgres>>, config: Arc<Configuration>) -> Self {
-        Self { database, config }
+    /// Create a new UserService passing in the Arc for the Sqlx database pool,
+    /// configuration, revocation list and API key list
+    pub fn new(
+        database: Arc<Pool<Postgres>>,
+        config: Arc<Configuration>,
+        revocation_list: Arc<domain::RevocationList>,
+        api_key_list: Arc<domain::ApiKeyList>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            revocation_list,
+            api_key_list,
+        }
     }
 
     /// Shorthand for reference to database pool
@@ -48,6 +63,29 @@ impl UsersService {
     fn config_ref(&self) -> &Configuration {
         &self.config
     }
+
+    /// Revoke the Access Tokens of every Session of the user, so they cannot
+    /// be used until they expire
+    async fn revoke_access_tokens(&self, user_id: &Uuid) -> Result<usize, BackendError> {
+        services::SessionsService::revoke_access_tokens(
+            database::RevocationScope::User(*user_id),
+            &self.revocation_list,
+            self.database_ref(),
+            self.config_ref(),
+        )
+        .await
+    }
+
+    /// Reload the API Keys of the user, so keys of deactivated or deleted users
+    /// stop working and keys act with the users current role
+    async fn reload_api_keys(&self, user_id: &Uuid) -> Result<(), BackendError> {
+        services::ApiKeysService::reload_user_api_keys(
+            user_id,
+            &self.api_key_list,
+            self.database_ref(),
+        )
+        .await
+    }
 }
 
 /// Convert a User Request message into a database::Users
@@ -73,6 +111,7 @@ impl TryFrom<CreateUserRequest> for database::Users {
             role,
             is_active,
             is_verified,
+            is_pending: false,
             created_on,
         })
     }
@@ -104,6 +143,9 @@ impl TryFrom<UpdateUserRequest> for database::Users {
             role,
             is_active,
             is_verified,
+            // Admins decide if the user is active, so they are no longer pending
+            // registration
+            is_pending: false,
             created_on,
         })
     }
@@ -144,29 +186,20 @@ impl Users for UsersService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
-        //-- 1. Check the token claim user role is admin
-        // Get access token claim from request extension
+        // Check the caller can create users
         let access_token_claim =
-            request_extensions.get::<domain::TokenClaim>().ok_or(
-                BackendError::Static("Token Claim not found in request extension."),
-            )?;
-
-        // TODO: Delete admin check as it is happening in middleware as well.
-        // Parse Token Claim user role into domain type
-        let requester_role = domain::UserRole::from_str(&access_token_claim.jur)?;
-
-        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-        if requester_role != domain::UserRole::Admin {
-            tracing::error!(
-                "User request admin endpoint: {}",
-                &access_token_claim.sub
-            );
-            return Err(Status::unauthenticated("Admin access required!"));
-        }
+            domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        access_token_claim.authorise(domain::Permission::UsersCreateAny, None)?;
 
         // Convert create user request message into a user instance
         let user: database::Users = request_message.try_into()?;
 
+        // Only callers that can assign roles create users with a role other
+        // than the default user role, so they cannot create an admin
+        if user.role != domain::UserRole::default() {
+            access_token_claim.authorise(domain::Permission::RolesAssignAny, None)?;
+        }
+
         // Insert user into the database
         let database_record = user.insert(self.database_ref()).await?;
 
@@ -192,32 +225,17 @@ impl Users for UsersService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
-        //-- 1. Check the token claim user role is admin
-        // Get access token claim from request extension
-        let access_token_claim =
-            request_extensions.get::<domain::TokenClaim>().ok_or(
-                BackendError::Static("Token Claim not found in request extension."),
-            )?;
-
-        // Parse Token Claim user role into domain type
-        let requester_role = domain::UserRole::from_str(&access_token_claim.jur)?;
-
-        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-        if requester_role != domain::UserRole::Admin {
-            tracing::error!(
-                "User request admin endpoint: {}",
-                &access_token_claim.sub
-            );
-            return Err(Status::unauthenticated("Admin access required!"));
-        }
-
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse user id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
+        // Users can read their own account, or any account with the permission
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::UsersReadAny, Some(&id))?;
+
         let database_record =
             database::Users::from_user_id(&id, self.database_ref()).await?;
 
@@ -243,24 +261,9 @@ impl Users for UsersService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
-        //-- 1. Check the token claim user role is admin
-        // Get access token claim from request extension
-        let access_token_claim =
-            request_extensions.get::<domain::TokenClaim>().ok_or(
-                BackendError::Static("Token Claim not found in request extension."),
-            )?;
-
-        // Parse Token Claim user role into domain type
-        let requester_role = domain::UserRole::from_str(&access_token_claim.jur)?;
-
-        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-        if requester_role != domain::UserRole::Admin {
-            tracing::error!(
-                "User request admin endpoint: {}",
-                &access_token_claim.sub
-            );
-            return Err(Status::unauthenticated("Admin access required!"));
-        }
+        // Check the caller can read every user
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::UsersReadAny, None)?;
 
         // Offset, where to start the records from
         let offset = request_message.offset;
@@ -302,31 +305,33 @@ impl Users for UsersService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
-        //-- 1. Check the token claim user role is admin
-        // Get access token claim from request extension
+        // Check the caller can update users
         let access_token_claim =
-            request_extensions.get::<domain::TokenClaim>().ok_or(
-                BackendError::Static("Token Claim not found in request extension."),
-            )?;
-
-        // Parse Token Claim user role into domain type
-        let requester_role = domain::UserRole::from_str(&access_token_claim.jur)?;
-
-        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-        if requester_role != domain::UserRole::Admin {
-            tracing::error!(
-                "User request admin endpoint: {}",
-                &access_token_claim.sub
-            );
-            return Err(Status::unauthenticated("Admin access required!"));
-        }
+            domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        access_token_claim.authorise(domain::Permission::UsersUpdateAny, None)?;
 
         // Convert create user request message into a user instance
         let user: database::Users = request_message.try_into()?;
 
+        // Only callers that can assign roles change the user role, so they
+        // cannot make themselves or anyone else an admin
+        let existing = database::Users::from_user_id(&user.id, self.database_ref()).await?;
+        if user.role != existing.role {
+            access_token_claim.authorise(domain::Permission::RolesAssignAny, None)?;
+        }
+
         // Insert user into the database
         let database_record = user.update(self.database_ref()).await?;
 
+        // Deactivated users can no longer refresh their Sessions or use the
+        // Access Tokens they hold
+        if !database_record.is_active {
+            database::Sessions::revoke_user_id(&database_record.id, self.database_ref())
+                .await?;
+            self.revoke_access_tokens(&database_record.id).await?;
+        }
+        self.reload_api_keys(&database_record.id).await?;
+
         // Convert database user record into a user response message
         let response_message: UserResponse = database_record.into();
 
@@ -349,41 +354,74 @@ impl Users for UsersService {
         let (_request_metadata, request_extensions, request_message) =
             request.into_parts();
 
-        //-- 1. Check the token claim user role is admin
-        // Get access token claim from request extension
-        let access_token_claim =
-            request_extensions.get::<domain::TokenClaim>().ok_or(
-                BackendError::Static("Token Claim not found in request extension."),
-            )?;
-
-        // Parse Token Claim user role into domain type
-        let requester_role = domain::UserRole::from_str(&access_token_claim.jur)?;
-
-        // If the User Role in the Token Claim is not Admin return early with Tonic Status error
-        if requester_role != domain::UserRole::Admin {
-            tracing::error!(
-                "User request admin endpoint: {}",
-                &access_token_claim.sub
-            );
-            return Err(Status::unauthenticated("Admin access required!"));
-        }
+        // Check the caller can delete users
+        domain::TokenClaim::from_request_extensions(&request_extensions)?
+            .authorise(domain::Permission::UsersDeleteAny, None)?;
 
         let id = Uuid::parse_str(&request_message.id).map_err(|_| {
             tracing::error!("Unable to parse user id to UUID!");
-            return BackendError::Generic(
+            BackendError::Generic(
                 "Unable to parse user id to UUID!".to_string(),
-            );
+            )
         })?;
 
         let database_record =
             database::Users::from_user_id(&id, self.database_ref()).await?;
 
+        // Revoke the Access Tokens before the users Sessions are deleted
+        self.revoke_access_tokens(&database_record.id).await?;
+
         let rows_affected =
             database_record.delete(self.database_ref()).await? as i64;
+        self.reload_api_keys(&database_record.id).await?;
 
         // Convert database user record into a user response message
         let response_message = DeleteUserResponse { rows_affected };
 
         Ok(Response::new(response_message))
     }
+
+    /// Clear the failed login attempts and any lockout on a user account
+    #[tracing::instrument(name = "Unlock User Request: ", skip_all)]
+    async fn unlock(
+        &self,
+        request: Request<UnlockUserRequest>,
+    ) -> Result<Response<UnlockUserResponse>, Status> {
+        // Break up the request into its three parts: 1. Metadata, 2. Extensions & 3. Message
+        let (_request_metadata, request_extensions, request_message) =
+            request.into_parts();
+
+        // Get access token claim from request extension, to log who unlocked the user
+        let access_token_claim =
+            domain::TokenClaim::from_request_extensions(&request_extensions)?;
+        access_token_claim.authorise(domain::Permission::UsersUnlockAny, None)?;
+
+        let id = Uuid::parse_str(&request_message.id).map_err(|_| {
+            tracing::error!("Unable to parse user id to UUID!");
+            BackendError::Generic(
+                "Unable to parse user id to UUID!".to_string(),
+            )
+        })?;
+
+        // Remove the account Login Lockout
+        let database_record =
+            database::Users::from_user_id(&id, self.database_ref()).await?;
+
+        let rows_affected = database::LoginLockouts::delete_by_subject(
+            database::LockoutScope::Account,
+            &database_record.id.to_string(),
+            self.database_ref(),
+        )
+        .await? as i64;
+
+        tracing::info!(
+            "User {} unlocked by: {}",
+            database_record.id,
+            &access_token_claim.sub
+        );
+
+        let response_message = UnlockUserResponse { rows_affected };
+
+        Ok(Response::new(response_message))
+    }
 }
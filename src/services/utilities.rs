@@ -6,21 +6,48 @@
 
 use std::sync::Arc;
 
+use jsonwebtoken::jwk::Jwk;
 use tonic::{Request, Response, Status};
 
 use crate::configuration::Configuration;
-use crate::rpc::proto::{Empty, PingResponse};
+use crate::domain;
+use crate::prelude::*;
+use crate::rpc::proto::{Empty, JsonWebKey, JwksResponse, PingResponse};
 use crate::rpc::proto::utilities_server::Utilities;
 
 // #[derive(Debug, Default)]
 pub struct UtilitiesService {
     #[allow(dead_code)]
     config: Arc<Configuration>,
+    /// Token signing keys Arc reference
+    token_keys: Arc<domain::TokenKeys>,
 }
 
 impl UtilitiesService {
-    pub fn new(config: Arc<Configuration>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Configuration>, token_keys: Arc<domain::TokenKeys>) -> Self {
+        Self { config, token_keys }
+    }
+}
+
+/// Convert a JSON Web Key into a proto::JsonWebKey, using the standard JWK
+/// member names
+impl TryFrom<&Jwk> for JsonWebKey {
+    type Error = BackendError;
+
+    fn try_from(value: &Jwk) -> Result<Self, Self::Error> {
+        let value = serde_json::to_value(value)?;
+        let member = |name: &str| value[name].as_str().map(|member| member.to_string());
+
+        Ok(Self {
+            kty: member("kty").unwrap_or_default(),
+            kid: member("kid").unwrap_or_default(),
+            alg: member("alg").unwrap_or_default(),
+            key_use: member("use").unwrap_or_default(),
+            crv: member("crv"),
+            x: member("x"),
+            n: member("n"),
+            e: member("e"),
+        })
     }
 }
 
@@ -41,4 +68,27 @@ impl Utilities for UtilitiesService {
         // Send back our ping response.
         Ok(Response::new(response_message))
     }
+
+    #[tracing::instrument(
+        name = "JWKS endpoint",
+        skip(self),
+    )]
+    async fn jwks(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<JwksResponse>, Status> {
+        let jwks = self.token_keys.jwks();
+
+        let keys = jwks
+            .keys
+            .iter()
+            .map(JsonWebKey::try_from)
+            .collect::<Result<Vec<JsonWebKey>, BackendError>>()?;
+
+        let json = serde_json::to_string(&jwks).map_err(BackendError::from)?;
+
+        let response_message = JwksResponse { keys, json };
+
+        Ok(Response::new(response_message))
+    }
 }
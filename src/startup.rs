@@ -7,10 +7,14 @@
 //! # Startup
 //!
 //! This module has a Tonic Server instance enum for reuse in the integration
-//! test suit.
+//! test suit. The OpenID Connect provider HTTP endpoints are served alongside
+//! the Tonic server on their own port.
 //! ---
 
-use crate::{configuration::Configuration, prelude::*, router};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use crate::{configuration::Configuration, domain, prelude::*, router, services};
 
 use sqlx::{Pool, Postgres};
 use tokio::net::TcpListener;
@@ -20,6 +24,11 @@ use tonic::transport::server::Router;
 pub struct TonicServer {
     pub router: Router,
     pub listener: TcpListener,
+    pub oidc_router: axum::Router,
+    pub oidc_listener: TcpListener,
+    pub token_keys: Arc<domain::TokenKeys>,
+    pub revocation_list: Arc<domain::RevocationList>,
+    pub api_key_list: Arc<domain::ApiKeyList>,
 }
 
 impl TonicServer {
@@ -34,13 +43,74 @@ impl TonicServer {
             &config.application.ip_address, &config.application.port
         );
 
-        let router = router::get_router(database, config)?;
+        // Key ring for signing and verifying tokens, loaded from the database and
+        // reloaded in the background to pick up keys rotated by other instances
+        let token_keys = Arc::new(
+            services::SigningKeysService::load_token_keys(&database, &config).await?,
+        );
+        tokio::spawn(services::SigningKeysService::refresh_token_keys(
+            Arc::clone(&token_keys),
+            database.clone(),
+            Arc::new(config.clone()),
+        ));
+
+        // Access Tokens revoked before they expire, loaded from the database and
+        // reloaded in the background to pick up tokens revoked by other instances
+        let revocation_list = Arc::new(
+            services::SessionsService::load_revocation_list(&database).await?,
+        );
+        tokio::spawn(services::SessionsService::refresh_revocation_list(
+            Arc::clone(&revocation_list),
+            database.clone(),
+            Arc::new(config.clone()),
+        ));
+
+        // API keys that can authenticate requests, loaded from the database and
+        // reloaded in the background to pick up keys changed by other instances
+        let api_key_list = Arc::new(
+            services::ApiKeysService::load_api_key_list(&database).await?,
+        );
+        tokio::spawn(services::ApiKeysService::refresh_api_key_list(
+            Arc::clone(&api_key_list),
+            database.clone(),
+            Arc::new(config.clone()),
+        ));
+
+        let oidc_address = format!(
+            "{}:{}",
+            &config.application.ip_address, &config.oidc.port
+        );
+
+        let oidc_router = router::get_oidc_router(
+            database.clone(),
+            config.clone(),
+            Arc::clone(&token_keys),
+            Arc::clone(&revocation_list),
+            Arc::clone(&api_key_list),
+        )?;
+
+        let router = router::get_router(
+            database,
+            config,
+            Arc::clone(&token_keys),
+            Arc::clone(&revocation_list),
+            Arc::clone(&api_key_list),
+        )?;
 
         // We are using listener as it will bind a random port when port setting
         // is '0'. This is important for integration test server spawn.
         let listener = TcpListener::bind(address).await?;
+        let oidc_listener = TcpListener::bind(oidc_address).await?;
 
-        Ok(Self { router, listener })
+        Ok(Self {
+            router,
+            listener,
+            oidc_router,
+            oidc_listener,
+            token_keys,
+            revocation_list,
+            api_key_list,
+        })
     }
 
     /// Run the Tonic server instance
@@ -51,9 +121,30 @@ impl TonicServer {
             self.listener.local_addr()?.port(),
         );
         tracing::info!("Tonic server started at '{}'", address);
+        tracing::info!("Token signing keys loaded: {:?}", self.token_keys);
+        tracing::info!("Revoked Access Tokens loaded: {}", self.revocation_list.len());
+        tracing::info!("API keys loaded: {}", self.api_key_list.len());
+        tracing::info!("OIDC provider started at '{}'", self.oidc_listener.local_addr()?);
+
+        // The OIDC endpoints need the client address for login lockouts
+        let oidc_service = self
+            .oidc_router
+            .into_make_service_with_connect_info::<SocketAddr>();
+        let oidc_server = async {
+            axum::serve(self.oidc_listener, oidc_service)
+                .await
+                .map_err(BackendError::from)
+        };
 
         let incoming = tokio_stream::wrappers::TcpListenerStream::new(self.listener);
-        self.router.serve_with_incoming(incoming).await?;
+        let tonic_server = async {
+            self.router
+                .serve_with_incoming(incoming)
+                .await
+                .map_err(BackendError::from)
+        };
+
+        tokio::try_join!(tonic_server, oidc_server)?;
 
         Ok(())
     }
@@ -45,7 +45,7 @@ pub fn init() -> Result<(), BackendError>{
 
     // Try to use env runtime level, if not present use default
     let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| default_env_filter);
+        .unwrap_or(default_env_filter);
 
     // Build event collector for console output
     let console_collector = tracing_subscriber::fmt::layer()
@@ -57,7 +57,7 @@ pub fn init() -> Result<(), BackendError>{
         .with(console_collector);
 
     // Convert all log records into tracing events.
-    let _log_tracer = tracing_log::LogTracer::init()?;
+    tracing_log::LogTracer::init()?;
 
     //-- 3. Initiate tracing
     set_global_default(registry)?;
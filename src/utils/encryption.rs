@@ -0,0 +1,59 @@
+//-- ./src/utils/encryption.rs
+
+//! Encrypt secrets stored in the database with AES-256-GCM
+//!
+//! The AES key is derived from a configured encryption key, and the random
+//! nonce is stored in front of the cipher text.
+//! ---
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha256};
+
+/// Length of the AES-GCM nonce prepended to the cipher text
+const NONCE_LENGTH: usize = 12;
+
+/// Derive the AES-256 key from the configured encryption key
+fn cipher(encryption_key: &Secret<String>) -> Aes256Gcm {
+    let key = Sha256::digest(encryption_key.expose_secret().as_bytes());
+
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+}
+
+/// Encrypt plain text, returning the nonce followed by the cipher text.
+///
+/// # Parameters
+///
+/// * `plain_text` - The secret to be encrypted
+/// * `encryption_key` - The configured encryption key
+/// ---
+pub fn encrypt(
+    plain_text: &[u8],
+    encryption_key: &Secret<String>,
+) -> Result<Vec<u8>, aes_gcm::Error> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let cipher_text = cipher(encryption_key).encrypt(&nonce, plain_text)?;
+
+    Ok([nonce.as_slice(), &cipher_text].concat())
+}
+
+/// Decrypt the nonce and cipher text returned by `encrypt`
+///
+/// # Parameters
+///
+/// * `encrypted` - The nonce and cipher text returned by `encrypt`
+/// * `encryption_key` - The configured encryption key
+/// ---
+pub fn decrypt(
+    encrypted: &[u8],
+    encryption_key: &Secret<String>,
+) -> Result<Vec<u8>, aes_gcm::Error> {
+    if encrypted.len() <= NONCE_LENGTH {
+        return Err(aes_gcm::Error);
+    }
+
+    let (nonce, cipher_text) = encrypted.split_at(NONCE_LENGTH);
+
+    cipher(encryption_key).decrypt(Nonce::from_slice(nonce), cipher_text)
+}
@@ -22,7 +22,5 @@ pub fn mock_uuid() -> uuid::Uuid {
     );
 
     // Generate Uuid V7
-    let uuid = uuid::Uuid::new_v7(random_uuid_timestamp);
-
-    uuid
+    uuid::Uuid::new_v7(random_uuid_timestamp)
 }
@@ -2,6 +2,8 @@
 
 //! Utility modules that don't fit into other places
 
+mod encryption;
 mod mock_uuid;
+pub use encryption::{decrypt, encrypt};
 #[cfg(test)]
 pub use mock_uuid::mock_uuid;
@@ -0,0 +1,193 @@
+//-- ./src/webauthn/authenticator_data.rs
+
+// #![allow(unused)] // For development only
+
+//! The authenticator data signed by the authenticator, as per the WebAuthn
+//! specification section 6.1.
+//!
+//! The layout is the 32 byte relying party id hash, one byte of flags, a four
+//! byte signature counter, then the attested credential data during
+//! registration.
+//! ---
+
+use ciborium::Value;
+use p256::ecdsa::VerifyingKey;
+
+use crate::prelude::*;
+
+/// The user was present during the ceremony (UP)
+pub const USER_PRESENT: u8 = 0x01;
+
+/// The user was verified by the authenticator, such as with a PIN or
+/// biometric (UV)
+pub const USER_VERIFIED: u8 = 0x04;
+
+/// Attested credential data is included (AT)
+const ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+/// Length of the relying party id hash, flags and signature counter
+const HEADER_LENGTH: usize = 32 + 1 + 4;
+
+/// Length of the authenticator AAGUID in the attested credential data
+const AAGUID_LENGTH: usize = 16;
+
+/// COSE key parameters for an ES256 (ECDSA P-256 with SHA-256) public key
+const COSE_KEY_TYPE: i128 = 1;
+const COSE_ALGORITHM: i128 = 3;
+const COSE_EC2_CURVE: i128 = -1;
+const COSE_EC2_X: i128 = -2;
+const COSE_EC2_Y: i128 = -3;
+const COSE_KEY_TYPE_EC2: i128 = 2;
+const COSE_ALGORITHM_ES256: i128 = -7;
+const COSE_CURVE_P256: i128 = 1;
+
+/// A credential created during registration
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttestedCredential {
+    /// The authenticator generated credential id
+    pub credential_id: Vec<u8>,
+    /// The credential public key, as an uncompressed SEC1 P-256 point
+    pub public_key: Vec<u8>,
+}
+
+/// Parsed authenticator data
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatorData {
+    /// SHA-256 hash of the relying party id the credential is scoped to
+    pub rp_id_hash: [u8; 32],
+    /// Flags describing the ceremony
+    pub flags: u8,
+    /// Signature counter, incremented by authenticators that support it
+    pub sign_count: u32,
+    /// The credential, present during registration only
+    pub attested_credential: Option<AttestedCredential>,
+}
+
+impl AuthenticatorData {
+    /// Parse the authenticator data bytes
+    pub fn parse(bytes: &[u8]) -> Result<Self, BackendError> {
+        let invalid = || BackendError::WebAuthn("authenticator data is not valid".to_string());
+
+        if bytes.len() < HEADER_LENGTH {
+            return Err(invalid());
+        }
+
+        let (header, mut rest) = bytes.split_at(HEADER_LENGTH);
+        let rp_id_hash: [u8; 32] = header[..32].try_into().map_err(|_| invalid())?;
+        let flags = header[32];
+        let sign_count = u32::from_be_bytes(header[33..].try_into().map_err(|_| invalid())?);
+
+        let attested_credential = if flags & ATTESTED_CREDENTIAL_DATA != 0 {
+            if rest.len() < AAGUID_LENGTH + 2 {
+                return Err(invalid());
+            }
+            let credential_id_length =
+                u16::from_be_bytes([rest[AAGUID_LENGTH], rest[AAGUID_LENGTH + 1]]) as usize;
+            rest = &rest[AAGUID_LENGTH + 2..];
+
+            if rest.len() < credential_id_length {
+                return Err(invalid());
+            }
+            let (credential_id, mut cose_key) = rest.split_at(credential_id_length);
+
+            // The COSE key is followed by any extension data, so only read one item
+            let cose_key: Value = ciborium::from_reader(&mut cose_key).map_err(|_| invalid())?;
+
+            Some(AttestedCredential {
+                credential_id: credential_id.to_vec(),
+                public_key: public_key_from_cose(&cose_key)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            attested_credential,
+        })
+    }
+
+    /// Was the user present during the ceremony
+    pub fn is_user_present(&self) -> bool {
+        self.flags & USER_PRESENT != 0
+    }
+
+    /// Was the user verified by the authenticator during the ceremony
+    pub fn is_user_verified(&self) -> bool {
+        self.flags & USER_VERIFIED != 0
+    }
+}
+
+/// Convert an ES256 COSE key into an uncompressed SEC1 public key, which is
+/// what we store in the database.
+fn public_key_from_cose(cose_key: &Value) -> Result<Vec<u8>, BackendError> {
+    let unsupported = || {
+        BackendError::WebAuthn("only ES256 (P-256) credential keys are supported".to_string())
+    };
+
+    let entries = cose_key.as_map().ok_or_else(unsupported)?;
+    let parameter = |label: i128| {
+        entries.iter().find_map(|(key, value)| {
+            key.as_integer()
+                .filter(|key| i128::from(*key) == label)
+                .map(|_| value)
+        })
+    };
+    let integer = |label: i128| {
+        parameter(label)
+            .and_then(Value::as_integer)
+            .map(i128::from)
+    };
+    let coordinate = |label: i128| {
+        parameter(label)
+            .and_then(Value::as_bytes)
+            .filter(|bytes| bytes.len() == 32)
+    };
+
+    if integer(COSE_KEY_TYPE) != Some(COSE_KEY_TYPE_EC2)
+        || integer(COSE_ALGORITHM) != Some(COSE_ALGORITHM_ES256)
+        || integer(COSE_EC2_CURVE) != Some(COSE_CURVE_P256)
+    {
+        return Err(unsupported());
+    }
+
+    let x = coordinate(COSE_EC2_X).ok_or_else(unsupported)?;
+    let y = coordinate(COSE_EC2_Y).ok_or_else(unsupported)?;
+    let public_key = [&[0x04], x.as_slice(), y.as_slice()].concat();
+
+    // Make sure the point is on the curve before we store it
+    VerifyingKey::from_sec1_bytes(&public_key).map_err(|_| unsupported())?;
+
+    Ok(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_authenticator_data_is_rejected() {
+        let authenticator_data = AuthenticatorData::parse(&[0u8; HEADER_LENGTH - 1]);
+
+        assert!(authenticator_data.is_err());
+    }
+
+    #[test]
+    fn parse_flags_and_counter() -> Result<(), BackendError> {
+        let mut bytes = vec![7u8; 32];
+        bytes.push(USER_PRESENT | USER_VERIFIED);
+        bytes.extend_from_slice(&42u32.to_be_bytes());
+
+        let authenticator_data = AuthenticatorData::parse(&bytes)?;
+
+        assert_eq!(authenticator_data.rp_id_hash, [7u8; 32]);
+        assert!(authenticator_data.is_user_present());
+        assert!(authenticator_data.is_user_verified());
+        assert_eq!(authenticator_data.sign_count, 42);
+        assert_eq!(authenticator_data.attested_credential, None);
+
+        Ok(())
+    }
+}
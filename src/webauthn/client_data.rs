@@ -0,0 +1,102 @@
+//-- ./src/webauthn/client_data.rs
+
+// #![allow(unused)] // For development only
+
+//! The client data JSON the browser, or mobile platform, passes to the
+//! authenticator, binding the ceremony to our challenge and the client origin.
+//! ---
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::{domain, prelude::*};
+
+use super::Ceremony;
+
+/// The members of the collected client data we check
+#[derive(Debug, serde::Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony_type: String,
+    challenge: String,
+    origin: String,
+}
+
+/// Parsed client data for a WebAuthn ceremony
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientData {
+    /// The challenge we issued for the ceremony
+    pub challenge: domain::OneTimeToken,
+    /// The origin of the client that performed the ceremony
+    pub origin: String,
+}
+
+impl ClientData {
+    /// Parse the client data JSON, checking it is for the expected ceremony.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_data_json` - The raw client data JSON from the client
+    /// * `ceremony` - The ceremony the client data is expected to be for
+    /// ---
+    pub fn parse(client_data_json: &[u8], ceremony: Ceremony) -> Result<Self, BackendError> {
+        let collected: CollectedClientData = serde_json::from_slice(client_data_json)
+            .map_err(|_| BackendError::WebAuthn("client data is not valid JSON".to_string()))?;
+
+        if collected.ceremony_type != ceremony.client_data_type() {
+            return Err(BackendError::WebAuthn(format!(
+                "client data type is {}",
+                collected.ceremony_type
+            )));
+        }
+
+        // Challenges are issued as the bytes of a One Time Token string
+        let challenge = URL_SAFE_NO_PAD
+            .decode(collected.challenge.trim_end_matches('='))
+            .ok()
+            .and_then(|challenge| String::from_utf8(challenge).ok())
+            .ok_or_else(|| BackendError::WebAuthn("challenge is not valid".to_string()))?;
+
+        Ok(Self {
+            challenge: domain::OneTimeToken::from(challenge),
+            origin: collected.origin,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_data_json(ceremony_type: &str, challenge: &domain::OneTimeToken) -> Vec<u8> {
+        serde_json::json!({
+            "type": ceremony_type,
+            "challenge": URL_SAFE_NO_PAD.encode(challenge.as_ref()),
+            "origin": "https://example.com",
+            "crossOrigin": false,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn parse_client_data() {
+        let challenge = domain::OneTimeToken::generate();
+        let json = client_data_json("webauthn.get", &challenge);
+
+        let client_data = ClientData::parse(&json, Ceremony::Authentication).unwrap();
+
+        assert_eq!(client_data.challenge, challenge);
+        assert_eq!(client_data.origin, "https://example.com");
+    }
+
+    #[test]
+    fn wrong_ceremony_is_rejected() {
+        let challenge = domain::OneTimeToken::generate();
+        let json = client_data_json("webauthn.create", &challenge);
+
+        let client_data = ClientData::parse(&json, Ceremony::Authentication);
+
+        assert!(client_data.is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//-- ./src/webauthn/mod.rs
+
+//! WebAuthn (passkey) relying party
+//!
+//! Verifies the registration and authentication ceremonies performed by a
+//! users authenticator. We ask for "none" attestation conveyance and support
+//! ES256 (P-256) credentials, which every passkey provider supports.
+//!
+//! # References
+//!
+//! * [Web Authentication Level 2](https://www.w3.org/TR/webauthn-2/)
+//! ---
+
+// #![allow(unused)] // For development only
+
+use ciborium::Value;
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use strum::Display;
+
+use crate::configuration::WebAuthnConfiguration;
+use crate::prelude::*;
+
+pub use authenticator_data::{AttestedCredential, AuthenticatorData};
+pub use client_data::ClientData;
+
+mod authenticator_data;
+mod client_data;
+
+/// The WebAuthn ceremonies a challenge can be issued for
+#[derive(Clone, Debug, PartialEq, Copy, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Ceremony {
+    /// Creating a new credential on the authenticator
+    Registration,
+    /// Asserting an existing credential to log in
+    Authentication,
+}
+
+impl Ceremony {
+    /// The client data type for the ceremony
+    fn client_data_type(&self) -> &'static str {
+        match self {
+            Ceremony::Registration => "webauthn.create",
+            Ceremony::Authentication => "webauthn.get",
+        }
+    }
+}
+
+/// Our WebAuthn relying party, as configured
+pub struct RelyingParty<'a> {
+    config: &'a WebAuthnConfiguration,
+}
+
+impl<'a> RelyingParty<'a> {
+    /// Build the relying party from the WebAuthn configuration
+    pub fn new(config: &'a WebAuthnConfiguration) -> Self {
+        Self { config }
+    }
+
+    /// Parse the client data for a ceremony, checking it came from one of our
+    /// configured origins. The caller must then check the challenge.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_data_json` - The raw client data JSON from the client
+    /// * `ceremony` - The ceremony the client data is expected to be for
+    /// ---
+    pub fn client_data(
+        &self,
+        client_data_json: &[u8],
+        ceremony: Ceremony,
+    ) -> Result<ClientData, BackendError> {
+        let client_data = ClientData::parse(client_data_json, ceremony)?;
+
+        if !self.config.origins.contains(&client_data.origin) {
+            return Err(BackendError::WebAuthn(format!(
+                "origin is not allowed: {}",
+                client_data.origin
+            )));
+        }
+
+        Ok(client_data)
+    }
+
+    /// Check the authenticator data is scoped to our relying party id and the
+    /// user was present.
+    fn check_authenticator_data(
+        &self,
+        authenticator_data: &AuthenticatorData,
+    ) -> Result<(), BackendError> {
+        let rp_id_hash: [u8; 32] = Sha256::digest(self.config.rp_id.as_bytes()).into();
+        if authenticator_data.rp_id_hash != rp_id_hash {
+            return Err(BackendError::WebAuthn("relying party id does not match".to_string()));
+        }
+
+        if !authenticator_data.is_user_present() {
+            return Err(BackendError::WebAuthn("user was not present".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Verify the attestation object from a registration ceremony, returning
+    /// the new credential and the authenticator signature counter.
+    ///
+    /// # Parameters
+    ///
+    /// * `attestation_object` - The CBOR attestation object from the client
+    /// ---
+    pub fn verify_registration(
+        &self,
+        attestation_object: &[u8],
+    ) -> Result<(AttestedCredential, u32), BackendError> {
+        let invalid = || BackendError::WebAuthn("attestation object is not valid".to_string());
+
+        let attestation_object: Value =
+            ciborium::from_reader(attestation_object).map_err(|_| invalid())?;
+        let entries = attestation_object.as_map().ok_or_else(invalid)?;
+        let entry = |name: &str| {
+            entries
+                .iter()
+                .find_map(|(key, value)| (key.as_text() == Some(name)).then_some(value))
+        };
+
+        // We ask for no attestation, so the client strips any attestation statement
+        if entry("fmt").and_then(Value::as_text) != Some("none") {
+            return Err(BackendError::WebAuthn(
+                "only none attestation is supported".to_string(),
+            ));
+        }
+
+        let authenticator_data = entry("authData")
+            .and_then(Value::as_bytes)
+            .ok_or_else(invalid)?;
+        let authenticator_data = AuthenticatorData::parse(authenticator_data)?;
+        self.check_authenticator_data(&authenticator_data)?;
+
+        let credential = authenticator_data
+            .attested_credential
+            .ok_or_else(invalid)?;
+
+        Ok((credential, authenticator_data.sign_count))
+    }
+
+    /// Verify the assertion from an authentication ceremony against the
+    /// stored credential public key, returning the authenticator data.
+    ///
+    /// # Parameters
+    ///
+    /// * `public_key` - The stored SEC1 credential public key
+    /// * `client_data_json` - The raw client data JSON from the client
+    /// * `authenticator_data` - The raw authenticator data from the client
+    /// * `signature` - The DER encoded ECDSA signature from the client
+    /// * `require_user_verification` - Must the user be verified, such as when
+    ///   the passkey is the only factor
+    /// ---
+    pub fn verify_assertion(
+        &self,
+        public_key: &[u8],
+        client_data_json: &[u8],
+        authenticator_data: &[u8],
+        signature: &[u8],
+        require_user_verification: bool,
+    ) -> Result<AuthenticatorData, BackendError> {
+        let parsed_authenticator_data = AuthenticatorData::parse(authenticator_data)?;
+        self.check_authenticator_data(&parsed_authenticator_data)?;
+
+        if require_user_verification && !parsed_authenticator_data.is_user_verified() {
+            return Err(BackendError::WebAuthn("user was not verified".to_string()));
+        }
+
+        // The authenticator signs its data followed by the client data hash
+        let message = [
+            authenticator_data,
+            Sha256::digest(client_data_json).as_slice(),
+        ]
+        .concat();
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| BackendError::WebAuthn("public key is not valid".to_string()))?;
+        let signature = Signature::from_der(signature)
+            .map_err(|_| BackendError::WebAuthn("signature is not valid".to_string()))?;
+        verifying_key
+            .verify(&message, &signature)
+            .map_err(|_| BackendError::WebAuthn("signature does not match".to_string()))?;
+
+        Ok(parsed_authenticator_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::SigningKey;
+
+    use crate::domain;
+
+    use super::authenticator_data::{USER_PRESENT, USER_VERIFIED};
+
+    use super::*;
+
+    const ORIGIN: &str = "https://ledger.example.com";
+
+    fn config() -> WebAuthnConfiguration {
+        WebAuthnConfiguration {
+            rp_id: "ledger.example.com".to_string(),
+            rp_name: "Ledger".to_string(),
+            origins: vec![ORIGIN.to_string()],
+        }
+    }
+
+    fn client_data_json(ceremony: Ceremony, challenge: &domain::OneTimeToken) -> Vec<u8> {
+        serde_json::json!({
+            "type": ceremony.client_data_type(),
+            "challenge": URL_SAFE_NO_PAD.encode(challenge.as_ref()),
+            "origin": ORIGIN,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn authenticator_data(rp_id: &str, flags: u8, sign_count: u32) -> Vec<u8> {
+        [
+            Sha256::digest(rp_id.as_bytes()).as_slice(),
+            &[flags],
+            &sign_count.to_be_bytes(),
+        ]
+        .concat()
+    }
+
+    fn attestation_object(signing_key: &SigningKey, credential_id: &[u8]) -> Vec<u8> {
+        let point = signing_key.verifying_key().to_encoded_point(false);
+        let cose_key = Value::Map(vec![
+            (Value::from(1), Value::from(2)),
+            (Value::from(3), Value::from(-7)),
+            (Value::from(-1), Value::from(1)),
+            (Value::from(-2), Value::Bytes(point.x().unwrap().to_vec())),
+            (Value::from(-3), Value::Bytes(point.y().unwrap().to_vec())),
+        ]);
+        let mut cose_key_bytes = Vec::new();
+        ciborium::into_writer(&cose_key, &mut cose_key_bytes).unwrap();
+
+        let authenticator_data = [
+            authenticator_data(&config().rp_id, USER_PRESENT | 0x40, 0).as_slice(),
+            &[0u8; 16],
+            &(credential_id.len() as u16).to_be_bytes(),
+            credential_id,
+            &cose_key_bytes,
+        ]
+        .concat();
+
+        let attestation_object = Value::Map(vec![
+            (Value::from("fmt"), Value::from("none")),
+            (Value::from("attStmt"), Value::Map(vec![])),
+            (Value::from("authData"), Value::Bytes(authenticator_data)),
+        ]);
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&attestation_object, &mut bytes).unwrap();
+
+        bytes
+    }
+
+    #[test]
+    fn registration_returns_credential() -> Result<(), BackendError> {
+        let config = config();
+        let relying_party = RelyingParty::new(&config);
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+
+        let (credential, sign_count) =
+            relying_party.verify_registration(&attestation_object(&signing_key, b"credential"))?;
+
+        assert_eq!(credential.credential_id, b"credential");
+        assert_eq!(sign_count, 0);
+        assert_eq!(
+            credential.public_key,
+            signing_key.verifying_key().to_encoded_point(false).as_bytes()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn client_data_from_other_origin_is_rejected() {
+        let mut config = config();
+        config.origins = vec!["https://other.example.com".to_string()];
+        let relying_party = RelyingParty::new(&config);
+        let challenge = domain::OneTimeToken::generate();
+
+        let client_data = relying_party.client_data(
+            &client_data_json(Ceremony::Authentication, &challenge),
+            Ceremony::Authentication,
+        );
+
+        assert!(client_data.is_err());
+    }
+
+    #[test]
+    fn assertion_signature_is_verified() -> Result<(), BackendError> {
+        let config = config();
+        let relying_party = RelyingParty::new(&config);
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+
+        let challenge = domain::OneTimeToken::generate();
+        let client_data_json = client_data_json(Ceremony::Authentication, &challenge);
+        let authenticator_data =
+            authenticator_data(&config.rp_id, USER_PRESENT | USER_VERIFIED, 7);
+        let message = [
+            authenticator_data.as_slice(),
+            Sha256::digest(&client_data_json).as_slice(),
+        ]
+        .concat();
+        let signature: Signature = signing_key.sign(&message);
+        let signature = signature.to_der();
+
+        let verified = relying_party.verify_assertion(
+            public_key.as_bytes(),
+            &client_data_json,
+            &authenticator_data,
+            signature.as_bytes(),
+            true,
+        )?;
+        assert_eq!(verified.sign_count, 7);
+
+        // A signature over different client data does not verify
+        let other_client_data_json = self::client_data_json(
+            Ceremony::Authentication,
+            &domain::OneTimeToken::generate(),
+        );
+        let tampered = relying_party.verify_assertion(
+            public_key.as_bytes(),
+            &other_client_data_json,
+            &authenticator_data,
+            signature.as_bytes(),
+            true,
+        );
+        assert!(tampered.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn passwordless_assertion_requires_user_verification() {
+        let config = config();
+        let relying_party = RelyingParty::new(&config);
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+
+        let challenge = domain::OneTimeToken::generate();
+        let client_data_json = client_data_json(Ceremony::Authentication, &challenge);
+        let authenticator_data = authenticator_data(&config.rp_id, USER_PRESENT, 1);
+        let message = [
+            authenticator_data.as_slice(),
+            Sha256::digest(&client_data_json).as_slice(),
+        ]
+        .concat();
+        let signature: Signature = signing_key.sign(&message);
+
+        let verified = relying_party.verify_assertion(
+            public_key.as_bytes(),
+            &client_data_json,
+            &authenticator_data,
+            signature.to_der().as_bytes(),
+            true,
+        );
+
+        assert!(verified.is_err());
+    }
+}
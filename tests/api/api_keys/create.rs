@@ -0,0 +1,245 @@
+//-- ./tests/api/api_keys/create.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the API keys create endpoint
+
+use chrono::{Duration, Utc};
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::logins_client::LoginsClient;
+use authentication_microservice::rpc::proto::sessions_client::SessionsClient;
+use authentication_microservice::rpc::proto::users_client::UsersClient;
+use authentication_microservice::rpc::proto::{
+    ApiKeysCreateRequest, ApiKeysIndexRequest, LoginsIndexRequest,
+    SessionsRevokeUserRequest, UserIndexRequest,
+};
+
+use crate::helpers;
+
+use super::{active_user, api_key_request, user_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn created_key_authenticates_requests(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+    let api_key = response_message.api_key.unwrap();
+
+    // API keys without scopes can call the endpoints their user can
+    let request = api_key_request(
+        SessionsRevokeUserRequest {
+            user_id: random_user.id.to_string(),
+        },
+        &response_message.key,
+    )?;
+    let revoke_response = SessionsClient::new(tonic_server.clone().client_channel().await?)
+        .revoke_user(request)
+        .await;
+
+    let request = user_request(
+        ApiKeysIndexRequest {
+            limit: 10,
+            offset: 0,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let index_response_message = tonic_client.api_keys().index(request).await?.into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.key.starts_with(&format!("ak_{}_", api_key.prefix)));
+    assert!(api_key.scopes.is_empty());
+    assert!(api_key.expires_on.is_none());
+    assert!(revoke_response.is_ok());
+    assert_eq!(index_response_message.api_keys, vec![api_key]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn scoped_key_only_calls_granted_services(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::Admin, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let expires_on = Utc::now() + Duration::days(30);
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Login Report".to_string(),
+            scopes: vec!["logins".to_string()],
+            expires_on: Some(expires_on.to_rfc3339()),
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+
+    //-- Execute Test (Act)
+    let request = api_key_request(
+        LoginsIndexRequest {
+            limit: 10,
+            offset: 0,
+            outcome: None,
+        },
+        &response_message.key,
+    )?;
+    let logins_response = LoginsClient::new(tonic_server.clone().client_channel().await?)
+        .index(request)
+        .await;
+
+    let request = api_key_request(
+        UserIndexRequest {
+            limit: 10,
+            offset: 0,
+        },
+        &response_message.key,
+    )?;
+    let users_response = UsersClient::new(tonic_server.clone().client_channel().await?)
+        .index(request)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.api_key.unwrap().scopes, vec!["logins"]);
+    assert!(logins_response.is_ok());
+    assert_eq!(users_response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn user_key_cannot_read_every_login(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Scopes limit the API key, but never grant more than the user has
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Login Report".to_string(),
+            scopes: vec!["logins".to_string()],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+
+    //-- Execute Test (Act)
+    let request = api_key_request(
+        LoginsIndexRequest {
+            limit: 10,
+            offset: 0,
+            outcome: None,
+        },
+        &response_message.key,
+    )?;
+    let response = LoginsClient::new(tonic_server.clone().client_channel().await?)
+        .index(request)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn api_key_cannot_create_api_keys(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+
+    //-- Execute Test (Act)
+    let request = api_key_request(
+        ApiKeysCreateRequest {
+            name: "Escalated Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &response_message.key,
+    )?;
+    let response = tonic_client.api_keys().create(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn past_expiry_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: Some((Utc::now() - Duration::days(1)).to_rfc3339()),
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response = tonic_client.api_keys().create(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
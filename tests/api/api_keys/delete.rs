@@ -0,0 +1,125 @@
+//-- ./tests/api/api_keys/delete.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the API keys delete endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::sessions_client::SessionsClient;
+use authentication_microservice::rpc::proto::{
+    ApiKeysCreateRequest, ApiKeysDeleteRequest, ApiKeysReadRequest, SessionsRevokeUserRequest,
+};
+
+use crate::helpers;
+
+use super::{active_user, api_key_request, user_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn deleted_key_stops_working(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+    let api_key_id = response_message.api_key.unwrap().id;
+
+    //-- Execute Test (Act)
+    let request = user_request(
+        ApiKeysDeleteRequest {
+            id: api_key_id.clone(),
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let delete_response_message = tonic_client.api_keys().delete(request).await?.into_inner();
+
+    let request = api_key_request(
+        SessionsRevokeUserRequest {
+            user_id: random_user.id.to_string(),
+        },
+        &response_message.key,
+    )?;
+    let revoke_response = SessionsClient::new(tonic_server.clone().client_channel().await?)
+        .revoke_user(request)
+        .await;
+
+    let request = user_request(ApiKeysReadRequest { id: api_key_id }, &random_user, &tonic_server)?;
+    let read_response = tonic_client.api_keys().read(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(delete_response_message.rows_affected, 1);
+    assert_eq!(revoke_response.unwrap_err().code(), Code::Unauthenticated);
+    assert!(read_response.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn cannot_delete_other_users_key(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_user = active_user(domain::UserRole::User, &database).await?;
+    let other_user = active_user(domain::UserRole::User, &database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let response_message = tonic_client.api_keys().create(request).await?.into_inner();
+
+    //-- Execute Test (Act)
+    let request = user_request(
+        ApiKeysDeleteRequest {
+            id: response_message.api_key.unwrap().id,
+        },
+        &other_user,
+        &tonic_server,
+    )?;
+    let delete_response_message = tonic_client.api_keys().delete(request).await?.into_inner();
+
+    let request = api_key_request(
+        SessionsRevokeUserRequest {
+            user_id: random_user.id.to_string(),
+        },
+        &response_message.key,
+    )?;
+    let revoke_response = SessionsClient::new(tonic_server.clone().client_channel().await?)
+        .revoke_user(request)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(delete_response_message.rows_affected, 0);
+    assert!(revoke_response.is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//-- ./tests/api/api_keys/mod.rs
+
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+mod create;
+mod delete;
+
+pub type Error = Box<dyn std::error::Error>;
+
+/// Build a request with an access token for the user, as API keys endpoints act
+/// on the user in the access token
+pub fn user_request<T>(
+    message: T,
+    user: &database::Users,
+    tonic_server: &helpers::TonicServer,
+) -> Result<tonic::Request<T>, Error> {
+    let token_keys = &tonic_server.token_keys;
+    let access_token =
+        domain::AccessToken::new(token_keys, &tonic_server.config.application, user, &[])?;
+
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", access_token.to_string().parse()?);
+
+    Ok(request)
+}
+
+/// Build a request authenticated with an API key instead of an access token
+pub fn api_key_request<T>(message: T, api_key: &str) -> Result<tonic::Request<T>, Error> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().append("api_key", api_key.parse()?);
+
+    Ok(request)
+}
+
+/// Insert an active user with the role into the database
+pub async fn active_user(
+    role: domain::UserRole,
+    database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<database::Users, Error> {
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = role;
+    random_user.is_active = true;
+
+    Ok(random_user.insert(database).await?)
+}
@@ -0,0 +1,158 @@
+//-- ./tests/api/authentication/client_credentials.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the client credentials grant for service
+//! accounts
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::logins_client::LoginsClient;
+use authentication_microservice::rpc::proto::users_client::UsersClient;
+use authentication_microservice::rpc::proto::{
+    ClientCredentialsRequest, LoginsIndexRequest, UserIndexRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Insert a service account granted the logins and users scopes
+async fn service_account(
+    database: &Pool<Postgres>,
+) -> Result<(database::ServiceAccounts, domain::OneTimeToken)> {
+    let client_secret = domain::OneTimeToken::generate();
+    let service_account = database::ServiceAccounts::new(
+        "Statement Importer",
+        &[domain::ServiceScope::Logins, domain::ServiceScope::Users],
+        &client_secret,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(database)
+    .await?;
+
+    Ok((service_account, client_secret))
+}
+
+#[sqlx::test]
+async fn access_token_is_limited_to_scope(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (service_account, client_secret) = service_account(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: Some("users".to_string()),
+        })
+        .await?
+        .into_inner();
+
+    let mut users_request = tonic::Request::new(UserIndexRequest {
+        limit: 10,
+        offset: 0,
+    });
+    users_request
+        .metadata_mut()
+        .append("access_token", response_message.access_token.parse()?);
+    let users_response = UsersClient::new(tonic_server.clone().client_channel().await?)
+        .index(users_request)
+        .await;
+
+    let mut logins_request = tonic::Request::new(LoginsIndexRequest {
+        limit: 10,
+        offset: 0,
+        outcome: None,
+    });
+    logins_request
+        .metadata_mut()
+        .append("access_token", response_message.access_token.parse()?);
+    let logins_response = LoginsClient::new(tonic_server.clone().client_channel().await?)
+        .index(logins_request)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.scope, "users");
+    assert_eq!(
+        response_message.expires_in,
+        tonic_server.config.application.access_token_seconds
+    );
+
+    let token_claim = domain::TokenClaim::from_token(
+        &response_message.access_token,
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+    )?;
+    assert_eq!(token_claim.sub, service_account.id.to_string());
+    assert!(token_claim.is_service_account());
+
+    assert!(users_response.is_ok());
+    assert_eq!(logins_response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_secret_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (service_account, _client_secret) = service_account(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: domain::OneTimeToken::generate().to_string(),
+            scope: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn ungranted_scope_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (service_account, client_secret) = service_account(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: Some("users signing_keys".to_string()),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
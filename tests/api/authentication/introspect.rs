@@ -0,0 +1,277 @@
+//-- ./tests/api/authentication/introspect.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    ClientCredentialsRequest, IntrospectRequest, LoginRequest, LogoutRequest, RevokeRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::admin_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn active_tokens_return_claims(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token and Refresh Token
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let access_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest {
+                token: login_response_message.access_token.unwrap(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    let refresh_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest {
+                token: login_response_message.refresh_token.unwrap(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(access_response_message.active);
+    assert_eq!(access_response_message.sub, Some(random_user.id.to_string()));
+    assert_eq!(access_response_message.role, Some(random_user.role.to_string()));
+    assert_eq!(access_response_message.token_type.as_deref(), Some("Access"));
+    assert!(access_response_message.exp.is_some());
+
+    assert!(refresh_response_message.active);
+    assert_eq!(refresh_response_message.sub, Some(random_user.id.to_string()));
+    assert_eq!(refresh_response_message.token_type.as_deref(), Some("Refresh"));
+    assert!(
+        refresh_response_message.exp.unwrap() > access_response_message.exp.unwrap()
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn logged_out_tokens_are_not_active(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token and Refresh Token, then logout
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+    let access_token = login_response_message.access_token.unwrap();
+    let refresh_token = login_response_message.refresh_token.unwrap();
+
+    tonic_client
+        .authentication()
+        .logout(LogoutRequest {
+            refresh_token: refresh_token.to_string(),
+        })
+        .await?;
+
+    //-- Execute Test (Act)
+    let access_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest {
+                token: access_token,
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    let refresh_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest {
+                token: refresh_token,
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // Inactive tokens reveal nothing else
+    assert!(!access_response_message.active);
+    assert_eq!(access_response_message.sub, None);
+    assert!(!refresh_response_message.active);
+    assert_eq!(refresh_response_message.token_type, None);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_token_is_not_active(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest {
+                token: "not-a-token".to_string(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(!response_message.active);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn requester_must_be_admin_or_service_account(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // A service account granted the tokens scope
+    let client_secret = domain::OneTimeToken::generate();
+    let service_account = database::ServiceAccounts::new(
+        "Resource Server",
+        &[domain::ServiceScope::Tokens],
+        &client_secret,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(&database)
+    .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+    let access_token = login_response_message.access_token.unwrap();
+
+    let service_access_token = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: None,
+        })
+        .await?
+        .into_inner()
+        .access_token;
+
+    //-- Execute Test (Act)
+    let unauthenticated_introspect = tonic_client
+        .authentication()
+        .introspect(IntrospectRequest {
+            token: access_token.to_string(),
+        })
+        .await;
+
+    let unauthenticated_revoke = tonic_client
+        .authentication()
+        .revoke(RevokeRequest {
+            token: access_token.to_string(),
+        })
+        .await;
+
+    // Users cannot introspect tokens, even their own
+    let mut user_request = tonic::Request::new(IntrospectRequest {
+        token: access_token.to_string(),
+    });
+    user_request
+        .metadata_mut()
+        .append("access_token", access_token.parse()?);
+    let user_introspect = tonic_client.authentication().introspect(user_request).await;
+
+    let mut service_request = tonic::Request::new(IntrospectRequest {
+        token: access_token.to_string(),
+    });
+    service_request
+        .metadata_mut()
+        .append("access_token", service_access_token.parse()?);
+    let service_response_message = tonic_client
+        .authentication()
+        .introspect(service_request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(unauthenticated_introspect.unwrap_err().code(), Code::Unauthenticated);
+    assert_eq!(unauthenticated_revoke.unwrap_err().code(), Code::Unauthenticated);
+    assert_eq!(user_introspect.unwrap_err().code(), Code::Unauthenticated);
+    assert!(service_response_message.active);
+
+    Ok(())
+}
@@ -32,7 +32,8 @@ async fn returns_access_refresh_tokens(database: Pool<Postgres>) -> Result<()> {
     //-- 1. Setup and Fixtures (Arrange)
     // Generate random user data and insert into database for testing
     let random_password = helpers::mocks::password()?;
-    let random_user = helpers::mocks::users(&random_password)?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
     let _database_record = random_user.insert(&database).await?;
 
     // Spawn Tonic test server
@@ -45,6 +46,7 @@ async fn returns_access_refresh_tokens(database: Pool<Postgres>) -> Result<()> {
     let request_message = LoginRequest {
         email: random_user.email.to_string(),
         password: random_password.to_string(),
+        remember_me_seconds: None,
     };
 
     // Build tonic request
@@ -56,15 +58,23 @@ async fn returns_access_refresh_tokens(database: Pool<Postgres>) -> Result<()> {
 
     //-- 3. Checks (Assertions)
     // Get token secret
-    let token_secret = &tonic_server.config.application.token_secret;
-    let token_secret = token_secret.to_owned();
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
 
     // Build Token Claims from token responses
     let access_token_claim =
-        domain::TokenClaim::from_token(&response_message.access_token, &token_secret)?;
+        domain::TokenClaim::from_token(
+            response_message.access_token.as_deref().unwrap(),
+            token_keys,
+            app_config,
+        )?;
 
     let refresh_token_claim =
-        domain::TokenClaim::from_token(&response_message.refresh_token, &token_secret)?;
+        domain::TokenClaim::from_token(
+            response_message.refresh_token.as_deref().unwrap(),
+            token_keys,
+            app_config,
+        )?;
 
     // Confirm User IDs (uuids) are the same
     assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
@@ -76,7 +86,8 @@ async fn returns_access_refresh_tokens(database: Pool<Postgres>) -> Result<()> {
 
     // Confirm Login is in database
     let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
-    assert_eq!(random_user.id, logins[0].user_id);
+    assert_eq!(Some(random_user.id), logins[0].user_id);
+    assert!(logins[0].login_ip.unwrap().is_loopback());
 
     // Confirm Session is in the database
     let sessions = database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
@@ -99,6 +110,7 @@ async fn default_user_login(database: Pool<Postgres>) -> Result<()> {
         role: domain::UserRole::Admin, 
         is_active: true, 
         is_verified: true, 
+        is_pending: false, 
         created_on: DateTime::parse_from_rfc3339("2019-10-17T00:00:00.000000Z")?.with_timezone(&Utc)
     };
 
@@ -112,6 +124,7 @@ async fn default_user_login(database: Pool<Postgres>) -> Result<()> {
     let request_message = LoginRequest {
         email: default_user.email.to_string(),
         password: default_password,
+        remember_me_seconds: None,
     };
 
     // Build tonic request
@@ -123,15 +136,23 @@ async fn default_user_login(database: Pool<Postgres>) -> Result<()> {
 
     //-- 3. Checks (Assertions)
     // Get token secret
-    let token_secret = &tonic_server.config.application.token_secret;
-    let token_secret = token_secret.to_owned();
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
 
     // Build Token Claims from token responses
     let access_token_claim =
-        domain::TokenClaim::from_token(&response_message.access_token, &token_secret)?;
+        domain::TokenClaim::from_token(
+            response_message.access_token.as_deref().unwrap(),
+            token_keys,
+            app_config,
+        )?;
 
     let refresh_token_claim =
-        domain::TokenClaim::from_token(&response_message.refresh_token, &token_secret)?;
+        domain::TokenClaim::from_token(
+            response_message.refresh_token.as_deref().unwrap(),
+            token_keys,
+            app_config,
+        )?;
 
     // Confirm User IDs (uuids) are the same
     assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, default_user.id);
@@ -143,7 +164,7 @@ async fn default_user_login(database: Pool<Postgres>) -> Result<()> {
 
     // Confirm Login is in database
     let logins = database::Logins::index_user(&default_user.id, &10, &0, &database).await?;
-    assert_eq!(default_user.id, logins[0].user_id);
+    assert_eq!(Some(default_user.id), logins[0].user_id);
 
     // Confirm Session is in the database
     let sessions = database::Sessions::index_from_user_id(&default_user.id, &10, &0, &database).await?;
@@ -158,7 +179,8 @@ async fn incorrect_password_returns_error(database: Pool<Postgres>) -> Result<()
     //-- 1. Setup and Fixtures (Arrange)
     // Generate random user data and insert into database for testing
     let random_password = helpers::mocks::password()?;
-    let random_user = helpers::mocks::users(&random_password)?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
     let _database_record = random_user.insert(&database).await?;
 
     // Generate an incorrect password
@@ -176,6 +198,7 @@ async fn incorrect_password_returns_error(database: Pool<Postgres>) -> Result<()
     let request_message = LoginRequest {
         email: random_user.email.to_string(),
         password: incorrect_password,
+        remember_me_seconds: None,
     };
 
     // Build tonic request
@@ -201,7 +224,8 @@ async fn incorrect_email_returns_error(database: Pool<Postgres>) -> Result<()> {
     //-- Setup and Fixtures (Arrange)
     // Generate random user data and insert into database for testing
     let random_password = helpers::mocks::password()?;
-    let random_user = helpers::mocks::users(&random_password)?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
     let _database_record = random_user.insert(&database).await?;
 
     // Generate an incorrect password
@@ -218,6 +242,7 @@ async fn incorrect_email_returns_error(database: Pool<Postgres>) -> Result<()> {
     let request_message = LoginRequest {
         email: incorrect_email,
         password: random_password,
+        remember_me_seconds: None,
     };
 
     // Build tonic request
@@ -236,3 +261,184 @@ async fn incorrect_email_returns_error(database: Pool<Postgres>) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn inactive_user_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random inactive user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = false;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    // Build tonic request message
+    let request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password,
+        remember_me_seconds: None,
+    };
+
+    // Send tonic client request to server
+    let response = tonic_client.authentication().login(request_message).await.unwrap_err();
+
+    //-- Checks (Assertions)
+    // Confirm Tonic response status code
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    // Confirm no Session was added to the database
+    let sessions = database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
+    assert!(sessions.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn refresh_token_is_stored_hashed(database: Pool<Postgres>) -> Result<()> {
+    //-- 1. Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- 2. Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- 3. Checks (Assertions)
+    let refresh_token = response_message.refresh_token.unwrap();
+
+    // The token itself is not in the database
+    let sessions =
+        database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(sessions.len(), 1);
+    assert_ne!(sessions[0].refresh_token_hash, refresh_token);
+
+    // The session is found by the keyed hash of the token
+    let session = database::Sessions::from_token(
+        &refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert_eq!(session, sessions[0]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn remember_me_extends_refresh_token(database: Pool<Postgres>) -> Result<()> {
+    //-- 1. Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let app_config = &tonic_server.config.application;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- 2. Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: Some(app_config.remember_me_max_seconds),
+        })
+        .await?
+        .into_inner();
+
+    //-- 3. Checks (Assertions)
+    let token_keys = &tonic_server.token_keys;
+    let access_token_claim = domain::TokenClaim::from_token(
+        response_message.access_token.as_deref().unwrap(),
+        token_keys,
+        app_config,
+    )?;
+    let refresh_token_claim = domain::TokenClaim::from_token(
+        response_message.refresh_token.as_deref().unwrap(),
+        token_keys,
+        app_config,
+    )?;
+
+    // Only the Refresh Token lives longer
+    assert_eq!(
+        access_token_claim.exp - access_token_claim.iat,
+        app_config.access_token_seconds
+    );
+    assert_eq!(
+        refresh_token_claim.exp - refresh_token_claim.iat,
+        app_config.remember_me_max_seconds
+    );
+    assert_eq!(refresh_token_claim.aud, app_config.token_audience);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn remember_me_out_of_bounds_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- 1. Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let app_config = tonic_server.config.application.clone();
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- 2. Execute Test (Act)
+    // Longer than allowed, or shorter than the default lifetime
+    for remember_me_seconds in [
+        app_config.remember_me_max_seconds + 1,
+        app_config.refresh_token_seconds - 1,
+    ] {
+        let response = tonic_client
+            .authentication()
+            .login(LoginRequest {
+                email: random_user.email.to_string(),
+                password: random_password.to_string(),
+                remember_me_seconds: Some(remember_me_seconds),
+            })
+            .await
+            .unwrap_err();
+
+        //-- 3. Checks (Assertions)
+        assert_eq!(response.code(), Code::InvalidArgument);
+    }
+
+    let sessions =
+        database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
+    assert!(sessions.is_empty());
+
+    Ok(())
+}
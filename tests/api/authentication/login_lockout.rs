@@ -0,0 +1,270 @@
+//-- ./tests/api/authentication/login_lockout.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the failed login delays and lockouts
+
+use std::time::{Duration, Instant};
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::configuration::Configuration;
+use authentication_microservice::{database, domain};
+use authentication_microservice::rpc::proto::{LoginMfaRequest, LoginRequest};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Parse the configuration with a low lockout threshold and no delays, so
+/// tests do not have to wait on failed attempts
+fn lockout_config() -> Result<Configuration> {
+    let mut config = Configuration::parse()?;
+    config.lockout.account_threshold = 3;
+    config.lockout.ip_threshold = 10;
+    config.lockout.delay_base_millis = 0;
+
+    Ok(config)
+}
+
+/// Build a login request message
+fn login_request(email: &str, password: &str) -> LoginRequest {
+    LoginRequest {
+        email: email.to_string(),
+        password: password.to_string(),
+        remember_me_seconds: None,
+    }
+}
+
+#[sqlx::test]
+async fn account_is_locked_after_threshold(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, lockout_config()?).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let email = random_user.email.to_string();
+    for _ in 0..3 {
+        let response = tonic_client
+            .authentication()
+            .login(login_request(&email, "wrong-password"))
+            .await;
+        assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+    }
+
+    //-- Execute Test (Act)
+    // Even the correct password is rejected while the account is locked
+    let response = tonic_client
+        .authentication()
+        .login(login_request(&email, &random_password))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::ResourceExhausted);
+
+    // Every attempt is recorded as a failed login
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    let outcomes: Vec<domain::LoginOutcome> = logins.iter().map(|login| login.outcome).collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            domain::LoginOutcome::BadPassword,
+            domain::LoginOutcome::BadPassword,
+            domain::LoginOutcome::BadPassword,
+            domain::LoginOutcome::Locked,
+        ]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn successful_login_clears_failed_attempts(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, lockout_config()?).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let email = random_user.email.to_string();
+    for _ in 0..2 {
+        let _ = tonic_client
+            .authentication()
+            .login(login_request(&email, "wrong-password"))
+            .await;
+    }
+    tonic_client
+        .authentication()
+        .login(login_request(&email, &random_password))
+        .await?;
+
+    //-- Execute Test (Act)
+    for _ in 0..2 {
+        let _ = tonic_client
+            .authentication()
+            .login(login_request(&email, "wrong-password"))
+            .await;
+    }
+    let response = tonic_client
+        .authentication()
+        .login(login_request(&email, &random_password))
+        .await;
+
+    //-- Checks (Assertions)
+    assert!(response.is_ok());
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    let is_success = |login: &&database::Logins| login.outcome == domain::LoginOutcome::Success;
+    assert_eq!(logins.iter().filter(is_success).count(), 2);
+    assert_eq!(logins.len(), 6);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn password_does_not_clear_failed_mfa_codes(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, lockout_config()?).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    // Enter the correct password before each MFA code guess
+    let email = random_user.email.to_string();
+    for _ in 0..3 {
+        let mfa_token = tonic_client
+            .authentication()
+            .login(login_request(&email, &random_password))
+            .await?
+            .into_inner()
+            .mfa_token
+            .unwrap();
+        let response = tonic_client
+            .authentication()
+            .login_mfa(LoginMfaRequest {
+                mfa_token,
+                code: "invalid".to_string(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+    }
+
+    let response = tonic_client
+        .authentication()
+        .login(login_request(&email, &random_password))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::ResourceExhausted);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn client_ip_is_locked_after_threshold(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, lockout_config()?).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Guess passwords for unregistered email addresses from the same address
+    for index in 0..tonic_server.config.lockout.ip_threshold {
+        let response = tonic_client
+            .authentication()
+            .login(login_request(&format!("unknown{index}@example.com"), "password"))
+            .await;
+        assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+    }
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login(login_request(random_user.email.as_ref(), &random_password))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::ResourceExhausted);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn failed_attempts_are_delayed(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    let mut config = lockout_config()?;
+    config.lockout.delay_base_millis = 500;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let email = random_user.email.to_string();
+    let _ = tonic_client
+        .authentication()
+        .login(login_request(&email, "wrong-password"))
+        .await;
+
+    //-- Execute Test (Act)
+    let started = Instant::now();
+    tonic_client
+        .authentication()
+        .login(login_request(&email, &random_password))
+        .await?;
+
+    //-- Checks (Assertions)
+    assert!(started.elapsed() >= Duration::from_millis(500));
+
+    Ok(())
+}
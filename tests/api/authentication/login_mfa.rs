@@ -0,0 +1,306 @@
+//-- ./tests/api/authentication/login_mfa.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing logging in users with MFA enabled
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::rpc::proto::{LoginMfaRequest, LoginRequest};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn login_returns_mfa_token_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.access_token, None);
+    assert_eq!(response_message.refresh_token, None);
+
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let mfa_token_claim = domain::TokenClaim::from_token(
+        response_message.mfa_token.as_deref().unwrap(),
+        token_keys,
+        app_config,
+    )?;
+    assert_eq!(Uuid::parse_str(&mfa_token_claim.sub)?, random_user.id);
+    assert_eq!(mfa_token_claim.jty, domain::TokenType::Mfa.to_string());
+
+    // No session or login is recorded until the MFA code is verified
+    let sessions =
+        database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
+    assert!(sessions.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn valid_code_returns_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login_mfa(LoginMfaRequest {
+            mfa_token: login_response_message.mfa_token.unwrap(),
+            code: helpers::mocks::totp_code(&secret),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let access_token_claim =
+        domain::TokenClaim::from_token(&response_message.access_token, token_keys, app_config)?;
+    assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
+    assert_eq!(access_token_claim.jty, domain::TokenType::Access.to_string());
+
+    // Confirm the session and login are in the database
+    let session = database::Sessions::from_token(
+        &response_message.refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert_eq!(session.user_id, random_user.id);
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn reused_code_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mfa_token = domain::MfaToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        tonic_server.config.application.refresh_token_seconds,
+    )?;
+    let request_message = LoginMfaRequest {
+        mfa_token: mfa_token.to_string(),
+        code: helpers::mocks::totp_code(&secret),
+    };
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .login_mfa(request_message.clone())
+        .await?;
+
+    let response = tonic_client
+        .authentication()
+        .login_mfa(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn access_token_is_not_mfa_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let access_token = domain::AccessToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        &[],
+    )?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login_mfa(LoginMfaRequest {
+            mfa_token: access_token.to_string(),
+            code: helpers::mocks::totp_code(&secret),
+        })
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn recovery_code_returns_tokens_once(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user, with a set of Recovery Codes
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    let recovery_codes = domain::RecoveryCode::generate_set();
+    database::RecoveryCodes::replace_user_id(
+        &random_user.id,
+        &recovery_codes,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mfa_token = domain::MfaToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        tonic_server.config.application.refresh_token_seconds,
+    )?;
+    let request_message = LoginMfaRequest {
+        mfa_token: mfa_token.to_string(),
+        code: recovery_codes[0].to_string(),
+    };
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login_mfa(request_message.clone())
+        .await?
+        .into_inner();
+
+    let reused_response = tonic_client
+        .authentication()
+        .login_mfa(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    let session = database::Sessions::from_token(
+        &response_message.refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert_eq!(session.user_id, random_user.id);
+
+    assert_eq!(reused_response.code(), Code::Unauthenticated);
+
+    let remaining =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(remaining, recovery_codes.len() as i64 - 1);
+
+    Ok(())
+}
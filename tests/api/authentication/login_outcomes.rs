@@ -0,0 +1,293 @@
+//-- ./tests/api/authentication/login_outcomes.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the outcome recorded for each login attempt
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    LoginMfaRequest, LoginRequest, LoginsIndexRequest, RefreshRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Get the outcomes of every login recorded in the database
+async fn outcomes(database: &Pool<Postgres>) -> Result<Vec<domain::LoginOutcome>> {
+    let logins = database::Logins::index(&100, &0, None, database).await?;
+
+    Ok(logins.iter().map(|login| login.outcome).collect())
+}
+
+#[sqlx::test]
+async fn unknown_email_is_recorded_without_user(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    let logins = database::Logins::index(&100, &0, None, &database).await?;
+    let login = logins
+        .iter()
+        .find(|login| login.outcome == domain::LoginOutcome::UnknownEmail)
+        .unwrap();
+    assert_eq!(login.user_id, None);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn bad_password_is_recorded(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: "wrong-password".to_string(),
+            remember_me_seconds: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 1);
+    assert_eq!(logins[0].outcome, domain::LoginOutcome::BadPassword);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn inactive_user_is_recorded(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert!(response.is_err());
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 1);
+    assert_eq!(logins[0].outcome, domain::LoginOutcome::Inactive);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn failed_mfa_code_is_recorded(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mfa_token = domain::MfaToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        tonic_server.config.application.refresh_token_seconds,
+    )?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .login_mfa(LoginMfaRequest {
+            mfa_token: mfa_token.to_string(),
+            code: "000000".to_string(),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 1);
+    assert_eq!(logins[0].outcome, domain::LoginOutcome::MfaFailed);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn refresh_is_recorded(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: response.refresh_token.unwrap(),
+        })
+        .await?;
+
+    let invalid = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: "not-a-token".to_string(),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(invalid.unwrap_err().code(), Code::Unauthenticated);
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 2);
+    assert!(logins
+        .iter()
+        .all(|login| login.outcome == domain::LoginOutcome::Success));
+
+    assert!(outcomes(&database)
+        .await?
+        .contains(&domain::LoginOutcome::InvalidToken));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn index_filters_by_outcome(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    for _ in 0..2 {
+        let _ = tonic_client
+            .authentication()
+            .login(LoginRequest {
+                email: random_user.email.to_string(),
+                password: "wrong-password".to_string(),
+                remember_me_seconds: None,
+            })
+            .await;
+    }
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .logins()
+        .index(LoginsIndexRequest {
+            limit: 100,
+            offset: 0,
+            outcome: Some(domain::LoginOutcome::BadPassword.to_string()),
+        })
+        .await?
+        .into_inner();
+
+    let invalid = tonic_client
+        .logins()
+        .index(LoginsIndexRequest {
+            limit: 100,
+            offset: 0,
+            outcome: Some("not_an_outcome".to_string()),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.logins.len(), 2);
+    assert!(response_message
+        .logins
+        .iter()
+        .all(|login| login.outcome == domain::LoginOutcome::BadPassword.to_string()
+            && login.user_id == Some(random_user.id.to_string())));
+
+    assert_eq!(invalid.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
@@ -0,0 +1,391 @@
+//-- ./tests/api/authentication/login_webauthn.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing logging in users with a WebAuthn passkey,
+//! either as a second factor or passwordless
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::rpc::proto::{
+    BeginWebAuthnLoginRequest, FinishWebAuthnLoginRequest, LoginMfaRequest, LoginRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+use crate::mfa::register_passkey;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Build the finish login request from an authenticator assertion
+fn finish_request(
+    mfa_token: Option<String>,
+    assertion: helpers::Assertion,
+) -> FinishWebAuthnLoginRequest {
+    FinishWebAuthnLoginRequest {
+        mfa_token,
+        credential_id: assertion.credential_id,
+        client_data_json: assertion.client_data_json,
+        authenticator_data: assertion.authenticator_data,
+        signature: assertion.signature,
+    }
+}
+
+#[sqlx::test]
+async fn passkey_as_second_factor_returns_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mut authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    // A registered passkey means the password alone is not enough
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+    assert_eq!(login_response_message.access_token, None);
+    let mfa_token = login_response_message.mfa_token.unwrap();
+
+    //-- Execute Test (Act)
+    let options = tonic_client
+        .authentication()
+        .begin_web_authn_login(BeginWebAuthnLoginRequest {
+            mfa_token: Some(mfa_token.to_owned()),
+        })
+        .await?
+        .into_inner();
+
+    // A second factor does not need user verification
+    authenticator.user_verified = false;
+    let assertion = authenticator.assert(&options.challenge);
+    let response_message = tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(Some(mfa_token), assertion))
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(options.allow_credential_ids, vec![authenticator.credential_id.to_owned()]);
+
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let access_token_claim =
+        domain::TokenClaim::from_token(&response_message.access_token, token_keys, app_config)?;
+    assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
+    assert_eq!(access_token_claim.jty, domain::TokenType::Access.to_string());
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    assert_eq!(logins.len(), 1);
+
+    let webauthn_credential =
+        database::WebAuthnCredentials::from_credential_id(&authenticator.credential_id, &database)
+            .await?;
+    assert_eq!(webauthn_credential.sign_count, authenticator.sign_count as i64);
+    assert!(webauthn_credential.last_used_on.is_some());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn passwordless_passkey_returns_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mut authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    //-- Execute Test (Act)
+    let options = tonic_client
+        .authentication()
+        .begin_web_authn_login(BeginWebAuthnLoginRequest { mfa_token: None })
+        .await?
+        .into_inner();
+
+    let assertion = authenticator.assert(&options.challenge);
+    let response_message = tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(None, assertion))
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(options.allow_credential_ids.is_empty());
+
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let access_token_claim =
+        domain::TokenClaim::from_token(&response_message.access_token, token_keys, app_config)?;
+    assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
+
+    let session = database::Sessions::from_token(
+        &response_message.refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert_eq!(session.user_id, random_user.id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn passwordless_requires_user_verification(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mut authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    //-- Execute Test (Act)
+    let options = tonic_client
+        .authentication()
+        .begin_web_authn_login(BeginWebAuthnLoginRequest { mfa_token: None })
+        .await?
+        .into_inner();
+
+    authenticator.user_verified = false;
+    let assertion = authenticator.assert(&options.challenge);
+    let response = tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(None, assertion))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn replayed_assertion_is_rejected(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let mut authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    let options = tonic_client
+        .authentication()
+        .begin_web_authn_login(BeginWebAuthnLoginRequest { mfa_token: None })
+        .await?
+        .into_inner();
+
+    let assertion = authenticator.assert(&options.challenge);
+    let replayed_assertion = helpers::Assertion {
+        credential_id: assertion.credential_id.to_owned(),
+        client_data_json: assertion.client_data_json.to_owned(),
+        authenticator_data: assertion.authenticator_data.to_owned(),
+        signature: assertion.signature.to_owned(),
+    };
+    tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(None, assertion))
+        .await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(None, replayed_assertion))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn other_users_passkey_is_rejected_as_second_factor(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random users and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+    let mut other_user = helpers::mocks::users(&random_password)?;
+    other_user.is_active = true;
+    let other_user = other_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+    let mut other_authenticator = register_passkey(
+        &other_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+    let mfa_token = login_response_message.mfa_token.unwrap();
+
+    //-- Execute Test (Act)
+    let options = tonic_client
+        .authentication()
+        .begin_web_authn_login(BeginWebAuthnLoginRequest {
+            mfa_token: Some(mfa_token.to_owned()),
+        })
+        .await?
+        .into_inner();
+
+    let assertion = other_authenticator.assert(&options.challenge);
+    let response = tonic_client
+        .authentication()
+        .finish_web_authn_login(finish_request(Some(mfa_token), assertion))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn recovery_code_replaces_lost_passkey(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // The user has only a passkey, and its Recovery Codes
+    register_passkey(&random_user, &random_password, &tonic_server, &mut tonic_client).await?;
+    let recovery_codes = domain::RecoveryCode::generate_set();
+    database::RecoveryCodes::replace_user_id(
+        &random_user.id,
+        &recovery_codes,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .login_mfa(LoginMfaRequest {
+            mfa_token: login_response_message.mfa_token.unwrap(),
+            code: recovery_codes[0].to_string(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let access_token_claim =
+        domain::TokenClaim::from_token(&response_message.access_token, token_keys, app_config)?;
+    assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
+
+    let remaining =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(remaining, domain::RECOVERY_CODE_COUNT as i64 - 1);
+
+    Ok(())
+}
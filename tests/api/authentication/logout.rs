@@ -0,0 +1,81 @@
+//-- ./tests/api/authentication/logout.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+
+use authentication_microservice::rpc::proto::{
+    LoginRequest, LogoutRequest, UpdatePasswordRequest,
+};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn logout_revokes_access_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token and Refresh Token
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+    let access_token = login_response_message.access_token.unwrap();
+
+    //-- Execute Test (Act)
+    // Logout using the Refresh Token from the login
+    let logout_request_message = LogoutRequest {
+        refresh_token: login_response_message.refresh_token.unwrap(),
+    };
+    let logout_response_message = tonic_client
+        .authentication()
+        .logout(logout_request_message)
+        .await?
+        .into_inner();
+
+    // Use the Access Token from the login, which has not expired yet
+    let update_password_request_message = UpdatePasswordRequest {
+        email: random_user.email.to_string(),
+        password_original: random_password.to_string(),
+        password_new: helpers::mocks::password()?,
+    };
+    let mut update_password_request =
+        tonic::Request::new(update_password_request_message);
+    update_password_request
+        .metadata_mut()
+        .append("access_token", access_token.parse().unwrap());
+    let response = tonic_client
+        .authentication()
+        .update_password(update_password_request)
+        .await;
+
+    //-- Checks (Assertions)
+    // The Session from the login is revoked
+    assert_eq!(logout_response_message.rows_affected, 1);
+
+    // The Access Token is rejected after logout
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    Ok(())
+}
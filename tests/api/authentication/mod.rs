@@ -1,8 +1,34 @@
 //-- ./tests/api/authentication/mod.rs
 
+mod client_credentials;
+mod introspect;
 mod login;
+mod login_mfa;
+mod login_lockout;
+mod login_outcomes;
+mod login_webauthn;
 mod refresh;
+mod register;
+mod reset_password;
+mod revoke;
 mod update_password;
+mod verify_email;
 mod logout;
 
+use crate::helpers;
+
+/// Build a request with the admin Access Token of the Tonic Server, for the
+/// endpoints only admins and service accounts can call
+pub fn admin_request<T>(
+    message: T,
+    tonic_server: &helpers::TonicServer,
+) -> Result<tonic::Request<T>, Box<dyn std::error::Error>> {
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", tonic_server.access_token.parse()?);
+
+    Ok(request)
+}
+
 
@@ -1,7 +1,8 @@
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 use uuid::Uuid;
 
-use authentication_microservice::domain;
+use authentication_microservice::{database, domain};
 use authentication_microservice::rpc::proto::{LoginRequest, RefreshRequest};
 
 use crate::helpers;
@@ -14,7 +15,8 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     //-- Setup and Fixtures (Arrange)
     // Generate random user data and insert into database for testing
     let random_password = helpers::mocks::password()?;
-    let random_user = helpers::mocks::users(&random_password)?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
     let _database_record = random_user.insert(&database).await?;
 
     // Spawn Tonic test server
@@ -27,6 +29,7 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     let request = tonic::Request::new(LoginRequest {
         email: random_user.email.to_string(),
         password: random_password.to_string(),
+        remember_me_seconds: None,
     });
 
     // Send tonic client request to server
@@ -38,7 +41,7 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     //-- Execute Test (Act)
     // Build tonic request
     let request = tonic::Request::new(RefreshRequest {
-        refresh_token: response.refresh_token,
+        refresh_token: response.refresh_token.unwrap(),
     });
 
     // Send tonic client request to server
@@ -47,15 +50,15 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
 
     //-- Checks (Assertions)
     // Get token secret
-    let token_secret = &tonic_server.config.application.token_secret;
-    let token_secret = token_secret.to_owned();
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
 
     // Build Token Claims
     let access_token_claim =
-        domain::TokenClaim::from_token(&response.access_token, &token_secret)?;
+        domain::TokenClaim::from_token(&response.access_token, token_keys, app_config)?;
 
     let refresh_token_claim =
-        domain::TokenClaim::from_token(&response.refresh_token, &token_secret)?;
+        domain::TokenClaim::from_token(&response.refresh_token, token_keys, app_config)?;
 
     // Confirm User IDs (uuids) are the same
     assert_eq!(
@@ -71,7 +74,206 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     assert_eq!(&access_token_claim.jty, "Access");
     assert_eq!(&refresh_token_claim.jty, "Refresh");
 
-    //TODO: Check database revokes all others
+    Ok(())
+}
+
+/// Log the user in, returning the Refresh Token
+async fn login(
+    tonic_client: &mut helpers::TonicClient,
+    email: &str,
+    password: &str,
+) -> Result<String> {
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: email.to_string(),
+            password: password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    Ok(response.refresh_token.unwrap())
+}
+
+#[sqlx::test]
+async fn refresh_rotates_only_its_family(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Log in on two devices
+    let email = random_user.email.to_string();
+    let first_device = login(&mut tonic_client, &email, &random_password).await?;
+    let second_device = login(&mut tonic_client, &email, &random_password).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: first_device.clone(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // The second device is still logged in
+    tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: second_device,
+        })
+        .await?;
+
+    // The rotated session stays in the family of the first device
+    let first_session = database::Sessions::from_token(
+        &first_device,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    let rotated_session = database::Sessions::from_token(
+        &response.refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert!(!first_session.is_active);
+    assert!(rotated_session.is_active);
+    assert_eq!(rotated_session.family_id, first_session.family_id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn reused_refresh_token_revokes_family(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Log in on two devices, and refresh the first device
+    let email = random_user.email.to_string();
+    let stolen_token = login(&mut tonic_client, &email, &random_password).await?;
+    let other_device = login(&mut tonic_client, &email, &random_password).await?;
+
+    let rotated_token = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: stolen_token.clone(),
+        })
+        .await?
+        .into_inner()
+        .refresh_token;
+
+    //-- Execute Test (Act)
+    // Replay the rotated Refresh Token
+    let response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: stolen_token,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    // The latest Refresh Token in the family is revoked
+    let response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: rotated_token,
+        })
+        .await;
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    // The other device is still logged in
+    tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: other_device,
+        })
+        .await?;
+
+    // The reuse is recorded as a security event
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    let outcomes: Vec<domain::LoginOutcome> =
+        logins.iter().map(|login| login.outcome).collect();
+    assert_eq!(
+        outcomes
+            .iter()
+            .filter(|outcome| **outcome == domain::LoginOutcome::TokenReuse)
+            .count(),
+        1
+    );
+    assert!(outcomes.contains(&domain::LoginOutcome::InvalidToken));
+
+    Ok(())
+}
+#[sqlx::test]
+async fn refresh_keeps_remember_me_lifetime(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let app_config = tonic_server.config.application.clone();
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Log in asking to be remembered
+    let remember_me_seconds = app_config.refresh_token_seconds * 2;
+    let response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: Some(remember_me_seconds),
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: response.refresh_token.unwrap(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let refresh_token_claim = domain::TokenClaim::from_token(
+        &response.refresh_token,
+        &tonic_server.token_keys,
+        &app_config,
+    )?;
+    assert_eq!(
+        refresh_token_claim.exp - refresh_token_claim.iat,
+        remember_me_seconds
+    );
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,209 @@
+//-- ./tests/api/authentication/register.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the register endpoint
+
+use fake::faker::internet::en::SafeEmail;
+use fake::faker::name::en::Name;
+use fake::Fake;
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::configuration::{Configuration, RegistrationPolicy};
+use authentication_microservice::rpc::proto::RegisterRequest;
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Build a random register request message
+fn register_request() -> Result<RegisterRequest> {
+    let random_email: String = SafeEmail().fake();
+    let random_name: String = Name().fake();
+    let random_password = helpers::mocks::password()?;
+
+    Ok(RegisterRequest {
+        email: random_email,
+        password: random_password,
+        name: random_name,
+        invitation_token: None,
+    })
+}
+
+#[sqlx::test]
+async fn open_policy_returns_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server with an open registration policy
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Open;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = register_request()?;
+    let response_message = tonic_client
+        .authentication()
+        .register(request_message.clone())
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let user_id = Uuid::parse_str(&response_message.user_id)?;
+
+    // Confirm the user is in the database, active but not verified
+    let database_record =
+        database::Users::from_user_id(&user_id, &database).await?;
+    assert_eq!(database_record.email.as_ref(), request_message.email);
+    assert_eq!(database_record.role, domain::UserRole::User);
+    assert!(database_record.is_active);
+    assert!(!database_record.is_verified);
+    assert!(response_message.is_active);
+    assert!(!response_message.is_verified);
+
+    // Confirm the access token is for the new user
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
+    let access_token = response_message.access_token.unwrap();
+    let access_token_claim =
+        domain::TokenClaim::from_token(&access_token, token_keys, app_config)?;
+    assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, user_id);
+
+    // Confirm the refresh token session is in the database
+    let refresh_token = response_message.refresh_token.unwrap();
+    let session = database::Sessions::from_token(
+        &refresh_token,
+        &tonic_server.config.jwt.token_hash_key,
+        &database,
+    )
+    .await?;
+    assert_eq!(session.user_id, user_id);
+
+    // Confirm an Email Verification was issued for the new user
+    let email_verification =
+        database::EmailVerifications::latest_from_user_id(&user_id, &database).await?;
+    assert_eq!(email_verification.unwrap().email, database_record.email);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn pending_policy_returns_no_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server with a pending registration policy
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Pending;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .register(register_request()?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let user_id = Uuid::parse_str(&response_message.user_id)?;
+    let database_record =
+        database::Users::from_user_id(&user_id, &database).await?;
+    assert!(!database_record.is_active);
+    assert!(!database_record.is_verified);
+    assert!(database_record.is_pending);
+    assert_eq!(response_message.access_token, None);
+    assert_eq!(response_message.refresh_token, None);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn closed_policy_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server with a closed registration policy
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Closed;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = register_request()?;
+    let response = tonic_client
+        .authentication()
+        .register(request_message.clone())
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::PermissionDenied);
+
+    // Confirm the user was not added to the database
+    let email = domain::EmailAddress::parse(request_message.email)?;
+    assert!(database::Users::from_user_email(&email, &database).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn duplicate_email_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let mut request_message = register_request()?;
+    request_message.email = random_user.email.to_string();
+    let response = tonic_client
+        .authentication()
+        .register(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::AlreadyExists);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn weak_password_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let mut request_message = register_request()?;
+    request_message.password = "password".to_string();
+    let response = tonic_client
+        .authentication()
+        .register(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::InvalidArgument);
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+//-- ./tests/api/authentication/reset_password.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the reset password endpoints
+
+use fake::faker::internet::en::SafeEmail;
+use fake::Fake;
+use secrecy::Secret;
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    ConfirmPasswordResetRequest, LoginRequest, ResetPasswordRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn response_does_not_enumerate_users(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let registered_response = tonic_client
+        .authentication()
+        .reset_password(ResetPasswordRequest {
+            email: random_user.email.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let unknown_email: String = SafeEmail().fake();
+    let unknown_response = tonic_client
+        .authentication()
+        .reset_password(ResetPasswordRequest {
+            email: unknown_email,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // Both responses are the same
+    assert_eq!(registered_response, unknown_response);
+
+    // Only the registered user has a password reset in the database
+    let password_resets: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM password_resets WHERE user_id = $1",
+    )
+    .bind(random_user.id)
+    .fetch_one(&database)
+    .await?;
+    assert_eq!(password_resets, 1);
+
+    // Only the registered user was emailed a reset token
+    let email = tonic_server
+        .email_spool
+        .wait_for_email(random_user.email.as_ref())
+        .await?;
+    assert_eq!(email.subject, "Reset your password");
+    assert!(email.one_time_token().is_some());
+    assert_eq!(tonic_server.email_spool.emails()?.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn confirm_resets_password(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Add a session for the user that should be revoked
+    let mut random_session = helpers::mocks::sessions(&random_user)?;
+    random_session.is_active = true;
+    random_session.insert(&database).await?;
+
+    // Add a password reset for the user
+    let token = domain::OneTimeToken::generate();
+    database::PasswordResets::new(&random_user.id, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let new_password = helpers::mocks::password()?;
+    tonic_client
+        .authentication()
+        .confirm_password_reset(ConfirmPasswordResetRequest {
+            token: token.to_string(),
+            password: new_password.clone(),
+        })
+        .await?;
+
+    //-- Checks (Assertions)
+    // Confirm the password hash was updated
+    let database_record =
+        database::Users::from_user_id(&random_user.id, &database).await?;
+    assert!(database_record
+        .password_hash
+        .verify_password(&Secret::new(new_password.clone()))?);
+
+    // Confirm existing sessions were revoked
+    let session = database::Sessions::from_id(&random_session.id, &database).await?;
+    assert!(!session.is_active);
+
+    // Confirm we can log in with the new password
+    tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: new_password,
+            remember_me_seconds: None,
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn confirm_token_is_single_use(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let _database_record = random_user.insert(&database).await?;
+
+    // Add a password reset for the user
+    let token = domain::OneTimeToken::generate();
+    database::PasswordResets::new(&random_user.id, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request_message = ConfirmPasswordResetRequest {
+        token: token.to_string(),
+        password: helpers::mocks::password()?,
+    };
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .confirm_password_reset(request_message.clone())
+        .await?;
+
+    let response = tonic_client
+        .authentication()
+        .confirm_password_reset(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    Ok(())
+}
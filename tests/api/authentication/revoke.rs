@@ -0,0 +1,181 @@
+//-- ./tests/api/authentication/revoke.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+
+use authentication_microservice::rpc::proto::{
+    IntrospectRequest, LoginRequest, LoginResponse, RevokeRequest,
+};
+
+use crate::helpers;
+
+use super::admin_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Insert an active user and login, returning the tonic server, tonic client
+/// and login response
+async fn login(
+    database: &Pool<Postgres>,
+) -> Result<(helpers::TonicServer, helpers::TonicClient, LoginResponse)> {
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let _database_record = random_user.insert(database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token and Refresh Token
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+
+    Ok((tonic_server, tonic_client, login_response_message))
+}
+
+#[sqlx::test]
+async fn revoked_access_token_is_not_active(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (tonic_server, mut tonic_client, login_response_message) = login(&database).await?;
+    let access_token = login_response_message.access_token.unwrap();
+    let refresh_token = login_response_message.refresh_token.unwrap();
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .revoke(admin_request(
+            RevokeRequest {
+                token: access_token.to_string(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 1);
+
+    // The Access Token is no longer active
+    let introspect_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest { token: access_token },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+    assert!(!introspect_response_message.active);
+
+    // The Refresh Token is still active
+    let introspect_response_message = tonic_client
+        .authentication()
+        .introspect(admin_request(
+            IntrospectRequest { token: refresh_token },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+    assert!(introspect_response_message.active);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoked_refresh_token_revokes_access_token(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (tonic_server, mut tonic_client, login_response_message) = login(&database).await?;
+    let access_token = login_response_message.access_token.unwrap();
+    let refresh_token = login_response_message.refresh_token.unwrap();
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .revoke(admin_request(
+            RevokeRequest {
+                token: refresh_token.to_string(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 1);
+
+    // Both the Refresh Token and the Access Token issued with it are no longer active
+    for token in [refresh_token, access_token] {
+        let introspect_response_message = tonic_client
+            .authentication()
+            .introspect(admin_request(
+                IntrospectRequest { token },
+                &tonic_server,
+            )?)
+            .await?
+            .into_inner();
+        assert!(!introspect_response_message.active);
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoking_inactive_token_returns_zero(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let (tonic_server, mut tonic_client, login_response_message) = login(&database).await?;
+    let access_token = login_response_message.access_token.unwrap();
+
+    // Revoke the Access Token once
+    tonic_client
+        .authentication()
+        .revoke(admin_request(
+            RevokeRequest {
+                token: access_token.to_string(),
+            },
+            &tonic_server,
+        )?)
+        .await?;
+
+    //-- Execute Test (Act)
+    let revoked_again_response_message = tonic_client
+        .authentication()
+        .revoke(admin_request(
+            RevokeRequest { token: access_token },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    let invalid_response_message = tonic_client
+        .authentication()
+        .revoke(admin_request(
+            RevokeRequest {
+                token: "not-a-token".to_string(),
+            },
+            &tonic_server,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(revoked_again_response_message.rows_affected, 0);
+    assert_eq!(invalid_response_message.rows_affected, 0);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // #![allow(unused)] // For beginning only.
 
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 use uuid::Uuid;
 
 use authentication_microservice::domain;
@@ -31,6 +32,7 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     let login_request_message = LoginRequest {
         email: random_user.email.to_string(),
         password: random_password_original.to_string(),
+        remember_me_seconds: None,
     };
     // println!("{request_message:#?}");
 
@@ -60,7 +62,7 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
     // Append access token from login to request
     update_password_request
         .metadata_mut()
-        .append("access_token", login_response_message.access_token.parse().unwrap());
+        .append("access_token", login_response_message.access_token.unwrap().parse().unwrap());
 
     // Send update password request to server
     let response = tonic_client
@@ -71,15 +73,15 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
 
     //-- Checks (Assertions)
     // Get Token Claim Secret before Tonic Client takes ownership of the server instance
-    let token_secret = &tonic_server.clone().config.application.token_secret;
-    let token_secret = token_secret.to_owned();
+    let token_keys = &tonic_server.token_keys;
+    let app_config = &tonic_server.config.application;
 
     // Build Token Claims from token responses
     let access_token_claim =
-        domain::TokenClaim::from_token(&response.access_token, &token_secret)?;
+        domain::TokenClaim::from_token(&response.access_token, token_keys, app_config)?;
 
     let refresh_token_claim =
-        domain::TokenClaim::from_token(&response.refresh_token, &token_secret)?;
+        domain::TokenClaim::from_token(&response.refresh_token, token_keys, app_config)?;
 
     // Confirm User IDs (uuids) are the same
     assert_eq!(Uuid::parse_str(&access_token_claim.sub)?, random_user.id);
@@ -91,3 +93,79 @@ async fn returns_access_refresh_access(database: Pool<Postgres>) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn refresh_token_is_rejected(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_password_original = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password_original)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    random_user.insert(&database).await?;
+
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password_original.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let mut request = tonic::Request::new(UpdatePasswordRequest {
+        email: random_user.email.to_string(),
+        password_original: random_password_original.to_string(),
+        password_new: helpers::mocks::password()?.to_string(),
+    });
+    request
+        .metadata_mut()
+        .append("access_token", login_response_message.refresh_token.unwrap().parse()?);
+    let response = tonic_client.authentication().update_password(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn mfa_token_is_rejected(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_password_original = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password_original)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    random_user.insert(&database).await?;
+
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // The MFA Token returned by Login when the password is correct
+    let mfa_token = domain::MfaToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        tonic_server.config.application.refresh_token_seconds,
+    )?;
+
+    //-- Execute Test (Act)
+    let mut request = tonic::Request::new(UpdatePasswordRequest {
+        email: random_user.email.to_string(),
+        password_original: random_password_original.to_string(),
+        password_new: random_password_original.to_string(),
+    });
+    request
+        .metadata_mut()
+        .append("access_token", mfa_token.to_string().parse()?);
+    let response = tonic_client.authentication().update_password(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
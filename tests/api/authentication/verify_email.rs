@@ -0,0 +1,347 @@
+//-- ./tests/api/authentication/verify_email.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the email verification endpoints
+
+use fake::faker::internet::en::SafeEmail;
+use fake::Fake;
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::configuration::{Configuration, RegistrationPolicy};
+use authentication_microservice::rpc::proto::{
+    SendVerificationEmailRequest, VerifyEmailRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Count the Email Verifications issued to a user
+async fn count_email_verifications(
+    user: &database::Users,
+    database: &Pool<Postgres>,
+) -> Result<i64> {
+    let count = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM email_verifications WHERE user_id = $1",
+    )
+    .bind(user.id)
+    .fetch_one(database)
+    .await?;
+
+    Ok(count)
+}
+
+#[sqlx::test]
+async fn verify_email_sets_verified(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random unverified user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Add an Email Verification for the user
+    let token = domain::OneTimeToken::generate();
+    database::EmailVerifications::new(&random_user, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .verify_email(VerifyEmailRequest {
+            token: token.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.is_verified);
+
+    let database_record =
+        database::Users::from_user_id(&random_user.id, &database).await?;
+    assert!(database_record.is_verified);
+    assert_eq!(database_record.is_active, random_user.is_active);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn verify_email_token_is_single_use(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random unverified user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Add an Email Verification for the user
+    let token = domain::OneTimeToken::generate();
+    database::EmailVerifications::new(&random_user, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request_message = VerifyEmailRequest {
+        token: token.to_string(),
+    };
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .verify_email(request_message.clone())
+        .await?;
+
+    let response = tonic_client
+        .authentication()
+        .verify_email(request_message)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn pending_user_is_activated(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random pending user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = false;
+    random_user.is_verified = false;
+    random_user.is_pending = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Add an Email Verification for the user
+    let token = domain::OneTimeToken::generate();
+    database::EmailVerifications::new(&random_user, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server with a pending registration policy
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Pending;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .verify_email(VerifyEmailRequest {
+            token: token.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.is_active);
+    assert!(response_message.is_verified);
+
+    let database_record =
+        database::Users::from_user_id(&random_user.id, &database).await?;
+    assert!(!database_record.is_pending);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivated_user_is_not_activated(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user an admin has deactivated and insert into database
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = false;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Add an Email Verification for the user, issued before they were deactivated
+    let token = domain::OneTimeToken::generate();
+    database::EmailVerifications::new(&random_user, &token, &helpers::mocks::hash_key()?)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server with a pending registration policy
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Pending;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .authentication()
+        .verify_email(VerifyEmailRequest {
+            token: token.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    tonic_client
+        .authentication()
+        .send_verification_email(SendVerificationEmailRequest {
+            email: random_user.email.to_string(),
+        })
+        .await?;
+
+    //-- Checks (Assertions)
+    assert!(!response_message.is_active);
+    assert!(response_message.is_verified);
+
+    let database_record =
+        database::Users::from_user_id(&random_user.id, &database).await?;
+    assert!(!database_record.is_active);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivated_user_is_not_sent_email(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random unverified user an admin has deactivated and insert into
+    // database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = false;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .send_verification_email(SendVerificationEmailRequest {
+            email: random_user.email.to_string(),
+        })
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(count_email_verifications(&random_user, &database).await?, 0);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn send_verification_email_is_throttled(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random unverified user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request_message = SendVerificationEmailRequest {
+        email: random_user.email.to_string(),
+    };
+
+    //-- Execute Test (Act)
+    let first_response = tonic_client
+        .authentication()
+        .send_verification_email(request_message.clone())
+        .await?
+        .into_inner();
+
+    let second_response = tonic_client
+        .authentication()
+        .send_verification_email(request_message)
+        .await?
+        .into_inner();
+
+    let unknown_email: String = SafeEmail().fake();
+    let unknown_response = tonic_client
+        .authentication()
+        .send_verification_email(SendVerificationEmailRequest {
+            email: unknown_email,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // Responses do not reveal throttling or unknown users
+    assert_eq!(first_response, second_response);
+    assert_eq!(first_response, unknown_response);
+
+    // Only one Email Verification was issued
+    assert_eq!(count_email_verifications(&random_user, &database).await?, 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn emailed_token_verifies_email(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random unverified user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = false;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    tonic_client
+        .authentication()
+        .send_verification_email(SendVerificationEmailRequest {
+            email: random_user.email.to_string(),
+        })
+        .await?;
+
+    // Read the token back out of the email that was sent
+    let email = tonic_server
+        .email_spool
+        .wait_for_email(random_user.email.as_ref())
+        .await?;
+    let token = email.one_time_token().unwrap();
+
+    let response_message = tonic_client
+        .authentication()
+        .verify_email(VerifyEmailRequest { token: token.clone() })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(email.subject, "Verify your email address");
+    assert!(email.html_body.contains(&token));
+    assert!(response_message.is_verified);
+
+    Ok(())
+}
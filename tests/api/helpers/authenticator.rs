@@ -0,0 +1,150 @@
+//-- ./tests/api/helpers/authenticator.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! A software WebAuthn authenticator for testing passkey registration and login
+//!
+//! Creates ES256 credentials with "none" attestation and signs assertions the
+//! same way a platform authenticator would, acting as the browser as well by
+//! building the client data JSON.
+//! ---
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ciborium::Value;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use authentication_microservice::configuration::WebAuthnConfiguration;
+
+/// User present authenticator data flag
+const USER_PRESENT: u8 = 0x01;
+
+/// User verified authenticator data flag
+const USER_VERIFIED: u8 = 0x04;
+
+/// Attested credential data included authenticator data flag
+const ATTESTED_CREDENTIAL: u8 = 0x40;
+
+/// A single credential on a software authenticator
+pub struct Authenticator {
+    pub credential_id: Vec<u8>,
+    pub sign_count: u32,
+    pub user_verified: bool,
+    signing_key: SigningKey,
+    rp_id: String,
+    origin: String,
+}
+
+/// An assertion response, as returned by navigator.credentials.get()
+pub struct Assertion {
+    pub credential_id: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl Authenticator {
+    /// Create a new authenticator with a random credential, for the relying
+    /// party and first origin in the WebAuthn configuration
+    pub fn new(config: &WebAuthnConfiguration) -> Self {
+        let mut credential_id = vec![0u8; 32];
+        rand::thread_rng().fill_bytes(&mut credential_id);
+
+        Self {
+            credential_id,
+            sign_count: 0,
+            user_verified: true,
+            signing_key: SigningKey::random(&mut rand::thread_rng()),
+            rp_id: config.rp_id.to_owned(),
+            origin: config.origins[0].to_owned(),
+        }
+    }
+
+    /// Build the client data JSON for a ceremony type and challenge
+    pub fn client_data_json(&self, ceremony_type: &str, challenge: &[u8]) -> Vec<u8> {
+        serde_json::json!({
+            "type": ceremony_type,
+            "challenge": URL_SAFE_NO_PAD.encode(challenge),
+            "origin": self.origin,
+            "crossOrigin": false,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    /// Build the authenticator data header, without attested credential data
+    fn authenticator_data(&self, flags: u8) -> Vec<u8> {
+        let flags = match self.user_verified {
+            true => flags | USER_PRESENT | USER_VERIFIED,
+            false => flags | USER_PRESENT,
+        };
+
+        [
+            Sha256::digest(self.rp_id.as_bytes()).as_slice(),
+            &[flags],
+            &self.sign_count.to_be_bytes(),
+        ]
+        .concat()
+    }
+
+    /// Create the credential, returning the client data JSON and attestation
+    /// object, as returned by navigator.credentials.create()
+    pub fn register(&self, challenge: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        let cose_key = Value::Map(vec![
+            (Value::from(1), Value::from(2)),
+            (Value::from(3), Value::from(-7)),
+            (Value::from(-1), Value::from(1)),
+            (Value::from(-2), Value::Bytes(point.x().unwrap().to_vec())),
+            (Value::from(-3), Value::Bytes(point.y().unwrap().to_vec())),
+        ]);
+        let mut cose_key_bytes = Vec::new();
+        ciborium::into_writer(&cose_key, &mut cose_key_bytes).unwrap();
+
+        let authenticator_data = [
+            self.authenticator_data(ATTESTED_CREDENTIAL).as_slice(),
+            &[0u8; 16],
+            &(self.credential_id.len() as u16).to_be_bytes(),
+            &self.credential_id,
+            &cose_key_bytes,
+        ]
+        .concat();
+
+        let attestation_object = Value::Map(vec![
+            (Value::from("fmt"), Value::from("none")),
+            (Value::from("attStmt"), Value::Map(vec![])),
+            (Value::from("authData"), Value::Bytes(authenticator_data)),
+        ]);
+        let mut attestation_object_bytes = Vec::new();
+        ciborium::into_writer(&attestation_object, &mut attestation_object_bytes).unwrap();
+
+        (
+            self.client_data_json("webauthn.create", challenge),
+            attestation_object_bytes,
+        )
+    }
+
+    /// Sign an assertion for the challenge, incrementing the signature counter
+    pub fn assert(&mut self, challenge: &[u8]) -> Assertion {
+        self.sign_count += 1;
+
+        let client_data_json = self.client_data_json("webauthn.get", challenge);
+        let authenticator_data = self.authenticator_data(0);
+        let message = [
+            authenticator_data.as_slice(),
+            Sha256::digest(&client_data_json).as_slice(),
+        ]
+        .concat();
+        let signature: Signature = self.signing_key.sign(&message);
+
+        Assertion {
+            credential_id: self.credential_id.to_owned(),
+            client_data_json,
+            authenticator_data,
+            signature: signature.to_der().as_bytes().to_vec(),
+        }
+    }
+}
@@ -1,14 +1,15 @@
 // #![allow(unused)] // For beginning only.
 
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+use authentication_microservice::configuration::{Configuration, JwtAlgorithm};
 use authentication_microservice::BackendError;
 use chrono::{DateTime, SubsecRound, Utc};
 // use chrono::prelude::*;
 use fake::faker::boolean::en::Boolean;
 use fake::faker::chrono::en::DateTime;
 use fake::faker::chrono::en::DateTimeAfter;
-use fake::faker::internet::en::IPv4;
+use fake::faker::internet::en::{IPv4, IPv6, Password};
 use fake::faker::name::en::Name;
 use fake::{faker::internet::en::SafeEmail, Fake};
 use secrecy::Secret;
@@ -76,12 +77,32 @@ pub fn users(password: &String) -> Result<database::Users, BackendError> {
         role: random_role,
         is_active: random_is_active,
         is_verified: random_is_verified,
+        is_pending: false,
         created_on: random_created_on,
     };
 
     Ok(random_user)
 }
 
+/// Load the development token signing keys, for tokens the test server will
+/// not verify
+pub fn token_keys() -> Result<domain::TokenKeys, BackendError> {
+    let signing_key = domain::SigningKey::from_pem(
+        "mock-ed25519",
+        JwtAlgorithm::EdDSA,
+        include_bytes!("../../../configuration/keys/development_ed25519_private.pem"),
+        include_bytes!("../../../configuration/keys/development_ed25519_public.pem"),
+    )?;
+
+    Ok(domain::TokenKeys::new(vec![signing_key]))
+}
+
+/// The configured token hash key, which the test server also hashes
+/// One Time Tokens with
+pub fn hash_key() -> Result<Secret<String>, BackendError> {
+    Ok(Configuration::parse()?.jwt.token_hash_key)
+}
+
 pub fn sessions(
     user: &database::Users,
 ) -> Result<database::Sessions, BackendError> {
@@ -89,16 +110,19 @@ pub fn sessions(
     use fake::faker::boolean::en::Boolean;
     use fake::faker::chrono::en::DateTime;
     use fake::Fake;
-    use rand::distributions::DistString;
-    use secrecy::Secret;
 
     let random_id = uuid_v7();
     let user_id = user.id.to_owned();
-    let random_secret =
-        rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 60);
-    let random_secret = Secret::new(random_secret);
+    let token_keys = token_keys()?;
+    let config = Configuration::parse()?.application;
 
-    let random_token = domain::RefreshToken::new(&random_secret, &user)?;
+    let random_token = domain::RefreshToken::new(
+        &token_keys,
+        &config,
+        user,
+        config.refresh_token_seconds,
+    )?;
+    let random_hash_key = Secret::new(Password(16..32).fake());
 
     // Generate random boolean value
     let random_is_active: bool = Boolean(4).fake();
@@ -110,9 +134,13 @@ pub fn sessions(
     let random_refresh_token = database::Sessions {
         id: random_id,
         user_id,
-        refresh_token: random_token,
+        family_id: random_id,
+        refresh_token_hash: random_token.hash(&random_hash_key),
         is_active: random_is_active,
         created_on: random_created_on,
+        access_token_id: Some(uuid_v7()),
+        organisation_id: None,
+        oidc_client_id: None,
     };
 
     Ok(random_refresh_token)
@@ -131,16 +159,39 @@ pub fn logins(user_id: &Uuid) -> Result<database::Logins, BackendError> {
     // Round up accuracy to be consistent with Postgres, so we can do asserts cleaner
     let random_login_on = random_login_on.round_subsecs(0);
 
-    // Generate random IPV4 address
-    let random_ip: Ipv4Addr = IPv4().fake();
-    // Convert IPV4 to an i32 to be consistent with Postgres INT type
-    let random_ip = u32::from(random_ip) as i32;
+    // Generate a random IPV4 or IPV6 address
+    let random_ip: IpAddr = match Boolean(50).fake() {
+        true => IpAddr::V4(IPv4().fake::<Ipv4Addr>()),
+        false => IpAddr::V6(IPv6().fake::<Ipv6Addr>()),
+    };
     let random_ip = Some(random_ip);
 
     Ok(database::Logins{ 
         id: random_id, 
-        user_id, 
+        user_id: Some(user_id), 
         login_on: random_login_on, 
         login_ip: random_ip, 
+        outcome: domain::LoginOutcome::Success,
     })
 }
+
+/// Generate an enabled TOTP Secret for the user, returning the database model
+/// and the plain secret used to generate codes
+pub fn totp_secrets(
+    user: &database::Users,
+    encryption_key: &Secret<String>,
+) -> Result<(database::TotpSecrets, domain::TotpSecret), BackendError> {
+    let secret = domain::TotpSecret::generate();
+
+    let mut totp_secret =
+        database::TotpSecrets::new(&user.id, secret.encrypt(encryption_key)?);
+    totp_secret.is_enabled = true;
+    totp_secret.enabled_on = Some(Utc::now().round_subsecs(0));
+
+    Ok((totp_secret, secret))
+}
+
+/// Generate the current TOTP code for a secret
+pub fn totp_code(secret: &domain::TotpSecret) -> String {
+    secret.generate_code(Utc::now().timestamp() as u64)
+}
@@ -2,7 +2,39 @@
 
 // #![allow(unused)] // For beginning only.
 
+mod authenticator;
 pub mod mocks;
 mod spawn;
+mod spool;
+pub use authenticator::{Assertion, Authenticator};
 pub use spawn::TonicClient;
 pub use spawn::TonicServer;
+pub use spool::{EmailSpool, SentEmail};
+
+/// Build a request with an Access Token carrying the permissions of the user,
+/// for testing endpoints as a user other than the admin the Tonic Client
+/// authenticates as
+pub async fn user_request<T>(
+    message: T,
+    user: &authentication_microservice::database::Users,
+    tonic_server: &TonicServer,
+) -> Result<tonic::Request<T>, Box<dyn std::error::Error>> {
+    let permissions = authentication_microservice::database::Roles::permissions_for_user(
+        &user.id,
+        &tonic_server.database,
+    )
+    .await?;
+    let access_token = authentication_microservice::domain::AccessToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        user,
+        &permissions,
+    )?;
+
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", access_token.to_string().parse()?);
+
+    Ok(request)
+}
@@ -2,12 +2,12 @@
 
 // #![allow(unused)] // For beginning only.
 
-/// Spawn a Tonic Client for testing server endpoints
-///
-/// #### Reference
-///
-/// * [Tonic LND client](https://github.com/Kixunil/tonic_lnd/blob/master/src/lib.rs)
-/// ---
+//! Spawn a Tonic Client for testing server endpoints
+//!
+//! #### Reference
+//!
+//! * [Tonic LND client](https://github.com/Kixunil/tonic_lnd/blob/master/src/lib.rs)
+//! ---
 
 /// This is part of public interface, so it's re-exported.
 pub extern crate tonic;
@@ -37,6 +37,51 @@ pub type LoginsClient =
         InterceptedService<Channel, AccessTokenInterceptor>,
     >;
 
+/// Convenience type alias for signing keys client
+pub type SigningKeysClient =
+    authentication_microservice::rpc::proto::signing_keys_client::SigningKeysClient<
+        InterceptedService<Channel, AccessTokenInterceptor>,
+    >;
+
+/// Convenience type alias for OIDC clients client
+pub type OidcClientsClient =
+    authentication_microservice::rpc::proto::oidc_clients_client::OidcClientsClient<
+        InterceptedService<Channel, AccessTokenInterceptor>,
+    >;
+
+/// Convenience type alias for service accounts client
+pub type ServiceAccountsClient =
+    authentication_microservice::rpc::proto::service_accounts_client::ServiceAccountsClient<
+        InterceptedService<Channel, AccessTokenInterceptor>,
+    >;
+
+/// Convenience type alias for roles client
+pub type RolesClient =
+    authentication_microservice::rpc::proto::roles_client::RolesClient<
+        InterceptedService<Channel, AccessTokenInterceptor>,
+    >;
+
+/// Convenience type alias for MFA client. MFA endpoints act on the user in the
+/// request access token, so tests append the access token themselves.
+pub type MfaClient =
+    authentication_microservice::rpc::proto::mfa_client::MfaClient<Channel>;
+
+/// Convenience type alias for API keys client. API keys endpoints act on the
+/// user in the request access token, so tests append the access token
+/// themselves.
+pub type ApiKeysClient =
+    authentication_microservice::rpc::proto::api_keys_client::ApiKeysClient<Channel>;
+
+/// Convenience type alias for Me client. Me endpoints act on the user in the
+/// request access token, so tests append the access token themselves.
+pub type MeClient = authentication_microservice::rpc::proto::me_client::MeClient<Channel>;
+
+/// Convenience type alias for Organisations client. Organisations endpoints act
+/// on the user in the request access token, so tests append the access token
+/// themselves.
+pub type OrganisationsClient =
+    authentication_microservice::rpc::proto::organisations_client::OrganisationsClient<Channel>;
+
 /// Tonic Client
 #[derive(Clone)]
 pub struct TonicClient {
@@ -44,6 +89,14 @@ pub struct TonicClient {
     sessions: SessionsClient,
     users: UsersClient,
     logins: LoginsClient,
+    signing_keys: SigningKeysClient,
+    oidc_clients: OidcClientsClient,
+    service_accounts: ServiceAccountsClient,
+    roles: RolesClient,
+    me: MeClient,
+    organisations: OrganisationsClient,
+    mfa: MfaClient,
+    api_keys: ApiKeysClient,
 }
 
 impl TonicClient {
@@ -67,6 +120,46 @@ impl TonicClient {
         &mut self.logins
     }
 
+    /// Returns the signing keys client.
+    pub fn signing_keys(&mut self) -> &mut SigningKeysClient {
+        &mut self.signing_keys
+    }
+
+    /// Returns the OIDC clients client.
+    pub fn oidc_clients(&mut self) -> &mut OidcClientsClient {
+        &mut self.oidc_clients
+    }
+
+    /// Returns the service accounts client.
+    pub fn service_accounts(&mut self) -> &mut ServiceAccountsClient {
+        &mut self.service_accounts
+    }
+
+    /// Returns the roles client.
+    pub fn roles(&mut self) -> &mut RolesClient {
+        &mut self.roles
+    }
+
+    /// Returns the me client.
+    pub fn me(&mut self) -> &mut MeClient {
+        &mut self.me
+    }
+
+    /// Returns the organisations client.
+    pub fn organisations(&mut self) -> &mut OrganisationsClient {
+        &mut self.organisations
+    }
+
+    /// Returns the mfa client.
+    pub fn mfa(&mut self) -> &mut MfaClient {
+        &mut self.mfa
+    }
+
+    /// Returns the api keys client.
+    pub fn api_keys(&mut self) -> &mut ApiKeysClient {
+        &mut self.api_keys
+    }
+
     //noinspection RsUnnecessaryQualifications
     //noinspection RsUnnecessaryQualifications
     /// Spawn a new tonic client based on the tonic server
@@ -93,11 +186,43 @@ impl TonicClient {
 
         let logins = authentication_microservice::rpc::proto::logins_client::LoginsClient::with_interceptor(inner.clone(), interceptor.clone());
 
+        // Build Signing Keys client request
+        let signing_keys = authentication_microservice::rpc::proto::signing_keys_client::SigningKeysClient::with_interceptor(inner.clone(), interceptor.clone());
+
+        // Build OIDC Clients client request
+        let oidc_clients = authentication_microservice::rpc::proto::oidc_clients_client::OidcClientsClient::with_interceptor(inner.clone(), interceptor.clone());
+
+        // Build Service Accounts client request
+        let service_accounts = authentication_microservice::rpc::proto::service_accounts_client::ServiceAccountsClient::with_interceptor(inner.clone(), interceptor.clone());
+
+        // Build Roles client request
+        let roles = authentication_microservice::rpc::proto::roles_client::RolesClient::with_interceptor(inner.clone(), interceptor.clone());
+
+        // Build Me client request
+        let me = MeClient::new(inner.clone());
+
+        // Build Organisations client request
+        let organisations = OrganisationsClient::new(inner.clone());
+
+        // Build MFA client request
+        let mfa = MfaClient::new(inner.clone());
+
+        // Build API Keys client request
+        let api_keys = ApiKeysClient::new(inner.clone());
+
         let client = TonicClient {
             authentication,
             sessions,
             users,
             logins,
+            signing_keys,
+            oidc_clients,
+            service_accounts,
+            roles,
+            me,
+            organisations,
+            mfa,
+            api_keys,
         };
 
         Ok(client)
@@ -2,7 +2,7 @@
 
 // #![allow(unused)] // For beginning only.
 
-/// Spawn Tonic Client and Server instances for use during testing
+//! Spawn Tonic Client and Server instances for use during testing
 
 pub use client::TonicClient;
 pub use server::TonicServer;
@@ -10,13 +10,14 @@
 use std::sync::Arc;
 
 use authentication_microservice::{
-    configuration::Configuration, domain, startup, telemetry,
+    configuration::{Configuration, EmailBackend},
+    database, domain, startup, telemetry,
 };
 use once_cell::sync::Lazy;
 use sqlx::{Pool, Postgres};
 use tonic::transport::{Channel, Uri};
 
-use crate::helpers::mocks;
+use crate::helpers::{mocks, EmailSpool};
 
 pub type Error = Box<dyn std::error::Error>;
 
@@ -28,20 +29,41 @@ static TRACING: Lazy<()> = Lazy::new(|| {
 #[derive(Clone)]
 pub struct TonicServer {
     pub address: String,
+    pub oidc_address: String,
     pub access_token: String,
     pub config: Arc<Configuration>,
+    pub token_keys: Arc<domain::TokenKeys>,
+    pub email_spool: EmailSpool,
+    pub database: Pool<Postgres>,
 }
 
 impl TonicServer {
     pub async fn spawn_server(database: &Pool<Postgres>) -> Result<Self, Error> {
+        // Parse configuration files
+        let config = Configuration::parse()?;
+
+        Self::spawn_server_with_config(database, config).await
+    }
+
+    /// Spawn a Tonic Server using a configuration modified by the test
+    pub async fn spawn_server_with_config(
+        database: &Pool<Postgres>,
+        config: Configuration,
+    ) -> Result<Self, Error> {
         // Initiate tracing in integration testing
         Lazy::force(&TRACING);
 
-        // Parse configuration files
+        // Spool emails into a directory unique to this server, so the test can
+        // read back what was sent
+        let email_spool = EmailSpool::default();
+
+        // Change port to `0` to avoid conflicts as the OS will assign an unused port
         let config = {
-            let mut s = Configuration::parse()?;
-            // Change port to `0` to avoid conflicts as the OS will assign an unused port
+            let mut s = config;
             s.application.port = 0;
+            s.oidc.port = 0;
+            s.email.backend = EmailBackend::Spool;
+            s.email.spool.directory = email_spool.directory.to_string_lossy().to_string();
             s
         };
 
@@ -49,12 +71,15 @@ impl TonicServer {
         let random_password = mocks::password()?; // In case we need it in the future
         let mut random_user = mocks::users(&random_password)?;
         random_user.role = domain::UserRole::Admin;
-        let random_user = random_user.insert(&database).await?;
+        let random_user = random_user.insert(database).await?;
 
         // Build Tonic server using main crate startup
         let tonic_server =
             startup::TonicServer::build(config.clone(), database.clone()).await?;
 
+        // Share the key ring the server signs and verifies tokens with
+        let token_keys = Arc::clone(&tonic_server.token_keys);
+
         // Set tonic server address as the port is randomly selected by the TCP Listener (in startup)
         // when config sets the port to 0
         let address = format!(
@@ -63,6 +88,9 @@ impl TonicServer {
             tonic_server.listener.local_addr()?.port()
         );
 
+        // OIDC provider HTTP endpoints are served on their own random port
+        let oidc_address = format!("http://{}", tonic_server.oidc_listener.local_addr()?);
+
         // Run as a background task by wrapping server instance in a tokio future
         tokio::spawn(async move {
             let _ = tonic_server.run().await;
@@ -72,10 +100,11 @@ impl TonicServer {
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
         // Generate access token for Tonic Client requests
-        let token_secret = &config.application.token_secret;
+        let permissions = database::Roles::permissions_for_user(&random_user.id, database).await?;
         let access_token_string =
-            domain::AccessToken::new(&token_secret, &random_user)?.to_string();
-        // let access_token = mocks::access_token(&random_user.id, &token_secret).await?.to_string();
+            domain::AccessToken::new(&token_keys, &config.application, &random_user, &permissions)?
+                .to_string();
+        // let access_token = mocks::access_token(&random_user.id, token_keys).await?.to_string();
 
         let config = Arc::new(config);
 
@@ -83,7 +112,11 @@ impl TonicServer {
         Ok(Self {
             access_token: access_token_string,
             address,
+            oidc_address,
             config,
+            token_keys,
+            email_spool,
+            database: database.clone(),
         })
     }
 
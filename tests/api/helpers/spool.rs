@@ -0,0 +1,123 @@
+//-- ./tests/api/helpers/spool.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Read back the emails "sent" by a test server
+//!
+//! Test servers are spawned with the spool email backend, writing each email
+//! as an `.eml` file into a directory unique to the server.
+//! ---
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use mail_parser::MessageParser;
+
+pub type Error = Box<dyn std::error::Error>;
+
+/// How long to wait for an email to be spooled, as emails are sent in the
+/// background after the response is returned
+const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often to check the spool directory while waiting
+const WAIT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An email read back from the spool directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentEmail {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+impl SentEmail {
+    /// Get the one time token from the email body, which is on a line of its own
+    pub fn one_time_token(&self) -> Option<String> {
+        self.text_body
+            .lines()
+            .map(str::trim)
+            .find(|line| line.len() == 48 && line.chars().all(|c| c.is_ascii_alphanumeric()))
+            .map(str::to_string)
+    }
+}
+
+/// Spool directory of a test server
+#[derive(Debug, Clone)]
+pub struct EmailSpool {
+    pub directory: PathBuf,
+}
+
+/// A new spool directory, unique to the test server
+impl Default for EmailSpool {
+    fn default() -> Self {
+        let directory = std::env::temp_dir()
+            .join("authentication_microservice_spool")
+            .join(uuid::Uuid::now_v7().to_string());
+
+        Self { directory }
+    }
+}
+
+impl EmailSpool {
+
+    /// Read all the emails in the spool directory, in the order they were sent
+    pub fn emails(&self) -> Result<Vec<SentEmail>, Error> {
+        let mut spool_files = match std::fs::read_dir(&self.directory) {
+            Ok(entries) => entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?,
+            // Nothing has been sent yet
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        spool_files.retain(|path| path.extension().is_some_and(|ext| ext == "eml"));
+        spool_files.sort();
+
+        spool_files
+            .iter()
+            .map(|path| {
+                let contents = std::fs::read(path)?;
+                let message = MessageParser::default()
+                    .parse(&contents)
+                    .ok_or("Unable to parse spooled email")?;
+
+                Ok(SentEmail {
+                    to: message
+                        .to()
+                        .and_then(|to| to.first())
+                        .and_then(|to| to.address())
+                        .unwrap_or_default()
+                        .to_string(),
+                    subject: message.subject().unwrap_or_default().to_string(),
+                    text_body: message.body_text(0).unwrap_or_default().to_string(),
+                    html_body: message.body_html(0).unwrap_or_default().to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Read all the emails sent to an email address
+    pub fn emails_to(&self, to: &str) -> Result<Vec<SentEmail>, Error> {
+        let emails = self.emails()?.into_iter().filter(|email| email.to == to).collect();
+
+        Ok(emails)
+    }
+
+    /// Wait for the first email sent to an email address
+    pub async fn wait_for_email(&self, to: &str) -> Result<SentEmail, Error> {
+        let started = tokio::time::Instant::now();
+
+        loop {
+            if let Some(email) = self.emails_to(to)?.into_iter().next() {
+                return Ok(email);
+            }
+
+            if started.elapsed() > WAIT_TIMEOUT {
+                return Err(format!("No email sent to {to}").into());
+            }
+
+            tokio::time::sleep(WAIT_INTERVAL).await;
+        }
+    }
+}
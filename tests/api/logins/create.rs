@@ -3,6 +3,7 @@
 // #![allow(unused)] // For beginning only.
 
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 
 use authentication_microservice::rpc::proto::LoginsCreateRequest;
 
@@ -58,9 +59,10 @@ async fn returns_created_logins(database: Pool<Postgres>) -> Result<()> {
 
     //-- Execute Test (Act)
     let request_message = LoginsCreateRequest {
-        user_id: random_login.user_id.to_string(),
+        user_id: random_login.user_id.unwrap().to_string(),
         login_on: random_login.login_on.to_string(),
-        login_ip: random_login.login_ip,
+        login_ip_address: random_login.login_ip.map(|login_ip| login_ip.to_string()),
+        outcome: Some(random_login.outcome.to_string()),
     };
 
     // Build new Tonic request
@@ -79,15 +81,84 @@ async fn returns_created_logins(database: Pool<Postgres>) -> Result<()> {
     assert_ne!(random_login.id.to_string(), response_message.id);
 
     // Login user id should be equal
-    assert_eq!(random_login.user_id.to_string(), response_message.user_id);
+    assert_eq!(random_login.user_id.map(|user_id| user_id.to_string()), response_message.user_id);
 
     // Login on should not be equal as the server will generate
     assert_ne!(random_login.login_on.to_string(), response_message.login_on);
 
     // Login login_ip should equal
-    assert_eq!(random_login.login_ip, response_message.login_ip);
+    assert_eq!(random_login.login_ip.map(|login_ip| login_ip.to_string()), response_message.login_ip_address);
 
     //-- Return
     Ok(())
 }
 
+
+#[sqlx::test]
+async fn returns_created_ipv6_login(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Generate a random user and add to the database
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    //-- Execute Test (Act)
+    let request_message = LoginsCreateRequest {
+        user_id: random_user.id.to_string(),
+        login_on: random_user.created_on.to_string(),
+        login_ip_address: Some("2001:db8::1".to_string()),
+        outcome: None,
+    };
+
+    let response_message = tonic_client
+        .logins()
+        .create(tonic::Request::new(request_message))
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.login_ip_address, Some("2001:db8::1".to_string()));
+
+    //-- Return
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_login_ip_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Generate a random user and add to the database
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    //-- Execute Test (Act)
+    let request_message = LoginsCreateRequest {
+        user_id: random_user.id.to_string(),
+        login_on: random_user.created_on.to_string(),
+        login_ip_address: Some("not-an-ip-address".to_string()),
+        outcome: None,
+    };
+
+    let response = tonic_client
+        .logins()
+        .create(tonic::Request::new(request_message))
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    //-- Return
+    Ok(())
+}
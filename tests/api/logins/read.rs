@@ -3,7 +3,10 @@
 // #![allow(unused)] // For beginning only.
 
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::logins_client::LoginsClient;
 use authentication_microservice::rpc::proto::LoginsReadRequest;
 
 use crate::helpers;
@@ -52,14 +55,60 @@ async fn read_returns_login(database: Pool<Postgres>) -> Result<()> {
     assert_eq!(random_login.id.to_string(), response_message.id);
 
     // Login user id should be equal
-    assert_eq!(random_login.user_id.to_string(), response_message.user_id);
+    assert_eq!(random_login.user_id.map(|user_id| user_id.to_string()), response_message.user_id);
 
     // Login on should not be equal as the server will generate
     assert_eq!(random_login.login_on.to_string(), response_message.login_on);
 
     // Login login_ip should equal
-    assert_eq!(random_login.login_ip, response_message.login_ip);
+    assert_eq!(random_login.login_ip.map(|login_ip| login_ip.to_string()), response_message.login_ip_address);
 
     //-- Return
     Ok(())
-}
\ No newline at end of file
+}
+#[sqlx::test]
+async fn user_reads_own_logins_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the User role, and a login for them and another user
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+    let other_user = helpers::mocks::users(&random_password)?.insert(&database).await?;
+
+    let own_login = helpers::mocks::logins(&random_user.id)?.insert(&database).await?;
+    let other_login = helpers::mocks::logins(&other_user.id)?.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Logins client that does not add the admin Access Token
+    let mut logins_client = LoginsClient::new(tonic_server.clone().client_channel().await?);
+
+    //-- Execute Test (Act)
+    let own_request = helpers::user_request(
+        LoginsReadRequest {
+            id: own_login.id.to_string(),
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let own_response = logins_client.read(own_request).await;
+
+    let other_request = helpers::user_request(
+        LoginsReadRequest {
+            id: other_login.id.to_string(),
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let other_response = logins_client.read(other_request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(own_response?.into_inner().id, own_login.id.to_string());
+    assert_eq!(other_response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
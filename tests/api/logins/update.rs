@@ -41,9 +41,10 @@ async fn update_returns_login(database: Pool<Postgres>) -> Result<()> {
     // Generate a new Logins Delete Request
     let request_message = LoginsUpdateRequest {
         id: random_login.id.to_string(),
-        user_id: random_login_update.user_id.to_string(),
+        user_id: random_login_update.user_id.unwrap().to_string(),
         login_on: random_login_update.login_on.to_string(),
-        login_ip: random_login_update.login_ip
+        login_ip_address: random_login_update.login_ip.map(|login_ip| login_ip.to_string()),
+        outcome: Some(random_login_update.outcome.to_string()),
     };
 
     // Generate a new Tonic Request
@@ -57,13 +58,13 @@ async fn update_returns_login(database: Pool<Postgres>) -> Result<()> {
     assert_eq!(random_login.id.to_string(), response_message.id);
 
     // Login user id should be equal
-    assert_eq!(random_login_update.user_id.to_string(), response_message.user_id);
+    assert_eq!(random_login_update.user_id.map(|user_id| user_id.to_string()), response_message.user_id);
 
     // Login on should not be equal as the server will generate
     assert_eq!(random_login_update.login_on.to_string(), response_message.login_on);
 
     // Login login_ip should equal
-    assert_eq!(random_login_update.login_ip, response_message.login_ip);
+    assert_eq!(random_login_update.login_ip.map(|login_ip| login_ip.to_string()), response_message.login_ip_address);
 
     //-- Return
     Ok(())
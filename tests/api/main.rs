@@ -2,9 +2,18 @@
 
 // Add modules to include in integration binary
 
+mod api_keys;
 mod authentication;
 pub mod helpers;
 mod logins;
+mod me;
+mod mfa;
+mod oidc;
+mod oidc_clients;
+mod organisations;
+mod roles;
+mod service_accounts;
 mod sessions;
+mod signing_keys;
 mod users;
 mod utilities;
@@ -0,0 +1,289 @@
+//-- ./tests/api/me/change_email.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the change of email address endpoints
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::configuration::Configuration;
+use authentication_microservice::rpc::proto::{ConfirmEmailChangeRequest, MeChangeEmailRequest};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::{active_user, login, me_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Build a change email request with the Access Token
+fn change_email_request(
+    password: &str,
+    new_email: &str,
+    access_token: &str,
+) -> Result<tonic::Request<MeChangeEmailRequest>> {
+    let request_message = MeChangeEmailRequest {
+        password: password.to_string(),
+        new_email: new_email.to_string(),
+    };
+
+    me_request(request_message, access_token)
+}
+
+/// Count the Email Changes requested by a user
+async fn count_email_changes(
+    user: &database::Users,
+    database: &Pool<Postgres>,
+) -> Result<i64> {
+    let count = sqlx::query_scalar("SELECT COUNT(*) FROM email_changes WHERE user_id = $1")
+        .bind(user.id)
+        .fetch_one(database)
+        .await?;
+
+    Ok(count)
+}
+
+#[sqlx::test]
+async fn change_email_once_confirmed(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let new_email = helpers::mocks::users(&random_password)?.email;
+
+    //-- Execute Test (Act)
+    tonic_client
+        .me()
+        .change_email(change_email_request(
+            &random_password,
+            new_email.as_ref(),
+            &access_token,
+        )?)
+        .await?;
+
+    // The email address does not change until confirmed
+    let pending_record = database::Users::from_user_id(&random_user.id, &database).await?;
+
+    // The token is sent to the new address, and a notice to the current one
+    let confirmation = tonic_server
+        .email_spool
+        .wait_for_email(new_email.as_ref())
+        .await?;
+    let notice = tonic_server
+        .email_spool
+        .wait_for_email(random_user.email.as_ref())
+        .await?;
+    let token = confirmation.one_time_token().ok_or("No token")?;
+
+    let response_message = tonic_client
+        .authentication()
+        .confirm_email_change(ConfirmEmailChangeRequest {
+            token: token.clone(),
+        })
+        .await?
+        .into_inner();
+    let second_confirm = tonic_client
+        .authentication()
+        .confirm_email_change(ConfirmEmailChangeRequest { token })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(pending_record.email, random_user.email);
+
+    assert_eq!(confirmation.subject, "Confirm your new email address");
+    assert!(notice.text_body.contains(new_email.as_ref()));
+    assert!(notice.one_time_token().is_none());
+
+    assert_eq!(response_message.user_id, random_user.id.to_string());
+    assert_eq!(response_message.email, new_email.to_string());
+    assert!(response_message.is_verified);
+
+    let database_record = database::Users::from_user_id(&random_user.id, &database).await?;
+    assert_eq!(database_record.email, new_email);
+    assert!(database_record.is_verified);
+
+    // The token can only be confirmed once
+    assert_eq!(second_confirm.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn change_email_requires_current_password(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let new_email = domain::EmailAddress::parse("new.address@example.com")?;
+
+    //-- Execute Test (Act)
+    let wrong_password_response = tonic_client
+        .me()
+        .change_email(change_email_request(
+            "wrong-password",
+            new_email.as_ref(),
+            &access_token,
+        )?)
+        .await;
+
+    let same_email_response = tonic_client
+        .me()
+        .change_email(change_email_request(
+            &random_password,
+            random_user.email.as_ref(),
+            &access_token,
+        )?)
+        .await;
+
+    let no_token_request = tonic::Request::new(MeChangeEmailRequest {
+        password: random_password.to_string(),
+        new_email: new_email.to_string(),
+    });
+    let no_token_response = tonic_client
+        .me()
+        .change_email(no_token_request)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(
+        wrong_password_response.unwrap_err().code(),
+        Code::Unauthenticated
+    );
+    assert_eq!(
+        same_email_response.unwrap_err().code(),
+        Code::InvalidArgument
+    );
+    assert_eq!(no_token_response.unwrap_err().code(), Code::Unauthenticated);
+    assert_eq!(count_email_changes(&random_user, &database).await?, 0);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn change_email_to_registered_email_sends_nothing(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let (other_user, _other_password) = active_user(domain::UserRole::User, &database).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .me()
+        .change_email(change_email_request(
+            &random_password,
+            other_user.email.as_ref(),
+            &access_token,
+        )?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // The response does not reveal the email address is registered
+    assert!(!response_message.message.is_empty());
+    assert_eq!(count_email_changes(&random_user, &database).await?, 0);
+    assert!(tonic_server.email_spool.emails()?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_passwords_lock_the_account(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let mut config = Configuration::parse()?;
+    config.lockout.account_threshold = 3;
+    config.lockout.delay_base_millis = 0;
+    let tonic_server = helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let new_email = domain::EmailAddress::parse("new.address@example.com")?;
+
+    for _ in 0..3 {
+        let response = tonic_client
+            .me()
+            .change_email(change_email_request(
+                "wrong-password",
+                new_email.as_ref(),
+                &access_token,
+            )?)
+            .await;
+        assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+    }
+
+    //-- Execute Test (Act)
+    // Even the correct password is rejected while the account is locked
+    let change_email_response = tonic_client
+        .me()
+        .change_email(change_email_request(
+            &random_password,
+            new_email.as_ref(),
+            &access_token,
+        )?)
+        .await;
+    let login_response = login(&mut tonic_client, &random_user, &random_password).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(
+        change_email_response.unwrap_err().code(),
+        Code::ResourceExhausted
+    );
+    assert!(login_response.is_err());
+    assert_eq!(count_email_changes(&random_user, &database).await?, 0);
+
+    let logins = database::Logins::index_user(&random_user.id, &10, &0, &database).await?;
+    let bad_passwords = logins
+        .iter()
+        .filter(|login| login.outcome == domain::LoginOutcome::BadPassword)
+        .count();
+    assert_eq!(bad_passwords, 3);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_passwords_lock_the_client_ip(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let mut config = Configuration::parse()?;
+    config.lockout.ip_threshold = 3;
+    config.lockout.delay_base_millis = 0;
+    let tonic_server = helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let (other_user, other_password) = active_user(domain::UserRole::User, &database).await?;
+    let new_email = domain::EmailAddress::parse("new.address@example.com")?;
+
+    for _ in 0..3 {
+        let response = tonic_client
+            .me()
+            .change_email(change_email_request(
+                "wrong-password",
+                new_email.as_ref(),
+                &access_token,
+            )?)
+            .await;
+        assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+    }
+
+    //-- Execute Test (Act)
+    // Other accounts cannot be tried from the locked client IP address
+    let login_response = login(&mut tonic_client, &other_user, &other_password).await;
+
+    //-- Checks (Assertions)
+    assert!(login_response.is_err());
+
+    Ok(())
+}
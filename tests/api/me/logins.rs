@@ -0,0 +1,53 @@
+//-- ./tests/api/me/logins.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::MeIndexRequest;
+
+use crate::helpers;
+
+use super::{active_user, login, me_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn logins_returns_own_history_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let (other_user, _other_password) = active_user(domain::UserRole::User, &database).await?;
+
+    // Logins of both users, then a login to make the request with
+    for _count in 0..3 {
+        helpers::mocks::logins(&random_user.id)?.insert(&database).await?;
+        helpers::mocks::logins(&other_user.id)?.insert(&database).await?;
+    }
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    //-- Execute Test (Act)
+    let index_request_message = MeIndexRequest {
+        limit: 10,
+        offset: 0,
+    };
+    let response_message = tonic_client
+        .me()
+        .logins(me_request(index_request_message, &access_token)?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.logins.len(), 4);
+    let random_user_id = random_user.id.to_string();
+    assert!(response_message
+        .logins
+        .iter()
+        .all(|login| login.user_id.as_ref() == Some(&random_user_id)));
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//-- ./tests/api/me/mod.rs
+
+use authentication_microservice::rpc::proto::LoginRequest;
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+mod change_email;
+mod logins;
+mod read;
+mod sessions;
+
+pub type Error = Box<dyn std::error::Error>;
+
+/// Insert an active, verified user with the role into the database, returning
+/// the user and their password
+pub async fn active_user(
+    role: domain::UserRole,
+    database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<(database::Users, String), Error> {
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = role;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+
+    Ok((random_user.insert(database).await?, random_password))
+}
+
+/// Login as the user, returning the Access Token of the new Session
+pub async fn login(
+    tonic_client: &mut helpers::TonicClient,
+    user: &database::Users,
+    password: &str,
+) -> Result<String, Error> {
+    let login_request_message = LoginRequest {
+        email: user.email.to_string(),
+        password: password.to_string(),
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+
+    Ok(login_response_message.access_token.ok_or("No Access Token")?)
+}
+
+/// Build a request with the Access Token, as Me endpoints act on the user in
+/// the Access Token
+pub fn me_request<T>(message: T, access_token: &str) -> Result<tonic::Request<T>, Error> {
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", access_token.parse()?);
+
+    Ok(request)
+}
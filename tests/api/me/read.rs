@@ -0,0 +1,147 @@
+//-- ./tests/api/me/read.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::{ApiKeysCreateRequest, Empty, MeUpdateRequest};
+
+use crate::helpers;
+
+use super::{active_user, login, me_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn read_returns_own_profile(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .me()
+        .read(me_request(Empty {}, &access_token)?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.id, random_user.id.to_string());
+    assert_eq!(response_message.email, random_user.email.to_string());
+    assert_eq!(response_message.name, random_user.name.to_string());
+    assert_eq!(response_message.role, random_user.role.to_string());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_changes_own_name_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let random_name = helpers::mocks::users(&random_password)?.name;
+
+    //-- Execute Test (Act)
+    let update_request_message = MeUpdateRequest {
+        name: random_name.to_string(),
+    };
+    let response_message = tonic_client
+        .me()
+        .update(me_request(update_request_message, &access_token)?)
+        .await?
+        .into_inner();
+
+    let invalid_request_message = MeUpdateRequest {
+        name: String::new(),
+    };
+    let invalid_response = tonic_client
+        .me()
+        .update(me_request(invalid_request_message, &access_token)?)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.id, random_user.id.to_string());
+    assert_eq!(response_message.name, random_name.to_string());
+    assert_eq!(response_message.email, random_user.email.to_string());
+    assert_eq!(response_message.role, random_user.role.to_string());
+    assert_eq!(response_message.is_active, random_user.is_active);
+
+    // Names are validated like any other
+    assert_eq!(invalid_response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn guest_cannot_update_own_profile(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::Guest, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    //-- Execute Test (Act)
+    let read_response = tonic_client
+        .me()
+        .read(me_request(Empty {}, &access_token)?)
+        .await;
+
+    let update_request_message = MeUpdateRequest {
+        name: helpers::mocks::users(&random_password)?.name.to_string(),
+    };
+    let update_response = tonic_client
+        .me()
+        .update(me_request(update_request_message, &access_token)?)
+        .await;
+
+    //-- Checks (Assertions)
+    // Guests can only read their own account
+    assert!(read_response.is_ok());
+    assert_eq!(update_response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn api_key_cannot_manage_own_account(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    let create_request_message = ApiKeysCreateRequest {
+        name: "Ledger Export".to_string(),
+        scopes: vec![],
+        expires_on: None,
+    };
+    let api_key = tonic_client
+        .api_keys()
+        .create(me_request(create_request_message, &access_token)?)
+        .await?
+        .into_inner()
+        .key;
+
+    //-- Execute Test (Act)
+    let mut request = tonic::Request::new(MeUpdateRequest {
+        name: helpers::mocks::users(&random_password)?.name.to_string(),
+    });
+    request.metadata_mut().append("api_key", api_key.parse()?);
+    let response = tonic_client.me().update(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
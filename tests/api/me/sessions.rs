@@ -0,0 +1,164 @@
+//-- ./tests/api/me/sessions.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{Empty, MeIndexRequest, MeRevokeSessionRequest};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::{active_user, login, me_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn sessions_marks_current_device(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login on two devices, then revoke a Session of another user
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let _other_device = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    let (other_user, other_password) = active_user(domain::UserRole::User, &database).await?;
+    let _other_user_device = login(&mut tonic_client, &other_user, &other_password).await?;
+
+    //-- Execute Test (Act)
+    let index_request_message = MeIndexRequest {
+        limit: 10,
+        offset: 0,
+    };
+    let response_message = tonic_client
+        .me()
+        .sessions(me_request(index_request_message, &access_token)?)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // Only the users own active Sessions, with the one making the request
+    // marked as current
+    assert_eq!(response_message.sessions.len(), 2);
+    let current_sessions = response_message
+        .sessions
+        .iter()
+        .filter(|session| session.is_current)
+        .count();
+    assert_eq!(current_sessions, 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoke_other_sessions_keeps_current_device(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login on three devices
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let other_device = login(&mut tonic_client, &random_user, &random_password).await?;
+    let _third_device = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .me()
+        .revoke_other_sessions(me_request(Empty {}, &access_token)?)
+        .await?
+        .into_inner();
+
+    let current_response = tonic_client
+        .me()
+        .read(me_request(Empty {}, &access_token)?)
+        .await;
+    let other_device_response = tonic_client
+        .me()
+        .read(me_request(Empty {}, &other_device)?)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 2);
+
+    // The device making the request stays signed in, while the Access Tokens of
+    // the other devices are revoked straight away
+    assert!(current_response.is_ok());
+    assert_eq!(
+        other_device_response.unwrap_err().code(),
+        Code::Unauthenticated
+    );
+
+    let active_sessions =
+        database::Sessions::index_active_from_user_id(&random_user.id, &10, &0, &database)
+            .await?;
+    assert_eq!(active_sessions.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoke_session_of_own_device_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (random_user, random_password) = active_user(domain::UserRole::User, &database).await?;
+    let access_token = login(&mut tonic_client, &random_user, &random_password).await?;
+    let other_device = login(&mut tonic_client, &random_user, &random_password).await?;
+
+    let (other_user, other_password) = active_user(domain::UserRole::User, &database).await?;
+    let _other_user_device = login(&mut tonic_client, &other_user, &other_password).await?;
+
+    let own_session =
+        database::Sessions::index_active_from_user_id(&random_user.id, &10, &0, &database)
+            .await?
+            .pop()
+            .ok_or("No Session")?;
+    let other_user_session =
+        database::Sessions::index_active_from_user_id(&other_user.id, &10, &0, &database)
+            .await?
+            .pop()
+            .ok_or("No Session")?;
+
+    //-- Execute Test (Act)
+    let other_user_request_message = MeRevokeSessionRequest {
+        id: other_user_session.id.to_string(),
+    };
+    let other_user_response = tonic_client
+        .me()
+        .revoke_session(me_request(other_user_request_message, &access_token)?)
+        .await;
+
+    let own_request_message = MeRevokeSessionRequest {
+        id: own_session.id.to_string(),
+    };
+    let own_response_message = tonic_client
+        .me()
+        .revoke_session(me_request(own_request_message, &access_token)?)
+        .await?
+        .into_inner();
+
+    let other_device_response = tonic_client
+        .me()
+        .read(me_request(Empty {}, &other_device)?)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(other_user_response.unwrap_err().code(), Code::PermissionDenied);
+    let database_record = database::Sessions::from_id(&other_user_session.id, &database).await?;
+    assert!(database_record.is_active);
+
+    // The most recent Session is the other device, which is signed out
+    assert_eq!(own_response_message.rows_affected, 1);
+    assert_eq!(
+        other_device_response.unwrap_err().code(),
+        Code::Unauthenticated
+    );
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//-- ./tests/api/mfa/begin_totp_enrolment.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the begin TOTP enrolment endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    ApiKeysCreateRequest, BeginTotpEnrolmentRequest, BeginWebAuthnRegistrationRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::user_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn returns_secret_and_uri(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request = user_request(BeginTotpEnrolmentRequest {}, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .begin_totp_enrolment(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.otpauth_uri.starts_with("otpauth://totp/"));
+    assert!(response_message.otpauth_uri.contains(&response_message.secret));
+
+    // The secret is stored encrypted and pending confirmation
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    assert!(!totp_secret.is_enabled);
+    let secret = domain::TotpSecret::decrypt(
+        &totp_secret.secret_encrypted,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    assert_eq!(secret.to_base32(), response_message.secret);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn enabled_totp_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request = user_request(BeginTotpEnrolmentRequest {}, &random_user, &tonic_server)?;
+    let response = tonic_client
+        .mfa()
+        .begin_totp_enrolment(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::FailedPrecondition);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn refresh_token_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Only Access Tokens can authorise requests
+    let refresh_token = domain::RefreshToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        tonic_server.config.application.refresh_token_seconds,
+    )?;
+    let mut request = tonic::Request::new(BeginTotpEnrolmentRequest {});
+    request
+        .metadata_mut()
+        .append("access_token", refresh_token.to_string().parse()?);
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .mfa()
+        .begin_totp_enrolment(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn api_key_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random active user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request = user_request(
+        ApiKeysCreateRequest {
+            name: "Ledger Export".to_string(),
+            scopes: vec![],
+            expires_on: None,
+        },
+        &random_user,
+        &tonic_server,
+    )?;
+    let api_key = tonic_client.api_keys().create(request).await?.into_inner().key;
+
+    //-- Execute Test (Act)
+    // A leaked API key cannot enrol TOTP or register a passkey
+    let mut request = tonic::Request::new(BeginTotpEnrolmentRequest {});
+    request.metadata_mut().append("api_key", api_key.parse()?);
+    let totp_response = tonic_client.mfa().begin_totp_enrolment(request).await;
+
+    let mut request = tonic::Request::new(BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    });
+    request.metadata_mut().append("api_key", api_key.parse()?);
+    let webauthn_response = tonic_client.mfa().begin_web_authn_registration(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(totp_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(webauthn_response.unwrap_err().code(), Code::PermissionDenied);
+    assert!(database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .is_none());
+
+    Ok(())
+}
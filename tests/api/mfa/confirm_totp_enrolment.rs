@@ -0,0 +1,141 @@
+//-- ./tests/api/mfa/confirm_totp_enrolment.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the confirm TOTP enrolment endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    BeginTotpEnrolmentRequest, ConfirmTotpEnrolmentRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::user_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn valid_code_enables_totp(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Begin enrolment and read the pending secret back from the database
+    let request = user_request(BeginTotpEnrolmentRequest {}, &random_user, &tonic_server)?;
+    tonic_client.mfa().begin_totp_enrolment(request).await?;
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    let secret = domain::TotpSecret::decrypt(
+        &totp_secret.secret_encrypted,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+
+    //-- Execute Test (Act)
+    let request_message = ConfirmTotpEnrolmentRequest {
+        code: helpers::mocks::totp_code(&secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .confirm_totp_enrolment(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.is_enabled);
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    assert!(totp_secret.is_enabled);
+    assert!(totp_secret.enabled_on.is_some());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_code_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request = user_request(BeginTotpEnrolmentRequest {}, &random_user, &tonic_server)?;
+    tonic_client.mfa().begin_totp_enrolment(request).await?;
+
+    //-- Execute Test (Act)
+    // Codes from a different secret are not valid
+    let wrong_secret = domain::TotpSecret::generate();
+    let request_message = ConfirmTotpEnrolmentRequest {
+        code: helpers::mocks::totp_code(&wrong_secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client
+        .mfa()
+        .confirm_totp_enrolment(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::InvalidArgument);
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    assert!(!totp_secret.is_enabled);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn not_enrolled_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = ConfirmTotpEnrolmentRequest {
+        code: "123456".to_string(),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client
+        .mfa()
+        .confirm_totp_enrolment(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::FailedPrecondition);
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//-- ./tests/api/mfa/disable_totp.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the disable TOTP endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::configuration::Configuration;
+use authentication_microservice::database;
+use authentication_microservice::rpc::proto::DisableTotpRequest;
+
+use crate::helpers;
+
+use super::user_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn valid_code_disables_totp(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = DisableTotpRequest {
+        code: helpers::mocks::totp_code(&secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .disable_totp(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(!response_message.is_enabled);
+    assert_eq!(
+        database::TotpSecrets::from_user_id(&random_user.id, &database).await?,
+        None
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_code_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = DisableTotpRequest {
+        code: "000000".to_string(),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client
+        .mfa()
+        .disable_totp(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::InvalidArgument);
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    assert!(totp_secret.is_enabled);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_codes_lock_the_account(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    let mut config = Configuration::parse()?;
+    config.lockout.account_threshold = 3;
+    config.lockout.delay_base_millis = 0;
+    let tonic_server = helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    for _ in 0..3 {
+        let request_message = DisableTotpRequest {
+            code: "000000".to_string(),
+        };
+        let request = user_request(request_message, &random_user, &tonic_server)?;
+        let response = tonic_client.mfa().disable_totp(request).await;
+        assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    //-- Execute Test (Act)
+    // Even a valid code is rejected while the account is locked
+    let request_message = DisableTotpRequest {
+        code: helpers::mocks::totp_code(&secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client.mfa().disable_totp(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::ResourceExhausted);
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    assert!(totp_secret.is_enabled);
+
+    Ok(())
+}
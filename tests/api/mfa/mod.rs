@@ -0,0 +1,68 @@
+//-- ./tests/api/mfa/mod.rs
+
+use authentication_microservice::rpc::proto::{
+    BeginWebAuthnRegistrationRequest, FinishWebAuthnRegistrationRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+mod begin_totp_enrolment;
+mod confirm_totp_enrolment;
+mod disable_totp;
+mod recovery_codes;
+mod webauthn_registration;
+
+pub type Error = Box<dyn std::error::Error>;
+
+/// Build a request with an access token for the user, as MFA endpoints act on
+/// the user in the access token
+pub fn user_request<T>(
+    message: T,
+    user: &database::Users,
+    tonic_server: &helpers::TonicServer,
+) -> Result<tonic::Request<T>, Error> {
+    let token_keys = &tonic_server.token_keys;
+    let access_token =
+        domain::AccessToken::new(token_keys, &tonic_server.config.application, user, &[])?;
+
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", access_token.to_string().parse()?);
+
+    Ok(request)
+}
+
+/// Register a passkey for the user with a new software authenticator, returning
+/// the authenticator so it can be used to log in
+pub async fn register_passkey(
+    user: &database::Users,
+    password: &str,
+    tonic_server: &helpers::TonicServer,
+    tonic_client: &mut helpers::TonicClient,
+) -> Result<helpers::Authenticator, Error> {
+    let authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, user, tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    let (client_data_json, attestation_object) = authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "Software Authenticator".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, user, tonic_server)?;
+    tonic_client.mfa().finish_web_authn_registration(request).await?;
+
+    Ok(authenticator)
+}
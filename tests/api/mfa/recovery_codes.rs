@@ -0,0 +1,178 @@
+//-- ./tests/api/mfa/recovery_codes.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the MFA Recovery Code endpoints
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    BeginTotpEnrolmentRequest, ConfirmTotpEnrolmentRequest, RecoveryCodesRemainingRequest,
+    RegenerateRecoveryCodesRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::user_request;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn confirm_enrolment_returns_recovery_codes(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Begin enrolment and read the pending secret back from the database
+    let request = user_request(BeginTotpEnrolmentRequest {}, &random_user, &tonic_server)?;
+    tonic_client.mfa().begin_totp_enrolment(request).await?;
+
+    let totp_secret = database::TotpSecrets::from_user_id(&random_user.id, &database)
+        .await?
+        .unwrap();
+    let secret = domain::TotpSecret::decrypt(
+        &totp_secret.secret_encrypted,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+
+    //-- Execute Test (Act)
+    let request_message = ConfirmTotpEnrolmentRequest {
+        code: helpers::mocks::totp_code(&secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .confirm_totp_enrolment(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.recovery_codes.len(), domain::RECOVERY_CODE_COUNT);
+
+    let request =
+        user_request(RecoveryCodesRemainingRequest {}, &random_user, &tonic_server)?;
+    let remaining_message = tonic_client
+        .mfa()
+        .recovery_codes_remaining(request)
+        .await?
+        .into_inner();
+    assert_eq!(remaining_message.remaining, domain::RECOVERY_CODE_COUNT as i64);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn regenerate_replaces_unused_codes(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user, with a set of Recovery Codes
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    let hash_key = &tonic_server.config.jwt.token_hash_key;
+    let old_recovery_codes = domain::RecoveryCode::generate_set();
+    database::RecoveryCodes::replace_user_id(
+        &random_user.id,
+        &old_recovery_codes,
+        hash_key,
+        &database,
+    )
+    .await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = RegenerateRecoveryCodesRequest {
+        code: helpers::mocks::totp_code(&secret),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .regenerate_recovery_codes(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.recovery_codes.len(), domain::RECOVERY_CODE_COUNT);
+
+    let remaining =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(remaining, domain::RECOVERY_CODE_COUNT as i64);
+
+    // Old codes can no longer be used
+    let old_code_redeemed =
+        database::RecoveryCodes::redeem(
+            &random_user.id,
+            &old_recovery_codes[0],
+            hash_key,
+            &database,
+        )
+        .await;
+    assert!(old_code_redeemed.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn regenerate_requires_valid_code(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, _secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let request_message = RegenerateRecoveryCodesRequest {
+        code: domain::RecoveryCode::generate().to_string(),
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client
+        .mfa()
+        .regenerate_recovery_codes(request)
+        .await
+        .unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.code(), Code::InvalidArgument);
+
+    let remaining =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(remaining, 0);
+
+    Ok(())
+}
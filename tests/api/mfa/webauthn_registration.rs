@@ -0,0 +1,407 @@
+//-- ./tests/api/mfa/webauthn_registration.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the WebAuthn (passkey) registration endpoints
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    BeginWebAuthnRegistrationRequest, DeleteWebAuthnCredentialRequest,
+    FinishWebAuthnRegistrationRequest, ListWebAuthnCredentialsRequest,
+    RecoveryCodesRemainingRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::{register_passkey, user_request};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn register_passkey_stores_credential(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+
+    //-- Execute Test (Act)
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    let (client_data_json, attestation_object) = authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "Laptop".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .finish_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(options.rp_id, tonic_server.config.webauthn.rp_id);
+    assert_eq!(options.user_handle, random_user.id.as_bytes().to_vec());
+    assert!(options.exclude_credential_ids.is_empty());
+
+    assert_eq!(response_message.name, "Laptop");
+    assert_eq!(response_message.credential_id, authenticator.credential_id);
+
+    // The first second factor comes with Recovery Codes
+    assert_eq!(response_message.recovery_codes.len(), domain::RECOVERY_CODE_COUNT);
+
+    let webauthn_credentials =
+        database::WebAuthnCredentials::index_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(webauthn_credentials.len(), 1);
+    assert_eq!(webauthn_credentials[0].id.to_string(), response_message.id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn registered_credentials_are_excluded(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    //-- Execute Test (Act)
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(options.exclude_credential_ids, vec![authenticator.credential_id]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn challenge_cannot_be_reused(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    // Register a first authenticator with the challenge
+    let authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+    let (client_data_json, attestation_object) = authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "First".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    tonic_client.mfa().finish_web_authn_registration(request).await?;
+
+    //-- Execute Test (Act)
+    let other_authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+    let (client_data_json, attestation_object) =
+        other_authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "Second".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client.mfa().finish_web_authn_registration(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    let webauthn_credentials =
+        database::WebAuthnCredentials::index_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(webauthn_credentials.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn challenge_for_other_user_is_rejected(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random users and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+    let other_user = helpers::mocks::users(&random_password)?;
+    let other_user = other_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // The challenge is issued to the other user
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &other_user, &tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+    let (client_data_json, attestation_object) = authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "Laptop".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response = tonic_client.mfa().finish_web_authn_registration(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn password_or_code_is_required(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    // An Access Token alone cannot add a passkey
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: None,
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let missing_response = tonic_client.mfa().begin_web_authn_registration(request).await;
+
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(format!("{random_password}wrong")),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let wrong_response = tonic_client.mfa().begin_web_authn_registration(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(missing_response.unwrap_err().code(), Code::Unauthenticated);
+    assert_eq!(wrong_response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn second_passkey_keeps_recovery_codes(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    register_passkey(&random_user, &random_password, &tonic_server, &mut tonic_client).await?;
+    let recovery_codes_before =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+
+    //-- Execute Test (Act)
+    let request_message = BeginWebAuthnRegistrationRequest {
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let options = tonic_client
+        .mfa()
+        .begin_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    let authenticator = helpers::Authenticator::new(&tonic_server.config.webauthn);
+    let (client_data_json, attestation_object) = authenticator.register(&options.challenge);
+    let request_message = FinishWebAuthnRegistrationRequest {
+        name: "Phone".to_string(),
+        client_data_json,
+        attestation_object,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let response_message = tonic_client
+        .mfa()
+        .finish_web_authn_registration(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(response_message.recovery_codes.is_empty());
+    assert_eq!(recovery_codes_before, domain::RECOVERY_CODE_COUNT as i64);
+
+    let recovery_codes_after =
+        database::RecoveryCodes::count_unused_by_user_id(&random_user.id, &database).await?;
+    assert_eq!(recovery_codes_after, recovery_codes_before);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_and_delete_passkeys(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random users and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+    let random_user = random_user.insert(&database).await?;
+    let other_user = helpers::mocks::users(&random_password)?;
+    let other_user = other_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let authenticator =
+        register_passkey(&random_user, &random_password, &tonic_server, &mut tonic_client)
+            .await?;
+    register_passkey(&other_user, &random_password, &tonic_server, &mut tonic_client).await?;
+
+    //-- Execute Test (Act)
+    let request = user_request(ListWebAuthnCredentialsRequest {}, &random_user, &tonic_server)?;
+    let list_response_message = tonic_client
+        .mfa()
+        .list_web_authn_credentials(request)
+        .await?
+        .into_inner();
+    let id = list_response_message.credentials[0].id.to_owned();
+
+    // The password is required to remove a passkey
+    let request_message = DeleteWebAuthnCredentialRequest {
+        id: id.to_owned(),
+        password: None,
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let missing_response = tonic_client.mfa().delete_web_authn_credential(request).await;
+
+    // Other users cannot remove the passkey
+    let request_message = DeleteWebAuthnCredentialRequest {
+        id: id.to_owned(),
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &other_user, &tonic_server)?;
+    let other_response_message = tonic_client
+        .mfa()
+        .delete_web_authn_credential(request)
+        .await?
+        .into_inner();
+
+    let request_message = DeleteWebAuthnCredentialRequest {
+        id,
+        password: Some(random_password.to_owned()),
+        code: None,
+    };
+    let request = user_request(request_message, &random_user, &tonic_server)?;
+    let delete_response_message = tonic_client
+        .mfa()
+        .delete_web_authn_credential(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(list_response_message.credentials.len(), 1);
+    assert_eq!(list_response_message.credentials[0].credential_id, authenticator.credential_id);
+    assert!(list_response_message.credentials[0].recovery_codes.is_empty());
+
+    assert_eq!(missing_response.unwrap_err().code(), Code::Unauthenticated);
+    assert_eq!(other_response_message.rows_affected, 0);
+    assert_eq!(delete_response_message.rows_affected, 1);
+
+    let webauthn_credentials =
+        database::WebAuthnCredentials::index_by_user_id(&random_user.id, &database).await?;
+    assert!(webauthn_credentials.is_empty());
+
+    // Without a second factor left the Recovery Codes are removed
+    let request =
+        user_request(RecoveryCodesRemainingRequest {}, &random_user, &tonic_server)?;
+    let remaining_message = tonic_client
+        .mfa()
+        .recovery_codes_remaining(request)
+        .await?
+        .into_inner();
+    assert_eq!(remaining_message.remaining, 0);
+
+    Ok(())
+}
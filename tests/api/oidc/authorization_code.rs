@@ -0,0 +1,746 @@
+//-- ./tests/api/oidc/authorization_code.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the OIDC authorization code flow
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use sqlx::{Pool, Postgres};
+use url::Url;
+
+use authentication_microservice::rpc::proto::RefreshRequest;
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+use crate::mfa::register_passkey;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+const REDIRECT_URI: &str = "https://client.example.com/callback";
+
+/// HTTP client that returns redirects to the test instead of following them
+fn http_client() -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?)
+}
+
+/// Register an OIDC client, returning its client secret if confidential
+async fn register_client(
+    is_confidential: bool,
+    database: &Pool<Postgres>,
+) -> Result<(database::OidcClients, Option<domain::OneTimeToken>)> {
+    let client_secret = is_confidential.then(domain::OneTimeToken::generate);
+    let oidc_client = database::OidcClients::new(
+        "Example Client",
+        vec![REDIRECT_URI.to_string()],
+        client_secret.as_ref(),
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(database)
+    .await?;
+
+    Ok((oidc_client, client_secret))
+}
+
+/// Build the authorization request parameters with a PKCE code challenge for
+/// the code verifier
+fn authorize_params(
+    oidc_client: &database::OidcClients,
+    code_verifier: &str,
+) -> Vec<(&'static str, String)> {
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+    vec![
+        ("response_type", "code".to_string()),
+        ("client_id", oidc_client.id.to_string()),
+        ("redirect_uri", REDIRECT_URI.to_string()),
+        ("scope", "openid profile email".to_string()),
+        ("state", "client-state".to_string()),
+        ("nonce", "client-nonce".to_string()),
+        ("code_challenge", code_challenge),
+        ("code_challenge_method", "S256".to_string()),
+    ]
+}
+
+/// Get a query parameter from a redirect location
+fn query_param(location: &Url, name: &str) -> Option<String> {
+    location
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Get the redirect location of a response
+fn location(response: &reqwest::Response) -> Result<Url> {
+    let location = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .ok_or("Missing location header")?
+        .to_str()?;
+
+    Ok(Url::parse(location)?)
+}
+
+/// Get the value of a form input from a sign in page
+fn input_value(page: &str, name: &str) -> Result<String> {
+    Ok(page
+        .split(&format!(r#"name="{name}" value=""#))
+        .nth(1)
+        .and_then(|value| value.split('"').next())
+        .ok_or(format!("Missing {name}"))?
+        .to_string())
+}
+
+/// Open the sign in page, returning the CSRF cookie and the form parameters
+/// with the CSRF token
+async fn sign_in_form(
+    tonic_server: &helpers::TonicServer,
+    params: &[(&'static str, String)],
+) -> Result<(String, Vec<(&'static str, String)>)> {
+    let response = http_client()?
+        .get(format!("{}/authorize", tonic_server.oidc_address))
+        .query(params)
+        .send()
+        .await?;
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let cookie = response
+        .headers()
+        .get(reqwest::header::SET_COOKIE)
+        .ok_or("Missing CSRF cookie")?
+        .to_str()?
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    let mut form = params.to_vec();
+    form.push(("csrf_token", input_value(&response.text().await?, "csrf_token")?));
+
+    Ok((cookie, form))
+}
+
+/// Post a sign in form with the CSRF cookie
+async fn post_sign_in(
+    tonic_server: &helpers::TonicServer,
+    cookie: &str,
+    form: &[(&'static str, String)],
+) -> Result<reqwest::Response> {
+    Ok(http_client()?
+        .post(format!("{}/authorize", tonic_server.oidc_address))
+        .header(reqwest::header::COOKIE, cookie)
+        .form(form)
+        .send()
+        .await?)
+}
+
+/// Sign a user in through the authorize endpoint, returning the authorization
+/// code from the redirect
+async fn sign_in(
+    tonic_server: &helpers::TonicServer,
+    params: &[(&'static str, String)],
+    email: &str,
+    password: &str,
+) -> Result<String> {
+    let (cookie, mut form) = sign_in_form(tonic_server, params).await?;
+    form.push(("email", email.to_string()));
+    form.push(("password", password.to_string()));
+
+    let response = post_sign_in(tonic_server, &cookie, &form).await?;
+    assert_eq!(response.status(), reqwest::StatusCode::SEE_OTHER);
+
+    let location = location(&response)?;
+    assert!(location.as_str().starts_with(REDIRECT_URI));
+    assert_eq!(query_param(&location, "state").as_deref(), Some("client-state"));
+
+    Ok(query_param(&location, "code").ok_or("Missing code")?)
+}
+
+/// Exchange an authorization code at the token endpoint
+async fn exchange_code(
+    tonic_server: &helpers::TonicServer,
+    oidc_client: &database::OidcClients,
+    code: &str,
+    code_verifier: &str,
+    client_secret: Option<&str>,
+) -> Result<reqwest::Response> {
+    let mut form = vec![
+        ("grant_type", "authorization_code".to_string()),
+        ("code", code.to_string()),
+        ("redirect_uri", REDIRECT_URI.to_string()),
+        ("client_id", oidc_client.id.to_string()),
+        ("code_verifier", code_verifier.to_string()),
+    ];
+    if let Some(client_secret) = client_secret {
+        form.push(("client_secret", client_secret.to_string()));
+    }
+
+    Ok(http_client()?
+        .post(format!("{}/token", tonic_server.oidc_address))
+        .form(&form)
+        .send()
+        .await?)
+}
+
+/// Exchange a Refresh Token at the token endpoint as the client
+async fn refresh(
+    tonic_server: &helpers::TonicServer,
+    oidc_client: &database::OidcClients,
+    refresh_token: &str,
+    client_secret: Option<&str>,
+) -> Result<reqwest::Response> {
+    let mut form = vec![
+        ("grant_type", "refresh_token".to_string()),
+        ("refresh_token", refresh_token.to_string()),
+        ("client_id", oidc_client.id.to_string()),
+    ];
+    if let Some(client_secret) = client_secret {
+        form.push(("client_secret", client_secret.to_string()));
+    }
+
+    Ok(http_client()?
+        .post(format!("{}/token", tonic_server.oidc_address))
+        .form(&form)
+        .send()
+        .await?)
+}
+
+#[sqlx::test]
+async fn signed_in_user_is_issued_tokens(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+
+    //-- Execute Test (Act)
+    // The sign in form is shown for a valid authorization request
+    let sign_in_page = http_client()?
+        .get(format!("{}/authorize", tonic_server.oidc_address))
+        .query(&params)
+        .send()
+        .await?;
+    assert_eq!(sign_in_page.status(), reqwest::StatusCode::OK);
+    assert!(sign_in_page.text().await?.contains("Example Client"));
+
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+    let response =
+        exchange_code(&tonic_server, &oidc_client, &code, &code_verifier, None).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let tokens: serde_json::Value = response.json().await?;
+    assert_eq!(tokens["token_type"], "Bearer");
+    assert_eq!(tokens["scope"], "openid profile email");
+
+    // The ID Token is issued to the client, with the nonce and user claims
+    let id_token_claim = domain::IdToken::decode(
+        tokens["id_token"].as_str().unwrap(),
+        &tonic_server.token_keys,
+        tonic_server.config.oidc.issuer.trim_end_matches('/'),
+        &oidc_client.id.to_string(),
+    )?;
+    assert_eq!(id_token_claim.sub, random_user.id.to_string());
+    assert_eq!(id_token_claim.nonce.as_deref(), Some("client-nonce"));
+    assert_eq!(id_token_claim.email, Some(random_user.email.to_string()));
+    assert_eq!(id_token_claim.name, Some(random_user.name.to_string()));
+
+    // The Access Token can be used with the userinfo endpoint
+    let userinfo: serde_json::Value = http_client()?
+        .get(format!("{}/userinfo", tonic_server.oidc_address))
+        .bearer_auth(tokens["access_token"].as_str().unwrap())
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(userinfo["sub"], random_user.id.to_string());
+    assert_eq!(userinfo["email"], random_user.email.to_string());
+
+    // The Refresh Token is rotated by the token endpoint
+    let refresh_response = refresh(
+        &tonic_server,
+        &oidc_client,
+        tokens["refresh_token"].as_str().unwrap(),
+        None,
+    )
+    .await?;
+    assert_eq!(refresh_response.status(), reqwest::StatusCode::OK);
+    let refreshed_tokens: serde_json::Value = refresh_response.json().await?;
+    assert_ne!(refreshed_tokens["refresh_token"], tokens["refresh_token"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn code_can_only_be_used_once(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+
+    //-- Execute Test (Act)
+    let first_response =
+        exchange_code(&tonic_server, &oidc_client, &code, &code_verifier, None).await?;
+    let second_response =
+        exchange_code(&tonic_server, &oidc_client, &code, &code_verifier, None).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(first_response.status(), reqwest::StatusCode::OK);
+    assert_eq!(second_response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let error: serde_json::Value = second_response.json().await?;
+    assert_eq!(error["error"], "invalid_grant");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_code_verifier_returns_invalid_grant(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+
+    //-- Execute Test (Act)
+    let wrong_code_verifier = domain::OneTimeToken::generate().to_string();
+    let response =
+        exchange_code(&tonic_server, &oidc_client, &code, &wrong_code_verifier, None)
+            .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let error: serde_json::Value = response.json().await?;
+    assert_eq!(error["error"], "invalid_grant");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn confidential_client_must_send_secret(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, client_secret) = register_client(true, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+
+    //-- Execute Test (Act)
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+    let wrong_secret_response = exchange_code(
+        &tonic_server,
+        &oidc_client,
+        &code,
+        &code_verifier,
+        Some("not-the-secret"),
+    )
+    .await?;
+
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+    let response = exchange_code(
+        &tonic_server,
+        &oidc_client,
+        &code,
+        &code_verifier,
+        client_secret.as_ref().map(|client_secret| client_secret.as_ref()),
+    )
+    .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(wrong_secret_response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let error: serde_json::Value = wrong_secret_response.json().await?;
+    assert_eq!(error["error"], "invalid_client");
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn refresh_requires_client_authentication(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (oidc_client, client_secret) = register_client(true, &database).await?;
+    let client_secret = client_secret.ok_or("No client secret")?;
+    let (other_client, _other_client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let code = sign_in(
+        &tonic_server,
+        &params,
+        random_user.email.as_ref(),
+        random_password.as_ref(),
+    )
+    .await?;
+    let tokens: serde_json::Value = exchange_code(
+        &tonic_server,
+        &oidc_client,
+        &code,
+        &code_verifier,
+        Some(client_secret.as_ref()),
+    )
+    .await?
+    .json()
+    .await?;
+    let refresh_token = tokens["refresh_token"].as_str().unwrap();
+
+    //-- Execute Test (Act)
+    let missing_secret_response = refresh(&tonic_server, &oidc_client, refresh_token, None).await?;
+    let other_client_response =
+        refresh(&tonic_server, &other_client, refresh_token, None).await?;
+
+    // The Refresh RPC has no client, so cannot refresh the clients Session
+    let rpc_response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: refresh_token.to_string(),
+        })
+        .await;
+
+    let response = refresh(
+        &tonic_server,
+        &oidc_client,
+        refresh_token,
+        Some(client_secret.as_ref()),
+    )
+    .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(missing_secret_response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let error: serde_json::Value = missing_secret_response.json().await?;
+    assert_eq!(error["error"], "invalid_client");
+
+    assert_eq!(other_client_response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let error: serde_json::Value = other_client_response.json().await?;
+    assert_eq!(error["error"], "invalid_grant");
+
+    assert_eq!(rpc_response.unwrap_err().code(), tonic::Code::Unauthenticated);
+
+    // The failed attempts did not rotate the Session
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn unregistered_redirect_uri_is_not_redirected(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let mut params = authorize_params(&oidc_client, &code_verifier);
+    params[2].1 = "https://attacker.example.com/callback".to_string();
+
+    //-- Execute Test (Act)
+    let response = http_client()?
+        .get(format!("{}/authorize", tonic_server.oidc_address))
+        .query(&params)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    assert!(response.headers().get(reqwest::header::LOCATION).is_none());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn missing_code_challenge_redirects_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params: Vec<_> = authorize_params(&oidc_client, &code_verifier)
+        .into_iter()
+        .filter(|(name, _)| !name.starts_with("code_challenge"))
+        .collect();
+
+    //-- Execute Test (Act)
+    let response = http_client()?
+        .get(format!("{}/authorize", tonic_server.oidc_address))
+        .query(&params)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert!(response.status().is_redirection());
+    let location = location(&response)?;
+    assert!(location.as_str().starts_with(REDIRECT_URI));
+    assert_eq!(query_param(&location, "error").as_deref(), Some("invalid_request"));
+    assert_eq!(query_param(&location, "state").as_deref(), Some("client-state"));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn wrong_password_shows_sign_in_form(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let (cookie, mut form) = sign_in_form(&tonic_server, &params).await?;
+    form.push(("email", random_user.email.to_string()));
+    form.push(("password", "not-the-password".to_string()));
+
+    //-- Execute Test (Act)
+    let response = post_sign_in(&tonic_server, &cookie, &form).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    assert!(response.headers().get(reqwest::header::LOCATION).is_none());
+    assert!(response.text().await?.contains(random_user.email.as_ref()));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn mfa_user_must_enter_code(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Enable TOTP for the user
+    let (totp_secret, secret) = helpers::mocks::totp_secrets(
+        &random_user,
+        &tonic_server.config.mfa.encryption_key,
+    )?;
+    totp_secret.insert(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let (cookie, sign_in_form) = sign_in_form(&tonic_server, &params).await?;
+    let mut form = sign_in_form.clone();
+    form.push(("email", random_user.email.to_string()));
+    form.push(("password", random_password.to_string()));
+
+    //-- Execute Test (Act)
+    // The password is accepted, but the MFA code page is shown instead of a code
+    let mfa_page = post_sign_in(&tonic_server, &cookie, &form).await?;
+    assert_eq!(mfa_page.status(), reqwest::StatusCode::OK);
+    let mfa_token = input_value(&mfa_page.text().await?, "mfa_token")?;
+
+    let mut form = sign_in_form.clone();
+    form.push(("mfa_token", mfa_token));
+    form.push(("mfa_code", helpers::mocks::totp_code(&secret)));
+    let response = post_sign_in(&tonic_server, &cookie, &form).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::SEE_OTHER);
+    let location = location(&response)?;
+    let code = query_param(&location, "code").ok_or("Missing code")?;
+
+    let response =
+        exchange_code(&tonic_server, &oidc_client, &code, &code_verifier, None).await?;
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn sign_in_requires_csrf_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let (cookie, mut form) = sign_in_form(&tonic_server, &params).await?;
+    form.push(("email", random_user.email.to_string()));
+    form.push(("password", random_password.to_string()));
+
+    //-- Execute Test (Act)
+    // A form posted from another site does not have the CSRF cookie
+    let without_cookie = http_client()?
+        .post(format!("{}/authorize", tonic_server.oidc_address))
+        .form(&form)
+        .send()
+        .await?;
+
+    // The CSRF token is only valid for the authorization request it was issued for
+    let mut other_request = form.clone();
+    other_request[4].1 = "other-state".to_string();
+    let other_request = post_sign_in(&tonic_server, &cookie, &other_request).await?;
+
+    let without_token: Vec<_> =
+        form.iter().filter(|(name, _)| *name != "csrf_token").cloned().collect();
+    let without_token = post_sign_in(&tonic_server, &cookie, &without_token).await?;
+
+    //-- Checks (Assertions)
+    for response in [without_cookie, other_request, without_token] {
+        assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert!(response.headers().get(reqwest::header::LOCATION).is_none());
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn passkey_user_signs_in_with_passkey(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server and client
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // The user has a passkey, but no TOTP
+    let mut authenticator = register_passkey(
+        &random_user,
+        &random_password,
+        &tonic_server,
+        &mut tonic_client,
+    )
+    .await?;
+
+    let (oidc_client, _client_secret) = register_client(false, &database).await?;
+    let code_verifier = domain::OneTimeToken::generate().to_string();
+    let params = authorize_params(&oidc_client, &code_verifier);
+    let (cookie, sign_in_form) = sign_in_form(&tonic_server, &params).await?;
+    let mut form = sign_in_form.clone();
+    form.push(("email", random_user.email.to_string()));
+    form.push(("password", random_password.to_string()));
+
+    //-- Execute Test (Act)
+    // The password is accepted, and the passkey is offered with the Recovery
+    // Codes issued when it was registered
+    let mfa_page = post_sign_in(&tonic_server, &cookie, &form).await?;
+    assert_eq!(mfa_page.status(), reqwest::StatusCode::OK);
+    let mfa_page = mfa_page.text().await?;
+    assert!(mfa_page.contains(r#"<form method="post" action="authorize" >"#));
+    let mfa_token = input_value(&mfa_page, "mfa_token")?;
+    let challenge = mfa_page
+        .split(r#"data-challenge=""#)
+        .nth(1)
+        .and_then(|value| value.split('"').next())
+        .ok_or("Missing challenge")?;
+
+    let assertion = authenticator.assert(&URL_SAFE_NO_PAD.decode(challenge)?);
+    let mut form = sign_in_form.clone();
+    form.push(("mfa_token", mfa_token));
+    form.push(("credential_id", URL_SAFE_NO_PAD.encode(&assertion.credential_id)));
+    form.push(("client_data_json", URL_SAFE_NO_PAD.encode(&assertion.client_data_json)));
+    form.push(("authenticator_data", URL_SAFE_NO_PAD.encode(&assertion.authenticator_data)));
+    form.push(("signature", URL_SAFE_NO_PAD.encode(&assertion.signature)));
+    let response = post_sign_in(&tonic_server, &cookie, &form).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::SEE_OTHER);
+    let location = location(&response)?;
+    let code = query_param(&location, "code").ok_or("Missing code")?;
+
+    let response =
+        exchange_code(&tonic_server, &oidc_client, &code, &code_verifier, None).await?;
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//-- ./tests/api/oidc/discovery.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the OIDC discovery endpoints
+
+use sqlx::{Pool, Postgres};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn openid_configuration_lists_endpoints(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    //-- Execute Test (Act)
+    let response = reqwest::get(format!(
+        "{}/.well-known/openid-configuration",
+        tonic_server.oidc_address
+    ))
+    .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let discovery: serde_json::Value = response.json().await?;
+    let issuer = tonic_server.config.oidc.issuer.trim_end_matches('/');
+    assert_eq!(discovery["issuer"], issuer);
+    assert_eq!(discovery["authorization_endpoint"], format!("{issuer}/authorize"));
+    assert_eq!(discovery["token_endpoint"], format!("{issuer}/token"));
+    assert_eq!(discovery["jwks_uri"], format!("{issuer}/jwks"));
+    assert_eq!(discovery["code_challenge_methods_supported"][0], "S256");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn jwks_publishes_signing_keys(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    //-- Execute Test (Act)
+    let response = reqwest::get(format!("{}/jwks", tonic_server.oidc_address)).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let jwks: serde_json::Value = response.json().await?;
+    assert_eq!(jwks["keys"][0]["kid"], tonic_server.config.jwt.key_id);
+
+    Ok(())
+}
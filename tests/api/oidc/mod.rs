@@ -0,0 +1,5 @@
+//-- ./tests/api/oidc/mod.rs
+
+mod authorization_code;
+mod discovery;
+mod userinfo;
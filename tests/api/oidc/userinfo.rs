@@ -0,0 +1,80 @@
+//-- ./tests/api/oidc/userinfo.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the OIDC userinfo endpoint
+
+use sqlx::{Pool, Postgres};
+
+use authentication_microservice::rpc::proto::LoginRequest;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn refresh_token_is_not_accepted(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token and Refresh Token
+    let login_response_message = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let access_response = reqwest::Client::new()
+        .get(format!("{}/userinfo", tonic_server.oidc_address))
+        .bearer_auth(login_response_message.access_token.unwrap())
+        .send()
+        .await?;
+
+    let refresh_response = reqwest::Client::new()
+        .get(format!("{}/userinfo", tonic_server.oidc_address))
+        .bearer_auth(login_response_message.refresh_token.unwrap())
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(access_response.status(), reqwest::StatusCode::OK);
+    let userinfo: serde_json::Value = access_response.json().await?;
+    assert_eq!(userinfo["name"], random_user.name.to_string());
+
+    assert_eq!(refresh_response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let error: serde_json::Value = refresh_response.json().await?;
+    assert_eq!(error["error"], "invalid_token");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn missing_token_returns_invalid_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    //-- Execute Test (Act)
+    let response = reqwest::get(format!("{}/userinfo", tonic_server.oidc_address)).await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//-- ./tests/api/oidc_clients/create.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the OIDC clients create endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::database;
+use authentication_microservice::rpc::proto::{OidcClientsCreateRequest, OidcClientsIndexRequest};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn confidential_client_returns_secret_once(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .oidc_clients()
+        .create(OidcClientsCreateRequest {
+            name: "Example Client".to_string(),
+            redirect_uris: vec!["https://client.example.com/callback".to_string()],
+            is_confidential: true,
+        })
+        .await?
+        .into_inner();
+
+    let index_response_message = tonic_client
+        .oidc_clients()
+        .index(OidcClientsIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    let client = response_message.client.unwrap();
+    assert!(client.is_confidential);
+    assert!(client.is_active);
+    assert_eq!(index_response_message.clients, vec![client.clone()]);
+
+    // Only the keyed hash of the client secret is stored
+    let client_secret = response_message.client_secret.unwrap();
+    let database_record =
+        database::OidcClients::from_id(&Uuid::parse_str(&client.id)?, &database).await?;
+    assert_ne!(database_record.client_secret_hash.as_deref(), Some(client_secret.as_str()));
+    assert!(database_record.verify_secret(
+        Some(&client_secret),
+        &tonic_server.config.jwt.token_hash_key,
+    ));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_redirect_uri_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .oidc_clients()
+        .create(OidcClientsCreateRequest {
+            name: "Example Client".to_string(),
+            redirect_uris: vec!["/callback".to_string()],
+            is_confidential: false,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//-- ./tests/api/oidc_clients/delete.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the OIDC clients delete endpoint
+
+use sqlx::{Pool, Postgres};
+
+use authentication_microservice::database;
+use authentication_microservice::rpc::proto::OidcClientsDeleteRequest;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn delete_removes_client(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let oidc_client = database::OidcClients::new(
+        "Example Client",
+        vec!["https://client.example.com/callback".to_string()],
+        None,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(&database)
+    .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .oidc_clients()
+        .delete(OidcClientsDeleteRequest {
+            id: oidc_client.id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 1);
+    assert!(database::OidcClients::from_id(&oidc_client.id, &database)
+        .await
+        .is_err());
+
+    Ok(())
+}
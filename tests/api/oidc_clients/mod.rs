@@ -0,0 +1,4 @@
+//-- ./tests/api/oidc_clients/mod.rs
+
+mod create;
+mod delete;
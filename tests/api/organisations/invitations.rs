@@ -0,0 +1,223 @@
+//-- ./tests/api/organisations/invitations.rs
+
+//! Module for integration testing organisation invitations
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::configuration::{Configuration, RegistrationPolicy};
+use authentication_microservice::rpc::proto::{
+    Empty, OrganisationsAcceptInvitationRequest, OrganisationsMembersRequest, RegisterRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::{create_organisation, invite, login_user, organisation_request, Error};
+
+#[sqlx::test]
+async fn existing_user_accepts_invitation(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (invitee, invitee_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (_other_user, other_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+
+    //-- Execute Test (Act)
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &invitee.email,
+        domain::OrganisationRole::Viewer,
+        &owner_tokens.access_token,
+    )
+    .await?;
+
+    // The invitation can only be accepted by the user it was sent to
+    let other_user_response = tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest {
+                token: token.clone(),
+            },
+            &other_tokens.access_token,
+        )?)
+        .await;
+
+    let accepted = tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest { token },
+            &invitee_tokens.access_token,
+        )?)
+        .await?
+        .into_inner();
+
+    let index = tonic_client
+        .organisations()
+        .index(organisation_request(Empty {}, &invitee_tokens.access_token)?)
+        .await?
+        .into_inner();
+
+    let members = tonic_client
+        .organisations()
+        .members(organisation_request(
+            OrganisationsMembersRequest {
+                organisation_id: organisation.id.clone(),
+            },
+            &invitee_tokens.access_token,
+        )?)
+        .await?
+        .into_inner()
+        .members;
+
+    //-- Checks (Assertions)
+    assert_eq!(other_user_response.unwrap_err().code(), Code::Unauthenticated);
+
+    assert_eq!(organisation.role, "owner");
+    assert_eq!(accepted.id, organisation.id);
+    assert_eq!(accepted.role, "viewer");
+    assert!(!accepted.is_active);
+    assert_eq!(index.organisations, vec![accepted]);
+
+    let mut member_roles = members
+        .iter()
+        .map(|member| (member.user_id.clone(), member.role.clone()))
+        .collect::<Vec<_>>();
+    member_roles.sort();
+    let mut expected_roles = vec![
+        (owner.id.to_string(), "owner".to_string()),
+        (invitee.id.to_string(), "viewer".to_string()),
+    ];
+    expected_roles.sort();
+    assert_eq!(member_roles, expected_roles);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invitation_registers_new_user(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server with registration closed, which invited users
+    // can still register through
+    let mut config = Configuration::parse()?;
+    config.registration.policy = RegistrationPolicy::Closed;
+    let tonic_server =
+        helpers::TonicServer::spawn_server_with_config(&database, config).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?;
+
+    //-- Execute Test (Act)
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &random_user.email,
+        domain::OrganisationRole::Member,
+        &owner_tokens.access_token,
+    )
+    .await?;
+
+    let register_request = |invitation_token: Option<String>| RegisterRequest {
+        email: random_user.email.to_string(),
+        password: random_password.clone(),
+        name: random_user.name.to_string(),
+        invitation_token,
+    };
+    let uninvited_response = tonic_client
+        .authentication()
+        .register(register_request(None))
+        .await;
+    let response_message = tonic_client
+        .authentication()
+        .register(register_request(Some(token)))
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(uninvited_response.unwrap_err().code(), Code::PermissionDenied);
+
+    // Invited users are active and verified, and join the organisation
+    assert!(response_message.is_active);
+    assert!(response_message.is_verified);
+    let user_id = Uuid::parse_str(&response_message.user_id)?;
+    let membership = database::OrganisationMembers::from_ids(
+        &Uuid::parse_str(&organisation.id)?,
+        &user_id,
+        &database,
+    )
+    .await?
+    .ok_or("No membership")?;
+    assert_eq!(membership.role, domain::OrganisationRole::Member);
+
+    // The first Access Token is active in the organisation
+    let access_token = response_message.access_token.ok_or("No Access Token")?;
+    let token_claim = domain::TokenClaim::from_token(
+        &access_token,
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+    )?;
+    assert_eq!(token_claim.org, Some(organisation.id));
+    assert_eq!(token_claim.jor, Some("member".to_string()));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn only_owners_invite(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (member, member_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &member.email,
+        domain::OrganisationRole::Member,
+        &owner_tokens.access_token,
+    )
+    .await?;
+    tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest { token },
+            &member_tokens.access_token,
+        )?)
+        .await?;
+
+    //-- Execute Test (Act)
+    let invitee_email = helpers::mocks::users(&helpers::mocks::password()?)?.email;
+    let member_invite = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &invitee_email,
+        domain::OrganisationRole::Owner,
+        &member_tokens.access_token,
+    )
+    .await;
+
+    //-- Checks (Assertions)
+    let status = member_invite
+        .unwrap_err()
+        .downcast::<tonic::Status>()
+        .map_err(|_| "Expected a tonic Status")?;
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert!(tonic_server.email_spool.emails_to(invitee_email.as_ref())?.is_empty());
+
+    Ok(())
+}
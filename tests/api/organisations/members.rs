@@ -0,0 +1,296 @@
+//-- ./tests/api/organisations/members.rs
+
+//! Module for integration testing listing and removing organisation members
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::{
+    ApiKeysCreateRequest, OrganisationsAcceptInvitationRequest, OrganisationsInviteRequest,
+    OrganisationsMembersRequest, OrganisationsRemoveMemberRequest,
+};
+
+use crate::helpers;
+
+use super::{create_organisation, invite, login_user, organisation_request, Error};
+
+/// Build a remove member request for the organisation and user
+fn remove_request(
+    organisation_id: &str,
+    user_id: &uuid::Uuid,
+    access_token: &str,
+) -> Result<tonic::Request<OrganisationsRemoveMemberRequest>, Error> {
+    organisation_request(
+        OrganisationsRemoveMemberRequest {
+            organisation_id: organisation_id.to_string(),
+            user_id: user_id.to_string(),
+        },
+        access_token,
+    )
+}
+
+#[sqlx::test]
+async fn owners_remove_members(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (member, member_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (_outsider, outsider_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &member.email,
+        domain::OrganisationRole::Member,
+        &owner_tokens.access_token,
+    )
+    .await?;
+    tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest { token },
+            &member_tokens.access_token,
+        )?)
+        .await?;
+
+    //-- Execute Test (Act)
+    // Users outside the organisation cannot list its members
+    let outsider_members = tonic_client
+        .organisations()
+        .members(organisation_request(
+            OrganisationsMembersRequest {
+                organisation_id: organisation.id.clone(),
+            },
+            &outsider_tokens.access_token,
+        )?)
+        .await;
+
+    // Members cannot remove other members
+    let member_removes_owner = tonic_client
+        .organisations()
+        .remove_member(remove_request(
+            &organisation.id,
+            &owner.id,
+            &member_tokens.access_token,
+        )?)
+        .await;
+
+    // The last owner cannot leave
+    let owner_leaves = tonic_client
+        .organisations()
+        .remove_member(remove_request(
+            &organisation.id,
+            &owner.id,
+            &owner_tokens.access_token,
+        )?)
+        .await;
+
+    let removed = tonic_client
+        .organisations()
+        .remove_member(remove_request(
+            &organisation.id,
+            &member.id,
+            &owner_tokens.access_token,
+        )?)
+        .await?
+        .into_inner();
+
+    let members = tonic_client
+        .organisations()
+        .members(organisation_request(
+            OrganisationsMembersRequest {
+                organisation_id: organisation.id.clone(),
+            },
+            &owner_tokens.access_token,
+        )?)
+        .await?
+        .into_inner()
+        .members;
+
+    //-- Checks (Assertions)
+    assert_eq!(outsider_members.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(member_removes_owner.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(owner_leaves.unwrap_err().code(), Code::FailedPrecondition);
+    assert_eq!(removed.rows_affected, 1);
+
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].user_id, owner.id.to_string());
+    assert_eq!(members[0].email, owner.email.to_string());
+    assert_eq!(members[0].role, "owner");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn members_leave(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (viewer, viewer_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &viewer.email,
+        domain::OrganisationRole::Viewer,
+        &owner_tokens.access_token,
+    )
+    .await?;
+    tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest { token },
+            &viewer_tokens.access_token,
+        )?)
+        .await?;
+
+    //-- Execute Test (Act)
+    let left = tonic_client
+        .organisations()
+        .remove_member(remove_request(
+            &organisation.id,
+            &viewer.id,
+            &viewer_tokens.access_token,
+        )?)
+        .await?
+        .into_inner();
+
+    let members_after_leaving = tonic_client
+        .organisations()
+        .members(organisation_request(
+            OrganisationsMembersRequest {
+                organisation_id: organisation.id.clone(),
+            },
+            &viewer_tokens.access_token,
+        )?)
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(left.rows_affected, 1);
+    assert_eq!(
+        members_after_leaving.unwrap_err().code(),
+        Code::PermissionDenied
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn owners_removing_each_other_keep_an_owner(
+    database: Pool<Postgres>,
+) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (first_owner, first_owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (second_owner, second_owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation =
+        create_organisation(&mut tonic_client, &first_owner_tokens.access_token).await?;
+    let token = invite(
+        &tonic_server,
+        &mut tonic_client,
+        &organisation.id,
+        &second_owner.email,
+        domain::OrganisationRole::Owner,
+        &first_owner_tokens.access_token,
+    )
+    .await?;
+    tonic_client
+        .organisations()
+        .accept_invitation(organisation_request(
+            OrganisationsAcceptInvitationRequest { token },
+            &second_owner_tokens.access_token,
+        )?)
+        .await?;
+
+    //-- Execute Test (Act)
+    // Each owner removes the other at the same time, from separate clients
+    let mut first_client = tonic_client.organisations().clone();
+    let mut second_client = tonic_client.organisations().clone();
+    let first_request = remove_request(
+        &organisation.id,
+        &second_owner.id,
+        &first_owner_tokens.access_token,
+    )?;
+    let second_request = remove_request(
+        &organisation.id,
+        &first_owner.id,
+        &second_owner_tokens.access_token,
+    )?;
+    let (first_removes_second, second_removes_first) = tokio::join!(
+        first_client.remove_member(first_request),
+        second_client.remove_member(second_request),
+    );
+
+    let owners: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM organisation_members WHERE organisation_id = $1 AND role = 'owner'",
+    )
+    .bind(uuid::Uuid::parse_str(&organisation.id)?)
+    .fetch_one(&database)
+    .await?;
+
+    //-- Checks (Assertions)
+    // One removal succeeds and the other is refused, either as the last owner
+    // or because the requester was already removed
+    let results = [first_removes_second, second_removes_first];
+    assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+    assert!(results.iter().any(|result| {
+        matches!(
+            result,
+            Err(status)
+                if status.code() == Code::FailedPrecondition
+                    || status.code() == Code::PermissionDenied
+        )
+    }));
+    assert_eq!(owners, 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn api_key_cannot_manage_members(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+    let api_key = tonic_client
+        .api_keys()
+        .create(organisation_request(
+            ApiKeysCreateRequest {
+                name: "Ledger Export".to_string(),
+                scopes: vec![],
+                expires_on: None,
+            },
+            &owner_tokens.access_token,
+        )?)
+        .await?
+        .into_inner()
+        .key;
+
+    //-- Execute Test (Act)
+    // A leaked API key cannot invite new owners
+    let invitee_email = helpers::mocks::users(&helpers::mocks::password()?)?.email;
+    let mut request = tonic::Request::new(OrganisationsInviteRequest {
+        organisation_id: organisation.id,
+        email: invitee_email.to_string(),
+        role: domain::OrganisationRole::Owner.to_string(),
+    });
+    request.metadata_mut().append("api_key", api_key.parse()?);
+    let response = tonic_client.organisations().invite(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+    assert!(tonic_server.email_spool.emails_to(invitee_email.as_ref())?.is_empty());
+
+    Ok(())
+}
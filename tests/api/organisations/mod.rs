@@ -0,0 +1,102 @@
+//-- ./tests/api/organisations/mod.rs
+
+use authentication_microservice::rpc::proto::{
+    LoginRequest, OrganisationResponse, OrganisationsCreateRequest, OrganisationsInviteRequest,
+    TokenResponse,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+mod invitations;
+mod members;
+mod switch;
+
+pub type Error = Box<dyn std::error::Error>;
+
+/// Insert an active, verified user into the database and login, returning the
+/// user and the Access and Refresh Tokens of the new Session
+pub async fn login_user(
+    tonic_client: &mut helpers::TonicClient,
+    database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<(database::Users, TokenResponse), Error> {
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let random_user = random_user.insert(database).await?;
+
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password,
+        remember_me_seconds: None,
+    };
+    let login_response_message = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner();
+
+    let tokens = TokenResponse {
+        access_token: login_response_message.access_token.ok_or("No Access Token")?,
+        refresh_token: login_response_message.refresh_token.ok_or("No Refresh Token")?,
+    };
+
+    Ok((random_user, tokens))
+}
+
+/// Build a request with the Access Token, as Organisations endpoints act on
+/// the user in the Access Token
+pub fn organisation_request<T>(
+    message: T,
+    access_token: &str,
+) -> Result<tonic::Request<T>, Error> {
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .append("access_token", access_token.parse()?);
+
+    Ok(request)
+}
+
+/// Create an organisation owned by the user with the Access Token
+pub async fn create_organisation(
+    tonic_client: &mut helpers::TonicClient,
+    access_token: &str,
+) -> Result<OrganisationResponse, Error> {
+    let request_message = OrganisationsCreateRequest {
+        name: "The Smith Household".to_string(),
+    };
+    let response_message = tonic_client
+        .organisations()
+        .create(organisation_request(request_message, access_token)?)
+        .await?
+        .into_inner();
+
+    Ok(response_message)
+}
+
+/// Invite the email address to the organisation with the role, returning the
+/// invitation token emailed to it
+pub async fn invite(
+    tonic_server: &helpers::TonicServer,
+    tonic_client: &mut helpers::TonicClient,
+    organisation_id: &str,
+    email: &domain::EmailAddress,
+    role: domain::OrganisationRole,
+    access_token: &str,
+) -> Result<String, Error> {
+    let request_message = OrganisationsInviteRequest {
+        organisation_id: organisation_id.to_string(),
+        email: email.to_string(),
+        role: role.to_string(),
+    };
+    tonic_client
+        .organisations()
+        .invite(organisation_request(request_message, access_token)?)
+        .await?;
+
+    let invitation = tonic_server.email_spool.wait_for_email(email.as_ref()).await?;
+
+    Ok(invitation.one_time_token().ok_or("No token")?)
+}
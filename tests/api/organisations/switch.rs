@@ -0,0 +1,183 @@
+//-- ./tests/api/organisations/switch.rs
+
+//! Module for integration testing switching the active organisation
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+use uuid::Uuid;
+
+use authentication_microservice::rpc::proto::{
+    Empty, OrganisationsRemoveMemberRequest, RefreshRequest, SwitchOrganisationRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+use super::{create_organisation, login_user, organisation_request, Error};
+
+/// Decode the organisation id claim of an Access Token
+fn organisation_claim(
+    tonic_server: &helpers::TonicServer,
+    access_token: &str,
+) -> Result<Option<String>, Error> {
+    let token_claim = domain::TokenClaim::from_token(
+        access_token,
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+    )?;
+
+    Ok(token_claim.org)
+}
+
+#[sqlx::test]
+async fn switch_active_organisation(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_user, tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &tokens.access_token).await?;
+
+    //-- Execute Test (Act)
+    let switched = tonic_client
+        .authentication()
+        .switch_organisation(SwitchOrganisationRequest {
+            refresh_token: tokens.refresh_token,
+            organisation_id: Some(organisation.id.clone()),
+        })
+        .await?
+        .into_inner();
+
+    let index = tonic_client
+        .organisations()
+        .index(organisation_request(Empty {}, &switched.access_token)?)
+        .await?
+        .into_inner();
+
+    // Refreshing keeps the organisation
+    let refreshed = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: switched.refresh_token,
+        })
+        .await?
+        .into_inner();
+
+    // Without an organisation the session returns to the users own account
+    let cleared = tonic_client
+        .authentication()
+        .switch_organisation(SwitchOrganisationRequest {
+            refresh_token: refreshed.refresh_token.clone(),
+            organisation_id: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(
+        organisation_claim(&tonic_server, &switched.access_token)?,
+        Some(organisation.id.clone())
+    );
+    assert!(index.organisations[0].is_active);
+    assert_eq!(
+        organisation_claim(&tonic_server, &refreshed.access_token)?,
+        Some(organisation.id)
+    );
+    assert_eq!(organisation_claim(&tonic_server, &cleared.access_token)?, None);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn switch_requires_membership(database: Pool<Postgres>) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (_owner, owner_tokens) = login_user(&mut tonic_client, &database).await?;
+    let (_user, tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &owner_tokens.access_token).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .authentication()
+        .switch_organisation(SwitchOrganisationRequest {
+            refresh_token: tokens.refresh_token.clone(),
+            organisation_id: Some(organisation.id),
+        })
+        .await;
+
+    // The Session was not rotated, so the Refresh Token can still be used
+    let refreshed = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: tokens.refresh_token,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(organisation_claim(&tonic_server, &refreshed.access_token)?, None);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn refresh_drops_organisation_after_leaving(
+    database: Pool<Postgres>,
+) -> Result<(), Error> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let (owner, tokens) = login_user(&mut tonic_client, &database).await?;
+    let organisation = create_organisation(&mut tonic_client, &tokens.access_token).await?;
+    let organisation_id = Uuid::parse_str(&organisation.id)?;
+
+    // Add a second owner, so the first can leave
+    let second_owner = helpers::mocks::users(&helpers::mocks::password()?)?
+        .insert(&database)
+        .await?;
+    database::OrganisationMembers::new(
+        &organisation_id,
+        &second_owner.id,
+        domain::OrganisationRole::Owner,
+    )
+    .insert(&database)
+    .await?;
+
+    let switched = tonic_client
+        .authentication()
+        .switch_organisation(SwitchOrganisationRequest {
+            refresh_token: tokens.refresh_token,
+            organisation_id: Some(organisation.id.clone()),
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    tonic_client
+        .organisations()
+        .remove_member(organisation_request(
+            OrganisationsRemoveMemberRequest {
+                organisation_id: organisation.id,
+                user_id: owner.id.to_string(),
+            },
+            &switched.access_token,
+        )?)
+        .await?;
+
+    let refreshed = tonic_client
+        .authentication()
+        .refresh(RefreshRequest {
+            refresh_token: switched.refresh_token,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(organisation_claim(&tonic_server, &refreshed.access_token)?, None);
+
+    Ok(())
+}
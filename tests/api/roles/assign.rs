@@ -0,0 +1,131 @@
+//-- ./tests/api/roles/assign.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the roles assign and unassign endpoints
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::users_client::UsersClient;
+use authentication_microservice::rpc::proto::{
+    RolesAssignRequest, RolesCreateRequest, RolesUserRequest, UserIndexRequest,
+};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn assigned_role_grants_permissions(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the User role
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Users client that does not add the admin Access Token
+    let mut users_client = UsersClient::new(tonic_server.clone().client_channel().await?);
+
+    let role = tonic_client
+        .roles()
+        .create(RolesCreateRequest {
+            name: "auditor".to_string(),
+            description: "Read only access to users".to_string(),
+            permissions: vec!["users:read:any".to_string()],
+        })
+        .await?
+        .into_inner();
+    let assign_request = RolesAssignRequest {
+        user_id: random_user.id.to_string(),
+        role_id: role.id.clone(),
+    };
+    let index_request = UserIndexRequest {
+        limit: 10,
+        offset: 0,
+    };
+
+    //-- Execute Test (Act)
+    let request = helpers::user_request(index_request, &random_user, &tonic_server).await?;
+    let before_response = users_client.index(request).await;
+
+    let assigned = tonic_client
+        .roles()
+        .assign(assign_request.clone())
+        .await?
+        .into_inner();
+    let request = helpers::user_request(index_request, &random_user, &tonic_server).await?;
+    let assigned_response = users_client.index(request).await;
+
+    let unassigned = tonic_client
+        .roles()
+        .unassign(assign_request)
+        .await?
+        .into_inner();
+    let request = helpers::user_request(index_request, &random_user, &tonic_server).await?;
+    let unassigned_response = users_client.index(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(before_response.unwrap_err().code(), Code::PermissionDenied);
+
+    assert_eq!(assigned.user_role, "user");
+    assert_eq!(assigned.roles, vec![role]);
+    assert!(assigned.permissions.contains(&"users:read:any".to_string()));
+    assert!(assigned_response.is_ok());
+
+    assert!(unassigned.roles.is_empty());
+    assert!(!unassigned.permissions.contains(&"users:read:any".to_string()));
+    assert_eq!(unassigned_response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn user_roles_list_user_role_permissions(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the Guest role
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::Guest;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .roles()
+        .user(RolesUserRequest {
+            user_id: random_user.id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let unknown_role = tonic_client
+        .roles()
+        .assign(RolesAssignRequest {
+            user_id: random_user.id.to_string(),
+            role_id: uuid::Uuid::now_v7().to_string(),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.user_role, "guest");
+    assert!(response_message.roles.is_empty());
+    assert_eq!(response_message.permissions, vec!["users:read:self"]);
+    assert_eq!(unknown_role.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//-- ./tests/api/roles/create.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the roles create endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::roles_client::RolesClient;
+use authentication_microservice::rpc::proto::{RolesCreateRequest, RolesIndexRequest};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn created_role_is_indexed(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let role = tonic_client
+        .roles()
+        .create(RolesCreateRequest {
+            name: "auditor".to_string(),
+            description: "Read only access to users and logins".to_string(),
+            permissions: vec!["users:read:any".to_string(), "logins:read:any".to_string()],
+        })
+        .await?
+        .into_inner();
+
+    let index_response_message = tonic_client
+        .roles()
+        .index(RolesIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(role.name, "auditor");
+    assert_eq!(role.permissions, vec!["users:read:any", "logins:read:any"]);
+    assert!(!role.is_system);
+    assert_eq!(
+        index_response_message
+            .roles
+            .iter()
+            .map(|role| role.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["admin", "auditor", "guest", "user"]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn unknown_permission_or_duplicate_name_returns_error(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let unknown_permission = tonic_client
+        .roles()
+        .create(RolesCreateRequest {
+            name: "bookkeeper".to_string(),
+            description: String::new(),
+            permissions: vec!["ledger:write:any".to_string()],
+        })
+        .await;
+
+    let duplicate_name = tonic_client
+        .roles()
+        .create(RolesCreateRequest {
+            name: "admin".to_string(),
+            description: String::new(),
+            permissions: vec![],
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(unknown_permission.unwrap_err().code(), Code::InvalidArgument);
+    assert_eq!(duplicate_name.unwrap_err().code(), Code::AlreadyExists);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn user_cannot_create_roles(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the User role
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Roles client that does not add the admin Access Token
+    let mut roles_client = RolesClient::new(tonic_server.clone().client_channel().await?);
+
+    //-- Execute Test (Act)
+    let request = helpers::user_request(
+        RolesCreateRequest {
+            name: "escalated".to_string(),
+            description: String::new(),
+            permissions: vec!["users:update:any".to_string()],
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let response = roles_client.create(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//-- ./tests/api/roles/delete.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the roles delete endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::database;
+use authentication_microservice::rpc::proto::{
+    RolesAssignRequest, RolesCreateRequest, RolesDeleteRequest, RolesIndexRequest,
+    RolesUserRequest,
+};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn deleted_role_is_unassigned(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user to assign the role to
+    let random_password = helpers::mocks::password()?;
+    let random_user = helpers::mocks::users(&random_password)?.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let role = tonic_client
+        .roles()
+        .create(RolesCreateRequest {
+            name: "household-member".to_string(),
+            description: "Can see the logins of the household".to_string(),
+            permissions: vec!["logins:read:any".to_string()],
+        })
+        .await?
+        .into_inner();
+    tonic_client
+        .roles()
+        .assign(RolesAssignRequest {
+            user_id: random_user.id.to_string(),
+            role_id: role.id.clone(),
+        })
+        .await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .roles()
+        .delete(RolesDeleteRequest { id: role.id })
+        .await?
+        .into_inner();
+
+    let user_roles = tonic_client
+        .roles()
+        .user(RolesUserRequest {
+            user_id: random_user.id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 1);
+    assert!(user_roles.roles.is_empty());
+    assert!(database::Roles::index_user_id(&random_user.id, &database)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn system_role_cannot_be_deleted(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let admin_role = tonic_client
+        .roles()
+        .index(RolesIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?
+        .into_inner()
+        .roles
+        .into_iter()
+        .find(|role| role.name == "admin")
+        .ok_or("Admin role not seeded")?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .roles()
+        .delete(RolesDeleteRequest { id: admin_role.id })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::FailedPrecondition);
+
+    Ok(())
+}
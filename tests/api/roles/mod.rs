@@ -0,0 +1,6 @@
+//-- ./tests/api/roles/mod.rs
+
+mod assign;
+mod create;
+mod delete;
+mod update;
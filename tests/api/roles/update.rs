@@ -0,0 +1,123 @@
+//-- ./tests/api/roles/update.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the roles update endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::logins_client::LoginsClient;
+use authentication_microservice::rpc::proto::{
+    LoginsIndexRequest, RolesIndexRequest, RolesResponse, RolesUpdateRequest,
+};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Find the system role with the name
+async fn system_role(
+    tonic_client: &mut helpers::TonicClient,
+    name: &str,
+) -> Result<RolesResponse> {
+    let roles = tonic_client
+        .roles()
+        .index(RolesIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?
+        .into_inner()
+        .roles;
+
+    roles
+        .into_iter()
+        .find(|role| role.is_system && role.name == name)
+        .ok_or_else(|| format!("System role not found: {name}").into())
+}
+
+#[sqlx::test]
+async fn updated_system_role_grants_permissions(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the User role
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Logins client that does not add the admin Access Token
+    let mut logins_client = LoginsClient::new(tonic_server.clone().client_channel().await?);
+
+    let user_role = system_role(&mut tonic_client, "user").await?;
+    let mut permissions = user_role.permissions.clone();
+    permissions.push("logins:read:any".to_string());
+
+    //-- Execute Test (Act)
+    let updated_role = tonic_client
+        .roles()
+        .update(RolesUpdateRequest {
+            id: user_role.id.clone(),
+            name: user_role.name.clone(),
+            description: "Users can see every login".to_string(),
+            permissions,
+        })
+        .await?
+        .into_inner();
+
+    let request = helpers::user_request(
+        LoginsIndexRequest {
+            limit: 10,
+            offset: 0,
+            outcome: None,
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let index_response = logins_client.index(request).await;
+
+    //-- Checks (Assertions)
+    assert!(updated_role.is_system);
+    assert_eq!(updated_role.description, "Users can see every login");
+    assert!(updated_role.permissions.contains(&"logins:read:any".to_string()));
+    assert!(index_response.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn system_role_cannot_be_renamed(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let guest_role = system_role(&mut tonic_client, "guest").await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .roles()
+        .update(RolesUpdateRequest {
+            id: guest_role.id,
+            name: "visitor".to_string(),
+            description: guest_role.description,
+            permissions: guest_role.permissions,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::FailedPrecondition);
+
+    Ok(())
+}
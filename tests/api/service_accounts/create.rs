@@ -0,0 +1,145 @@
+//-- ./tests/api/service_accounts/create.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the service accounts create endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::service_accounts_client::ServiceAccountsClient;
+use authentication_microservice::rpc::proto::{
+    ClientCredentialsRequest, ServiceAccountsCreateRequest, ServiceAccountsIndexRequest,
+};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn created_secret_issues_access_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .service_accounts()
+        .create(ServiceAccountsCreateRequest {
+            name: "Reconciliation Worker".to_string(),
+            scopes: vec!["users".to_string(), "logins".to_string()],
+        })
+        .await?
+        .into_inner();
+    let service_account = response_message.service_account.unwrap();
+
+    let token_response_message = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.clone(),
+            client_secret: response_message.client_secret,
+            scope: None,
+        })
+        .await?
+        .into_inner();
+
+    let index_response_message = tonic_client
+        .service_accounts()
+        .index(ServiceAccountsIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(service_account.scopes, vec!["logins", "users"]);
+    assert!(service_account.is_active);
+    assert_eq!(token_response_message.scope, "logins users");
+    assert_eq!(index_response_message.service_accounts, vec![service_account]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn unknown_scope_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .service_accounts()
+        .create(ServiceAccountsCreateRequest {
+            name: "Reconciliation Worker".to_string(),
+            scopes: vec!["service_accounts".to_string()],
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn service_account_cannot_create_service_accounts(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Create a service account granted every scope
+    let response_message = tonic_client
+        .service_accounts()
+        .create(ServiceAccountsCreateRequest {
+            name: "Reconciliation Worker".to_string(),
+            scopes: vec![
+                "logins".to_string(),
+                "oidc_clients".to_string(),
+                "sessions".to_string(),
+                "signing_keys".to_string(),
+                "users".to_string(),
+            ],
+        })
+        .await?
+        .into_inner();
+    let token_response_message = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: response_message.service_account.unwrap().id,
+            client_secret: response_message.client_secret,
+            scope: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    let mut request = tonic::Request::new(ServiceAccountsCreateRequest {
+        name: "Escalated Worker".to_string(),
+        scopes: vec!["users".to_string()],
+    });
+    request
+        .metadata_mut()
+        .append("access_token", token_response_message.access_token.parse()?);
+    let response =
+        ServiceAccountsClient::new(tonic_server.clone().client_channel().await?)
+            .create(request)
+            .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
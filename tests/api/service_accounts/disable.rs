@@ -0,0 +1,151 @@
+//-- ./tests/api/service_accounts/disable.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the service accounts disable endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::users_client::UsersClient;
+use authentication_microservice::rpc::proto::{
+    ClientCredentialsRequest, IntrospectRequest, ServiceAccountsDisableRequest, UserIndexRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn disabled_service_account_is_not_issued_tokens(
+    database: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let client_secret = domain::OneTimeToken::generate();
+    let service_account = database::ServiceAccounts::new(
+        "Statement Importer",
+        &[domain::ServiceScope::Users],
+        &client_secret,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(&database)
+    .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .service_accounts()
+        .disable(ServiceAccountsDisableRequest {
+            id: service_account.id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let response = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert!(!response_message.is_active);
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn disabled_service_account_tokens_are_revoked(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let client_secret = domain::OneTimeToken::generate();
+    let service_account = database::ServiceAccounts::new(
+        "Statement Importer",
+        &[domain::ServiceScope::Users],
+        &client_secret,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(&database)
+    .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let access_token = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: None,
+        })
+        .await?
+        .into_inner()
+        .access_token;
+
+    // The Access Token is active for the service account
+    let mut request = tonic::Request::new(IntrospectRequest {
+        token: access_token.to_string(),
+    });
+    request
+        .metadata_mut()
+        .append("access_token", tonic_server.access_token.parse()?);
+    let active_response_message = tonic_client
+        .authentication()
+        .introspect(request)
+        .await?
+        .into_inner();
+
+    //-- Execute Test (Act)
+    tonic_client
+        .service_accounts()
+        .disable(ServiceAccountsDisableRequest {
+            id: service_account.id.to_string(),
+        })
+        .await?;
+
+    let mut request = tonic::Request::new(UserIndexRequest {
+        limit: 10,
+        offset: 0,
+    });
+    request
+        .metadata_mut()
+        .append("access_token", access_token.parse()?);
+    let users_response = UsersClient::new(tonic_server.clone().client_channel().await?)
+        .index(request)
+        .await;
+
+    let mut request = tonic::Request::new(IntrospectRequest {
+        token: access_token.to_string(),
+    });
+    request
+        .metadata_mut()
+        .append("access_token", tonic_server.access_token.parse()?);
+    let revoked_response_message = tonic_client
+        .authentication()
+        .introspect(request)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert!(active_response_message.active);
+    assert_eq!(active_response_message.sub, Some(service_account.id.to_string()));
+    assert_eq!(active_response_message.role, None);
+
+    // The Access Token issued before the service account was disabled is revoked
+    assert_eq!(users_response.unwrap_err().code(), Code::Unauthenticated);
+    assert!(!revoked_response_message.active);
+
+    Ok(())
+}
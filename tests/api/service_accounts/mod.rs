@@ -0,0 +1,5 @@
+//-- ./tests/api/service_accounts/mod.rs
+
+mod create;
+mod disable;
+mod rotate;
@@ -0,0 +1,77 @@
+//-- ./tests/api/service_accounts/rotate.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the service accounts rotate endpoint
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::rpc::proto::{
+    ClientCredentialsRequest, ServiceAccountsRotateRequest,
+};
+use authentication_microservice::{database, domain};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn old_secret_stops_working(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let old_client_secret = domain::OneTimeToken::generate();
+    let service_account = database::ServiceAccounts::new(
+        "Statement Importer",
+        &[domain::ServiceScope::Users],
+        &old_client_secret,
+        &helpers::mocks::hash_key()?,
+    )
+    .insert(&database)
+    .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .service_accounts()
+        .rotate(ServiceAccountsRotateRequest {
+            id: service_account.id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let old_secret_response = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: old_client_secret.to_string(),
+            scope: None,
+        })
+        .await;
+
+    let new_secret_response = tonic_client
+        .authentication()
+        .client_credentials(ClientCredentialsRequest {
+            client_id: service_account.id.to_string(),
+            client_secret: response_message.client_secret.clone(),
+            scope: None,
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_ne!(response_message.client_secret, old_client_secret.to_string());
+    assert!(response_message
+        .service_account
+        .unwrap()
+        .secret_rotated_on
+        .is_some());
+    assert_eq!(old_secret_response.unwrap_err().code(), Code::Unauthenticated);
+    assert!(new_secret_response.is_ok());
+
+    Ok(())
+}
@@ -4,8 +4,14 @@
 
 use fake::Fake;
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 
-use authentication_microservice::rpc::proto::{Empty, SessionsRevokeRequest, SessionsRevokeUserRequest};
+use authentication_microservice::domain;
+use authentication_microservice::rpc::proto::sessions_client::SessionsClient;
+use authentication_microservice::rpc::proto::{
+    Empty, LoginRequest, SessionsRevokeRequest, SessionsRevokeUserRequest,
+    UpdatePasswordRequest,
+};
 
 use crate::helpers;
 
@@ -147,3 +153,130 @@ async fn revoke_all_returns_int(database: Pool<Postgres>) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn revoke_user_revokes_access_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token associated with a Session
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let access_token = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner()
+        .access_token
+        .unwrap();
+
+    //-- Execute Test (Act)
+    // Revoke the users Sessions
+    let request_message = SessionsRevokeUserRequest {
+        user_id: random_user.id.to_string(),
+    };
+    let _response_message = tonic_client
+        .sessions()
+        .revoke_user(request_message)
+        .await?;
+
+    // Use the Access Token from the login, which has not expired yet
+    let update_password_request_message = UpdatePasswordRequest {
+        email: random_user.email.to_string(),
+        password_original: random_password.to_string(),
+        password_new: helpers::mocks::password()?,
+    };
+    let mut update_password_request =
+        tonic::Request::new(update_password_request_message);
+    update_password_request
+        .metadata_mut()
+        .append("access_token", access_token.parse().unwrap());
+    let response = tonic_client
+        .authentication()
+        .update_password(update_password_request)
+        .await;
+
+    //-- Checks (Assertions)
+    // The Access Token is rejected once its Session is revoked
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn user_revokes_own_sessions_only(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user with the User role, and another user with a Session
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+    let other_user = helpers::mocks::users(&random_password)?.insert(&database).await?;
+
+    let own_session = helpers::mocks::sessions(&random_user)?.insert(&database).await?;
+    let other_session = helpers::mocks::sessions(&other_user)?.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Sessions client that does not add the admin Access Token
+    let mut sessions_client =
+        SessionsClient::new(tonic_server.clone().client_channel().await?);
+
+    //-- Execute Test (Act)
+    let own_request = helpers::user_request(
+        SessionsRevokeRequest {
+            id: own_session.id.to_string(),
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let own_response = sessions_client.revoke(own_request).await;
+
+    let other_request = helpers::user_request(
+        SessionsRevokeRequest {
+            id: other_session.id.to_string(),
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let other_response = sessions_client.revoke(other_request).await;
+
+    let other_user_request = helpers::user_request(
+        SessionsRevokeUserRequest {
+            user_id: other_user.id.to_string(),
+        },
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let other_user_response = sessions_client.revoke_user(other_user_request).await;
+
+    let all_request = helpers::user_request(Empty {}, &random_user, &tonic_server).await?;
+    let all_response = sessions_client.revoke_all(all_request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(own_response?.into_inner().rows_affected, 1);
+    assert_eq!(other_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(other_user_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(all_response.unwrap_err().code(), Code::PermissionDenied);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//-- ./tests/api/signing_keys/index.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing the signing keys index endpoint
+
+use authentication_microservice::{database, domain};
+use authentication_microservice::rpc::proto::signing_keys_client::SigningKeysClient;
+use authentication_microservice::rpc::proto::Empty;
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn configured_key_is_imported(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .signing_keys()
+        .index(Empty {})
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.signing_keys.len(), 1);
+    let signing_key = &response_message.signing_keys[0];
+    assert_eq!(signing_key.key_id, tonic_server.config.jwt.key_id);
+    assert_eq!(signing_key.algorithm, tonic_server.config.jwt.algorithm.to_string());
+    assert_eq!(signing_key.retires_on, None);
+
+    // The private key is stored encrypted
+    let database_records = database::SigningKeys::index(&database).await?;
+    let private_key = std::fs::read(&tonic_server.config.jwt.private_key_path)?;
+    assert_ne!(database_records[0].private_key, private_key);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn non_admin_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Build a signing keys client without the admin access token
+    let mut signing_keys_client =
+        SigningKeysClient::new(tonic_server.clone().client_channel().await?);
+
+    let access_token = domain::AccessToken::new(
+        &tonic_server.token_keys,
+        &tonic_server.config.application,
+        &random_user,
+        &[],
+    )?;
+    let mut request = tonic::Request::new(Empty {});
+    request
+        .metadata_mut()
+        .append("access_token", access_token.to_string().parse()?);
+
+    //-- Execute Test (Act)
+    let response = signing_keys_client.index(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::Unauthenticated);
+
+    Ok(())
+}
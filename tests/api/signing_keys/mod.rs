@@ -0,0 +1,4 @@
+//-- ./tests/api/signing_keys/mod.rs
+
+mod index;
+mod rotate;
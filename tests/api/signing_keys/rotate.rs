@@ -0,0 +1,164 @@
+//-- ./tests/api/signing_keys/rotate.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Module for integration testing signing key rotation
+
+use authentication_microservice::rpc::proto::utilities_client::UtilitiesClient;
+use authentication_microservice::rpc::proto::{
+    Empty, LoginRequest, SessionsIndexRequest, SigningKeysRotateRequest,
+};
+use jsonwebtoken::decode_header;
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn rotation_keeps_existing_tokens_valid(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client, which holds an access token signed by the
+    // configured key
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response_message = tonic_client
+        .signing_keys()
+        .rotate(SigningKeysRotateRequest {
+            promotion_seconds: Some(0),
+        })
+        .await?
+        .into_inner();
+
+    let login_response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_ne!(response_message.key_id, tonic_server.config.jwt.key_id);
+    assert_eq!(response_message.retires_on, None);
+
+    // New tokens are signed with the new key
+    let header = decode_header(&login_response.access_token.unwrap())?;
+    assert_eq!(header.kid, Some(response_message.key_id.clone()));
+
+    // The access token signed by the old key is still accepted
+    tonic_client
+        .sessions()
+        .index(SessionsIndexRequest {
+            limit: 10,
+            offset: 0,
+        })
+        .await?;
+
+    // The old key is scheduled for retirement, and both keys are published
+    let signing_keys = tonic_client
+        .signing_keys()
+        .index(Empty {})
+        .await?
+        .into_inner()
+        .signing_keys;
+    assert_eq!(signing_keys.len(), 2);
+    assert!(signing_keys[0].retires_on.is_some());
+
+    let mut tonic_utilities_client =
+        UtilitiesClient::new(tonic_server.clone().client_channel().await?);
+    let jwks = tonic_utilities_client.jwks(Empty {}).await?.into_inner();
+    let key_ids: Vec<String> = jwks.keys.into_iter().map(|key| key.kid).collect();
+    assert_eq!(
+        key_ids,
+        vec![tonic_server.config.jwt.key_id.clone(), response_message.key_id]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn pending_key_does_not_sign(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    // Use the configured promotion delay
+    let response_message = tonic_client
+        .signing_keys()
+        .rotate(SigningKeysRotateRequest {
+            promotion_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    let login_response = tonic_client
+        .authentication()
+        .login(LoginRequest {
+            email: random_user.email.to_string(),
+            password: random_password.to_string(),
+            remember_me_seconds: None,
+        })
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    // The configured key keeps signing until the new key is promoted
+    let header = decode_header(&login_response.access_token.unwrap())?;
+    assert_eq!(header.kid, Some(tonic_server.config.jwt.key_id.clone()));
+
+    // The new key is published before it signs
+    let mut tonic_utilities_client =
+        UtilitiesClient::new(tonic_server.clone().client_channel().await?);
+    let jwks = tonic_utilities_client.jwks(Empty {}).await?.into_inner();
+    assert!(jwks.keys.iter().any(|key| key.kid == response_message.key_id));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn negative_promotion_returns_error(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    //-- Execute Test (Act)
+    let response = tonic_client
+        .signing_keys()
+        .rotate(SigningKeysRotateRequest {
+            promotion_seconds: Some(-1),
+        })
+        .await;
+
+    //-- Checks (Assertions)
+    assert_eq!(response.unwrap_err().code(), Code::InvalidArgument);
+
+    Ok(())
+}
@@ -3,8 +3,10 @@
 // #![allow(unused)] // For beginning only.
 
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 
-use authentication_microservice::rpc::proto::CreateUserRequest;
+use authentication_microservice::rpc::proto::{users_client::UsersClient, CreateUserRequest};
+use authentication_microservice::{database, domain};
 
 use crate::helpers;
 
@@ -78,3 +80,65 @@ async fn returns_created_user(database: Pool<Postgres>) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn admin_role_needs_role_assignment(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user that can create and update users through a custom role,
+    // but not assign roles
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+    let user_manager_role = database::Roles::new(
+        "user_manager",
+        "Creates and updates users",
+        &[domain::Permission::UsersCreateAny, domain::Permission::UsersUpdateAny],
+    )
+    .insert(&database)
+    .await?;
+    database::UserRoles::new(&random_user.id, &user_manager_role.id)
+        .insert(&database)
+        .await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Users client that does not add the admin Access Token
+    let mut users_client = UsersClient::new(tonic_server.clone().client_channel().await?);
+
+    let create_request = |role: domain::UserRole| -> Result<CreateUserRequest> {
+        let new_user = helpers::mocks::users(&random_password)?;
+        Ok(CreateUserRequest {
+            email: new_user.email.to_string(),
+            name: new_user.name.to_string(),
+            password: random_password.to_owned(),
+            role: role.to_string(),
+            is_active: true,
+            is_verified: true,
+        })
+    };
+
+    //-- Execute Test (Act)
+    let request = helpers::user_request(
+        create_request(domain::UserRole::Admin)?,
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let admin_response = users_client.create(request).await;
+
+    let request = helpers::user_request(
+        create_request(domain::UserRole::User)?,
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let user_response = users_client.create(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(admin_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(user_response?.into_inner().role, "user");
+
+    Ok(())
+}
@@ -3,4 +3,5 @@
 mod create;
 mod delete;
 mod read;
+mod unlock;
 mod update;
@@ -0,0 +1,70 @@
+//-- ./tests/api/users/unlock.rs
+
+// #![allow(unused)] // For beginning only.
+
+use sqlx::{Pool, Postgres};
+use tonic::Code;
+
+use authentication_microservice::database;
+use authentication_microservice::rpc::proto::{LoginRequest, UnlockUserRequest};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn unlock_allows_locked_user_to_login(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Lock the user account
+    for _ in 0..tonic_server.config.lockout.account_threshold {
+        database::LoginLockouts::record_failure(
+            database::LockoutScope::Account,
+            &random_user.id.to_string(),
+            &tonic_server.config.lockout,
+            &database,
+        )
+        .await?;
+    }
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    let login_request = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let response = tonic_client
+        .authentication()
+        .login(login_request.clone())
+        .await;
+    assert_eq!(response.unwrap_err().code(), Code::ResourceExhausted);
+
+    //-- Execute Test (Act)
+    let request_message = UnlockUserRequest {
+        id: random_user.id.to_string(),
+    };
+    let response_message = tonic_client
+        .users()
+        .unlock(request_message)
+        .await?
+        .into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(response_message.rows_affected, 1);
+
+    let response = tonic_client.authentication().login(login_request).await;
+    assert!(response.is_ok());
+
+    Ok(())
+}
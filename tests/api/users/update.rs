@@ -3,8 +3,13 @@
 // #![allow(unused)] // For beginning only.
 
 use sqlx::{Pool, Postgres};
+use tonic::Code;
 
-use authentication_microservice::rpc::proto::UpdateUserRequest;
+use authentication_microservice::rpc::proto::{
+    users_client::UsersClient, LoginRequest, RefreshRequest, UpdatePasswordRequest,
+    UpdateUserRequest,
+};
+use authentication_microservice::{database, domain};
 
 use crate::helpers;
 
@@ -84,3 +89,206 @@ async fn user_returns_update(database: Pool<Postgres>) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn deactivate_revokes_access_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get an Access Token for the user
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let access_token = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner()
+        .access_token
+        .unwrap();
+
+    //-- Execute Test (Act)
+    // Deactivate the user
+    let request_message = UpdateUserRequest {
+        id: random_user.id.to_string(),
+        email: random_user.email.to_string(),
+        name: random_user.name.to_string(),
+        role: random_user.role.to_string(),
+        is_active: false,
+        is_verified: random_user.is_verified,
+    };
+    let _response_message = tonic_client.users().update(request_message).await?;
+
+    // Use the Access Token from the login, which has not expired yet
+    let update_password_request_message = UpdatePasswordRequest {
+        email: random_user.email.to_string(),
+        password_original: random_password.to_string(),
+        password_new: helpers::mocks::password()?,
+    };
+    let mut update_password_request =
+        tonic::Request::new(update_password_request_message);
+    update_password_request
+        .metadata_mut()
+        .append("access_token", access_token.parse().unwrap());
+    let response = tonic_client
+        .authentication()
+        .update_password(update_password_request)
+        .await;
+
+    //-- Checks (Assertions)
+    // The Access Token is rejected once the user is deactivated
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_revokes_refresh_token(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate random user data and insert into database for testing
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.is_active = true;
+    random_user.is_verified = true;
+    let random_user = random_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Spawn Tonic test client
+    let mut tonic_client = helpers::TonicClient::spawn_client(&tonic_server).await?;
+
+    // Login to get a Refresh Token for the user
+    let login_request_message = LoginRequest {
+        email: random_user.email.to_string(),
+        password: random_password.to_string(),
+        remember_me_seconds: None,
+    };
+    let refresh_token = tonic_client
+        .authentication()
+        .login(login_request_message)
+        .await?
+        .into_inner()
+        .refresh_token
+        .unwrap();
+
+    //-- Execute Test (Act)
+    // Deactivate the user
+    let request_message = UpdateUserRequest {
+        id: random_user.id.to_string(),
+        email: random_user.email.to_string(),
+        name: random_user.name.to_string(),
+        role: random_user.role.to_string(),
+        is_active: false,
+        is_verified: random_user.is_verified,
+    };
+    let _response_message = tonic_client.users().update(request_message).await?;
+
+    // Refresh the Session from the login
+    let response = tonic_client
+        .authentication()
+        .refresh(RefreshRequest { refresh_token })
+        .await;
+
+    //-- Checks (Assertions)
+    // The Refresh Token is rejected once the user is deactivated
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    // The users Sessions are revoked
+    let sessions =
+        database::Sessions::index_from_user_id(&random_user.id, &10, &0, &database).await?;
+    assert!(sessions.iter().all(|session| !session.is_active));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn role_change_needs_role_assignment(database: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Generate a user that can create and update users through a custom role,
+    // but not assign roles
+    let random_password = helpers::mocks::password()?;
+    let mut random_user = helpers::mocks::users(&random_password)?;
+    random_user.role = domain::UserRole::User;
+    let random_user = random_user.insert(&database).await?;
+    let user_manager_role = database::Roles::new(
+        "user_manager",
+        "Creates and updates users",
+        &[domain::Permission::UsersCreateAny, domain::Permission::UsersUpdateAny],
+    )
+    .insert(&database)
+    .await?;
+    database::UserRoles::new(&random_user.id, &user_manager_role.id)
+        .insert(&database)
+        .await?;
+
+    // Generate another user with the User role to update
+    let mut other_user = helpers::mocks::users(&random_password)?;
+    other_user.role = domain::UserRole::User;
+    let other_user = other_user.insert(&database).await?;
+
+    // Spawn Tonic test server
+    let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+    // Users client that does not add the admin Access Token
+    let mut users_client = UsersClient::new(tonic_server.clone().client_channel().await?);
+
+    let update_request = |user: &database::Users, role: domain::UserRole| UpdateUserRequest {
+        id: user.id.to_string(),
+        email: user.email.to_string(),
+        name: user.name.to_string(),
+        role: role.to_string(),
+        is_active: user.is_active,
+        is_verified: user.is_verified,
+    };
+
+    //-- Execute Test (Act)
+    let request = helpers::user_request(
+        update_request(&random_user, domain::UserRole::Admin),
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let own_response = users_client.update(request).await;
+
+    let request = helpers::user_request(
+        update_request(&other_user, domain::UserRole::Admin),
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let other_response = users_client.update(request).await;
+
+    let request = helpers::user_request(
+        update_request(&other_user, domain::UserRole::User),
+        &random_user,
+        &tonic_server,
+    )
+    .await?;
+    let unchanged_response = users_client.update(request).await;
+
+    //-- Checks (Assertions)
+    assert_eq!(own_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(other_response.unwrap_err().code(), Code::PermissionDenied);
+    assert_eq!(unchanged_response?.into_inner().role, "user");
+
+    let database_record = database::Users::from_user_id(&random_user.id, &database).await?;
+    assert_eq!(database_record.role, domain::UserRole::User);
+
+    Ok(())
+}
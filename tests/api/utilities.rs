@@ -5,10 +5,14 @@
 //! Endpoints include
 //!
 //! * `ping`: For checking the backend server is up and running
+//! * `jwks`: For publishing the public keys tokens are verified with
 
 // #![allow(unused)] // For beginning only.
 
+use authentication_microservice::domain;
 use authentication_microservice::rpc::proto::{utilities_client::UtilitiesClient, Empty};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
 
 use sqlx::{Pool, Postgres};
 
@@ -41,3 +45,49 @@ async fn ping_returns_pong(database: Pool<Postgres>) -> Result<()> {
 
 	Ok(())
 }
+
+#[sqlx::test]
+async fn jwks_verifies_access_token(database: Pool<Postgres>) -> Result<()> {
+	//-- Setup and Fixtures (Arrange)
+	// Spawn Tonic test server
+	let tonic_server = helpers::TonicServer::spawn_server(&database).await?;
+
+	// Build Tonic utilities client, the endpoint does not need authentication
+	let mut tonic_utilities_client = UtilitiesClient::new(
+		tonic_server.clone().client_channel().await?
+	);
+
+	//-- Execute Test (Act)
+	let response = tonic_utilities_client
+		.jwks(tonic::Request::new(Empty {}))
+		.await?
+		.into_inner();
+
+	//-- Checks (Assertions)
+	assert_eq!(response.keys.len(), 1);
+	let key = &response.keys[0];
+	assert_eq!(key.kid, tonic_server.config.jwt.key_id);
+	assert_eq!(key.kty, "OKP");
+	assert_eq!(key.alg, "EdDSA");
+	assert_eq!(key.key_use, "sig");
+	assert_eq!(key.crv.as_deref(), Some("Ed25519"));
+	assert!(key.x.is_some());
+
+	// The published key set verifies the servers access tokens, without the
+	// private key
+	let jwks: JwkSet = serde_json::from_str(&response.json)?;
+	let header = decode_header(&tonic_server.access_token)?;
+	let jwk = jwks.find(&header.kid.unwrap()).unwrap();
+	let mut validation = Validation::new(header.alg);
+	validation.set_issuer(&[&tonic_server.config.application.token_issuer]);
+	validation.set_audience(&[&tonic_server.config.application.token_audience]);
+	let token_claim = decode::<domain::TokenClaim>(
+		&tonic_server.access_token,
+		&DecodingKey::from_jwk(jwk)?,
+		&validation,
+	)?
+	.claims;
+	assert_eq!(token_claim.jty, domain::TokenType::Access.to_string());
+
+	Ok(())
+}